@@ -0,0 +1,442 @@
+//! truncate-integer-codegen: generate field structs from build scripts
+//!
+//! Register maps and packet headers are tables of named fields, each a
+//! few bits wide. This crate turns such a table into a struct whose
+//! fields are [`truncate_integer::bitsint`] integers of the right width,
+//! with constructors and setters that narrow wider values using the
+//! truncation traits, and methods to pack and unpack the raw word.
+//!
+//! Call it from `build.rs`:
+//!
+//! ```no_run
+//! use truncate_integer_codegen::{write_out_dir, Layout};
+//!
+//! let header = Layout::new("Header")
+//!     .unsigned("version", 4)
+//!     .unsigned("len", 12)
+//!     .signed("offset", 8);
+//! write_out_dir("layouts.rs", &[header]).unwrap();
+//! ```
+//!
+//! Then include the generated code in the crate, which must depend on
+//! `truncate-integer`:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/layouts.rs"));
+//! ```
+//!
+//! For each layout, the generated struct has:
+//!
+//! - `BITS`, the total width of the fields.
+//! - `try_new` and `shrink_new`, which take one argument per field, of
+//!   any type that can be narrowed into that field.
+//! - For each field `x`, a getter `x()`, and setters `set_x`,
+//!   `try_set_x` and `shrink_set_x`.
+//! - `from_bits` and `to_bits`, which convert from and to the smallest
+//!   unsigned primitive that holds every field. The first field is in
+//!   the least significant bits.
+//!
+//! [`truncate_integer::bitsint`]: https://docs.rs/truncate-integer/latest/truncate_integer/bitsint/index.html
+
+use std::collections::HashSet;
+use std::fmt::{self, Write};
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+/// A field in a [`Layout`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    name: String,
+    bits: u32,
+    signed: bool,
+}
+
+/// A named table of fields, to be generated as a struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    name: String,
+    fields: Vec<Field>,
+}
+
+impl Layout {
+    /// Start a new layout, which will be generated as a struct called
+    /// `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Layout {
+            name: name.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Add an unsigned field of `bits` bits.
+    pub fn unsigned(mut self, name: impl Into<String>, bits: u32) -> Self {
+        self.fields.push(Field {
+            name: name.into(),
+            bits,
+            signed: false,
+        });
+        self
+    }
+
+    /// Add a signed field of `bits` bits.
+    pub fn signed(mut self, name: impl Into<String>, bits: u32) -> Self {
+        self.fields.push(Field {
+            name: name.into(),
+            bits,
+            signed: true,
+        });
+        self
+    }
+
+    /// The total width of the fields, in bits.
+    pub fn bits(&self) -> u32 {
+        self.fields.iter().map(|f| f.bits).sum()
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        if !is_ident(&self.name) {
+            return Err(Error::InvalidName(self.name.clone()));
+        }
+        let mut names = HashSet::new();
+        let mut methods: HashSet<String> = RESERVED.iter().map(|&m| m.to_owned()).collect();
+        for field in &self.fields {
+            if !is_ident(&field.name) {
+                return Err(Error::InvalidName(field.name.clone()));
+            }
+            if !names.insert(&field.name) {
+                return Err(Error::DuplicateField(field.name.clone()));
+            }
+            let f = &field.name;
+            let generated = [
+                f.clone(),
+                format!("set_{}", f),
+                format!("try_set_{}", f),
+                format!("shrink_set_{}", f),
+            ];
+            if !generated.iter().all(|m| methods.insert(m.clone())) {
+                return Err(Error::ReservedName(field.name.clone()));
+            }
+            if !(1..=128).contains(&field.bits) {
+                return Err(Error::InvalidWidth(field.name.clone(), field.bits));
+            }
+        }
+        if self.fields.is_empty() || self.bits() > 128 {
+            return Err(Error::InvalidWidth(self.name.clone(), self.bits()));
+        }
+        Ok(())
+    }
+
+    /// Generate the Rust source for this layout.
+    pub fn generate(&self) -> Result<String, Error> {
+        self.validate()?;
+        let mut out = String::new();
+        self.write(&mut out)
+            .expect("writing to a String can't fail");
+        Ok(out)
+    }
+
+    fn write(&self, out: &mut String) -> fmt::Result {
+        const KRATE: &str = "::truncate_integer";
+        let name = &self.name;
+        let raw = raw_type(self.bits());
+        let types: Vec<String> = self
+            .fields
+            .iter()
+            .map(|f| {
+                let kind = if f.signed { "I" } else { "U" };
+                format!("{}::bitsint::{}<{}>", KRATE, kind, f.bits)
+            })
+            .collect();
+
+        writeln!(
+            out,
+            "#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]"
+        )?;
+        writeln!(out, "pub struct {} {{", name)?;
+        for (field, ty) in self.fields.iter().zip(&types) {
+            writeln!(out, "    {}: {},", field.name, ty)?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+
+        writeln!(
+            out,
+            "#[allow(dead_code, clippy::len_without_is_empty, clippy::too_many_arguments)]"
+        )?;
+        writeln!(out, "impl {} {{", name)?;
+        writeln!(out, "    /// The total width of the fields, in bits.")?;
+        writeln!(out, "    pub const BITS: u32 = {};", self.bits())?;
+
+        // Constructors.
+        let params: Vec<String> = (0..self.fields.len()).map(|i| format!("T{}", i)).collect();
+        let args: Vec<String> = self
+            .fields
+            .iter()
+            .zip(&params)
+            .map(|(f, p)| format!("{}: {}", f.name, p))
+            .collect();
+        let constructors = [
+            (
+                "try_new",
+                "Create a value, or `None` if any field doesn't fit.",
+                "TryTruncate",
+                "try_truncate",
+                "Option<Self>",
+            ),
+            (
+                "shrink_new",
+                "Create a value, saturating any field that doesn't fit.",
+                "Shrink",
+                "shrink",
+                "Self",
+            ),
+        ];
+        for &(method, doc, bound, call, ret) in &constructors {
+            let checked = ret != "Self";
+            writeln!(out)?;
+            writeln!(out, "    /// {}", doc)?;
+            writeln!(
+                out,
+                "    pub fn {}<{}>({}) -> {}",
+                method,
+                params.join(", "),
+                args.join(", "),
+                ret
+            )?;
+            writeln!(out, "    where")?;
+            for (p, ty) in params.iter().zip(&types) {
+                writeln!(out, "        {}: {}::{}<{}>,", p, KRATE, bound, ty)?;
+            }
+            writeln!(out, "    {{")?;
+            writeln!(
+                out,
+                "        {}{} {{",
+                if checked { "Some(" } else { "" },
+                name
+            )?;
+            for field in &self.fields {
+                writeln!(
+                    out,
+                    "            {}: {}::{}::{}({}){},",
+                    field.name,
+                    KRATE,
+                    bound,
+                    call,
+                    field.name,
+                    if checked { "?" } else { "" }
+                )?;
+            }
+            writeln!(out, "        }}{}", if checked { ")" } else { "" })?;
+            writeln!(out, "    }}")?;
+        }
+
+        // Accessors.
+        for (field, ty) in self.fields.iter().zip(&types) {
+            let f = &field.name;
+            writeln!(out)?;
+            writeln!(out, "    /// The `{}` field.", f)?;
+            writeln!(out, "    pub fn {}(&self) -> {} {{", f, ty)?;
+            writeln!(out, "        self.{}", f)?;
+            writeln!(out, "    }}")?;
+            writeln!(out)?;
+            writeln!(out, "    /// Set the `{}` field.", f)?;
+            writeln!(out, "    pub fn set_{}(&mut self, value: {}) {{", f, ty)?;
+            writeln!(out, "        self.{} = value;", f)?;
+            writeln!(out, "    }}")?;
+            writeln!(out)?;
+            writeln!(
+                out,
+                "    /// Set the `{}` field, failing if the value doesn't fit.",
+                f
+            )?;
+            writeln!(
+                out,
+                "    pub fn try_set_{}<T>(&mut self, value: T) -> Result<(), {}::TruncateError>",
+                f, KRATE
+            )?;
+            writeln!(out, "    where")?;
+            writeln!(
+                out,
+                "        T: {}::TryTruncate<{}> + PartialOrd + Default,",
+                KRATE, ty
+            )?;
+            writeln!(out, "    {{")?;
+            writeln!(out, "        let negative = value < T::default();")?;
+            writeln!(
+                out,
+                "        match {}::TryTruncate::try_truncate(value) {{",
+                KRATE
+            )?;
+            writeln!(out, "            Some(value) => {{")?;
+            writeln!(out, "                self.{} = value;", f)?;
+            writeln!(out, "                Ok(())")?;
+            writeln!(out, "            }}")?;
+            writeln!(
+                out,
+                "            None if negative => Err({}::TruncateError::Underflow),",
+                KRATE
+            )?;
+            writeln!(
+                out,
+                "            None => Err({}::TruncateError::Overflow),",
+                KRATE
+            )?;
+            writeln!(out, "        }}")?;
+            writeln!(out, "    }}")?;
+            writeln!(out)?;
+            writeln!(
+                out,
+                "    /// Set the `{}` field, saturating the value if it doesn't fit.",
+                f
+            )?;
+            writeln!(
+                out,
+                "    pub fn shrink_set_{}<T: {}::Shrink<{}>>(&mut self, value: T) {{",
+                f, KRATE, ty
+            )?;
+            writeln!(
+                out,
+                "        self.{} = {}::Shrink::shrink(value);",
+                f, KRATE
+            )?;
+            writeln!(out, "    }}")?;
+        }
+
+        // Packing.
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    /// Unpack the fields from a raw value, ignoring any unused high bits."
+        )?;
+        writeln!(out, "    pub fn from_bits(bits: {}) -> Self {{", raw)?;
+        writeln!(out, "        {} {{", name)?;
+        let mut shift = 0;
+        for field in &self.fields {
+            let bits = match shift {
+                0 => "bits".to_string(),
+                _ => format!("bits >> {}", shift),
+            };
+            writeln!(
+                out,
+                "            {}: {}::TruncateUnchecked::truncate_unchecked({}),",
+                field.name, KRATE, bits
+            )?;
+            shift += field.bits;
+        }
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    /// Pack the fields into a raw value.")?;
+        writeln!(out, "    pub fn to_bits(&self) -> {} {{", raw)?;
+        writeln!(out, "        let mut bits = 0;")?;
+        let mut shift = 0;
+        for field in &self.fields {
+            let mask = u128::MAX >> (128 - field.bits);
+            let value = format!("self.{}.get() as {} & {:#x}", field.name, raw, mask);
+            match shift {
+                0 => writeln!(out, "        bits |= {};", value)?,
+                _ => writeln!(out, "        bits |= ({}) << {};", value, shift)?,
+            }
+            shift += field.bits;
+        }
+        writeln!(out, "        bits")?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "}}")?;
+        Ok(())
+    }
+}
+
+/// The smallest unsigned primitive type that holds `bits` bits.
+fn raw_type(bits: u32) -> &'static str {
+    match bits {
+        0..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        _ => "u128",
+    }
+}
+
+/// The items generated for every layout, which a field's methods can't
+/// share a name with.
+const RESERVED: &[&str] = &["BITS", "try_new", "shrink_new", "from_bits", "to_bits"];
+
+/// Keywords in any edition, which can't be used as plain identifiers.
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+fn is_ident(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    name != "_" && chars.all(|c| c.is_ascii_alphanumeric() || c == '_') && !KEYWORDS.contains(&name)
+}
+
+/// Generate the Rust source for several layouts.
+pub fn generate(layouts: &[Layout]) -> Result<String, Error> {
+    let mut out = String::new();
+    for (i, layout) in layouts.iter().enumerate() {
+        if i != 0 {
+            out.push('\n');
+        }
+        out.push_str(&layout.generate()?);
+    }
+    Ok(out)
+}
+
+/// Generate the Rust source for several layouts, and write it to
+/// `file_name` in the build script's `OUT_DIR`.
+pub fn write_out_dir(file_name: &str, layouts: &[Layout]) -> Result<(), Error> {
+    let source = generate(layouts)?;
+    let dir = env::var_os("OUT_DIR").ok_or(Error::NoOutDir)?;
+    fs::write(PathBuf::from(dir).join(file_name), source).map_err(Error::Io)
+}
+
+/// The error returned when code can't be generated.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A layout or field name isn't a valid identifier, or is a keyword.
+    InvalidName(String),
+    /// A field name is used twice in one layout.
+    DuplicateField(String),
+    /// A field's getter or setters would have the same name as another
+    /// generated item, e.g. a field called `to_bits`.
+    ReservedName(String),
+    /// A field isn't 1 to 128 bits wide, or a layout's fields don't fit
+    /// in 128 bits.
+    InvalidWidth(String, u32),
+    /// `OUT_DIR` isn't set; this isn't running in a build script.
+    NoOutDir,
+    /// The generated code couldn't be written.
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidName(name) => write!(f, "invalid name {:?}", name),
+            Error::DuplicateField(name) => write!(f, "duplicate field {:?}", name),
+            Error::ReservedName(name) => write!(f, "reserved field name {:?}", name),
+            Error::InvalidWidth(name, bits) => write!(f, "invalid width {} for {:?}", bits, name),
+            Error::NoOutDir => f.write_str("OUT_DIR is not set"),
+            Error::Io(e) => write!(f, "failed to write generated code: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
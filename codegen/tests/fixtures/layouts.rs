@@ -0,0 +1,265 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Header {
+    version: ::truncate_integer::bitsint::U<4>,
+    len: ::truncate_integer::bitsint::U<12>,
+    offset: ::truncate_integer::bitsint::I<8>,
+}
+
+#[allow(dead_code, clippy::len_without_is_empty, clippy::too_many_arguments)]
+impl Header {
+    /// The total width of the fields, in bits.
+    pub const BITS: u32 = 24;
+
+    /// Create a value, or `None` if any field doesn't fit.
+    pub fn try_new<T0, T1, T2>(version: T0, len: T1, offset: T2) -> Option<Self>
+    where
+        T0: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::U<4>>,
+        T1: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::U<12>>,
+        T2: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::I<8>>,
+    {
+        Some(Header {
+            version: ::truncate_integer::TryTruncate::try_truncate(version)?,
+            len: ::truncate_integer::TryTruncate::try_truncate(len)?,
+            offset: ::truncate_integer::TryTruncate::try_truncate(offset)?,
+        })
+    }
+
+    /// Create a value, saturating any field that doesn't fit.
+    pub fn shrink_new<T0, T1, T2>(version: T0, len: T1, offset: T2) -> Self
+    where
+        T0: ::truncate_integer::Shrink<::truncate_integer::bitsint::U<4>>,
+        T1: ::truncate_integer::Shrink<::truncate_integer::bitsint::U<12>>,
+        T2: ::truncate_integer::Shrink<::truncate_integer::bitsint::I<8>>,
+    {
+        Header {
+            version: ::truncate_integer::Shrink::shrink(version),
+            len: ::truncate_integer::Shrink::shrink(len),
+            offset: ::truncate_integer::Shrink::shrink(offset),
+        }
+    }
+
+    /// The `version` field.
+    pub fn version(&self) -> ::truncate_integer::bitsint::U<4> {
+        self.version
+    }
+
+    /// Set the `version` field.
+    pub fn set_version(&mut self, value: ::truncate_integer::bitsint::U<4>) {
+        self.version = value;
+    }
+
+    /// Set the `version` field, failing if the value doesn't fit.
+    pub fn try_set_version<T>(&mut self, value: T) -> Result<(), ::truncate_integer::TruncateError>
+    where
+        T: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::U<4>> + PartialOrd + Default,
+    {
+        let negative = value < T::default();
+        match ::truncate_integer::TryTruncate::try_truncate(value) {
+            Some(value) => {
+                self.version = value;
+                Ok(())
+            }
+            None if negative => Err(::truncate_integer::TruncateError::Underflow),
+            None => Err(::truncate_integer::TruncateError::Overflow),
+        }
+    }
+
+    /// Set the `version` field, saturating the value if it doesn't fit.
+    pub fn shrink_set_version<T: ::truncate_integer::Shrink<::truncate_integer::bitsint::U<4>>>(&mut self, value: T) {
+        self.version = ::truncate_integer::Shrink::shrink(value);
+    }
+
+    /// The `len` field.
+    pub fn len(&self) -> ::truncate_integer::bitsint::U<12> {
+        self.len
+    }
+
+    /// Set the `len` field.
+    pub fn set_len(&mut self, value: ::truncate_integer::bitsint::U<12>) {
+        self.len = value;
+    }
+
+    /// Set the `len` field, failing if the value doesn't fit.
+    pub fn try_set_len<T>(&mut self, value: T) -> Result<(), ::truncate_integer::TruncateError>
+    where
+        T: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::U<12>> + PartialOrd + Default,
+    {
+        let negative = value < T::default();
+        match ::truncate_integer::TryTruncate::try_truncate(value) {
+            Some(value) => {
+                self.len = value;
+                Ok(())
+            }
+            None if negative => Err(::truncate_integer::TruncateError::Underflow),
+            None => Err(::truncate_integer::TruncateError::Overflow),
+        }
+    }
+
+    /// Set the `len` field, saturating the value if it doesn't fit.
+    pub fn shrink_set_len<T: ::truncate_integer::Shrink<::truncate_integer::bitsint::U<12>>>(&mut self, value: T) {
+        self.len = ::truncate_integer::Shrink::shrink(value);
+    }
+
+    /// The `offset` field.
+    pub fn offset(&self) -> ::truncate_integer::bitsint::I<8> {
+        self.offset
+    }
+
+    /// Set the `offset` field.
+    pub fn set_offset(&mut self, value: ::truncate_integer::bitsint::I<8>) {
+        self.offset = value;
+    }
+
+    /// Set the `offset` field, failing if the value doesn't fit.
+    pub fn try_set_offset<T>(&mut self, value: T) -> Result<(), ::truncate_integer::TruncateError>
+    where
+        T: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::I<8>> + PartialOrd + Default,
+    {
+        let negative = value < T::default();
+        match ::truncate_integer::TryTruncate::try_truncate(value) {
+            Some(value) => {
+                self.offset = value;
+                Ok(())
+            }
+            None if negative => Err(::truncate_integer::TruncateError::Underflow),
+            None => Err(::truncate_integer::TruncateError::Overflow),
+        }
+    }
+
+    /// Set the `offset` field, saturating the value if it doesn't fit.
+    pub fn shrink_set_offset<T: ::truncate_integer::Shrink<::truncate_integer::bitsint::I<8>>>(&mut self, value: T) {
+        self.offset = ::truncate_integer::Shrink::shrink(value);
+    }
+
+    /// Unpack the fields from a raw value, ignoring any unused high bits.
+    pub fn from_bits(bits: u32) -> Self {
+        Header {
+            version: ::truncate_integer::TruncateUnchecked::truncate_unchecked(bits),
+            len: ::truncate_integer::TruncateUnchecked::truncate_unchecked(bits >> 4),
+            offset: ::truncate_integer::TruncateUnchecked::truncate_unchecked(bits >> 16),
+        }
+    }
+
+    /// Pack the fields into a raw value.
+    pub fn to_bits(&self) -> u32 {
+        let mut bits = 0;
+        bits |= self.version.get() as u32 & 0xf;
+        bits |= (self.len.get() as u32 & 0xfff) << 4;
+        bits |= (self.offset.get() as u32 & 0xff) << 16;
+        bits
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Wide {
+    a: ::truncate_integer::bitsint::I<100>,
+    b: ::truncate_integer::bitsint::U<28>,
+}
+
+#[allow(dead_code, clippy::len_without_is_empty, clippy::too_many_arguments)]
+impl Wide {
+    /// The total width of the fields, in bits.
+    pub const BITS: u32 = 128;
+
+    /// Create a value, or `None` if any field doesn't fit.
+    pub fn try_new<T0, T1>(a: T0, b: T1) -> Option<Self>
+    where
+        T0: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::I<100>>,
+        T1: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::U<28>>,
+    {
+        Some(Wide {
+            a: ::truncate_integer::TryTruncate::try_truncate(a)?,
+            b: ::truncate_integer::TryTruncate::try_truncate(b)?,
+        })
+    }
+
+    /// Create a value, saturating any field that doesn't fit.
+    pub fn shrink_new<T0, T1>(a: T0, b: T1) -> Self
+    where
+        T0: ::truncate_integer::Shrink<::truncate_integer::bitsint::I<100>>,
+        T1: ::truncate_integer::Shrink<::truncate_integer::bitsint::U<28>>,
+    {
+        Wide {
+            a: ::truncate_integer::Shrink::shrink(a),
+            b: ::truncate_integer::Shrink::shrink(b),
+        }
+    }
+
+    /// The `a` field.
+    pub fn a(&self) -> ::truncate_integer::bitsint::I<100> {
+        self.a
+    }
+
+    /// Set the `a` field.
+    pub fn set_a(&mut self, value: ::truncate_integer::bitsint::I<100>) {
+        self.a = value;
+    }
+
+    /// Set the `a` field, failing if the value doesn't fit.
+    pub fn try_set_a<T>(&mut self, value: T) -> Result<(), ::truncate_integer::TruncateError>
+    where
+        T: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::I<100>> + PartialOrd + Default,
+    {
+        let negative = value < T::default();
+        match ::truncate_integer::TryTruncate::try_truncate(value) {
+            Some(value) => {
+                self.a = value;
+                Ok(())
+            }
+            None if negative => Err(::truncate_integer::TruncateError::Underflow),
+            None => Err(::truncate_integer::TruncateError::Overflow),
+        }
+    }
+
+    /// Set the `a` field, saturating the value if it doesn't fit.
+    pub fn shrink_set_a<T: ::truncate_integer::Shrink<::truncate_integer::bitsint::I<100>>>(&mut self, value: T) {
+        self.a = ::truncate_integer::Shrink::shrink(value);
+    }
+
+    /// The `b` field.
+    pub fn b(&self) -> ::truncate_integer::bitsint::U<28> {
+        self.b
+    }
+
+    /// Set the `b` field.
+    pub fn set_b(&mut self, value: ::truncate_integer::bitsint::U<28>) {
+        self.b = value;
+    }
+
+    /// Set the `b` field, failing if the value doesn't fit.
+    pub fn try_set_b<T>(&mut self, value: T) -> Result<(), ::truncate_integer::TruncateError>
+    where
+        T: ::truncate_integer::TryTruncate<::truncate_integer::bitsint::U<28>> + PartialOrd + Default,
+    {
+        let negative = value < T::default();
+        match ::truncate_integer::TryTruncate::try_truncate(value) {
+            Some(value) => {
+                self.b = value;
+                Ok(())
+            }
+            None if negative => Err(::truncate_integer::TruncateError::Underflow),
+            None => Err(::truncate_integer::TruncateError::Overflow),
+        }
+    }
+
+    /// Set the `b` field, saturating the value if it doesn't fit.
+    pub fn shrink_set_b<T: ::truncate_integer::Shrink<::truncate_integer::bitsint::U<28>>>(&mut self, value: T) {
+        self.b = ::truncate_integer::Shrink::shrink(value);
+    }
+
+    /// Unpack the fields from a raw value, ignoring any unused high bits.
+    pub fn from_bits(bits: u128) -> Self {
+        Wide {
+            a: ::truncate_integer::TruncateUnchecked::truncate_unchecked(bits),
+            b: ::truncate_integer::TruncateUnchecked::truncate_unchecked(bits >> 100),
+        }
+    }
+
+    /// Pack the fields into a raw value.
+    pub fn to_bits(&self) -> u128 {
+        let mut bits = 0;
+        bits |= self.a.get() as u128 & 0xfffffffffffffffffffffffff;
+        bits |= (self.b.get() as u128 & 0xfffffff) << 100;
+        bits
+    }
+}
@@ -0,0 +1,123 @@
+use truncate_integer::bitsint::{I, U};
+use truncate_integer::TruncateError;
+use truncate_integer_codegen::{generate, Error, Layout};
+
+include!("fixtures/layouts.rs");
+
+fn layouts() -> Vec<Layout> {
+    let header = Layout::new("Header")
+        .unsigned("version", 4)
+        .unsigned("len", 12)
+        .signed("offset", 8);
+    let wide = Layout::new("Wide").signed("a", 100).unsigned("b", 28);
+    vec![header, wide]
+}
+
+#[test]
+fn test_fixture_is_current() {
+    assert_eq!(
+        generate(&layouts()).unwrap(),
+        include_str!("fixtures/layouts.rs")
+    );
+}
+
+#[test]
+fn test_generated_constructors() {
+    assert_eq!(Header::BITS, 24);
+    let header = Header::try_new(2u64, 1000u32, -3i64).unwrap();
+    assert_eq!(header.version().get(), 2);
+    assert_eq!(header.len().get(), 1000);
+    assert_eq!(header.offset().get(), -3);
+    assert_eq!(Header::try_new(16u8, 0u8, 0u8), None);
+    assert_eq!(Header::try_new(0u8, 0u8, 128i32), None);
+
+    let header = Header::shrink_new(100u8, -1i32, -1000i32);
+    assert_eq!(header.version(), U::<4>::MAX);
+    assert_eq!(header.len(), U::<12>::MIN);
+    assert_eq!(header.offset(), I::<8>::MIN);
+}
+
+#[test]
+fn test_generated_setters() {
+    let mut header = Header::default();
+    assert_eq!(header.try_set_len(4095u32), Ok(()));
+    assert_eq!(header.try_set_len(4096u32), Err(TruncateError::Overflow));
+    assert_eq!(header.try_set_len(-1i32), Err(TruncateError::Underflow));
+    assert_eq!(header.len(), U::<12>::MAX);
+    header.shrink_set_offset(1000u32);
+    assert_eq!(header.offset(), I::<8>::MAX);
+    header.set_version(U::<4>::MIN);
+    assert_eq!(header.version().get(), 0);
+}
+
+#[test]
+fn test_generated_bits() {
+    let header = Header::try_new(0xAu8, 0x123u16, -2i8).unwrap();
+    assert_eq!(header.to_bits(), 0xFE_123A);
+    assert_eq!(Header::from_bits(0xFE_123A), header);
+    assert_eq!(Header::from_bits(0xFFFF_FFFF).offset().get(), -1);
+
+    let wide = Wide::try_new(-1i8, 0xABCu16).unwrap();
+    assert_eq!(wide.to_bits() >> 100, 0xABC);
+    assert_eq!(wide.to_bits() & 1 << 99, 1 << 99);
+    assert_eq!(Wide::from_bits(wide.to_bits()), wide);
+}
+
+#[test]
+fn test_invalid_layouts() {
+    assert!(matches!(
+        Layout::new("1st").unsigned("a", 1).generate(),
+        Err(Error::InvalidName(_))
+    ));
+    assert!(matches!(
+        Layout::new("L").unsigned("a", 1).signed("a", 2).generate(),
+        Err(Error::DuplicateField(_))
+    ));
+    assert!(matches!(
+        Layout::new("L").unsigned("a", 0).generate(),
+        Err(Error::InvalidWidth(_, 0))
+    ));
+    assert!(matches!(
+        Layout::new("L")
+            .unsigned("a", 100)
+            .unsigned("b", 29)
+            .generate(),
+        Err(Error::InvalidWidth(_, 129))
+    ));
+    assert!(matches!(
+        Layout::new("L").generate(),
+        Err(Error::InvalidWidth(_, 0))
+    ));
+}
+
+#[test]
+fn test_keyword_names() {
+    assert!(matches!(
+        Layout::new("R").unsigned("type", 4).generate(),
+        Err(Error::InvalidName(_))
+    ));
+    assert!(matches!(
+        Layout::new("Self").unsigned("a", 4).generate(),
+        Err(Error::InvalidName(_))
+    ));
+    assert!(Layout::new("R").unsigned("types", 4).generate().is_ok());
+}
+
+#[test]
+fn test_reserved_names() {
+    for &name in &["to_bits", "from_bits", "try_new", "shrink_new", "BITS"] {
+        assert!(matches!(
+            Layout::new("R").unsigned(name, 4).generate(),
+            Err(Error::ReservedName(_))
+        ));
+    }
+    // `set_a` is also the setter for `a`.
+    assert!(matches!(
+        Layout::new("R")
+            .unsigned("a", 4)
+            .unsigned("set_a", 4)
+            .generate(),
+        Err(Error::ReservedName(_))
+    ));
+    assert!(Layout::new("R").unsigned("new", 4).generate().is_ok());
+}
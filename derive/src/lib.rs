@@ -0,0 +1,244 @@
+//! Procedural macros for truncate-integer.
+//!
+//! These are re-exported by `truncate-integer` when its `derive`
+//! feature is enabled; use them from there.
+//!
+//! This crate needs Rust 1.61 or later, for `syn` 2.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Type};
+
+/// Generate constructors that accept wider integers for each field.
+#[proc_macro_attribute]
+pub fn narrowing(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return Error::new(Span::call_site(), "#[narrowing] takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let input = parse_macro_input!(item as DeriveInput);
+    match narrowing_impl(&input) {
+        Ok(output) => quote!(#input #output).into(),
+        Err(e) => {
+            let e = e.to_compile_error();
+            quote!(#input #e).into()
+        }
+    }
+}
+
+fn narrowing_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "#[narrowing] needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "#[narrowing] can only be used on structs",
+            ))
+        }
+    };
+    if fields.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "#[narrowing] needs at least one field",
+        ));
+    }
+    if fields.len() > 64 {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "#[narrowing] supports at most 64 fields",
+        ));
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let names: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let params: Vec<_> = (0..fields.len())
+        .map(|i| format_ident!("__T{}", i))
+        .collect();
+
+    Ok(quote! {
+        #[allow(clippy::too_many_arguments)]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Create a value, narrowing each argument into its field with
+            /// [`TryTruncate`](::truncate_integer::TryTruncate).
+            ///
+            /// If any argument doesn't fit, the error lists every field
+            /// that failed.
+            #vis fn try_new<#(#params),*>(#(#names: #params),*)
+                -> ::core::result::Result<Self, ::truncate_integer::NarrowStructError>
+            where
+                #(#params: ::truncate_integer::TryTruncate<#types>,)*
+            {
+                ::truncate_integer::narrow_struct!(Self { #(#names: #names),* })
+            }
+
+            /// Create a value, narrowing each argument into its field with
+            /// [`Shrink`](::truncate_integer::Shrink).
+            #vis fn new_saturating<#(#params),*>(#(#names: #params),*) -> Self
+            where
+                #(#params: ::truncate_integer::Shrink<#types>,)*
+            {
+                Self {
+                    #(#names: ::truncate_integer::Shrink::shrink(#names),)*
+                }
+            }
+        }
+    })
+}
+
+/// Generate a `TryFrom` conversion from a struct with wider fields.
+#[proc_macro_derive(ShrinkFromStruct, attributes(narrow))]
+pub fn shrink_from_struct(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match shrink_from_struct_impl(&input) {
+        Ok(output) => output.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// How a field is narrowed.
+enum Mode {
+    Checked,
+    Saturate,
+    Panic,
+    Unchecked,
+}
+
+fn shrink_from_struct_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "ShrinkFromStruct needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "ShrinkFromStruct can only be derived for structs",
+            ))
+        }
+    };
+    if fields.len() > 64 {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "ShrinkFromStruct supports at most 64 fields",
+        ));
+    }
+
+    let mut source: Option<Type> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("narrow")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("from") {
+                source = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `from = Type`"))
+            }
+        })?;
+    }
+    let source = source.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "ShrinkFromStruct needs a `#[narrow(from = Type)]` attribute",
+        )
+    })?;
+
+    let mut names = Vec::new();
+    let mut lets = Vec::new();
+    let mut checked = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let mut mode = Mode::Checked;
+        let mut from: Option<Ident> = None;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("narrow")) {
+            attr.parse_nested_meta(|meta| {
+                let path = &meta.path;
+                if path.is_ident("checked") {
+                    mode = Mode::Checked;
+                } else if path.is_ident("saturate") {
+                    mode = Mode::Saturate;
+                } else if path.is_ident("panic") {
+                    mode = Mode::Panic;
+                } else if path.is_ident("unchecked") {
+                    mode = Mode::Unchecked;
+                } else if path.is_ident("from") {
+                    from = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error(
+                        "expected `checked`, `saturate`, `panic`, `unchecked` or `from = field`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        let from = from.as_ref().unwrap_or(name);
+        let value = quote!(__source.#from);
+        lets.push(match mode {
+            Mode::Checked => {
+                let bit = 1u64 << index;
+                checked.push(name);
+                quote! {
+                    let #name: ::core::option::Option<#ty> =
+                        ::truncate_integer::TryTruncate::try_truncate(#value);
+                    if #name.is_none() {
+                        __failed |= #bit;
+                    }
+                }
+            }
+            Mode::Saturate => quote! {
+                let #name: #ty = ::truncate_integer::Shrink::shrink(#value);
+            },
+            Mode::Panic => quote! {
+                let #name: #ty = ::truncate_integer::Chop::chop(#value);
+            },
+            Mode::Unchecked => quote! {
+                let #name: #ty = ::truncate_integer::TruncateUnchecked::truncate_unchecked(#value);
+            },
+        });
+        names.push(name);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let field_names: Vec<_> = names.iter().map(|n| n.to_string()).collect();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<#source> for #name #ty_generics #where_clause {
+            type Error = ::truncate_integer::NarrowStructError;
+
+            #[track_caller]
+            #[allow(unused_mut)]
+            fn try_from(__source: #source) -> ::core::result::Result<Self, Self::Error> {
+                const FIELDS: &[&str] = &[#(#field_names),*];
+                let mut __failed = 0u64;
+                #(#lets)*
+                if __failed != 0 {
+                    return ::core::result::Result::Err(
+                        ::truncate_integer::NarrowStructError::new(FIELDS, __failed),
+                    );
+                }
+                #(let #checked = #checked.unwrap();)*
+                ::core::result::Result::Ok(Self { #(#names),* })
+            }
+        }
+    })
+}
@@ -0,0 +1,108 @@
+//! Rounding addresses and sizes to a power-of-two alignment.
+//!
+//! ```rust
+//! use truncate_integer::align::{Align, AlignError};
+//!
+//! assert_eq!(0x1234usize.try_align_down(0x1000), Ok(0x1000));
+//! assert_eq!(0x1234usize.try_align_up(0x1000), Ok(0x2000));
+//! assert_eq!(0x1234usize.try_align_up(0x1001), Err(AlignError::NotPowerOfTwo));
+//! assert_eq!(u64::MAX.try_align_up(16), Err(AlignError::Overflow));
+//! assert_eq!(u64::MAX.saturating_align_up(16), Ok(u64::MAX - 15));
+//! ```
+
+use core::fmt;
+
+/// The error returned when a value can't be aligned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlignError {
+    /// The alignment isn't a power of two.
+    NotPowerOfTwo,
+    /// The aligned value doesn't fit into the type.
+    Overflow,
+}
+
+impl fmt::Display for AlignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlignError::NotPowerOfTwo => f.write_str("alignment is not a power of two"),
+            AlignError::Overflow => f.write_str("integer overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AlignError {}
+
+/// Round an integer to a power-of-two alignment.
+pub trait Align: Sized {
+    /// Round down to a multiple of `align`.
+    ///
+    /// If `align` isn't a power of two, return an error.
+    fn try_align_down(self, align: Self) -> Result<Self, AlignError>;
+
+    /// Round up to a multiple of `align`.
+    ///
+    /// If `align` isn't a power of two, or the result doesn't fit,
+    /// return an error.
+    fn try_align_up(self, align: Self) -> Result<Self, AlignError>;
+
+    /// Round up to a multiple of `align`.
+    ///
+    /// If the result doesn't fit, return the largest multiple of `align`.
+    /// If `align` isn't a power of two, return an error.
+    fn saturating_align_up(self, align: Self) -> Result<Self, AlignError>;
+}
+
+macro_rules! make_align {
+    ($($Type:ty),*) => {
+        $(
+            impl Align for $Type {
+                #[inline]
+                fn try_align_down(self, align: Self) -> Result<Self, AlignError> {
+                    let mask = low_mask(align)?;
+                    Ok(self & !mask)
+                }
+
+                #[inline]
+                fn try_align_up(self, align: Self) -> Result<Self, AlignError> {
+                    let mask = low_mask(align)?;
+                    match self.checked_add(mask) {
+                        Some(x) => Ok(x & !mask),
+                        None => Err(AlignError::Overflow),
+                    }
+                }
+
+                #[inline]
+                fn saturating_align_up(self, align: Self) -> Result<Self, AlignError> {
+                    let mask = low_mask(align)?;
+                    Ok(self.saturating_add(mask) & !mask)
+                }
+            }
+
+            impl private::LowMask for $Type {
+                #[inline]
+                fn low_mask(self) -> Option<Self> {
+                    if self.is_power_of_two() {
+                        Some(self - 1)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+mod private {
+    pub trait LowMask: Sized {
+        /// For a power of two, return a mask of the bits below it.
+        fn low_mask(self) -> Option<Self>;
+    }
+}
+
+#[inline]
+fn low_mask<T: private::LowMask>(align: T) -> Result<T, AlignError> {
+    align.low_mask().ok_or(AlignError::NotPowerOfTwo)
+}
+
+make_align!(u32, u64, usize);
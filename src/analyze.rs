@@ -0,0 +1,187 @@
+//! Choosing a column type for a dataset.
+//!
+//! [`analyze`] scans a slice once, and reports what a bulk conversion
+//! into each primitive type would do: the range of the values, the bits
+//! they need, the narrowest type that holds all of them, and how many
+//! values would fail a checked conversion (or be clamped by a saturating
+//! one) for every candidate type.
+//!
+//! ```rust
+//! use truncate_integer::analyze::{analyze, IntType};
+//!
+//! let report = analyze(&[3, 250, 1000, 70000]);
+//! assert_eq!(report.min(), Some(3));
+//! assert_eq!(report.max(), Some(70000));
+//! assert_eq!(report.bits(), 17);
+//! assert_eq!(report.narrowest(), IntType::U32);
+//! assert_eq!(report.failures(IntType::U8), 2);
+//! assert_eq!(report.failures(IntType::I16), 1);
+//! ```
+
+use crate::MinBits;
+use core::fmt;
+
+/// A candidate destination type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum IntType {
+    /// `u8`
+    U8,
+    /// `i8`
+    I8,
+    /// `u16`
+    U16,
+    /// `i16`
+    I16,
+    /// `u32`
+    U32,
+    /// `i32`
+    I32,
+    /// `u64`
+    U64,
+    /// `i64`
+    I64,
+}
+
+impl IntType {
+    /// Every candidate type, from narrowest to widest.
+    pub const ALL: [IntType; 8] = [
+        IntType::U8,
+        IntType::I8,
+        IntType::U16,
+        IntType::I16,
+        IntType::U32,
+        IntType::I32,
+        IntType::U64,
+        IntType::I64,
+    ];
+
+    /// The width in bits.
+    pub fn bits(self) -> u32 {
+        match self {
+            IntType::U8 | IntType::I8 => 8,
+            IntType::U16 | IntType::I16 => 16,
+            IntType::U32 | IntType::I32 => 32,
+            IntType::U64 | IntType::I64 => 64,
+        }
+    }
+
+    /// Returns `true` for the signed types.
+    pub fn is_signed(self) -> bool {
+        matches!(
+            self,
+            IntType::I8 | IntType::I16 | IntType::I32 | IntType::I64
+        )
+    }
+
+    /// The largest value of the type.
+    pub fn max_value(self) -> u64 {
+        u64::MAX >> (64 - self.bits() + self.is_signed() as u32)
+    }
+
+    /// The name of the type, such as `"u8"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            IntType::U8 => "u8",
+            IntType::I8 => "i8",
+            IntType::U16 => "u16",
+            IntType::I16 => "i16",
+            IntType::U32 => "u32",
+            IntType::I32 => "i32",
+            IntType::U64 => "u64",
+            IntType::I64 => "i64",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for IntType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The result of [`analyze`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WidthReport {
+    len: usize,
+    min: Option<u64>,
+    max: Option<u64>,
+    failures: [usize; 8],
+}
+
+impl WidthReport {
+    /// The number of values analyzed.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there were no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The smallest value, if there were any.
+    pub fn min(&self) -> Option<u64> {
+        self.min
+    }
+
+    /// The largest value, if there were any.
+    pub fn max(&self) -> Option<u64> {
+        self.max
+    }
+
+    /// The number of bits needed to hold every value.
+    pub fn bits(&self) -> u32 {
+        self.max.map_or(0, MinBits::min_bits)
+    }
+
+    /// The narrowest type that holds every value.
+    pub fn narrowest(&self) -> IntType {
+        let max = self.max.unwrap_or(0);
+        IntType::ALL
+            .iter()
+            .copied()
+            .find(|ty| max <= ty.max_value())
+            .unwrap_or(IntType::U64)
+    }
+
+    /// The number of values that don't fit in `ty`.
+    ///
+    /// These would fail a checked conversion, or be clamped by a
+    /// saturating one.
+    pub fn failures(&self, ty: IntType) -> usize {
+        self.failures[ty.index()]
+    }
+
+    /// Each candidate type, from narrowest to widest, with its number of
+    /// failures.
+    pub fn candidates(&self) -> impl Iterator<Item = (IntType, usize)> + '_ {
+        IntType::ALL.iter().map(move |&ty| (ty, self.failures(ty)))
+    }
+}
+
+/// Scan `values`, and report how they would narrow into each candidate
+/// type.
+pub fn analyze(values: &[u64]) -> WidthReport {
+    let mut failures = [0; 8];
+    let mut min = u64::MAX;
+    let mut max = 0;
+    for &value in values {
+        min = min.min(value);
+        max = max.max(value);
+        for (count, ty) in failures.iter_mut().zip(IntType::ALL.iter()) {
+            *count += (value > ty.max_value()) as usize;
+        }
+    }
+    let seen = !values.is_empty();
+    WidthReport {
+        len: values.len(),
+        min: if seen { Some(min) } else { None },
+        max: if seen { Some(max) } else { None },
+        failures,
+    }
+}
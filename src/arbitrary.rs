@@ -0,0 +1,144 @@
+//! Support for the [`arbitrary`](https://docs.rs/arbitrary) crate.
+//!
+//! [`Arbitrary`] is implemented for [`TruncateError`] and for the
+//! arbitrary-width integers [`U<N>`] and [`I<N>`].
+//!
+//! For structure-aware fuzzing of code that narrows its inputs,
+//! [`that_fits_in`] and [`that_overflows`] generate values of one type
+//! that do or don't fit into another, so that a fuzz target can reach
+//! both paths without wasting most of its inputs on one of them.
+//!
+//! ```rust
+//! use arbitrary::Unstructured;
+//! use truncate_integer::arbitrary::{that_fits_in, that_overflows};
+//! use truncate_integer::TryTruncate;
+//!
+//! let mut u = Unstructured::new(&[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0]);
+//! let len: u64 = that_fits_in::<u64, u8>(&mut u).unwrap();
+//! assert!(len <= 255);
+//! let bad: i32 = that_overflows::<i32, i16>(&mut u).unwrap();
+//! assert!(TryTruncate::<i16>::try_truncate(bad).is_none());
+//! ```
+
+use crate::bitsint::{Bits, Storage, I, U};
+use crate::wide::{FromWide, Primitive, Wide, Widen};
+use crate::TruncateError;
+use ::arbitrary::unstructured::Int;
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+impl<'a> Arbitrary<'a> for TruncateError {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            TruncateError::Overflow,
+            TruncateError::Underflow,
+            TruncateError::Invalid,
+        ])?)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+impl<'a, const N: u32> Arbitrary<'a> for U<N>
+where
+    Bits<N>: Storage,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let max = Self::MAX.widen().to_bits();
+        Ok(Self::from_wide_wrapping(Wide::NonNegative(
+            u.int_in_range(0..=max)?,
+        )))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(((N + 7) / 8) as usize))
+    }
+}
+
+impl<'a, const N: u32> Arbitrary<'a> for I<N>
+where
+    Bits<N>: Storage,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let min = i128::MIN >> (128 - N);
+        let max = i128::MAX >> (128 - N);
+        let value = u.int_in_range(min..=max)?;
+        Ok(Self::from_wide_wrapping(Wide::NonNegative(value as u128)))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(((N + 7) / 8) as usize))
+    }
+}
+
+/// The next value up, if there is one.
+fn wide_succ(value: Wide) -> Option<Wide> {
+    match value {
+        Wide::Negative(-1) => Some(Wide::NonNegative(0)),
+        Wide::Negative(x) => Some(Wide::Negative(x + 1)),
+        Wide::NonNegative(x) => x.checked_add(1).map(Wide::NonNegative),
+    }
+}
+
+/// The next value down, if there is one.
+fn wide_pred(value: Wide) -> Option<Wide> {
+    match value {
+        Wide::NonNegative(0) => Some(Wide::Negative(-1)),
+        Wide::NonNegative(x) => Some(Wide::NonNegative(x - 1)),
+        Wide::Negative(x) => x.checked_sub(1).map(Wide::Negative),
+    }
+}
+
+/// Generate a `Src` that fits into `Dest`.
+pub fn that_fits_in<Src, Dest>(u: &mut Unstructured<'_>) -> Result<Src>
+where
+    Src: Primitive + Int,
+    Dest: Primitive,
+{
+    let min = Src::from_wide_saturating(Dest::MIN_WIDE);
+    let max = Src::from_wide_saturating(Dest::MAX_WIDE);
+    u.int_in_range(min..=max)
+}
+
+/// Generate a `Src` that doesn't fit into `Dest`.
+///
+/// # Panics
+///
+/// Panics if every `Src` fits into `Dest`.
+#[track_caller]
+pub fn that_overflows<Src, Dest>(u: &mut Unstructured<'_>) -> Result<Src>
+where
+    Src: Primitive + Int,
+    Dest: Primitive,
+{
+    // The values of `Src` below and above the range of `Dest`.
+    let below = wide_pred(Dest::MIN_WIDE)
+        .filter(|&x| x >= Src::MIN_WIDE)
+        .map(|x| {
+            (
+                Src::from_wide_wrapping(Src::MIN_WIDE),
+                Src::from_wide_wrapping(x),
+            )
+        });
+    let above = wide_succ(Dest::MAX_WIDE)
+        .filter(|&x| x <= Src::MAX_WIDE)
+        .map(|x| {
+            (
+                Src::from_wide_wrapping(x),
+                Src::from_wide_wrapping(Src::MAX_WIDE),
+            )
+        });
+    let (min, max) = match (below, above) {
+        (Some(below), Some(above)) => {
+            if u.arbitrary()? {
+                below
+            } else {
+                above
+            }
+        }
+        (Some(range), None) | (None, Some(range)) => range,
+        (None, None) => panic!("every value fits in the destination type"),
+    };
+    u.int_in_range(min..=max)
+}
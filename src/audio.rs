@@ -0,0 +1,129 @@
+//! Audio sample conversion, for [`dasp`](https://docs.rs/dasp) and
+//! [`cpal`](https://docs.rs/cpal).
+//!
+//! dasp's own conversions to `i16` truncate toward zero (for `f32`) or
+//! toward negative infinity (for `i32`). The conversions here round to
+//! the nearest value instead, and always clip out-of-range samples to
+//! the `i16` range. `f32` samples use dasp's scaling, so `-1.0` maps to
+//! `-32768`, and `1.0` clips to `32767`.
+//!
+//! [`Dither`] adds triangular (TPDF) dither before rounding, which turns
+//! the quantization error into a low, constant noise floor rather than
+//! distortion that follows the signal.
+//!
+//! ```rust
+//! use truncate_integer::audio::{shrink_f32_i16, write_i16};
+//!
+//! assert_eq!(shrink_f32_i16(0.5), 16384);
+//! assert_eq!(shrink_f32_i16(1.5), i16::MAX);
+//!
+//! // In a cpal output callback:
+//! let mixed = [0.25f32, -2.0, 1.0];
+//! let mut data = [0i16; 3];
+//! write_i16(&mixed, &mut data);
+//! assert_eq!(data, [8192, i16::MIN, i16::MAX]);
+//! ```
+
+use crate::norm::round;
+use crate::Shrink;
+use ::dasp::conv::ToSample;
+
+/// Convert an `f32` sample into an `i16`, with rounding and clipping.
+///
+/// NaN is converted to zero.
+#[inline]
+pub fn shrink_f32_i16(sample: f32) -> i16 {
+    // NaN survives `clamp`, but `round` turns it into zero.
+    let value = round(sample.clamp(-1.0, 1.0) * 32768.0) as i32;
+    value.shrink()
+}
+
+/// Convert an `i32` sample into an `i16`, with rounding and clipping.
+#[inline]
+pub fn shrink_i32_i16(sample: i32) -> i16 {
+    ((i64::from(sample) + 0x8000) >> 16).shrink()
+}
+
+/// Convert any dasp sample into an `i16`, with rounding and clipping.
+///
+/// The sample is converted to `f32` first, using dasp's conversion.
+#[inline]
+pub fn to_i16<S: ToSample<f32>>(sample: S) -> i16 {
+    shrink_f32_i16(sample.to_sample_())
+}
+
+/// Convert a buffer of samples into `i16`, with rounding and clipping.
+///
+/// This is meant for filling the buffer passed to a cpal output callback.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+#[track_caller]
+pub fn write_i16<S: ToSample<f32> + Copy>(input: &[S], output: &mut [i16]) {
+    assert_eq!(input.len(), output.len(), "sample buffer length mismatch");
+    for (out, &sample) in output.iter_mut().zip(input) {
+        *out = to_i16(sample);
+    }
+}
+
+/// A source of TPDF dither.
+///
+/// This uses a small, fast pseudo-random generator, which is plenty
+/// for dither but not suitable for anything else.
+#[derive(Clone, Debug)]
+pub struct Dither {
+    state: u32,
+}
+
+impl Dither {
+    /// Create a dither source from a seed.
+    pub fn new(seed: u32) -> Self {
+        // xorshift gets stuck at zero.
+        Dither { state: seed | 1 }
+    }
+
+    #[inline]
+    fn next_u16(&mut self) -> i32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        (x >> 16) as i32
+    }
+
+    /// Triangular noise in `-65535..=65535`, in units of 1/65536 LSB.
+    #[inline]
+    fn noise(&mut self) -> i32 {
+        self.next_u16() - self.next_u16()
+    }
+
+    /// Convert an `f32` sample into an `i16`, with dither and clipping.
+    #[inline]
+    pub fn f32_i16(&mut self, sample: f32) -> i16 {
+        let noise = self.noise() as f32 / 65536.0;
+        let value = round(sample.clamp(-1.0, 1.0) * 32768.0 + noise) as i32;
+        value.shrink()
+    }
+
+    /// Convert an `i32` sample into an `i16`, with dither and clipping.
+    #[inline]
+    pub fn i32_i16(&mut self, sample: i32) -> i16 {
+        let noise = i64::from(self.noise());
+        ((i64::from(sample) + noise + 0x8000) >> 16).shrink()
+    }
+
+    /// Convert a buffer of samples into `i16`, with dither and clipping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` and `output` have different lengths.
+    #[track_caller]
+    pub fn write_i16<S: ToSample<f32> + Copy>(&mut self, input: &[S], output: &mut [i16]) {
+        assert_eq!(input.len(), output.len(), "sample buffer length mismatch");
+        for (out, &sample) in output.iter_mut().zip(input) {
+            *out = self.f32_i16(sample.to_sample_());
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Finding unchecked truncations that lose data.
+//!
+//! With the `audit` feature enabled, every [`TruncateUnchecked`] call
+//! that changes a value records where it was called from. The report
+//! lists each call site, with the number of times it lost data, so that
+//! the casts that matter can be found among the ones that don't.
+//!
+//! ```rust
+//! use truncate_integer::{audit, TruncateUnchecked};
+//!
+//! for x in [1u32, 300, 1000].iter() {
+//!     let _: u8 = x.truncate_unchecked();
+//! }
+//! let report = audit::report();
+//! assert_eq!(report.len(), 1);
+//! assert_eq!(report[0].count(), 2);
+//! audit::write_report(&mut std::io::stderr()).unwrap();
+//! ```
+//!
+//! [`TruncateUnchecked`]: crate::TruncateUnchecked
+
+use core::cmp::Reverse;
+use core::fmt;
+use core::panic::Location;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+type Site = (&'static str, u32, u32);
+
+type Sites = Mutex<BTreeMap<Site, u64>>;
+
+static SITES: AtomicPtr<Sites> = AtomicPtr::new(ptr::null_mut());
+
+/// The table of call sites, created on first use.
+fn sites() -> &'static Sites {
+    let mut sites = SITES.load(Ordering::Acquire);
+    if sites.is_null() {
+        let new = Box::into_raw(Box::new(Mutex::new(BTreeMap::new())));
+        match SITES.compare_exchange(ptr::null_mut(), new, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => sites = new,
+            Err(existing) => {
+                // Another thread got there first.
+                // SAFETY: `new` came from `Box::into_raw`, and was never shared.
+                drop(unsafe { Box::from_raw(new) });
+                sites = existing;
+            }
+        }
+    }
+    // SAFETY: the table is never freed once it's been published.
+    unsafe { &*sites }
+}
+
+/// Record a truncation that lost data.
+#[cold]
+pub(crate) fn record(location: &'static Location<'static>) {
+    let site = (location.file(), location.line(), location.column());
+    // A panic while holding the lock shouldn't stop the audit.
+    let mut sites = sites().lock().unwrap_or_else(|e| e.into_inner());
+    *sites.entry(site).or_insert(0) += 1;
+}
+
+/// A call site that lost data.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEntry {
+    file: &'static str,
+    line: u32,
+    column: u32,
+    count: u64,
+}
+
+impl AuditEntry {
+    /// The source file of the call.
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+
+    /// The line number of the call.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// The column of the call.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+
+    /// The number of times this call lost data.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl fmt::Display for AuditEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {} lossy truncations",
+            self.file, self.line, self.column, self.count
+        )
+    }
+}
+
+/// Every call site that has lost data, most frequent first.
+pub fn report() -> Vec<AuditEntry> {
+    let sites = sites().lock().unwrap_or_else(|e| e.into_inner());
+    let mut entries: Vec<_> = sites
+        .iter()
+        .map(|(&(file, line, column), &count)| AuditEntry {
+            file,
+            line,
+            column,
+            count,
+        })
+        .collect();
+    entries.sort_by_key(|e| Reverse(e.count));
+    entries
+}
+
+/// Write the report, one call site per line.
+pub fn write_report<W: io::Write>(out: &mut W) -> io::Result<()> {
+    for entry in report() {
+        writeln!(out, "{}", entry)?;
+    }
+    Ok(())
+}
+
+/// Forget every recorded call site.
+pub fn reset() {
+    sites().lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
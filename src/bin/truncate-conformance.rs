@@ -0,0 +1,31 @@
+//! Write the conformance table to stdout, as JSON or (with `--csv`) CSV.
+
+use std::io::{self, BufWriter};
+use std::process;
+use truncate_integer::conformance::{table, write_csv, write_json};
+
+fn main() {
+    let csv = match std::env::args().nth(1).as_deref() {
+        None | Some("--json") => false,
+        Some("--csv") => true,
+        Some(_) => {
+            eprintln!("usage: truncate-conformance [--json | --csv]");
+            process::exit(2);
+        }
+    };
+    let rows = table();
+    let stdout = io::stdout();
+    let out = BufWriter::new(stdout.lock());
+    let result = if csv {
+        write_csv(out, &rows)
+    } else {
+        write_json(out, &rows)
+    };
+    match result {
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => {
+            eprintln!("truncate-conformance: {}", e);
+            process::exit(1);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,67 @@
+//! Adapters for the [`binrw`](https://docs.rs/binrw) crate.
+//!
+//! These functions are meant to be used in `map` and `try_map`
+//! attributes, so that a field can be stored in a narrower type
+//! than the one that's on the wire.
+//!
+//! ```rust
+//! use binrw::{io::Cursor, BinRead};
+//! use truncate_integer::binrw::{shrink, try_truncate};
+//!
+//! #[derive(BinRead)]
+//! #[br(big)]
+//! struct Header {
+//!     #[br(try_map = try_truncate::<u32, _>)]
+//!     len: u16,
+//!     #[br(map = shrink::<u64, _>)]
+//!     id: u8,
+//! }
+//!
+//! let mut input = Cursor::new([0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0]);
+//! let header = Header::read(&mut input).unwrap();
+//! assert_eq!(header.len, 256);
+//! assert_eq!(header.id, 255);
+//! ```
+//!
+//! A value that doesn't fit causes `try_truncate` to fail with a
+//! [`TruncateError`], which binrw reports as a custom error.
+//! The same functions can be used in `#[bw(map = ...)]` and
+//! `#[bw(try_map = ...)]` to narrow a field while writing.
+
+use crate::{Chop, Shrink, TruncateError, TruncateUnchecked, TryTruncate};
+
+/// Perform checked truncation, returning [`TruncateError`] on failure.
+pub fn try_truncate<Source, Dest>(value: Source) -> Result<Dest, TruncateError>
+where
+    Source: TryTruncate<Dest> + Copy + PartialOrd + Default,
+{
+    value
+        .try_truncate()
+        .ok_or_else(|| TruncateError::for_value(&value))
+}
+
+/// Perform panicking truncation.
+#[track_caller]
+pub fn chop<Source, Dest>(value: Source) -> Dest
+where
+    Source: Chop<Dest>,
+{
+    value.chop()
+}
+
+/// Perform saturating truncation.
+pub fn shrink<Source, Dest>(value: Source) -> Dest
+where
+    Source: Shrink<Dest>,
+{
+    value.shrink()
+}
+
+/// Perform unchecked bitwise truncation.
+#[track_caller]
+pub fn truncate_unchecked<Source, Dest>(value: Source) -> Dest
+where
+    Source: TruncateUnchecked<Dest>,
+{
+    value.truncate_unchecked()
+}
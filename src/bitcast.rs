@@ -0,0 +1,64 @@
+/// Reinterpret the bits of an integer as the other-signed type of the
+/// same width.
+///
+/// This is the same as an `as` cast between those types, but it can't
+/// be used to truncate or extend by mistake: it's only implemented for
+/// same-width pairs, such as `i8` and `u8`, or `isize` and `usize`.
+///
+/// ```rust
+/// use truncate_integer::{BitCast, BitCastFrom};
+///
+/// let x: u8 = (-1i8).bit_cast();
+/// assert_eq!(x, 0xFF);
+/// let x: i64 = u64::MAX.bit_cast();
+/// assert_eq!(x, -1);
+/// assert_eq!(i16::bit_cast_from(0x8000u16), i16::MIN);
+/// ```
+pub trait BitCast<T> {
+    /// Reinterpret the two's complement bits of `self` as a `T`.
+    fn bit_cast(self) -> T;
+}
+
+/// Reinterpret the bits of an integer as the other-signed type of the
+/// same width.
+///
+/// This is the inverse of [`BitCast`].
+pub trait BitCastFrom<T> {
+    /// Reinterpret the two's complement bits of `value` as a `Self`.
+    fn bit_cast_from(value: T) -> Self;
+}
+
+impl<Source, Dest> BitCastFrom<Source> for Dest
+where
+    Source: BitCast<Dest>,
+{
+    #[inline]
+    fn bit_cast_from(x: Source) -> Self {
+        x.bit_cast()
+    }
+}
+
+macro_rules! make_bit_cast {
+    ($Unsigned:ty, $Signed:ty) => {
+        impl BitCast<$Signed> for $Unsigned {
+            #[inline]
+            fn bit_cast(self) -> $Signed {
+                self as $Signed
+            }
+        }
+
+        impl BitCast<$Unsigned> for $Signed {
+            #[inline]
+            fn bit_cast(self) -> $Unsigned {
+                self as $Unsigned
+            }
+        }
+    };
+}
+
+make_bit_cast!(u8, i8);
+make_bit_cast!(u16, i16);
+make_bit_cast!(u32, i32);
+make_bit_cast!(u64, i64);
+make_bit_cast!(u128, i128);
+make_bit_cast!(usize, isize);
@@ -0,0 +1,145 @@
+//! Narrowing values headed into bitfield setters.
+//!
+//! Bitfield generators like
+//! [`bitfield-struct`](https://docs.rs/bitfield-struct) and
+//! [`modular-bitfield`](https://docs.rs/modular-bitfield) store each field
+//! in its low `bits` bits, and silently mask, panic on, or reject a
+//! value that's too wide. These adapters narrow a value to the field
+//! width first, with the mode the caller chose: [`try_field`] returns an
+//! error, and [`shrink_field`] saturates. A signed field type holds a
+//! two's complement value of `bits` bits.
+//!
+//! ```rust
+//! use truncate_integer::bitfield::{shrink_field, try_field, BitfieldError};
+//! use truncate_integer::TruncateError;
+//!
+//! // A 4-bit unsigned field and a 5-bit signed field.
+//! assert_eq!(try_field::<u8, _>(9u32, 4), Ok(9));
+//! assert_eq!(
+//!     try_field::<u8, _>(16u32, 4),
+//!     Err(BitfieldError::new(TruncateError::Overflow, 4))
+//! );
+//! assert_eq!(shrink_field::<u8, _>(300u32, 4), 15);
+//! assert_eq!(shrink_field::<i8, _>(-100i64, 5), -16);
+//! ```
+//!
+//! With the `modular-bitfield` feature, [`try_specifier`] and
+//! [`shrink_specifier`] take the field width from a `Specifier` such as
+//! `B4`.
+
+use crate::wide::{Primitive, Wide};
+use crate::TruncateError;
+use core::fmt;
+
+/// The error returned when a value doesn't fit in a bitfield.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BitfieldError {
+    error: TruncateError,
+    bits: u32,
+}
+
+impl BitfieldError {
+    /// Create an error for a value that didn't fit in a `bits`-bit field.
+    pub fn new(error: TruncateError, bits: u32) -> Self {
+        BitfieldError { error, bits }
+    }
+
+    /// Whether the value was too large or too small.
+    pub fn error(&self) -> TruncateError {
+        self.error
+    }
+
+    /// The width of the field.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+}
+
+impl fmt::Display for BitfieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in a {}-bit field", self.error, self.bits)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitfieldError {}
+
+/// The range of a `bits`-bit field of type `T`.
+#[track_caller]
+fn field_range<T: Primitive>(bits: u32) -> (Wide, Wide) {
+    let signed = T::MIN_WIDE < Wide::NonNegative(0);
+    let width = 128 - T::MAX_WIDE.to_bits().leading_zeros() + signed as u32;
+    assert!(bits != 0 && bits <= width, "invalid field width");
+    if signed {
+        let max = (1u128 << (bits - 1)) - 1;
+        (Wide::Negative(-(max as i128) - 1), Wide::NonNegative(max))
+    } else {
+        (
+            Wide::NonNegative(0),
+            Wide::NonNegative(u128::MAX >> (128 - bits)),
+        )
+    }
+}
+
+/// Narrow `value` into a `bits`-bit field of type `T`.
+///
+/// # Panics
+///
+/// Panics if `bits` is zero or wider than `T`.
+#[track_caller]
+pub fn try_field<T, V>(value: V, bits: u32) -> Result<T, BitfieldError>
+where
+    T: Primitive,
+    V: Primitive,
+{
+    let (min, max) = field_range::<T>(bits);
+    let value = value.widen();
+    if value < min {
+        Err(BitfieldError::new(TruncateError::Underflow, bits))
+    } else if value > max {
+        Err(BitfieldError::new(TruncateError::Overflow, bits))
+    } else {
+        Ok(T::from_wide_wrapping(value))
+    }
+}
+
+/// Narrow `value` into a `bits`-bit field of type `T`, saturating if it
+/// doesn't fit.
+///
+/// # Panics
+///
+/// Panics if `bits` is zero or wider than `T`.
+#[track_caller]
+pub fn shrink_field<T, V>(value: V, bits: u32) -> T
+where
+    T: Primitive,
+    V: Primitive,
+{
+    let (min, max) = field_range::<T>(bits);
+    T::from_wide_wrapping(value.widen().max(min).min(max))
+}
+
+/// Narrow `value` into a `modular-bitfield` field with specifier `S`.
+#[cfg(feature = "modular-bitfield")]
+#[inline]
+pub fn try_specifier<S, V>(value: V) -> Result<S::InOut, BitfieldError>
+where
+    S: modular_bitfield::Specifier,
+    S::InOut: Primitive,
+    V: Primitive,
+{
+    try_field(value, S::BITS as u32)
+}
+
+/// Narrow `value` into a `modular-bitfield` field with specifier `S`,
+/// saturating if it doesn't fit.
+#[cfg(feature = "modular-bitfield")]
+#[inline]
+pub fn shrink_specifier<S, V>(value: V) -> S::InOut
+where
+    S: modular_bitfield::Specifier,
+    S::InOut: Primitive,
+    V: Primitive,
+{
+    shrink_field(value, S::BITS as u32)
+}
@@ -0,0 +1,107 @@
+//! Narrowing into [`bitflags`](https://docs.rs/bitflags) types.
+//!
+//! A protocol often carries flags in a wider field than the local flags
+//! type, and a peer speaking a newer version may set flags that aren't
+//! defined here. [`try_narrow_flags`] rejects a value with any bit set
+//! outside the defined flags, including bits too high for the flags'
+//! backing integer. [`narrow_flags_truncate`] drops those bits instead.
+//!
+//! ```rust
+//! use bitflags::bitflags;
+//! use truncate_integer::bitflags::{narrow_flags_truncate, try_narrow_flags};
+//!
+//! bitflags! {
+//!     #[derive(Debug, PartialEq)]
+//!     struct Perms: u8 {
+//!         const READ = 1;
+//!         const WRITE = 2;
+//!     }
+//! }
+//!
+//! let perms: Perms = try_narrow_flags(3u32).unwrap();
+//! assert_eq!(perms, Perms::READ | Perms::WRITE);
+//!
+//! let err = try_narrow_flags::<Perms, _>(0x101u32).unwrap_err();
+//! assert_eq!(err.unknown(), 0x100);
+//! assert_eq!(err.to_string(), "unknown flag bits 0x100");
+//!
+//! let perms: Perms = narrow_flags_truncate(0x105u32);
+//! assert_eq!(perms, Perms::READ);
+//! ```
+
+use crate::wide::{FromWide, Primitive, Wide, Widen};
+use ::bitflags::Flags;
+use core::fmt;
+
+/// The error returned when a value has bits set outside the defined
+/// flags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagsError {
+    unknown: u128,
+}
+
+impl FlagsError {
+    /// Create an error for the `unknown` bits.
+    pub fn new(unknown: u128) -> Self {
+        FlagsError { unknown }
+    }
+
+    /// The bits that were set outside the defined flags.
+    ///
+    /// A negative source value is treated as its two's complement bits,
+    /// extended to 128 bits.
+    pub fn unknown(&self) -> u128 {
+        self.unknown
+    }
+}
+
+impl fmt::Display for FlagsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown flag bits {:#x}", self.unknown)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FlagsError {}
+
+/// The bits of `value` that can be stored in a `T`.
+fn width_mask<T: Primitive>() -> u128 {
+    let max = T::MAX_WIDE.to_bits();
+    if T::MIN_WIDE < Wide::NonNegative(0) {
+        (max << 1) | 1
+    } else {
+        max
+    }
+}
+
+/// Narrow `value` into the flags type `F`, dropping any bits that
+/// aren't defined flags.
+#[inline]
+pub fn narrow_flags_truncate<F, T>(value: T) -> F
+where
+    F: Flags,
+    F::Bits: Primitive,
+    T: Primitive,
+{
+    F::from_bits_truncate(F::Bits::from_wide_wrapping(value.widen()))
+}
+
+/// Narrow `value` into the flags type `F`.
+///
+/// Returns an error if any bit is set that isn't a defined flag of `F`.
+pub fn try_narrow_flags<F, T>(value: T) -> Result<F, FlagsError>
+where
+    F: Flags,
+    F::Bits: Primitive,
+    T: Primitive,
+{
+    let bits = value.widen().to_bits();
+    let flags: F = narrow_flags_truncate(value);
+    let known = flags.bits().widen().to_bits() & width_mask::<F::Bits>();
+    let unknown = bits & !known;
+    if unknown == 0 {
+        Ok(flags)
+    } else {
+        Err(FlagsError::new(unknown))
+    }
+}
@@ -0,0 +1,55 @@
+/// Count the bits needed to represent a value.
+///
+/// For unsigned integers, this is the position of the highest set bit,
+/// so `0` needs zero bits and `255` needs eight.
+///
+/// For signed integers, this is the width of the smallest two's complement
+/// integer that can hold the value, so `0` and `-1` need one bit and
+/// `-128` and `127` need eight.
+///
+/// ```rust
+/// use truncate_integer::MinBits;
+///
+/// assert_eq!(0u32.min_bits(), 0);
+/// assert_eq!(256u32.min_bits(), 9);
+/// assert_eq!((-129i64).min_bits(), 9);
+/// assert_eq!(127i64.min_bits(), 8);
+/// ```
+pub trait MinBits {
+    /// Count the bits needed to represent a value.
+    fn min_bits(self) -> u32;
+}
+
+macro_rules! make_min_bits_unsigned {
+    ($($Type:ty),*) => {
+        $(
+            impl MinBits for $Type {
+                #[inline]
+                fn min_bits(self) -> u32 {
+                    (core::mem::size_of::<$Type>() * 8) as u32 - self.leading_zeros()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! make_min_bits_signed {
+    ($($Type:ty),*) => {
+        $(
+            impl MinBits for $Type {
+                #[inline]
+                fn min_bits(self) -> u32 {
+                    let bits = (core::mem::size_of::<$Type>() * 8) as u32;
+                    if self < 0 {
+                        bits - self.leading_ones() + 1
+                    } else {
+                        bits - self.leading_zeros() + 1
+                    }
+                }
+            }
+        )*
+    };
+}
+
+make_min_bits_unsigned!(u8, u16, u32, u64, u128, usize);
+make_min_bits_signed!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,367 @@
+//! Arbitrary-width integers.
+//!
+//! [`U<N>`](U) is an unsigned integer of `N` bits, and [`I<N>`](I) is a
+//! signed (two's complement) integer of `N` bits, for any `N` from 1 to
+//! 128. Each is stored in the smallest primitive integer that can hold it,
+//! so `U<12>` is stored in a `u16`.
+//!
+//! All of the truncation traits are implemented between these types and
+//! the primitive integers, and between `U` and `I` types of any width.
+//!
+//! ```rust
+//! use truncate_integer::bitsint::{I, U};
+//! use truncate_integer::{Shrink, TruncateUnchecked, TryTruncate};
+//!
+//! let x: Option<U<12>> = 4095u32.try_truncate();
+//! assert_eq!(x.unwrap().get(), 4095u16);
+//! let x: Option<U<12>> = 4096u32.try_truncate();
+//! assert!(x.is_none());
+//!
+//! let y: I<4> = (-100i32).shrink();
+//! assert_eq!(y.get(), -8i8);
+//! let y: I<4> = 0xFu8.truncate_unchecked();
+//! assert_eq!(y.get(), -1i8);
+//!
+//! let z: u8 = U::<12>::MAX.shrink();
+//! assert_eq!(z, 255);
+//! ```
+//!
+//! In generic code, the width `N` needs a `Bits<N>: Storage` bound:
+//!
+//! ```rust
+//! use truncate_integer::bitsint::{Bits, Storage, U};
+//! use truncate_integer::TryTruncate;
+//!
+//! fn field<const N: u32>(raw: u64) -> Option<U<N>>
+//! where
+//!     Bits<N>: Storage,
+//! {
+//!     raw.try_truncate()
+//! }
+//!
+//! assert!(field::<3>(7).is_some());
+//! assert!(field::<3>(8).is_none());
+//! ```
+
+use crate::wide::{FromWide, Primitive, Wide, Widen};
+use crate::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+use core::fmt;
+use core::hash::Hash;
+
+mod private {
+    /// A primitive integer used to store a `U<N>` or `I<N>`.
+    pub trait Repr: Copy {
+        fn to_bits(self) -> u128;
+        fn from_bits(bits: u128) -> Self;
+    }
+}
+
+use private::Repr;
+
+macro_rules! make_repr {
+    ($($Type:ty),*) => {
+        $(
+            impl Repr for $Type {
+                #[inline]
+                fn to_bits(self) -> u128 {
+                    self as u128
+                }
+
+                #[inline]
+                fn from_bits(bits: u128) -> Self {
+                    bits as $Type
+                }
+            }
+        )*
+    };
+}
+
+make_repr!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// A marker type for a width in bits.
+///
+/// [`Storage`] is implemented for `Bits<1>` through `Bits<128>`.
+#[derive(Clone, Copy, Debug)]
+pub struct Bits<const N: u32>;
+
+/// The primitive types used to store integers of a given width.
+pub trait Storage {
+    /// The storage for [`U<N>`](U).
+    type Unsigned: Repr + fmt::Debug + fmt::Display + Default + Eq + Ord + Hash;
+    /// The storage for [`I<N>`](I).
+    type Signed: Repr + fmt::Debug + fmt::Display + Default + Eq + Ord + Hash;
+
+    /// The smallest value of [`U<N>`](U).
+    const UNSIGNED_MIN: Self::Unsigned;
+    /// The largest value of [`U<N>`](U).
+    const UNSIGNED_MAX: Self::Unsigned;
+    /// The smallest value of [`I<N>`](I).
+    const SIGNED_MIN: Self::Signed;
+    /// The largest value of [`I<N>`](I).
+    const SIGNED_MAX: Self::Signed;
+}
+
+macro_rules! make_storage {
+    ($Unsigned:ty, $Signed:ty; $($N:literal)*) => {
+        $(
+            impl Storage for Bits<$N> {
+                type Unsigned = $Unsigned;
+                type Signed = $Signed;
+
+                const UNSIGNED_MIN: $Unsigned = 0;
+                const UNSIGNED_MAX: $Unsigned = (u128::MAX >> (128 - $N)) as $Unsigned;
+                const SIGNED_MIN: $Signed = (i128::MIN >> (128 - $N)) as $Signed;
+                const SIGNED_MAX: $Signed = (i128::MAX >> (128 - $N)) as $Signed;
+            }
+        )*
+    };
+}
+
+make_storage!(u8, i8; 1 2 3 4 5 6 7 8);
+make_storage!(u16, i16; 9 10 11 12 13 14 15 16);
+make_storage!(u32, i32; 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32);
+make_storage!(u64, i64;
+    33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48
+    49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64);
+make_storage!(u128, i128;
+    65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80
+    81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96
+    97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112
+    113 114 115 116 117 118 119 120 121 122 123 124 125 126 127 128);
+
+/// An unsigned integer of `N` bits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U<const N: u32>(<Bits<N> as Storage>::Unsigned)
+where
+    Bits<N>: Storage;
+
+/// A signed integer of `N` bits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct I<const N: u32>(<Bits<N> as Storage>::Signed)
+where
+    Bits<N>: Storage;
+
+impl<const N: u32> U<N>
+where
+    Bits<N>: Storage,
+{
+    /// The width in bits.
+    pub const BITS: u32 = N;
+    /// The smallest value, zero.
+    pub const MIN: Self = U(<Bits<N> as Storage>::UNSIGNED_MIN);
+    /// The largest value, `2^N - 1`.
+    pub const MAX: Self = U(<Bits<N> as Storage>::UNSIGNED_MAX);
+
+    fn from_wrapping_bits(bits: u128) -> Self {
+        U(Repr::from_bits(bits & (u128::MAX >> (128 - N))))
+    }
+
+    /// Get the value, as its storage type.
+    pub fn get(self) -> <Bits<N> as Storage>::Unsigned {
+        self.0
+    }
+}
+
+impl<const N: u32> I<N>
+where
+    Bits<N>: Storage,
+{
+    /// The width in bits.
+    pub const BITS: u32 = N;
+    /// The smallest value, `-2^(N-1)`.
+    pub const MIN: Self = I(<Bits<N> as Storage>::SIGNED_MIN);
+    /// The largest value, `2^(N-1) - 1`.
+    pub const MAX: Self = I(<Bits<N> as Storage>::SIGNED_MAX);
+
+    fn from_wrapping_bits(bits: u128) -> Self {
+        // Sign-extend from N bits.
+        let shift = 128 - N;
+        I(Repr::from_bits(((bits << shift) as i128 >> shift) as u128))
+    }
+
+    /// Get the value, as its storage type.
+    pub fn get(self) -> <Bits<N> as Storage>::Signed {
+        self.0
+    }
+}
+
+impl<const N: u32> fmt::Display for U<N>
+where
+    Bits<N>: Storage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: u32> fmt::Display for I<N>
+where
+    Bits<N>: Storage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: u32> Widen for U<N>
+where
+    Bits<N>: Storage,
+{
+    #[inline]
+    fn widen(self) -> Wide {
+        Wide::NonNegative(self.0.to_bits())
+    }
+}
+
+impl<const N: u32> Widen for I<N>
+where
+    Bits<N>: Storage,
+{
+    #[inline]
+    fn widen(self) -> Wide {
+        // The storage is sign-extended, so its bits are the same as i128's.
+        let value = self.0.to_bits() as i128;
+        if value < 0 {
+            Wide::Negative(value)
+        } else {
+            Wide::NonNegative(value as u128)
+        }
+    }
+}
+
+impl<const N: u32> FromWide for U<N>
+where
+    Bits<N>: Storage,
+{
+    const MIN_WIDE: Wide = Wide::NonNegative(0);
+    const MAX_WIDE: Wide = Wide::NonNegative(u128::MAX >> (128 - N));
+
+    #[inline]
+    fn from_wide_wrapping(value: Wide) -> Self {
+        U::from_wrapping_bits(value.to_bits())
+    }
+}
+
+impl<const N: u32> FromWide for I<N>
+where
+    Bits<N>: Storage,
+{
+    const MIN_WIDE: Wide = Wide::Negative(i128::MIN >> (128 - N));
+    const MAX_WIDE: Wide = Wide::NonNegative((i128::MAX >> (128 - N)) as u128);
+
+    #[inline]
+    fn from_wide_wrapping(value: Wide) -> Self {
+        I::from_wrapping_bits(value.to_bits())
+    }
+}
+
+macro_rules! make_bitsint_truncate {
+    ($Type:ident) => {
+        impl<Source: Widen, const N: u32> TryTruncate<$Type<N>> for Source
+        where
+            Bits<N>: Storage,
+        {
+            #[inline]
+            fn try_truncate(self) -> Option<$Type<N>> {
+                FromWide::from_wide_checked(self.widen())
+            }
+        }
+
+        impl<Source: Widen, const N: u32> Chop<$Type<N>> for Source
+        where
+            Bits<N>: Storage,
+        {
+            #[track_caller]
+            #[inline]
+            fn chop(self) -> $Type<N> {
+                match FromWide::from_wide_checked(self.widen()) {
+                    Some(val) => val,
+                    None => panic!("chop overflow"),
+                }
+            }
+        }
+
+        impl<Source: Widen, const N: u32> Shrink<$Type<N>> for Source
+        where
+            Bits<N>: Storage,
+        {
+            #[inline]
+            fn shrink(self) -> $Type<N> {
+                FromWide::from_wide_saturating(self.widen())
+            }
+        }
+
+        impl<Source: Widen, const N: u32> TruncateUnchecked<$Type<N>> for Source
+        where
+            Bits<N>: Storage,
+        {
+            #[track_caller]
+            #[inline]
+            fn truncate_unchecked(self) -> $Type<N> {
+                let result: $Type<N> = FromWide::from_wide_wrapping(self.widen());
+                #[cfg(feature = "audit")]
+                {
+                    if result.widen() != self.widen() {
+                        crate::audit::record(core::panic::Location::caller());
+                    }
+                }
+                result
+            }
+        }
+
+        impl<Dest: Primitive, const N: u32> TryTruncate<Dest> for $Type<N>
+        where
+            Bits<N>: Storage,
+        {
+            #[inline]
+            fn try_truncate(self) -> Option<Dest> {
+                Dest::from_wide_checked(self.widen())
+            }
+        }
+
+        impl<Dest: Primitive, const N: u32> Chop<Dest> for $Type<N>
+        where
+            Bits<N>: Storage,
+        {
+            #[track_caller]
+            #[inline]
+            fn chop(self) -> Dest {
+                match Dest::from_wide_checked(self.widen()) {
+                    Some(val) => val,
+                    None => panic!("chop overflow"),
+                }
+            }
+        }
+
+        impl<Dest: Primitive, const N: u32> Shrink<Dest> for $Type<N>
+        where
+            Bits<N>: Storage,
+        {
+            #[inline]
+            fn shrink(self) -> Dest {
+                Dest::from_wide_saturating(self.widen())
+            }
+        }
+
+        impl<Dest: Primitive, const N: u32> TruncateUnchecked<Dest> for $Type<N>
+        where
+            Bits<N>: Storage,
+        {
+            #[track_caller]
+            #[inline]
+            fn truncate_unchecked(self) -> Dest {
+                let result = Dest::from_wide_wrapping(self.widen());
+                #[cfg(feature = "audit")]
+                {
+                    if result.widen() != self.widen() {
+                        crate::audit::record(core::panic::Location::caller());
+                    }
+                }
+                result
+            }
+        }
+    };
+}
+
+make_bitsint_truncate!(U);
+make_bitsint_truncate!(I);
@@ -0,0 +1,69 @@
+//! Mapping values into histogram buckets.
+//!
+//! [`bucket_index`] divides a value by the bucket width, clamps it to
+//! the last bucket, and narrows it into the type used to count buckets.
+//! Values past the end of the histogram land in the last bucket.
+//!
+//! ```rust
+//! use truncate_integer::bucket::{bucket_index, bucket_index_shift};
+//!
+//! // 10 buckets of width 100ms.
+//! assert_eq!(bucket_index(250, 100, 10u8), 2u8);
+//! assert_eq!(bucket_index(1_000_000, 100, 10u8), 9u8);
+//!
+//! // 1000 buckets of width 16.
+//! assert_eq!(bucket_index_shift(100, 4, 1000u16), 6u16);
+//! ```
+
+use crate::TruncateUnchecked;
+
+/// Find the bucket containing `value`, for buckets of `bucket_width`.
+///
+/// If `bucket_width` is a power of two, this uses a shift rather than a
+/// division.
+///
+/// # Panics
+///
+/// Panics if `bucket_width` or `n_buckets` is zero.
+#[track_caller]
+#[inline]
+pub fn bucket_index<T>(value: u64, bucket_width: u64, n_buckets: T) -> T
+where
+    T: Into<u64>,
+    u64: TruncateUnchecked<T>,
+{
+    assert!(bucket_width != 0, "bucket width is zero");
+    if bucket_width.is_power_of_two() {
+        return bucket_index_shift(value, bucket_width.trailing_zeros(), n_buckets);
+    }
+    clamp(value / bucket_width, n_buckets)
+}
+
+/// Find the bucket containing `value`, for buckets of `2^width_log2`.
+///
+/// # Panics
+///
+/// Panics if `width_log2 >= 64` or `n_buckets` is zero.
+#[track_caller]
+#[inline]
+pub fn bucket_index_shift<T>(value: u64, width_log2: u32, n_buckets: T) -> T
+where
+    T: Into<u64>,
+    u64: TruncateUnchecked<T>,
+{
+    assert!(width_log2 < 64, "bucket width is too large");
+    clamp(value >> width_log2, n_buckets)
+}
+
+#[track_caller]
+#[inline]
+fn clamp<T>(index: u64, n_buckets: T) -> T
+where
+    T: Into<u64>,
+    u64: TruncateUnchecked<T>,
+{
+    let n_buckets: u64 = n_buckets.into();
+    assert!(n_buckets != 0, "number of buckets is zero");
+    // The result is less than `n_buckets`, so it always fits.
+    index.min(n_buckets - 1).truncate_unchecked()
+}
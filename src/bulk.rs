@@ -0,0 +1,304 @@
+//! Checked truncation of whole buffers.
+//!
+//! [`try_truncate_masked`] narrows every lane of a buffer, and records
+//! which lanes didn't fit in a bitmask instead of stopping at the first
+//! one. The loop has no early exit and no data-dependent branches, so
+//! the compiler can vectorize it; validating a large buffer doesn't have
+//! to fall back to calling [`TryTruncate`] one value at a time.
+//!
+//! Narrowing from 32-bit to 16-bit or 8-bit integers, or from 16-bit to
+//! 8-bit integers, has hand-written [`Kernel`]s as well. With the `std`
+//! feature [`kernel`] picks the best one the CPU supports at runtime;
+//! otherwise it's limited to what the build target enables. The AVX-512
+//! kernels need the `avx512` feature, and Rust 1.89. Other pairs of
+//! types, and builds with the `fault-injection` feature, always use the
+//! scalar loop.
+//!
+//! Bit `i % 64` of `mask[i / 64]` is set if lane `i` didn't fit.
+//! [`failed_lanes`] lists the lanes that are set.
+//!
+//! [`try_truncate_strided`] and [`try_truncate_field`] read their lanes
+//! from every `n`th element of a slice, or from one field of each
+//! record, so interleaved data can be split and narrowed in one pass.
+//!
+//! ```rust
+//! use truncate_integer::bulk::{failed_lanes, mask_len, try_truncate_masked};
+//!
+//! let src = [1u32, 300, 7, u32::MAX, 255];
+//! let mut dst = [0u8; 5];
+//! let mut mask = [0u64; mask_len(5)];
+//! assert_eq!(try_truncate_masked(&src, &mut dst, &mut mask), 2);
+//! assert_eq!(dst, [1, 255, 7, 255, 255]);
+//! assert_eq!(failed_lanes(&mask, src.len()).collect::<Vec<_>>(), [1, 3]);
+//! ```
+
+use crate::{Shrink, TryTruncate};
+
+// Whether the CPU has all of some x86 features, or failing that, whether
+// the build target does.
+#[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+macro_rules! x86_feature {
+    ($($feature:tt),+) => {
+        $(std::is_x86_feature_detected!($feature))&&+
+    };
+}
+
+#[cfg(not(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64"))))]
+macro_rules! x86_feature {
+    ($($feature:tt),+) => {
+        cfg!(all($(target_feature = $feature),+))
+    };
+}
+
+/// A way of narrowing a whole buffer.
+///
+/// Every kernel gives the same results; they only differ in speed.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Kernel {
+    /// One lane at a time, leaving any vectorizing to the compiler.
+    Scalar,
+    /// A whole `u64` of lanes at a time, on any CPU.
+    Swar,
+    /// SSE4.1, 16 lanes at a time.
+    Sse41,
+    /// AVX2, 32 lanes at a time.
+    Avx2,
+    /// AVX-512F and AVX-512BW, 64 lanes at a time.
+    Avx512,
+}
+
+impl Kernel {
+    /// Whether this kernel can be used here.
+    ///
+    /// ```rust
+    /// use truncate_integer::bulk::Kernel;
+    ///
+    /// assert!(Kernel::Scalar.is_supported());
+    /// assert!(Kernel::Swar.is_supported());
+    /// ```
+    pub fn is_supported(self) -> bool {
+        match self {
+            Kernel::Scalar | Kernel::Swar => true,
+            Kernel::Sse41 => x86_feature!("sse4.1"),
+            Kernel::Avx2 => x86_feature!("avx2"),
+            #[cfg(feature = "avx512")]
+            Kernel::Avx512 => x86_feature!("avx512f", "avx512bw"),
+            #[cfg(not(feature = "avx512"))]
+            Kernel::Avx512 => false,
+        }
+    }
+}
+
+/// The fastest [`Kernel`] that can be used here.
+///
+/// This is the kernel [`try_truncate_masked`] uses.
+pub fn kernel() -> Kernel {
+    [Kernel::Avx512, Kernel::Avx2, Kernel::Sse41]
+        .iter()
+        .copied()
+        .find(|kernel| kernel.is_supported())
+        .unwrap_or(Kernel::Swar)
+}
+
+/// The number of `u64` mask words needed for `lanes` lanes.
+#[inline]
+pub const fn mask_len(lanes: usize) -> usize {
+    (lanes + 63) / 64
+}
+
+/// Narrow each lane of `src` into `dst`, and mark the lanes that didn't
+/// fit in `mask`.
+///
+/// A lane that doesn't fit is saturated, as with [`Shrink`]. Returns the
+/// number of lanes that didn't fit.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths, or `mask` is shorter
+/// than [`mask_len(src.len())`](mask_len).
+#[track_caller]
+pub fn try_truncate_masked<S, D>(src: &[S], dst: &mut [D], mask: &mut [u64]) -> usize
+where
+    S: TryTruncate<D> + Shrink<D> + Copy,
+{
+    try_truncate_masked_with(kernel(), src, dst, mask)
+}
+
+/// Like [`try_truncate_masked`], using `kernel`.
+///
+/// ```rust
+/// use truncate_integer::bulk::{mask_len, try_truncate_masked_with, Kernel};
+///
+/// let src = [1i32, -300, 7, 128, -128];
+/// let mut dst = [0i8; 5];
+/// let mut mask = [0u64; mask_len(5)];
+/// assert_eq!(try_truncate_masked_with(Kernel::Swar, &src, &mut dst, &mut mask), 2);
+/// assert_eq!(dst, [1, -128, 7, 127, -128]);
+/// assert_eq!(mask, [0b1010]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `kernel` isn't [supported](Kernel::is_supported), `src` and
+/// `dst` have different lengths, or `mask` is shorter than
+/// [`mask_len(src.len())`](mask_len).
+#[track_caller]
+pub fn try_truncate_masked_with<S, D>(
+    kernel: Kernel,
+    src: &[S],
+    dst: &mut [D],
+    mask: &mut [u64],
+) -> usize
+where
+    S: TryTruncate<D> + Shrink<D> + Copy,
+{
+    assert!(kernel.is_supported(), "unsupported bulk kernel");
+    assert_eq!(src.len(), dst.len(), "bulk buffer length mismatch");
+    assert!(mask.len() >= mask_len(src.len()), "mask buffer too short");
+    S::try_truncate_bulk(src, dst, mask, kernel)
+}
+
+/// Narrow every `stride`th element of `src`, starting at `offset`, into
+/// `dst`, and mark the lanes that didn't fit in `mask`.
+///
+/// Lane `i` is read from `src[offset + i * stride]`, so this
+/// de-interleaves and narrows in one pass. Otherwise this is the same as
+/// [`try_truncate_masked`].
+///
+/// # Panics
+///
+/// Panics if `stride` is zero, `src` is too short to hold `dst.len()`
+/// lanes, or `mask` is shorter than [`mask_len(dst.len())`](mask_len).
+#[track_caller]
+pub fn try_truncate_strided<S, D>(
+    src: &[S],
+    offset: usize,
+    stride: usize,
+    dst: &mut [D],
+    mask: &mut [u64],
+) -> usize
+where
+    S: TryTruncate<D> + Shrink<D> + Copy,
+{
+    assert!(stride != 0, "invalid stride");
+    if let Some(last) = dst.len().checked_sub(1) {
+        let end = last.checked_mul(stride).and_then(|x| x.checked_add(offset));
+        assert!(
+            end.map_or(false, |end| end < src.len()),
+            "strided source too short"
+        );
+    }
+    masked_by(dst, mask, |i| src[offset + i * stride])
+}
+
+/// Narrow one field of each record in `records` into `dst`, and mark the
+/// lanes that didn't fit in `mask`.
+///
+/// `field` picks the value out of a record, such as one channel of a
+/// packed pixel. Otherwise this is the same as [`try_truncate_masked`].
+///
+/// # Panics
+///
+/// Panics if `records` and `dst` have different lengths, or `mask` is
+/// shorter than [`mask_len(dst.len())`](mask_len).
+#[track_caller]
+pub fn try_truncate_field<R, S, D, F>(
+    records: &[R],
+    field: F,
+    dst: &mut [D],
+    mask: &mut [u64],
+) -> usize
+where
+    F: Fn(&R) -> S,
+    S: TryTruncate<D> + Shrink<D> + Copy,
+{
+    assert_eq!(records.len(), dst.len(), "bulk buffer length mismatch");
+    masked_by(dst, mask, |i| field(&records[i]))
+}
+
+/// Like [`try_truncate_masked`], reading lane `i` with `get(i)`.
+#[track_caller]
+#[inline(always)]
+pub(crate) fn masked_by<S, D, G>(dst: &mut [D], mask: &mut [u64], get: G) -> usize
+where
+    G: Fn(usize) -> S,
+    S: TryTruncate<D> + Shrink<D> + Copy,
+{
+    assert!(mask.len() >= mask_len(dst.len()), "mask buffer too short");
+    let mut failed = 0;
+    for (chunk, (dst, word)) in dst.chunks_mut(64).zip(mask.iter_mut()).enumerate() {
+        let mut bits = 0u64;
+        for (lane, out) in dst.iter_mut().enumerate() {
+            let value = get(chunk * 64 + lane);
+            let fits = TryTruncate::<D>::try_truncate(value).is_some();
+            *out = value.shrink();
+            bits |= (!fits as u64) << lane;
+        }
+        *word = bits;
+        failed += bits.count_ones() as usize;
+    }
+    failed
+}
+
+/// An iterator over the set lanes of a mask, in order.
+///
+/// This is returned by [`failed_lanes`].
+#[derive(Clone, Debug)]
+pub struct FailedLanes<'a> {
+    mask: &'a [u64],
+    base: usize,
+    word: u64,
+    lanes: usize,
+}
+
+impl Iterator for FailedLanes<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            let (&next, rest) = self.mask.split_first()?;
+            self.mask = rest;
+            self.base += 64;
+            self.word = next;
+        }
+        let lane = self.base + self.word.trailing_zeros() as usize;
+        if lane >= self.lanes {
+            // Lanes come out in order, so the rest are past the end too.
+            self.mask = &[];
+            self.word = 0;
+            return None;
+        }
+        // Clear the lowest set bit.
+        self.word &= self.word - 1;
+        Some(lane)
+    }
+}
+
+/// List the lanes before `lanes` that are set in `mask`.
+///
+/// Bits past the last lane are ignored, so a mask buffer that's longer
+/// than needed can be reused.
+///
+/// # Panics
+///
+/// Panics if `mask` is shorter than [`mask_len(lanes)`](mask_len).
+#[track_caller]
+pub fn failed_lanes(mask: &[u64], lanes: usize) -> FailedLanes<'_> {
+    assert!(mask.len() >= mask_len(lanes), "mask buffer too short");
+    let mask = &mask[..mask_len(lanes)];
+    match mask.split_first() {
+        Some((&word, rest)) => FailedLanes {
+            mask: rest,
+            base: 0,
+            word,
+            lanes,
+        },
+        None => FailedLanes {
+            mask,
+            base: 0,
+            word: 0,
+            lanes,
+        },
+    }
+}
@@ -0,0 +1,551 @@
+// Vectorized kernels for bulk narrowing between primitive types.
+//
+// Each kernel handles whole 64-lane blocks, one mask word at a time,
+// and leaves the last partial block to the scalar loop in
+// `bulk::masked_by`. Every kernel clamps each lane into the range of the
+// destination, compares the result with the input to find the lanes
+// that didn't fit, and only then narrows, so saturation and the mask
+// always agree with `Shrink` and `TryTruncate`.
+
+use crate::bulk::{mask_len, masked_by, Kernel};
+
+// Narrow `$src` into `$dst` with `$kernel`, if there's a kernel for this
+// pair of types; otherwise use the scalar loop.
+macro_rules! bulk_kernel {
+    (u32, u16, $($args:expr),*) => { crate::bulk_kernel::u32_u16($($args),*) };
+    (u32, i16, $($args:expr),*) => { crate::bulk_kernel::u32_i16($($args),*) };
+    (i32, u16, $($args:expr),*) => { crate::bulk_kernel::i32_u16($($args),*) };
+    (i32, i16, $($args:expr),*) => { crate::bulk_kernel::i32_i16($($args),*) };
+    (u32, u8, $($args:expr),*) => { crate::bulk_kernel::u32_u8($($args),*) };
+    (u32, i8, $($args:expr),*) => { crate::bulk_kernel::u32_i8($($args),*) };
+    (i32, u8, $($args:expr),*) => { crate::bulk_kernel::i32_u8($($args),*) };
+    (i32, i8, $($args:expr),*) => { crate::bulk_kernel::i32_i8($($args),*) };
+    (u16, u8, $($args:expr),*) => { crate::bulk_kernel::u16_u8($($args),*) };
+    (u16, i8, $($args:expr),*) => { crate::bulk_kernel::u16_i8($($args),*) };
+    (i16, u8, $($args:expr),*) => { crate::bulk_kernel::i16_u8($($args),*) };
+    (i16, i8, $($args:expr),*) => { crate::bulk_kernel::i16_i8($($args),*) };
+    ($Source:ident, $Dest:ident, $src:expr, $dst:expr, $mask:expr, $kernel:expr) => {{
+        let (src, _): (&[$Source], crate::bulk::Kernel) = ($src, $kernel);
+        crate::bulk::masked_by::<$Source, $Dest, _>($dst, $mask, |i| src[i])
+    }};
+}
+
+/// Narrow the lanes after the last whole block with the scalar loop.
+#[inline(always)]
+fn tail<S, D>(src: &[S], dst: &mut [D], mask: &mut [u64], blocks: usize) -> usize
+where
+    S: crate::TryTruncate<D> + crate::Shrink<D> + Copy,
+{
+    let start = blocks * 64;
+    masked_by(&mut dst[start..], &mut mask[blocks..], |i| src[start + i])
+}
+
+macro_rules! make_pair {
+    ($name:ident, $Source:ident, $Dest:ident) => {
+        pub(crate) fn $name(
+            src: &[$Source],
+            dst: &mut [$Dest],
+            mask: &mut [u64],
+            kernel: Kernel,
+        ) -> usize {
+            assert_eq!(src.len(), dst.len(), "bulk buffer length mismatch");
+            assert!(mask.len() >= mask_len(src.len()), "mask buffer too short");
+            // Injected faults only reach the scalar loop.
+            #[cfg(feature = "fault-injection")]
+            let kernel = {
+                let _ = kernel;
+                Kernel::Scalar
+            };
+            match kernel {
+                Kernel::Swar => swar::$name(src, dst, mask),
+                // SAFETY: each vector kernel is only used if the CPU
+                // supports it.
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Kernel::Sse41 if kernel.is_supported() => unsafe { sse41::$name(src, dst, mask) },
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Kernel::Avx2 if kernel.is_supported() => unsafe { avx2::$name(src, dst, mask) },
+                #[cfg(all(feature = "avx512", any(target_arch = "x86", target_arch = "x86_64")))]
+                Kernel::Avx512 if kernel.is_supported() => unsafe { avx512::$name(src, dst, mask) },
+                _ => masked_by(dst, mask, |i| src[i]),
+            }
+        }
+    };
+}
+
+make_pair!(u32_u16, u32, u16);
+make_pair!(u32_i16, u32, i16);
+make_pair!(i32_u16, i32, u16);
+make_pair!(i32_i16, i32, i16);
+make_pair!(u32_u8, u32, u8);
+make_pair!(u32_i8, u32, i8);
+make_pair!(i32_u8, i32, u8);
+make_pair!(i32_i8, i32, i8);
+make_pair!(u16_u8, u16, u8);
+make_pair!(u16_i8, u16, i8);
+make_pair!(i16_u8, i16, u8);
+make_pair!(i16_i8, i16, i8);
+
+/// Portable kernels, which check every lane of a 64-bit word at once.
+mod swar {
+    use super::tail;
+    use core::mem::size_of;
+
+    macro_rules! make_swar {
+        ($name:ident, $Source:ident, $Unsigned:ident, $Dest:ident) => {
+            pub(super) fn $name(src: &[$Source], dst: &mut [$Dest], mask: &mut [u64]) -> usize {
+                const W: usize = size_of::<$Source>() * 8;
+                const N: usize = 64 / W;
+                const LANE: u64 = u64::MAX >> (64 - W);
+                // The top bit of each lane, and the rest.
+                const H: u64 = (u64::MAX / LANE) << (W - 1);
+                const L: u64 = !H;
+                const SIGNED: bool = <$Source>::MIN != 0 && <$Dest>::MIN != 0;
+                // The bits of a lane that must be zero for it to fit. If
+                // both types are signed, they're the bits of `x ^ x << 1`
+                // that must be zero, i.e. the copies of the sign bit.
+                const HIGH: u64 = {
+                    let max = <$Dest>::MAX as u64;
+                    let low = if SIGNED { max << 1 | 1 } else { max };
+                    (u64::MAX / LANE) * (LANE & !low)
+                };
+
+                let blocks = src.len() / 64;
+                let mut failed = 0;
+                for b in 0..blocks {
+                    let mut word = 0;
+                    for w in 0..64 / N {
+                        let base = b * 64 + w * N;
+                        let mut x = 0u64;
+                        for j in 0..N {
+                            x |= (src[base + j] as $Unsigned as u64) << (W * j);
+                        }
+                        let z = if SIGNED {
+                            (x ^ x << 1) & HIGH
+                        } else {
+                            x & HIGH
+                        };
+                        // The top bit of each lane where `z` isn't zero.
+                        let fails = ((z & L) + L | z) & H;
+                        for j in 0..N {
+                            let value = (x >> (W * j)) as $Unsigned as $Source;
+                            let fail = fails >> (W * j + W - 1) & 1;
+                            dst[base + j] = if fail == 0 {
+                                value as $Dest
+                            } else if value <= 0 as $Source {
+                                <$Dest>::MIN
+                            } else {
+                                <$Dest>::MAX
+                            };
+                            word |= fail << (w * N + j);
+                        }
+                    }
+                    mask[b] = word;
+                    failed += word.count_ones() as usize;
+                }
+                failed + tail(src, dst, mask, blocks)
+            }
+        };
+    }
+
+    make_swar!(u32_u16, u32, u32, u16);
+    make_swar!(u32_i16, u32, u32, i16);
+    make_swar!(i32_u16, i32, u32, u16);
+    make_swar!(i32_i16, i32, u32, i16);
+    make_swar!(u32_u8, u32, u32, u8);
+    make_swar!(u32_i8, u32, u32, i8);
+    make_swar!(i32_u8, i32, u32, u8);
+    make_swar!(i32_i8, i32, u32, i8);
+    make_swar!(u16_u8, u16, u16, u8);
+    make_swar!(u16_i8, u16, u16, i8);
+    make_swar!(i16_u8, i16, u16, u8);
+    make_swar!(i16_i8, i16, u16, i8);
+}
+
+/// SSE4.1 kernels, 16 lanes at a time.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod sse41 {
+    use super::tail;
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    // Clamp the lanes of `$x` into the range of `$Dest`.
+    macro_rules! clamp {
+        (u32, $Dest:ident, $x:expr) => {
+            _mm_min_epu32($x, _mm_set1_epi32(<$Dest>::MAX as i32))
+        };
+        (i32, $Dest:ident, $x:expr) => {
+            _mm_max_epi32(
+                _mm_min_epi32($x, _mm_set1_epi32(<$Dest>::MAX as i32)),
+                _mm_set1_epi32(<$Dest>::MIN as i32),
+            )
+        };
+        (u16, $Dest:ident, $x:expr) => {
+            _mm_min_epu16($x, _mm_set1_epi16(<$Dest>::MAX as i16))
+        };
+        (i16, $Dest:ident, $x:expr) => {
+            _mm_max_epi16(
+                _mm_min_epi16($x, _mm_set1_epi16(<$Dest>::MAX as i16)),
+                _mm_set1_epi16(<$Dest>::MIN as i16),
+            )
+        };
+    }
+
+    // Pack two vectors of lanes that already fit into `$Dest`.
+    macro_rules! pack {
+        (u16, $a:expr, $b:expr) => {
+            _mm_packus_epi32($a, $b)
+        };
+        (i16, $a:expr, $b:expr) => {
+            _mm_packs_epi32($a, $b)
+        };
+        (u8, $a:expr, $b:expr) => {
+            _mm_packus_epi16($a, $b)
+        };
+        (i8, $a:expr, $b:expr) => {
+            _mm_packs_epi16($a, $b)
+        };
+    }
+
+    macro_rules! make_sse41 {
+        ($name:ident, $Source:ident, $Dest:ident, $shape:ident) => {
+            #[target_feature(enable = "sse4.1")]
+            pub(super) unsafe fn $name(
+                src: &[$Source],
+                dst: &mut [$Dest],
+                mask: &mut [u64],
+            ) -> usize {
+                let blocks = src.len() / 64;
+                let mut failed = 0;
+                for b in 0..blocks {
+                    let mut fits = 0;
+                    for k in 0..4 {
+                        let lane = b * 64 + k * 16;
+                        let s = src.as_ptr().add(lane) as *const __m128i;
+                        let d = dst.as_mut_ptr().add(lane) as *mut __m128i;
+                        let bits = make_sse41!(@$shape, $Source, $Dest, s, d);
+                        fits |= (bits as u16 as u64) << (k * 16);
+                    }
+                    mask[b] = !fits;
+                    failed += (!fits).count_ones() as usize;
+                }
+                failed + tail(src, dst, mask, blocks)
+            }
+        };
+        (@wide, $Source:ident, $Dest:ident, $s:expr, $d:expr) => {{
+            let x = [
+                _mm_loadu_si128($s),
+                _mm_loadu_si128($s.add(1)),
+                _mm_loadu_si128($s.add(2)),
+                _mm_loadu_si128($s.add(3)),
+            ];
+            let c = [
+                clamp!($Source, $Dest, x[0]),
+                clamp!($Source, $Dest, x[1]),
+                clamp!($Source, $Dest, x[2]),
+                clamp!($Source, $Dest, x[3]),
+            ];
+            let e01 = _mm_packs_epi32(_mm_cmpeq_epi32(c[0], x[0]), _mm_cmpeq_epi32(c[1], x[1]));
+            let e23 = _mm_packs_epi32(_mm_cmpeq_epi32(c[2], x[2]), _mm_cmpeq_epi32(c[3], x[3]));
+            make_sse41!(@store, $Dest, $d, c);
+            _mm_movemask_epi8(_mm_packs_epi16(e01, e23))
+        }};
+        (@narrow, $Source:ident, $Dest:ident, $s:expr, $d:expr) => {{
+            let x = [_mm_loadu_si128($s), _mm_loadu_si128($s.add(1))];
+            let c = [clamp!($Source, $Dest, x[0]), clamp!($Source, $Dest, x[1])];
+            _mm_storeu_si128($d, pack!($Dest, c[0], c[1]));
+            _mm_movemask_epi8(_mm_packs_epi16(
+                _mm_cmpeq_epi16(c[0], x[0]),
+                _mm_cmpeq_epi16(c[1], x[1]),
+            ))
+        }};
+        // 32-bit lanes into 16-bit lanes.
+        (@store, u16, $d:expr, $c:expr) => {
+            make_sse41!(@store16, u16, $d, $c)
+        };
+        (@store, i16, $d:expr, $c:expr) => {
+            make_sse41!(@store16, i16, $d, $c)
+        };
+        (@store16, $Dest:ident, $d:expr, $c:expr) => {
+            _mm_storeu_si128($d, pack!($Dest, $c[0], $c[1]));
+            _mm_storeu_si128($d.add(1), pack!($Dest, $c[2], $c[3]));
+        };
+        // 32-bit lanes into 8-bit lanes. The lanes already fit, so the
+        // first pack doesn't saturate.
+        (@store, $Dest:ident, $d:expr, $c:expr) => {
+            _mm_storeu_si128(
+                $d,
+                pack!(
+                    $Dest,
+                    _mm_packs_epi32($c[0], $c[1]),
+                    _mm_packs_epi32($c[2], $c[3])
+                ),
+            );
+        };
+    }
+
+    make_sse41!(u32_u16, u32, u16, wide);
+    make_sse41!(u32_i16, u32, i16, wide);
+    make_sse41!(i32_u16, i32, u16, wide);
+    make_sse41!(i32_i16, i32, i16, wide);
+    make_sse41!(u32_u8, u32, u8, wide);
+    make_sse41!(u32_i8, u32, i8, wide);
+    make_sse41!(i32_u8, i32, u8, wide);
+    make_sse41!(i32_i8, i32, i8, wide);
+    make_sse41!(u16_u8, u16, u8, narrow);
+    make_sse41!(u16_i8, u16, i8, narrow);
+    make_sse41!(i16_u8, i16, u8, narrow);
+    make_sse41!(i16_i8, i16, i8, narrow);
+}
+
+/// AVX2 kernels, 32 lanes at a time.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2 {
+    use super::tail;
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    macro_rules! clamp {
+        (u32, $Dest:ident, $x:expr) => {
+            _mm256_min_epu32($x, _mm256_set1_epi32(<$Dest>::MAX as i32))
+        };
+        (i32, $Dest:ident, $x:expr) => {
+            _mm256_max_epi32(
+                _mm256_min_epi32($x, _mm256_set1_epi32(<$Dest>::MAX as i32)),
+                _mm256_set1_epi32(<$Dest>::MIN as i32),
+            )
+        };
+        (u16, $Dest:ident, $x:expr) => {
+            _mm256_min_epu16($x, _mm256_set1_epi16(<$Dest>::MAX as i16))
+        };
+        (i16, $Dest:ident, $x:expr) => {
+            _mm256_max_epi16(
+                _mm256_min_epi16($x, _mm256_set1_epi16(<$Dest>::MAX as i16)),
+                _mm256_set1_epi16(<$Dest>::MIN as i16),
+            )
+        };
+    }
+
+    macro_rules! pack {
+        (u16, $a:expr, $b:expr) => {
+            _mm256_packus_epi32($a, $b)
+        };
+        (i16, $a:expr, $b:expr) => {
+            _mm256_packs_epi32($a, $b)
+        };
+        (u8, $a:expr, $b:expr) => {
+            _mm256_packus_epi16($a, $b)
+        };
+        (i8, $a:expr, $b:expr) => {
+            _mm256_packs_epi16($a, $b)
+        };
+    }
+
+    // The packs work within each 128-bit half, so their results need
+    // putting back in order: `QWORDS` after one pack, and `DWORDS` after
+    // two.
+    const QWORDS: i32 = 0b11_01_10_00;
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn dwords(x: __m256i) -> __m256i {
+        _mm256_permutevar8x32_epi32(x, _mm256_setr_epi32(0, 4, 1, 5, 2, 6, 3, 7))
+    }
+
+    macro_rules! make_avx2 {
+        ($name:ident, $Source:ident, $Dest:ident, $shape:ident) => {
+            #[target_feature(enable = "avx2")]
+            pub(super) unsafe fn $name(
+                src: &[$Source],
+                dst: &mut [$Dest],
+                mask: &mut [u64],
+            ) -> usize {
+                let blocks = src.len() / 64;
+                let mut failed = 0;
+                for b in 0..blocks {
+                    let mut fits = 0;
+                    for k in 0..2 {
+                        let lane = b * 64 + k * 32;
+                        let s = src.as_ptr().add(lane) as *const __m256i;
+                        let d = dst.as_mut_ptr().add(lane) as *mut __m256i;
+                        let bits = make_avx2!(@$shape, $Source, $Dest, s, d);
+                        fits |= (bits as u32 as u64) << (k * 32);
+                    }
+                    mask[b] = !fits;
+                    failed += (!fits).count_ones() as usize;
+                }
+                failed + tail(src, dst, mask, blocks)
+            }
+        };
+        (@wide, $Source:ident, $Dest:ident, $s:expr, $d:expr) => {{
+            let x = [
+                _mm256_loadu_si256($s),
+                _mm256_loadu_si256($s.add(1)),
+                _mm256_loadu_si256($s.add(2)),
+                _mm256_loadu_si256($s.add(3)),
+            ];
+            let c = [
+                clamp!($Source, $Dest, x[0]),
+                clamp!($Source, $Dest, x[1]),
+                clamp!($Source, $Dest, x[2]),
+                clamp!($Source, $Dest, x[3]),
+            ];
+            let e01 = _mm256_packs_epi32(
+                _mm256_cmpeq_epi32(c[0], x[0]),
+                _mm256_cmpeq_epi32(c[1], x[1]),
+            );
+            let e23 = _mm256_packs_epi32(
+                _mm256_cmpeq_epi32(c[2], x[2]),
+                _mm256_cmpeq_epi32(c[3], x[3]),
+            );
+            make_avx2!(@store, $Dest, $d, c);
+            _mm256_movemask_epi8(dwords(_mm256_packs_epi16(e01, e23)))
+        }};
+        (@narrow, $Source:ident, $Dest:ident, $s:expr, $d:expr) => {{
+            let x = [_mm256_loadu_si256($s), _mm256_loadu_si256($s.add(1))];
+            let c = [clamp!($Source, $Dest, x[0]), clamp!($Source, $Dest, x[1])];
+            let packed = pack!($Dest, c[0], c[1]);
+            _mm256_storeu_si256($d, _mm256_permute4x64_epi64(packed, QWORDS));
+            let e = _mm256_packs_epi16(
+                _mm256_cmpeq_epi16(c[0], x[0]),
+                _mm256_cmpeq_epi16(c[1], x[1]),
+            );
+            _mm256_movemask_epi8(_mm256_permute4x64_epi64(e, QWORDS))
+        }};
+        (@store, u16, $d:expr, $c:expr) => {
+            make_avx2!(@store16, u16, $d, $c)
+        };
+        (@store, i16, $d:expr, $c:expr) => {
+            make_avx2!(@store16, i16, $d, $c)
+        };
+        (@store16, $Dest:ident, $d:expr, $c:expr) => {
+            let lo = pack!($Dest, $c[0], $c[1]);
+            let hi = pack!($Dest, $c[2], $c[3]);
+            _mm256_storeu_si256($d, _mm256_permute4x64_epi64(lo, QWORDS));
+            _mm256_storeu_si256($d.add(1), _mm256_permute4x64_epi64(hi, QWORDS));
+        };
+        (@store, $Dest:ident, $d:expr, $c:expr) => {
+            let packed = pack!(
+                $Dest,
+                _mm256_packs_epi32($c[0], $c[1]),
+                _mm256_packs_epi32($c[2], $c[3])
+            );
+            _mm256_storeu_si256($d, dwords(packed));
+        };
+    }
+
+    make_avx2!(u32_u16, u32, u16, wide);
+    make_avx2!(u32_i16, u32, i16, wide);
+    make_avx2!(i32_u16, i32, u16, wide);
+    make_avx2!(i32_i16, i32, i16, wide);
+    make_avx2!(u32_u8, u32, u8, wide);
+    make_avx2!(u32_i8, u32, i8, wide);
+    make_avx2!(i32_u8, i32, u8, wide);
+    make_avx2!(i32_i8, i32, i8, wide);
+    make_avx2!(u16_u8, u16, u8, narrow);
+    make_avx2!(u16_i8, u16, i8, narrow);
+    make_avx2!(i16_u8, i16, u8, narrow);
+    make_avx2!(i16_i8, i16, i8, narrow);
+}
+
+/// AVX-512 kernels, a whole block at a time, with the lanes that fit
+/// read straight out of a mask register.
+#[cfg(all(feature = "avx512", any(target_arch = "x86", target_arch = "x86_64")))]
+#[clippy::msrv = "1.89"]
+mod avx512 {
+    use super::tail;
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    macro_rules! clamp {
+        (u32, $Dest:ident, $x:expr) => {
+            _mm512_min_epu32($x, _mm512_set1_epi32(<$Dest>::MAX as i32))
+        };
+        (i32, $Dest:ident, $x:expr) => {
+            _mm512_max_epi32(
+                _mm512_min_epi32($x, _mm512_set1_epi32(<$Dest>::MAX as i32)),
+                _mm512_set1_epi32(<$Dest>::MIN as i32),
+            )
+        };
+        (u16, $Dest:ident, $x:expr) => {
+            _mm512_min_epu16($x, _mm512_set1_epi16(<$Dest>::MAX as i16))
+        };
+        (i16, $Dest:ident, $x:expr) => {
+            _mm512_max_epi16(
+                _mm512_min_epi16($x, _mm512_set1_epi16(<$Dest>::MAX as i16)),
+                _mm512_set1_epi16(<$Dest>::MIN as i16),
+            )
+        };
+    }
+
+    macro_rules! make_avx512 {
+        ($name:ident, $Source:ident, $Dest:ident, $shape:ident) => {
+            #[target_feature(enable = "avx512f,avx512bw")]
+            pub(super) unsafe fn $name(
+                src: &[$Source],
+                dst: &mut [$Dest],
+                mask: &mut [u64],
+            ) -> usize {
+                let blocks = src.len() / 64;
+                let mut failed = 0;
+                for b in 0..blocks {
+                    let s = src.as_ptr().add(b * 64);
+                    let d = dst.as_mut_ptr().add(b * 64);
+                    let fits = make_avx512!(@$shape, $Source, $Dest, s, d);
+                    mask[b] = !fits;
+                    failed += (!fits).count_ones() as usize;
+                }
+                failed + tail(src, dst, mask, blocks)
+            }
+        };
+        // 16 lanes of 32 bits in each vector.
+        (@wide, $Source:ident, $Dest:ident, $s:expr, $d:expr) => {{
+            let mut fits = 0;
+            for k in 0..4 {
+                let x = _mm512_loadu_si512($s.add(k * 16) as *const _);
+                let c = clamp!($Source, $Dest, x);
+                fits |= u64::from(_mm512_cmpeq_epi32_mask(c, x)) << (k * 16);
+                make_avx512!(@store, $Dest, $d.add(k * 16), c);
+            }
+            fits
+        }};
+        // 32 lanes of 16 bits in each vector.
+        (@narrow, $Source:ident, $Dest:ident, $s:expr, $d:expr) => {{
+            let mut fits = 0;
+            for k in 0..2 {
+                let x = _mm512_loadu_si512($s.add(k * 32) as *const _);
+                let c = clamp!($Source, $Dest, x);
+                fits |= u64::from(_mm512_cmpeq_epi16_mask(c, x)) << (k * 32);
+                _mm256_storeu_si256($d.add(k * 32) as *mut __m256i, _mm512_cvtepi16_epi8(c));
+            }
+            fits
+        }};
+        (@store, u16, $d:expr, $c:expr) => {
+            _mm256_storeu_si256($d as *mut __m256i, _mm512_cvtepi32_epi16($c))
+        };
+        (@store, i16, $d:expr, $c:expr) => {
+            _mm256_storeu_si256($d as *mut __m256i, _mm512_cvtepi32_epi16($c))
+        };
+        (@store, $Dest:ident, $d:expr, $c:expr) => {
+            _mm_storeu_si128($d as *mut __m128i, _mm512_cvtepi32_epi8($c))
+        };
+    }
+
+    make_avx512!(u32_u16, u32, u16, wide);
+    make_avx512!(u32_i16, u32, i16, wide);
+    make_avx512!(i32_u16, i32, u16, wide);
+    make_avx512!(i32_i16, i32, i16, wide);
+    make_avx512!(u32_u8, u32, u8, wide);
+    make_avx512!(u32_i8, u32, i8, wide);
+    make_avx512!(i32_u8, i32, u8, wide);
+    make_avx512!(i32_i8, i32, i8, wide);
+    make_avx512!(u16_u8, u16, u8, narrow);
+    make_avx512!(u16_i8, u16, i8, narrow);
+    make_avx512!(i16_u8, i16, u8, narrow);
+    make_avx512!(i16_i8, i16, i8, narrow);
+}
@@ -0,0 +1,113 @@
+//! Narrowing writes for [`byteorder`](https://docs.rs/byteorder).
+//!
+//! [`WriteNarrowExt`] extends every [`io::Write`] with methods that
+//! narrow a value and write it in a chosen byte order, like
+//! `byteorder::WriteBytesExt` does for values that are already the
+//! right width.
+//!
+//! - `write_*_try` methods use [`TryTruncate`]; a value that doesn't
+//!   fit is reported as an [`io::Error`] of kind `InvalidInput`, wrapping
+//!   a [`TruncateError`], so there's only one error path to handle.
+//! - `write_*_shrink` methods use [`Shrink`].
+//! - `write_*_chop` methods use [`Chop`], so they panic if the value
+//!   doesn't fit.
+//!
+//! ```rust
+//! use byteorder::{BigEndian, LittleEndian};
+//! use std::io::ErrorKind;
+//! use truncate_integer::byteorder::WriteNarrowExt;
+//!
+//! let mut out = Vec::new();
+//! out.write_u16_shrink::<BigEndian, _>(70000u64).unwrap();
+//! out.write_i32_try::<LittleEndian, _>(-2i64).unwrap();
+//! assert_eq!(out, [0xFF, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF]);
+//!
+//! let err = out.write_u8_try(256u32).unwrap_err();
+//! assert_eq!(err.kind(), ErrorKind::InvalidInput);
+//! assert_eq!(err.to_string(), "integer overflow");
+//! ```
+
+use crate::{Chop, Shrink, TruncateError, TryTruncate};
+use ::byteorder::{ByteOrder, WriteBytesExt};
+use std::io;
+
+fn narrow<Source, Dest>(value: Source) -> io::Result<Dest>
+where
+    Source: TryTruncate<Dest> + Copy + PartialOrd + Default,
+{
+    value.try_truncate().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            TruncateError::for_value(&value),
+        )
+    })
+}
+
+macro_rules! write_byte {
+    ($Dest:ty, $write:ident, $try:ident, $shrink:ident, $chop:ident) => {
+        #[doc = concat!("Narrow a value into a `", stringify!($Dest), "` with [`TryTruncate`], then write it.")]
+        #[inline]
+        fn $try<V>(&mut self, value: V) -> io::Result<()>
+        where
+            V: TryTruncate<$Dest> + Copy + PartialOrd + Default,
+        {
+            let value = narrow(value)?;
+            self.$write(value)
+        }
+
+        #[doc = concat!("Narrow a value into a `", stringify!($Dest), "` with [`Shrink`], then write it.")]
+        #[inline]
+        fn $shrink<V: Shrink<$Dest>>(&mut self, value: V) -> io::Result<()> {
+            self.$write(value.shrink())
+        }
+
+        #[doc = concat!("Narrow a value into a `", stringify!($Dest), "` with [`Chop`], then write it.")]
+        #[track_caller]
+        #[inline]
+        fn $chop<V: Chop<$Dest>>(&mut self, value: V) -> io::Result<()> {
+            self.$write(value.chop())
+        }
+    };
+}
+
+macro_rules! write_ordered {
+    ($Dest:ty, $write:ident, $try:ident, $shrink:ident, $chop:ident) => {
+        #[doc = concat!("Narrow a value into a `", stringify!($Dest), "` with [`TryTruncate`], then write it in byte order `B`.")]
+        #[inline]
+        fn $try<B, V>(&mut self, value: V) -> io::Result<()>
+        where
+            B: ByteOrder,
+            V: TryTruncate<$Dest> + Copy + PartialOrd + Default,
+        {
+            let value = narrow(value)?;
+            self.$write::<B>(value)
+        }
+
+        #[doc = concat!("Narrow a value into a `", stringify!($Dest), "` with [`Shrink`], then write it in byte order `B`.")]
+        #[inline]
+        fn $shrink<B: ByteOrder, V: Shrink<$Dest>>(&mut self, value: V) -> io::Result<()> {
+            self.$write::<B>(value.shrink())
+        }
+
+        #[doc = concat!("Narrow a value into a `", stringify!($Dest), "` with [`Chop`], then write it in byte order `B`.")]
+        #[track_caller]
+        #[inline]
+        fn $chop<B: ByteOrder, V: Chop<$Dest>>(&mut self, value: V) -> io::Result<()> {
+            self.$write::<B>(value.chop())
+        }
+    };
+}
+
+/// Extends [`io::Write`] with methods for narrowing and writing integers.
+pub trait WriteNarrowExt: io::Write {
+    write_byte! { u8, write_u8, write_u8_try, write_u8_shrink, write_u8_chop }
+    write_byte! { i8, write_i8, write_i8_try, write_i8_shrink, write_i8_chop }
+    write_ordered! { u16, write_u16, write_u16_try, write_u16_shrink, write_u16_chop }
+    write_ordered! { i16, write_i16, write_i16_try, write_i16_shrink, write_i16_chop }
+    write_ordered! { u32, write_u32, write_u32_try, write_u32_shrink, write_u32_chop }
+    write_ordered! { i32, write_i32, write_i32_try, write_i32_shrink, write_i32_chop }
+    write_ordered! { u64, write_u64, write_u64_try, write_u64_shrink, write_u64_chop }
+    write_ordered! { i64, write_i64, write_i64_try, write_i64_shrink, write_i64_chop }
+}
+
+impl<W: io::Write + ?Sized> WriteNarrowExt for W {}
@@ -0,0 +1,142 @@
+//! Reading integers from byte slices of any length.
+//!
+//! [`FromBytesTruncate`] reads an integer from a slice that may be
+//! longer than the integer, with the usual choice of what to do when
+//! the extra high-order bytes don't fit. Slices that are shorter than
+//! the integer are zero-extended (for unsigned types) or sign-extended
+//! (for signed types).
+//!
+//! The bytes are read as the same signedness as the destination type,
+//! so `[0xFF, 0xFF, 0x80]` is `-128` when read into an `i8`, but too
+//! large for a `u8`.
+//!
+//! ```rust
+//! use truncate_integer::bytes::FromBytesTruncate;
+//!
+//! assert_eq!(u16::try_truncate_from_be_bytes(&[0, 0, 0x12, 0x34]), Some(0x1234));
+//! assert_eq!(u16::try_truncate_from_be_bytes(&[0, 1, 0x12, 0x34]), None);
+//! assert_eq!(u16::shrink_from_be_bytes(&[0, 1, 0x12, 0x34]), u16::MAX);
+//! assert_eq!(u16::wrap_from_le_bytes(&[0x34, 0x12, 1, 0]), 0x1234);
+//! assert_eq!(i8::try_truncate_from_be_bytes(&[0xFF, 0xFF, 0x80]), Some(-128));
+//! assert_eq!(u32::try_truncate_from_be_bytes(&[0x12]), Some(0x12));
+//! ```
+
+/// Read an integer from a byte slice of any length.
+pub trait FromBytesTruncate: Sized {
+    /// Read a big-endian integer, if it fits.
+    fn try_truncate_from_be_bytes(bytes: &[u8]) -> Option<Self>;
+    /// Read a little-endian integer, if it fits.
+    fn try_truncate_from_le_bytes(bytes: &[u8]) -> Option<Self>;
+    /// Read a big-endian integer, saturating if it doesn't fit.
+    fn shrink_from_be_bytes(bytes: &[u8]) -> Self;
+    /// Read a little-endian integer, saturating if it doesn't fit.
+    fn shrink_from_le_bytes(bytes: &[u8]) -> Self;
+    /// Read a big-endian integer, keeping only the low-order bytes.
+    fn wrap_from_be_bytes(bytes: &[u8]) -> Self;
+    /// Read a little-endian integer, keeping only the low-order bytes.
+    fn wrap_from_le_bytes(bytes: &[u8]) -> Self;
+}
+
+/// Split a big-endian slice into its high-order bytes and up to `size`
+/// low-order bytes.
+#[inline]
+fn split_be(bytes: &[u8], size: usize) -> (&[u8], &[u8]) {
+    bytes.split_at(bytes.len().saturating_sub(size))
+}
+
+/// Split a little-endian slice into up to `size` low-order bytes and
+/// its high-order bytes.
+#[inline]
+fn split_le(bytes: &[u8], size: usize) -> (&[u8], &[u8]) {
+    bytes.split_at(bytes.len().min(size))
+}
+
+macro_rules! make_from_bytes {
+    ($Type:ty, $signed:expr) => {
+        impl FromBytesTruncate for $Type {
+            #[inline]
+            fn try_truncate_from_be_bytes(bytes: &[u8]) -> Option<Self> {
+                let (high, _) = split_be(bytes, core::mem::size_of::<$Type>());
+                let value = Self::wrap_from_be_bytes(bytes);
+                // The high bytes must all be the sign extension of the value.
+                let fill = if $signed && value < (0 as $Type) {
+                    0xFF
+                } else {
+                    0
+                };
+                if high.iter().all(|&b| b == fill) {
+                    Some(value)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn try_truncate_from_le_bytes(bytes: &[u8]) -> Option<Self> {
+                let (_, high) = split_le(bytes, core::mem::size_of::<$Type>());
+                let value = Self::wrap_from_le_bytes(bytes);
+                let fill = if $signed && value < (0 as $Type) {
+                    0xFF
+                } else {
+                    0
+                };
+                if high.iter().all(|&b| b == fill) {
+                    Some(value)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn shrink_from_be_bytes(bytes: &[u8]) -> Self {
+                match Self::try_truncate_from_be_bytes(bytes) {
+                    Some(value) => value,
+                    None if $signed && bytes[0] & 0x80 != 0 => <$Type>::MIN,
+                    None => <$Type>::MAX,
+                }
+            }
+
+            #[inline]
+            fn shrink_from_le_bytes(bytes: &[u8]) -> Self {
+                match Self::try_truncate_from_le_bytes(bytes) {
+                    Some(value) => value,
+                    None if $signed && bytes[bytes.len() - 1] & 0x80 != 0 => <$Type>::MIN,
+                    None => <$Type>::MAX,
+                }
+            }
+
+            #[inline]
+            fn wrap_from_be_bytes(bytes: &[u8]) -> Self {
+                const SIZE: usize = core::mem::size_of::<$Type>();
+                let (_, low) = split_be(bytes, SIZE);
+                let negative = $signed && low.first().map_or(false, |&b| b & 0x80 != 0);
+                let mut buf = [if negative { 0xFF } else { 0 }; SIZE];
+                buf[SIZE - low.len()..].copy_from_slice(low);
+                <$Type>::from_be_bytes(buf)
+            }
+
+            #[inline]
+            fn wrap_from_le_bytes(bytes: &[u8]) -> Self {
+                const SIZE: usize = core::mem::size_of::<$Type>();
+                let (low, _) = split_le(bytes, SIZE);
+                let negative = $signed && low.last().map_or(false, |&b| b & 0x80 != 0);
+                let mut buf = [if negative { 0xFF } else { 0 }; SIZE];
+                buf[..low.len()].copy_from_slice(low);
+                <$Type>::from_le_bytes(buf)
+            }
+        }
+    };
+}
+
+make_from_bytes!(u8, false);
+make_from_bytes!(u16, false);
+make_from_bytes!(u32, false);
+make_from_bytes!(u64, false);
+make_from_bytes!(u128, false);
+make_from_bytes!(usize, false);
+make_from_bytes!(i8, true);
+make_from_bytes!(i16, true);
+make_from_bytes!(i32, true);
+make_from_bytes!(i64, true);
+make_from_bytes!(i128, true);
+make_from_bytes!(isize, true);
@@ -0,0 +1,109 @@
+//! CAN bus identifiers.
+//!
+//! A CAN frame has either an 11-bit standard identifier or a 29-bit
+//! extended identifier. [`CanStdId`] and [`CanExtId`] hold identifiers
+//! that are known to fit, and the truncation traits are implemented from
+//! every integer type into them.
+//!
+//! ```rust
+//! use truncate_integer::can::{CanExtId, CanStdId};
+//! use truncate_integer::{Shrink, TryTruncate};
+//!
+//! let id: Option<CanStdId> = 0x7DFu32.try_truncate();
+//! assert_eq!(id.map(CanStdId::raw), Some(0x7DF));
+//! let id: Option<CanStdId> = 0x800u32.try_truncate();
+//! assert!(id.is_none());
+//!
+//! let id: CanExtId = u64::MAX.shrink();
+//! assert_eq!(id, CanExtId::MAX);
+//! assert_eq!(u32::from(id), 0x1FFF_FFFF);
+//! assert_eq!(id.to_string(), "0x1fffffff");
+//! ```
+
+use crate::bitsint::U;
+use crate::wide::Widen;
+use crate::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+use core::fmt;
+
+macro_rules! make_can_id {
+    ($Id:ident, $N:literal, $Raw:ty, $($Into:ty),*) => {
+        impl $Id {
+            /// The width in bits.
+            pub const BITS: u32 = $N;
+            /// The smallest identifier, zero.
+            pub const MIN: Self = $Id(U::<$N>::MIN);
+            /// The largest identifier.
+            pub const MAX: Self = $Id(U::<$N>::MAX);
+
+            /// Create an identifier.
+            ///
+            /// Returns `None` if `raw` doesn't fit.
+            #[inline]
+            pub fn new(raw: $Raw) -> Option<Self> {
+                raw.try_truncate()
+            }
+
+            /// Get the identifier.
+            #[inline]
+            pub fn raw(self) -> $Raw {
+                self.0.get()
+            }
+        }
+
+        impl<Source: Widen> TryTruncate<$Id> for Source {
+            #[inline]
+            fn try_truncate(self) -> Option<$Id> {
+                TryTruncate::<U<$N>>::try_truncate(self).map($Id)
+            }
+        }
+
+        impl<Source: Widen> Chop<$Id> for Source {
+            #[track_caller]
+            #[inline]
+            fn chop(self) -> $Id {
+                $Id(Chop::<U<$N>>::chop(self))
+            }
+        }
+
+        impl<Source: Widen> Shrink<$Id> for Source {
+            #[inline]
+            fn shrink(self) -> $Id {
+                $Id(Shrink::<U<$N>>::shrink(self))
+            }
+        }
+
+        impl<Source: Widen> TruncateUnchecked<$Id> for Source {
+            #[track_caller]
+            #[inline]
+            fn truncate_unchecked(self) -> $Id {
+                $Id(TruncateUnchecked::<U<$N>>::truncate_unchecked(self))
+            }
+        }
+
+        $(
+            impl From<$Id> for $Into {
+                #[inline]
+                fn from(id: $Id) -> Self {
+                    id.raw().into()
+                }
+            }
+        )*
+
+        impl fmt::Display for $Id {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:#x}", self.raw())
+            }
+        }
+    };
+}
+
+/// An 11-bit standard CAN identifier.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CanStdId(U<11>);
+
+/// A 29-bit extended CAN identifier.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CanExtId(U<29>);
+
+make_can_id!(CanStdId, 11, u16, u16, u32, u64, u128, i32, i64, i128);
+make_can_id!(CanExtId, 29, u32, u32, u64, u128, i64, i128);
@@ -0,0 +1,40 @@
+/// Convert an integer with an explicit truncation mode.
+///
+/// `cast!(x => T, mode)` expands to the trait call for `mode`:
+///
+/// - `checked`: [`TryTruncate`](crate::TryTruncate), returning `Option<T>`.
+/// - `panic`: [`Chop`](crate::Chop), panicking if the value doesn't fit.
+/// - `saturate`: [`Shrink`](crate::Shrink), returning the closest value
+///   that fits.
+/// - `wrap`: [`TruncateUnchecked`](crate::TruncateUnchecked), keeping
+///   the low-order bits, like `as`.
+///
+/// ```rust
+/// use truncate_integer::cast;
+///
+/// fn pack(len: u32, level: i32) -> Option<(u16, u8)> {
+///     let len = cast!(len => u16, checked)?;
+///     let level = cast!(level => u8, saturate);
+///     Some((len, level))
+/// }
+///
+/// assert_eq!(pack(1000, -5), Some((1000, 0)));
+/// assert_eq!(pack(70000, 5), None);
+/// assert_eq!(cast!(0x1234u32 => u8, wrap), 0x34);
+/// assert_eq!(cast!(200u32 => u8, panic), 200);
+/// ```
+#[macro_export]
+macro_rules! cast {
+    ($x:expr => $T:ty, checked) => {
+        <_ as $crate::TryTruncate<$T>>::try_truncate($x)
+    };
+    ($x:expr => $T:ty, panic) => {
+        <_ as $crate::Chop<$T>>::chop($x)
+    };
+    ($x:expr => $T:ty, saturate) => {
+        <_ as $crate::Shrink<$T>>::shrink($x)
+    };
+    ($x:expr => $T:ty, wrap) => {
+        <_ as $crate::TruncateUnchecked<$T>>::truncate_unchecked($x)
+    };
+}
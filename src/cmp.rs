@@ -0,0 +1,82 @@
+use core::cmp::Ordering;
+
+use crate::wide::{FromWide, Widen};
+
+/// Compare integers of different types.
+///
+/// The comparison is done on the mathematical values, so unlike comparing
+/// after an `as` cast, a negative number is always less than an unsigned one.
+///
+/// ```rust
+/// use std::cmp::Ordering;
+/// use truncate_integer::CrossCmp;
+///
+/// assert_eq!(u64::MAX.cmp_across(-1i32), Ordering::Greater);
+/// assert_eq!((-1i8).cmp_across(255u8), Ordering::Less);
+/// assert!(255u8.eq_across(255i64));
+/// ```
+pub trait CrossCmp<Rhs> {
+    /// Compare two integers of possibly different types.
+    fn cmp_across(self, other: Rhs) -> Ordering;
+
+    /// Test two integers of possibly different types for equality.
+    fn eq_across(self, other: Rhs) -> bool;
+}
+
+impl<Lhs, Rhs> CrossCmp<Rhs> for Lhs
+where
+    Lhs: Widen,
+    Rhs: Widen,
+{
+    #[inline]
+    fn cmp_across(self, other: Rhs) -> Ordering {
+        self.widen().cmp(&other.widen())
+    }
+
+    #[inline]
+    fn eq_across(self, other: Rhs) -> bool {
+        self.widen() == other.widen()
+    }
+}
+
+/// Return the lesser of two integers of different types, with saturating
+/// truncation into `T`.
+///
+/// ```rust
+/// use truncate_integer::min_shrink;
+///
+/// let limit: u8 = min_shrink(100_000u64, 200u8);
+/// assert_eq!(limit, 200);
+/// let limit: u8 = min_shrink(300u64, 1000u32);
+/// assert_eq!(limit, 255);
+/// ```
+#[inline]
+pub fn min_shrink<T, A, B>(a: A, b: B) -> T
+where
+    T: FromWide,
+    A: Widen,
+    B: Widen,
+{
+    T::from_wide_saturating(a.widen().min(b.widen()))
+}
+
+/// Return the greater of two integers of different types, with saturating
+/// truncation into `T`.
+///
+/// ```rust
+/// use truncate_integer::max_shrink;
+///
+/// let floor: i8 = max_shrink(-1000i32, -100i64);
+/// assert_eq!(floor, -100);
+/// let floor: u16 = max_shrink(-5i8, 3u64);
+/// assert_eq!(floor, 3);
+/// ```
+#[inline]
+pub fn max_shrink<T, A, B>(a: A, b: B) -> T
+where
+    T: FromWide,
+    A: Widen,
+    B: Widen,
+{
+    T::from_wide_saturating(a.widen().max(b.widen()))
+}
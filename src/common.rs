@@ -0,0 +1,133 @@
+/// The narrowest integer type that can hold any value of `Self` or `Rhs`.
+///
+/// This makes it possible to write generic code that widens two values,
+/// computes something, then shrinks the result.
+///
+/// ```rust
+/// use core::ops::Add;
+/// use truncate_integer::{CommonWider, Shrink, Wider};
+///
+/// fn add_shrink<A, B, T>(a: A, b: B) -> T
+/// where
+///     A: CommonWider<B>,
+///     Wider<A, B>: Add<Output = Wider<A, B>> + Shrink<T>,
+/// {
+///     (Wider::<A, B>::from(a) + Wider::<A, B>::from(b)).shrink()
+/// }
+///
+/// let x: u8 = add_shrink(200u32, -7i16);
+/// assert_eq!(x, 193);
+/// let x: i8 = add_shrink(u32::MAX, -1i16);
+/// assert_eq!(x, i8::MAX);
+///
+/// let _: Wider<u32, i16> = 0i64;
+/// ```
+///
+/// There is no common type for `u128` and a signed type, so those pairs
+/// don't implement this trait.
+pub trait CommonWider<Rhs>: Sized {
+    /// The common type.
+    type Output: From<Self> + From<Rhs>;
+}
+
+/// The narrowest integer type that can hold any value of `A` or `B`.
+pub type Wider<A, B> = <A as CommonWider<B>>::Output;
+
+macro_rules! make_common_wider {
+    ($A:ty, $B:ty => $Output:ty) => {
+        impl CommonWider<$B> for $A {
+            type Output = $Output;
+        }
+    };
+}
+
+make_common_wider!(u8, u8 => u8);
+make_common_wider!(u8, u16 => u16);
+make_common_wider!(u8, u32 => u32);
+make_common_wider!(u8, u64 => u64);
+make_common_wider!(u8, u128 => u128);
+make_common_wider!(u8, i8 => i16);
+make_common_wider!(u8, i16 => i16);
+make_common_wider!(u8, i32 => i32);
+make_common_wider!(u8, i64 => i64);
+make_common_wider!(u8, i128 => i128);
+make_common_wider!(u16, u8 => u16);
+make_common_wider!(u16, u16 => u16);
+make_common_wider!(u16, u32 => u32);
+make_common_wider!(u16, u64 => u64);
+make_common_wider!(u16, u128 => u128);
+make_common_wider!(u16, i8 => i32);
+make_common_wider!(u16, i16 => i32);
+make_common_wider!(u16, i32 => i32);
+make_common_wider!(u16, i64 => i64);
+make_common_wider!(u16, i128 => i128);
+make_common_wider!(u32, u8 => u32);
+make_common_wider!(u32, u16 => u32);
+make_common_wider!(u32, u32 => u32);
+make_common_wider!(u32, u64 => u64);
+make_common_wider!(u32, u128 => u128);
+make_common_wider!(u32, i8 => i64);
+make_common_wider!(u32, i16 => i64);
+make_common_wider!(u32, i32 => i64);
+make_common_wider!(u32, i64 => i64);
+make_common_wider!(u32, i128 => i128);
+make_common_wider!(u64, u8 => u64);
+make_common_wider!(u64, u16 => u64);
+make_common_wider!(u64, u32 => u64);
+make_common_wider!(u64, u64 => u64);
+make_common_wider!(u64, u128 => u128);
+make_common_wider!(u64, i8 => i128);
+make_common_wider!(u64, i16 => i128);
+make_common_wider!(u64, i32 => i128);
+make_common_wider!(u64, i64 => i128);
+make_common_wider!(u64, i128 => i128);
+make_common_wider!(u128, u8 => u128);
+make_common_wider!(u128, u16 => u128);
+make_common_wider!(u128, u32 => u128);
+make_common_wider!(u128, u64 => u128);
+make_common_wider!(u128, u128 => u128);
+make_common_wider!(i8, u8 => i16);
+make_common_wider!(i8, u16 => i32);
+make_common_wider!(i8, u32 => i64);
+make_common_wider!(i8, u64 => i128);
+make_common_wider!(i8, i8 => i8);
+make_common_wider!(i8, i16 => i16);
+make_common_wider!(i8, i32 => i32);
+make_common_wider!(i8, i64 => i64);
+make_common_wider!(i8, i128 => i128);
+make_common_wider!(i16, u8 => i16);
+make_common_wider!(i16, u16 => i32);
+make_common_wider!(i16, u32 => i64);
+make_common_wider!(i16, u64 => i128);
+make_common_wider!(i16, i8 => i16);
+make_common_wider!(i16, i16 => i16);
+make_common_wider!(i16, i32 => i32);
+make_common_wider!(i16, i64 => i64);
+make_common_wider!(i16, i128 => i128);
+make_common_wider!(i32, u8 => i32);
+make_common_wider!(i32, u16 => i32);
+make_common_wider!(i32, u32 => i64);
+make_common_wider!(i32, u64 => i128);
+make_common_wider!(i32, i8 => i32);
+make_common_wider!(i32, i16 => i32);
+make_common_wider!(i32, i32 => i32);
+make_common_wider!(i32, i64 => i64);
+make_common_wider!(i32, i128 => i128);
+make_common_wider!(i64, u8 => i64);
+make_common_wider!(i64, u16 => i64);
+make_common_wider!(i64, u32 => i64);
+make_common_wider!(i64, u64 => i128);
+make_common_wider!(i64, i8 => i64);
+make_common_wider!(i64, i16 => i64);
+make_common_wider!(i64, i32 => i64);
+make_common_wider!(i64, i64 => i64);
+make_common_wider!(i64, i128 => i128);
+make_common_wider!(i128, u8 => i128);
+make_common_wider!(i128, u16 => i128);
+make_common_wider!(i128, u32 => i128);
+make_common_wider!(i128, u64 => i128);
+make_common_wider!(i128, i8 => i128);
+make_common_wider!(i128, i16 => i128);
+make_common_wider!(i128, i32 => i128);
+make_common_wider!(i128, i64 => i128);
+make_common_wider!(i128, i128 => i128);
@@ -0,0 +1,141 @@
+//! A compact, length-prefixed integer encoding.
+//!
+//! Each integer is written as a header byte followed by only as many
+//! little-endian bytes as the value needs, as counted by
+//! [`MinBits`](crate::MinBits). The encoding depends only on the value,
+//! not on its type, so a value written from a `u64` can be read back
+//! into a `u8` if it fits.
+//!
+//! The low five bits of the header are the number of value bytes, from
+//! 0 to 16. If the high bit is set, the value is negative, and the
+//! omitted high-order bytes are `0xFF`; otherwise they are zero. Zero
+//! is encoded as a single header byte.
+//!
+//! ```rust
+//! use truncate_integer::compact::{decode, encode, CompactError};
+//! use truncate_integer::TruncateError;
+//!
+//! let mut buf = [0u8; 8];
+//! assert_eq!(encode(300u64, &mut buf), Ok(3));
+//! assert_eq!(buf[..3], [0x02, 0x2C, 0x01]);
+//! assert_eq!(decode::<u16>(&buf), Ok((300, 3)));
+//! assert_eq!(decode::<u8>(&buf), Err(CompactError::Truncate(TruncateError::Overflow)));
+//!
+//! assert_eq!(encode(-2i32, &mut buf), Ok(2));
+//! assert_eq!(decode::<i8>(&buf), Ok((-2, 2)));
+//! ```
+
+use crate::wide::{Primitive, Wide};
+use crate::{MinBits, TruncateError};
+use core::fmt;
+
+/// The longest possible encoding of an integer.
+pub const MAX_ENCODED_LEN: usize = 17;
+
+const NEGATIVE: u8 = 0x80;
+
+/// The error returned when encoding or decoding fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CompactError {
+    /// The buffer is too short.
+    BufferTooShort,
+    /// The header byte is not valid.
+    InvalidHeader(u8),
+    /// The value doesn't fit into the destination type.
+    Truncate(TruncateError),
+}
+
+impl fmt::Display for CompactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompactError::BufferTooShort => f.write_str("buffer too short"),
+            CompactError::InvalidHeader(header) => write!(f, "invalid header byte {:#04x}", header),
+            CompactError::Truncate(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CompactError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CompactError::Truncate(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<TruncateError> for CompactError {
+    fn from(e: TruncateError) -> Self {
+        CompactError::Truncate(e)
+    }
+}
+
+/// The header byte for a value.
+#[inline]
+fn header(value: Wide) -> u8 {
+    let bits = match value {
+        Wide::Negative(x) => x.min_bits(),
+        Wide::NonNegative(x) => x.min_bits(),
+    };
+    let len = ((bits + 7) / 8) as u8;
+    match value {
+        Wide::Negative(_) => len | NEGATIVE,
+        Wide::NonNegative(_) => len,
+    }
+}
+
+/// The number of bytes needed to encode a value, including the header.
+#[inline]
+pub fn encoded_len<T: Primitive>(value: T) -> usize {
+    1 + (header(value.widen()) & !NEGATIVE) as usize
+}
+
+/// Encode a value at the start of `buf`.
+///
+/// Returns the number of bytes written, which is at most
+/// [`MAX_ENCODED_LEN`].
+pub fn encode<T: Primitive>(value: T, buf: &mut [u8]) -> Result<usize, CompactError> {
+    let wide = value.widen();
+    let header = header(wide);
+    let len = (header & !NEGATIVE) as usize;
+    let out = buf.get_mut(..1 + len).ok_or(CompactError::BufferTooShort)?;
+    out[0] = header;
+    out[1..].copy_from_slice(&wide.to_bits().to_le_bytes()[..len]);
+    Ok(1 + len)
+}
+
+/// Decode a value from the start of `buf`, failing if it doesn't fit
+/// into `T`.
+///
+/// Returns the value and the number of bytes read.
+pub fn decode<T: Primitive>(buf: &[u8]) -> Result<(T, usize), CompactError> {
+    let (&header, rest) = buf.split_first().ok_or(CompactError::BufferTooShort)?;
+    let len = (header & !NEGATIVE) as usize;
+    if len > 16 {
+        return Err(CompactError::InvalidHeader(header));
+    }
+    let bytes = rest.get(..len).ok_or(CompactError::BufferTooShort)?;
+    let negative = header & NEGATIVE != 0;
+
+    let mut wide = [if negative { 0xFF } else { 0 }; 16];
+    wide[..len].copy_from_slice(bytes);
+    let bits = u128::from_le_bytes(wide);
+    let wide = if negative {
+        // Only a full-width value can lose its sign bit.
+        if (bits as i128) >= 0 {
+            return Err(CompactError::InvalidHeader(header));
+        }
+        Wide::Negative(bits as i128)
+    } else {
+        Wide::NonNegative(bits)
+    };
+
+    let value = T::from_wide_checked(wide).ok_or(if wide < T::MIN_WIDE {
+        TruncateError::Underflow
+    } else {
+        TruncateError::Overflow
+    })?;
+    Ok((value, 1 + len))
+}
@@ -0,0 +1,152 @@
+//! Logarithmic companding: lossy narrowing with bounded relative error.
+//!
+//! Narrowing with [`Shrink`](crate::Shrink) keeps small values exact and
+//! clips large ones. Companding instead spends the narrow code on a
+//! logarithmic scale, so every value keeps roughly the same number of
+//! significant bits.
+//!
+//! - [`mulaw_encode`] and [`alaw_encode`] are the G.711 µ-law and A-law
+//!   codecs, between 16-bit samples and 8-bit codes.
+//! - [`log_shrink`] encodes any `u64` as a tiny floating point number
+//!   with `mantissa_bits` bits of precision, for telemetry values with
+//!   a large dynamic range. [`log_expand`] decodes it.
+//!
+//! ```rust
+//! use truncate_integer::companding::{log_expand, log_shrink, mulaw_decode, mulaw_encode};
+//!
+//! assert_eq!(mulaw_encode(0), 0xFF);
+//! assert_eq!(mulaw_decode(mulaw_encode(1000)), 988);
+//!
+//! let code: u8 = log_shrink(1_000_000, 3);
+//! assert_eq!(log_expand(code, 3), 983_040);
+//! ```
+
+use crate::wide::{Primitive, Wide};
+use crate::Shrink;
+
+const MULAW_BIAS: i32 = 0x84;
+const MULAW_CLIP: i32 = 32635;
+
+/// The position of the highest set bit, counting from 1.
+#[inline]
+fn bit_len(value: u64) -> u32 {
+    64 - value.leading_zeros()
+}
+
+/// Encode a 16-bit sample as a G.711 µ-law code.
+pub fn mulaw_encode(sample: i16) -> u8 {
+    let sample = i32::from(sample);
+    let (sign, magnitude) = if sample < 0 {
+        (0x80, -sample)
+    } else {
+        (0, sample)
+    };
+    let biased = magnitude.min(MULAW_CLIP) + MULAW_BIAS;
+    let exponent = bit_len(biased as u64) - 8;
+    let mantissa = (biased >> (exponent + 3)) & 0x0F;
+    !(sign | (exponent << 4) as i32 | mantissa) as u8
+}
+
+/// Decode a G.711 µ-law code into a 16-bit sample.
+pub fn mulaw_decode(code: u8) -> i16 {
+    let code = i32::from(!code);
+    let magnitude = (((code & 0x0F) << 3) + MULAW_BIAS) << ((code & 0x70) >> 4);
+    let sample = if code & 0x80 != 0 {
+        MULAW_BIAS - magnitude
+    } else {
+        magnitude - MULAW_BIAS
+    };
+    sample as i16
+}
+
+/// Encode a 16-bit sample as a G.711 A-law code.
+pub fn alaw_encode(sample: i16) -> u8 {
+    let sample = i32::from(sample) >> 3;
+    let (mask, magnitude) = if sample >= 0 {
+        (0xD5, sample)
+    } else {
+        (0x55, -sample - 1)
+    };
+    let segment = bit_len(magnitude as u64).saturating_sub(5);
+    let code = if segment >= 8 {
+        0x7F
+    } else {
+        let shift = segment.max(1);
+        (segment << 4) as i32 | ((magnitude >> shift) & 0x0F)
+    };
+    (code ^ mask) as u8
+}
+
+/// Decode a G.711 A-law code into a 16-bit sample.
+pub fn alaw_decode(code: u8) -> i16 {
+    let code = i32::from(code ^ 0x55);
+    let segment = (code & 0x70) >> 4;
+    let mut magnitude = (code & 0x0F) << 4;
+    magnitude += match segment {
+        0 => 8,
+        _ => 0x108,
+    };
+    if segment > 1 {
+        magnitude <<= segment - 1;
+    }
+    let sample = if code & 0x80 != 0 {
+        magnitude
+    } else {
+        -magnitude
+    };
+    sample as i16
+}
+
+/// Encode `value` logarithmically, keeping `mantissa_bits` bits of
+/// precision, and narrow the code into `T`.
+///
+/// Values below `2^(mantissa_bits + 1)` are encoded exactly. Larger ones
+/// are rounded to the nearest representable value, with halfway cases
+/// rounded up, so the relative error is at most `2^-(mantissa_bits + 1)`.
+/// If the code doesn't fit in `T`, it saturates.
+///
+/// # Panics
+///
+/// Panics if `mantissa_bits` is 64 or more.
+#[track_caller]
+pub fn log_shrink<T: Primitive>(value: u64, mantissa_bits: u32) -> T {
+    assert!(mantissa_bits < 64, "invalid mantissa width");
+    let code = if bit_len(value) <= mantissa_bits {
+        u128::from(value)
+    } else {
+        let exponent = bit_len(value) - mantissa_bits;
+        let fraction = (value >> (exponent - 1)) - (1 << mantissa_bits);
+        let code = u128::from(exponent) << mantissa_bits | u128::from(fraction);
+        // Round on the first bit that was shifted out. A carry out of
+        // the fraction moves to the next exponent, which is still right.
+        let round = exponent >= 2 && value >> (exponent - 2) & 1 != 0;
+        code + round as u128
+    };
+    T::from_wide_saturating(Wide::NonNegative(code))
+}
+
+/// Decode a code produced by [`log_shrink`] with the same
+/// `mantissa_bits`.
+///
+/// If the value doesn't fit in a `u64`, it saturates.
+///
+/// # Panics
+///
+/// Panics if `mantissa_bits` is 64 or more.
+#[track_caller]
+pub fn log_expand<T: Primitive>(code: T, mantissa_bits: u32) -> u64 {
+    assert!(mantissa_bits < 64, "invalid mantissa width");
+    let code = match code.widen() {
+        Wide::NonNegative(x) => x,
+        Wide::Negative(_) => return 0,
+    };
+    let exponent = code >> mantissa_bits;
+    let fraction = code & ((1 << mantissa_bits) - 1);
+    if exponent == 0 {
+        return fraction as u64;
+    }
+    if exponent > 64 {
+        return u64::MAX;
+    }
+    (((1 << mantissa_bits) + fraction) << (exponent - 1)).shrink()
+}
@@ -0,0 +1,264 @@
+//! A machine-readable table of this crate's behavior.
+//!
+//! [`table`] evaluates every truncation impl between primitive types, in
+//! every mode, at the boundary values of its source and destination
+//! types, and [`write_csv`] and [`write_json`] write the result. The
+//! output is deterministic, so a table saved from one version can be
+//! diffed against another, or used as golden data when porting these
+//! semantics to another language.
+//!
+//! The `truncate-conformance` binary writes the same table to stdout:
+//!
+//! ```text
+//! cargo run --features conformance --bin truncate-conformance -- --csv
+//! ```
+//!
+//! ```rust
+//! use truncate_integer::conformance::{table, Mode, Outcome};
+//!
+//! let rows = table();
+//! let row = rows
+//!     .iter()
+//!     .find(|r| r.source == "u16" && r.dest == "u8" && r.mode == Mode::Shrink)
+//!     .unwrap();
+//! assert_eq!(row.input.to_string(), "0");
+//! assert_eq!(row.output, Outcome::Value(0u8.into()));
+//! ```
+//!
+//! [`Chop`] panics exactly when [`TryTruncate`] returns `None`, so its
+//! rows come from the same check that `chop` makes, without panicking.
+//!
+//! With the `fault-injection` feature, the `try` and `shrink` rows see
+//! any faults injected while [`table`] runs. Injection is off unless
+//! `fault::set_rate` has been called, and the table is only
+//! deterministic while it stays off.
+//!
+//! [`Chop`]: crate::Chop
+
+use crate::wide::{Primitive, Wide};
+use crate::{Shrink, TruncateUnchecked, TryTruncate};
+use core::fmt;
+use std::io::{self, Write};
+use std::vec::Vec;
+
+/// A truncation mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Mode {
+    /// [`TryTruncate`]
+    Try,
+    /// [`Shrink`]
+    Shrink,
+    /// [`Chop`](crate::Chop)
+    Chop,
+    /// [`TruncateUnchecked`]
+    Unchecked,
+}
+
+impl Mode {
+    /// The name used in the table.
+    pub fn name(self) -> &'static str {
+        match self {
+            Mode::Try => "try",
+            Mode::Shrink => "shrink",
+            Mode::Chop => "chop",
+            Mode::Unchecked => "unchecked",
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An integer value of any primitive type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Value(Wide);
+
+impl<T: Primitive> From<T> for Value {
+    fn from(value: T) -> Self {
+        Value(value.widen())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Wide::Negative(x) => x.fmt(f),
+            Wide::NonNegative(x) => x.fmt(f),
+        }
+    }
+}
+
+/// What a conversion did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The conversion returned this value.
+    Value(Value),
+    /// [`TryTruncate`] returned `None`.
+    None,
+    /// [`Chop`](crate::Chop) panicked.
+    Panic,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Value(value) => value.fmt(f),
+            Outcome::None => f.write_str("none"),
+            Outcome::Panic => f.write_str("panic"),
+        }
+    }
+}
+
+/// One conversion of one value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Row {
+    /// The name of the source type, such as `"u32"`.
+    pub source: &'static str,
+    /// The name of the destination type.
+    pub dest: &'static str,
+    /// The truncation mode.
+    pub mode: Mode,
+    /// The value converted.
+    pub input: Value,
+    /// The result.
+    pub output: Outcome,
+}
+
+/// Add or subtract one, if the result is still a 128-bit integer.
+fn step(value: Wide, up: bool) -> Option<Wide> {
+    match (value, up) {
+        (Wide::NonNegative(x), true) => x.checked_add(1).map(Wide::NonNegative),
+        (Wide::Negative(-1), true) => Some(Wide::NonNegative(0)),
+        (Wide::Negative(x), true) => Some(Wide::Negative(x + 1)),
+        (Wide::NonNegative(0), false) => Some(Wide::Negative(-1)),
+        (Wide::NonNegative(x), false) => Some(Wide::NonNegative(x - 1)),
+        (Wide::Negative(x), false) => x.checked_sub(1).map(Wide::Negative),
+    }
+}
+
+/// The boundary values of `S` and `D` that `S` can hold, in order.
+fn inputs<S: Primitive, D: Primitive>() -> Vec<S> {
+    let mut wide = Vec::new();
+    for &edge in &[
+        S::MIN_WIDE,
+        S::MAX_WIDE,
+        D::MIN_WIDE,
+        D::MAX_WIDE,
+        Wide::NonNegative(0),
+    ] {
+        wide.push(edge);
+        wide.extend(step(edge, true));
+        wide.extend(step(edge, false));
+    }
+    wide.sort();
+    wide.dedup();
+    wide.into_iter().filter_map(S::from_wide_checked).collect()
+}
+
+/// Evaluate every mode for one pair of types.
+///
+/// `chop` is `Chop::chop` without the panic: it returns `None` where
+/// `chop` would panic.
+fn pair_rows<S, D>(
+    source: &'static str,
+    dest: &'static str,
+    chop: fn(S) -> Option<D>,
+    rows: &mut Vec<Row>,
+) where
+    S: Primitive + TryTruncate<D> + Shrink<D> + TruncateUnchecked<D>,
+    D: Primitive,
+{
+    for value in inputs::<S, D>() {
+        let mut row = |mode, output| {
+            rows.push(Row {
+                source,
+                dest,
+                mode,
+                input: value.into(),
+                output,
+            })
+        };
+        let output = match TryTruncate::<D>::try_truncate(value) {
+            Some(x) => Outcome::Value(x.into()),
+            None => Outcome::None,
+        };
+        row(Mode::Try, output);
+        row(
+            Mode::Shrink,
+            Outcome::Value(Shrink::<D>::shrink(value).into()),
+        );
+        let output = match chop(value) {
+            Some(x) => Outcome::Value(x.into()),
+            None => Outcome::Panic,
+        };
+        row(Mode::Chop, output);
+        row(
+            Mode::Unchecked,
+            Outcome::Value(TruncateUnchecked::<D>::truncate_unchecked(value).into()),
+        );
+    }
+}
+
+/// Evaluate every truncation impl between primitive types.
+///
+/// Rows are sorted by source type, destination type, mode and input.
+pub fn table() -> Vec<Row> {
+    let mut rows = Vec::new();
+    macro_rules! pair {
+        ($Source:ident, $Dest:ident) => {
+            pair_rows::<$Source, $Dest>(
+                stringify!($Source),
+                stringify!($Dest),
+                |x| narrow!($Source, $Dest, x),
+                &mut rows,
+            )
+        };
+    }
+
+    crate::truncate_pairs!(pair);
+
+    rows.sort_by_key(|r| (type_order(r.source), type_order(r.dest), r.mode, r.input));
+    rows
+}
+
+/// Sort types by width, then unsigned before signed.
+fn type_order(name: &str) -> usize {
+    const ORDER: [&str; 12] = [
+        "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize",
+    ];
+    ORDER.iter().position(|&n| n == name).unwrap_or(ORDER.len())
+}
+
+/// Write `rows` as CSV, with a header line.
+pub fn write_csv<W: Write>(mut out: W, rows: &[Row]) -> io::Result<()> {
+    writeln!(out, "source,dest,mode,input,output")?;
+    for row in rows {
+        writeln!(
+            out,
+            "{},{},{},{},{}",
+            row.source, row.dest, row.mode, row.input, row.output
+        )?;
+    }
+    Ok(())
+}
+
+/// Write `rows` as a JSON array of objects, one per line.
+///
+/// Values are written as strings, since most JSON readers can't hold a
+/// 128-bit integer exactly.
+pub fn write_json<W: Write>(mut out: W, rows: &[Row]) -> io::Result<()> {
+    writeln!(out, "[")?;
+    for (i, row) in rows.iter().enumerate() {
+        let comma = if i + 1 < rows.len() { "," } else { "" };
+        writeln!(
+            out,
+            r#"  {{"source":"{}","dest":"{}","mode":"{}","input":"{}","output":"{}"}}{}"#,
+            row.source, row.dest, row.mode, row.input, row.output, comma
+        )?;
+    }
+    writeln!(out, "]")
+}
@@ -0,0 +1,84 @@
+//! Saturating counter arrays.
+//!
+//! Count-min sketches, counting Bloom filters and histograms keep many
+//! small counters, and a counter that wraps around to zero is much worse
+//! than one that sticks at its maximum. [`SaturatingSlice`] adds
+//! saturating updates to slices of unsigned integers.
+//!
+//! ```rust
+//! use truncate_integer::counter::SaturatingSlice;
+//!
+//! let mut counts = [0u8; 4];
+//! counts.bump_saturating(1);
+//! counts.add_saturating(2, 1000);
+//! assert_eq!(counts, [0, 1, 255, 0]);
+//!
+//! let other = [10u8, 255, 1, 0];
+//! counts.merge_saturating(&other);
+//! assert_eq!(counts, [10, 255, 255, 0]);
+//! ```
+
+use crate::Shrink;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Saturating updates for a slice of counters.
+pub trait SaturatingSlice: private::Sealed {
+    /// Increment the counter at `idx`, unless it's already at its
+    /// maximum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn bump_saturating(&mut self, idx: usize);
+
+    /// Add `delta` to the counter at `idx`, saturating at its maximum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn add_saturating(&mut self, idx: usize, delta: u32);
+
+    /// Add each counter in `other` to the counter at the same position,
+    /// saturating at the maximum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    fn merge_saturating(&mut self, other: &Self);
+}
+
+macro_rules! make_saturating_slice {
+    ($($Type:ty),*) => {
+        $(
+            impl private::Sealed for [$Type] {}
+
+            impl SaturatingSlice for [$Type] {
+                #[track_caller]
+                #[inline]
+                fn bump_saturating(&mut self, idx: usize) {
+                    self[idx] = self[idx].saturating_add(1);
+                }
+
+                #[track_caller]
+                #[inline]
+                fn add_saturating(&mut self, idx: usize, delta: u32) {
+                    let sum = self[idx] as u128 + delta as u128;
+                    self[idx] = sum.shrink();
+                }
+
+                #[track_caller]
+                fn merge_saturating(&mut self, other: &Self) {
+                    assert_eq!(self.len(), other.len(), "counter slice length mismatch");
+                    for (count, &other) in self.iter_mut().zip(other) {
+                        *count = count.saturating_add(other);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+make_saturating_slice!(u8, u16, u32, u64);
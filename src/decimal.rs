@@ -0,0 +1,131 @@
+//! Keeping the first or last few decimal digits of an integer.
+//!
+//! One-time passwords, check digits and shortened displays truncate in
+//! base 10 rather than base 2. [`last_decimal_digits`] keeps the value
+//! modulo `10^n`, and [`first_decimal_digits`] keeps the leading `n`
+//! digits. Both return a [`Digits`], which remembers how many digits
+//! were kept, so leading zeros aren't lost when it's displayed.
+//!
+//! The result type is checked up front: it must be able to hold every
+//! `n`-digit number, not just the one that was produced. Signed values
+//! keep the digits of their magnitude.
+//!
+//! ```rust
+//! use truncate_integer::decimal::{first_decimal_digits, last_decimal_digits};
+//!
+//! let otp = last_decimal_digits::<u32, _>(1_234_000_042u64, 6).unwrap();
+//! assert_eq!(otp.value(), 42);
+//! assert_eq!(otp.leading_zeros(), 4);
+//! assert_eq!(otp.to_string(), "000042");
+//!
+//! let head = first_decimal_digits::<u16, _>(987_654_321u32, 3).unwrap();
+//! assert_eq!(head.to_string(), "987");
+//!
+//! // A u8 can't hold every 3-digit number.
+//! assert!(last_decimal_digits::<u8, _>(5u32, 3).is_none());
+//! ```
+
+use crate::wide::{Primitive, Wide};
+use core::fmt;
+
+/// Some decimal digits of an integer, with the number of digits kept.
+///
+/// This is returned by [`last_decimal_digits`] and
+/// [`first_decimal_digits`]. It displays as exactly [`width`] digits,
+/// padded with leading zeros.
+///
+/// [`width`]: Digits::width
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Digits<T> {
+    value: T,
+    width: u32,
+}
+
+impl<T: Primitive> Digits<T> {
+    /// The value of the digits.
+    #[inline]
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// The number of digits kept.
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The number of leading zeros among the digits kept.
+    #[inline]
+    pub fn leading_zeros(&self) -> u32 {
+        self.width - decimal_len(self.value.widen().to_bits())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Digits<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.width == 0 {
+            return Ok(());
+        }
+        write!(f, "{:01$}", self.value, self.width as usize)
+    }
+}
+
+/// The magnitude of a value.
+#[inline]
+fn magnitude(value: Wide) -> u128 {
+    match value {
+        Wide::Negative(x) => (x as u128).wrapping_neg(),
+        Wide::NonNegative(x) => x,
+    }
+}
+
+/// The number of significant decimal digits; zero has none.
+fn decimal_len(mut value: u128) -> u32 {
+    let mut len = 0;
+    while value != 0 {
+        value /= 10;
+        len += 1;
+    }
+    len
+}
+
+/// Returns `10^n`, if `T` can hold every `n`-digit number.
+fn capacity<T: Primitive>(n: u32) -> Option<u128> {
+    // Only a 39-digit type could hold 10^39 - 1, and there isn't one.
+    let limit = 10u128.checked_pow(n)?;
+    let largest = limit - 1;
+    T::from_wide_checked(Wide::NonNegative(largest))?;
+    Some(limit)
+}
+
+/// Keep the last `n` decimal digits of `value`, as a `T`.
+///
+/// This is the magnitude of `value` modulo `10^n`. Returns `None` if `T`
+/// can't hold every `n`-digit number.
+pub fn last_decimal_digits<T: Primitive, V: Primitive>(value: V, n: u32) -> Option<Digits<T>> {
+    let limit = capacity::<T>(n)?;
+    let digits = magnitude(value.widen()) % limit;
+    Some(Digits {
+        value: T::from_wide_wrapping(Wide::NonNegative(digits)),
+        width: n,
+    })
+}
+
+/// Keep the first `n` decimal digits of `value`, as a `T`.
+///
+/// If `value` has `n` digits or fewer, all of them are kept. Returns
+/// `None` if `T` can't hold every `n`-digit number.
+pub fn first_decimal_digits<T: Primitive, V: Primitive>(value: V, n: u32) -> Option<Digits<T>> {
+    capacity::<T>(n)?;
+    let mut digits = magnitude(value.widen());
+    // Zero is written with one digit.
+    let len = decimal_len(digits).max(1);
+    let width = if n == 0 { 0 } else { len.min(n) };
+    for _ in width..len {
+        digits /= 10;
+    }
+    Some(Digits {
+        value: T::from_wide_wrapping(Wide::NonNegative(digits)),
+        width,
+    })
+}
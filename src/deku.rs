@@ -0,0 +1,63 @@
+//! Adapters for the [`deku`](https://docs.rs/deku) crate.
+//!
+//! These functions are meant to be used in `map` attributes, so that
+//! a field can be stored in a narrower type than the one that's on the
+//! wire.
+//!
+//! ```rust
+//! use deku::prelude::*;
+//!
+//! #[derive(DekuRead)]
+//! #[deku(endian = "big")]
+//! struct Header {
+//!     #[deku(map = "truncate_integer::deku::try_truncate::<u32, _>")]
+//!     len: u16,
+//!     #[deku(map = "truncate_integer::deku::shrink::<u64, _>")]
+//!     id: u8,
+//! }
+//!
+//! let input = [0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0];
+//! let (_, header) = Header::from_bytes((&input, 0)).unwrap();
+//! assert_eq!(header.len, 256);
+//! assert_eq!(header.id, 255);
+//! ```
+//!
+//! deku requires `map` functions to return a [`DekuError`], so a
+//! [`TruncateError`] is converted into a `DekuError::Parse`.
+
+use crate::{Shrink, TruncateError, TryTruncate};
+use ::deku::DekuError;
+
+impl From<TruncateError> for DekuError {
+    // The message type is `Cow<str>` or `&str`, depending on deku features.
+    #[allow(clippy::useless_conversion)]
+    fn from(err: TruncateError) -> Self {
+        match err {
+            TruncateError::Overflow => DekuError::Parse("integer overflow".into()),
+            TruncateError::Underflow => DekuError::Parse("integer underflow".into()),
+            TruncateError::Invalid => DekuError::Parse("invalid value".into()),
+        }
+    }
+}
+
+/// Perform checked truncation, returning a [`DekuError`] on failure.
+pub fn try_truncate<Source, Dest>(value: Source) -> Result<Dest, DekuError>
+where
+    Source: TryTruncate<Dest> + Copy + PartialOrd + Default,
+{
+    match value.try_truncate() {
+        Some(value) => Ok(value),
+        None => Err(TruncateError::for_value(&value).into()),
+    }
+}
+
+/// Perform saturating truncation.
+///
+/// This never fails; it returns a `Result` because that's what deku
+/// expects from a `map` function.
+pub fn shrink<Source, Dest>(value: Source) -> Result<Dest, DekuError>
+where
+    Source: Shrink<Dest>,
+{
+    Ok(value.shrink())
+}
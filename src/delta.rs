@@ -0,0 +1,85 @@
+//! Narrowing the difference between two positions.
+//!
+//! Relocation tables, jump offsets and delta encodings store `a - b`
+//! in a narrow relative-offset field. These helpers compute the
+//! difference without overflowing, then narrow it, so a negative delta
+//! is only accepted by a signed field.
+//!
+//! ```rust
+//! use truncate_integer::delta::{delta_shrink, try_delta};
+//!
+//! let target = 0x1000u64;
+//! let here = 0x1040u64;
+//! assert_eq!(try_delta::<i8, _>(target, here), Some(-64));
+//! assert_eq!(try_delta::<u8, _>(target, here), None);
+//! assert_eq!(delta_shrink::<i16, _>(0usize, 100_000usize), i16::MIN);
+//! ```
+
+use crate::{Chop, Shrink, TryTruncate};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An unsigned integer type used for positions.
+pub trait Position: private::Sealed + Copy {
+    /// Widen the position.
+    fn to_u64(self) -> u64;
+}
+
+macro_rules! make_position {
+    ($($Type:ty),*) => {
+        $(
+            impl private::Sealed for $Type {}
+
+            impl Position for $Type {
+                #[inline]
+                fn to_u64(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+}
+
+make_position!(u8, u16, u32, u64, usize);
+
+#[inline]
+fn delta<P: Position>(a: P, b: P) -> i128 {
+    a.to_u64() as i128 - b.to_u64() as i128
+}
+
+/// Compute `a - b`, and narrow it into `T`.
+///
+/// Returns `None` if the difference doesn't fit.
+#[inline]
+pub fn try_delta<T, P: Position>(a: P, b: P) -> Option<T>
+where
+    i128: TryTruncate<T>,
+{
+    delta(a, b).try_truncate()
+}
+
+/// Compute `a - b`, and narrow it into `T`.
+///
+/// # Panics
+///
+/// Panics if the difference doesn't fit.
+#[track_caller]
+#[inline]
+pub fn delta_chop<T, P: Position>(a: P, b: P) -> T
+where
+    i128: Chop<T>,
+{
+    delta(a, b).chop()
+}
+
+/// Compute `a - b`, and narrow it into `T`, saturating if it doesn't
+/// fit.
+#[inline]
+pub fn delta_shrink<T, P: Position>(a: P, b: P) -> T
+where
+    i128: Shrink<T>,
+{
+    delta(a, b).shrink()
+}
@@ -0,0 +1,157 @@
+//! Formatting adapters that display part of a wider value.
+//!
+//! These are handy in log messages that show a shortened ID or address.
+//!
+//! ```rust
+//! use truncate_integer::display::{HighHex, LowBits, LowHex};
+//!
+//! let id = 0x0123_4567_89AB_CDEFu64;
+//! assert_eq!(LowHex::<u16>::new(id).to_string(), "cdef");
+//! assert_eq!(HighHex::<u16>::new(id).to_string(), "0123");
+//! assert_eq!(LowBits::<12>::new(id).to_string(), "def");
+//! assert_eq!(format!("{:b}", LowBits::<5>::new(id)), "01111");
+//! ```
+
+use crate::TruncateUnchecked;
+use core::fmt;
+use core::mem::size_of;
+use core::ops::Shr;
+
+/// Display the low-order bits of a value that fit into `T`, as hex.
+///
+/// [`Display`](fmt::Display) and [`Debug`](fmt::Debug) show all of the
+/// digits of `T`, with leading zeros. [`LowerHex`](fmt::LowerHex) and
+/// [`UpperHex`](fmt::UpperHex) use the usual formatting flags.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LowHex<T>(T);
+
+impl<T> LowHex<T> {
+    /// Keep the low-order bits of `value` that fit into `T`.
+    #[track_caller]
+    pub fn new<S>(value: S) -> Self
+    where
+        S: TruncateUnchecked<T>,
+    {
+        LowHex(value.truncate_unchecked())
+    }
+
+    /// Get the truncated value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Display the high-order bits of a value that fit into `T`, as hex.
+///
+/// [`Display`](fmt::Display) and [`Debug`](fmt::Debug) show all of the
+/// digits of `T`, with leading zeros. [`LowerHex`](fmt::LowerHex) and
+/// [`UpperHex`](fmt::UpperHex) use the usual formatting flags.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HighHex<T>(T);
+
+impl<T> HighHex<T> {
+    /// Keep the high-order bits of `value` that fit into `T`.
+    #[track_caller]
+    pub fn new<S>(value: S) -> Self
+    where
+        S: Shr<u32, Output = S> + TruncateUnchecked<T>,
+    {
+        let shift = size_of::<S>().saturating_sub(size_of::<T>()) * 8;
+        HighHex((value >> shift as u32).truncate_unchecked())
+    }
+
+    /// Get the truncated value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! make_hex_fmt {
+    ($Adapter:ident) => {
+        impl<T: fmt::LowerHex> fmt::Display for $Adapter<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:01$x}", self.0, size_of::<T>() * 2)
+            }
+        }
+
+        impl<T: fmt::LowerHex> fmt::Debug for $Adapter<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl<T: fmt::LowerHex> fmt::LowerHex for $Adapter<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        impl<T: fmt::UpperHex> fmt::UpperHex for $Adapter<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+make_hex_fmt!(LowHex);
+make_hex_fmt!(HighHex);
+
+/// Display the low-order `N` bits of a value.
+///
+/// [`Display`](fmt::Display) and [`Debug`](fmt::Debug) show the bits as
+/// hex, with enough digits to hold `N` bits. [`Binary`](fmt::Binary)
+/// shows exactly `N` digits. [`LowerHex`](fmt::LowerHex) and
+/// [`UpperHex`](fmt::UpperHex) use the usual formatting flags.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LowBits<const N: u32>(u128);
+
+impl<const N: u32> LowBits<N> {
+    /// Keep the low-order `N` bits of `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero or greater than 128.
+    pub fn new<S>(value: S) -> Self
+    where
+        S: Into<u128>,
+    {
+        assert!((1..=128).contains(&N), "invalid bit count");
+        LowBits(value.into() & (u128::MAX >> (128 - N)))
+    }
+
+    /// Get the truncated value.
+    pub fn into_inner(self) -> u128 {
+        self.0
+    }
+}
+
+impl<const N: u32> fmt::Display for LowBits<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:01$x}", self.0, ((N + 3) / 4) as usize)
+    }
+}
+
+impl<const N: u32> fmt::Debug for LowBits<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const N: u32> fmt::Binary for LowBits<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:01$b}", self.0, N as usize)
+    }
+}
+
+impl<const N: u32> fmt::LowerHex for LowBits<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl<const N: u32> fmt::UpperHex for LowBits<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,155 @@
+//! Checking DMA buffers against device constraints.
+//!
+//! A DMA descriptor usually has narrow address and length fields, and
+//! the device can only reach part of the physical address space, often
+//! with an alignment requirement. [`DmaConstraint::narrow`] checks a
+//! buffer against all of these at once and narrows it into the
+//! descriptor's field types.
+//!
+//! ```rust
+//! use truncate_integer::dma::{DmaConstraint, DmaError};
+//!
+//! // A device that can reach the low 4 GiB, with 16-byte alignment.
+//! let device = DmaConstraint::new(0xFFFF_FFFF, 16).unwrap();
+//!
+//! let (addr, len): (u32, u16) = device.narrow(0x8000_0000, 4096).unwrap();
+//! assert_eq!((addr, len), (0x8000_0000, 4096));
+//!
+//! let err = device.narrow::<u32, u16>(0x1_0000_0000, 4096).unwrap_err();
+//! assert_eq!(err.to_string(), "DMA buffer 0x100000000+0x1000 is beyond 0xffffffff");
+//! let err = device.narrow::<u32, u16>(0x8000_0008, 4096).unwrap_err();
+//! assert_eq!(err, DmaError::Misaligned { addr: 0x8000_0008, align: 16 });
+//! let err = device.narrow::<u32, u16>(0x8000_0000, 0x10000).unwrap_err();
+//! assert_eq!(err, DmaError::LengthField { len: 0x10000 });
+//! ```
+
+use crate::TryTruncate;
+use core::fmt;
+
+/// The addresses a device can reach, and its alignment requirement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DmaConstraint {
+    max_address: u64,
+    align: u64,
+}
+
+impl DmaConstraint {
+    /// Create a constraint for a device that can reach addresses up to
+    /// and including `max_address`, with buffers aligned to `align`.
+    ///
+    /// Returns `None` if `align` isn't a power of two.
+    pub fn new(max_address: u64, align: u64) -> Option<Self> {
+        if align.is_power_of_two() {
+            Some(DmaConstraint { max_address, align })
+        } else {
+            None
+        }
+    }
+
+    /// The highest address the device can reach.
+    pub fn max_address(&self) -> u64 {
+        self.max_address
+    }
+
+    /// The required buffer alignment.
+    pub fn align(&self) -> u64 {
+        self.align
+    }
+
+    /// Check that the buffer at `addr` of `len` bytes is aligned, and
+    /// is entirely within reach of the device.
+    pub fn check(&self, addr: u64, len: u64) -> Result<(), DmaError> {
+        if addr & (self.align - 1) != 0 {
+            return Err(DmaError::Misaligned {
+                addr,
+                align: self.align,
+            });
+        }
+        // Compare the end of the buffer without overflowing.
+        if addr as u128 + len as u128 > self.max_address as u128 + 1 {
+            return Err(DmaError::OutOfRange {
+                addr,
+                len,
+                max_address: self.max_address,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check a buffer, then narrow its address and length into the
+    /// descriptor field types `A` and `L`.
+    pub fn narrow<A, L>(&self, addr: u64, len: u64) -> Result<(A, L), DmaError>
+    where
+        u64: TryTruncate<A> + TryTruncate<L>,
+    {
+        self.check(addr, len)?;
+        let narrow_addr = addr.try_truncate().ok_or(DmaError::AddressField { addr })?;
+        let narrow_len = len.try_truncate().ok_or(DmaError::LengthField { len })?;
+        Ok((narrow_addr, narrow_len))
+    }
+}
+
+/// The error returned when a DMA buffer doesn't meet a device constraint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DmaError {
+    /// The address isn't aligned.
+    Misaligned {
+        /// The buffer address.
+        addr: u64,
+        /// The required alignment.
+        align: u64,
+    },
+    /// Part of the buffer is beyond the highest address the device can
+    /// reach.
+    OutOfRange {
+        /// The buffer address.
+        addr: u64,
+        /// The buffer length.
+        len: u64,
+        /// The highest address the device can reach.
+        max_address: u64,
+    },
+    /// The address doesn't fit in the descriptor's address field.
+    AddressField {
+        /// The buffer address.
+        addr: u64,
+    },
+    /// The length doesn't fit in the descriptor's length field.
+    LengthField {
+        /// The buffer length.
+        len: u64,
+    },
+}
+
+impl fmt::Display for DmaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DmaError::Misaligned { addr, align } => {
+                write!(
+                    f,
+                    "DMA address {:#x} is not aligned to {} bytes",
+                    addr, align
+                )
+            }
+            DmaError::OutOfRange {
+                addr,
+                len,
+                max_address,
+            } => write!(
+                f,
+                "DMA buffer {:#x}+{:#x} is beyond {:#x}",
+                addr, len, max_address
+            ),
+            DmaError::AddressField { addr } => {
+                write!(f, "DMA address {:#x} doesn't fit in the descriptor", addr)
+            }
+            DmaError::LengthField { len } => {
+                write!(f, "DMA length {:#x} doesn't fit in the descriptor", len)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DmaError {}
@@ -0,0 +1,150 @@
+//! Read integers from environment variables.
+//!
+//! [`env_try`] and [`env_shrink`] read a variable, parse it, and narrow
+//! it into the requested type, with one error type covering everything
+//! that can go wrong.
+//!
+//! ```rust
+//! use truncate_integer::env::{env_shrink, env_try};
+//!
+//! std::env::set_var("EXAMPLE_PORT", "8080");
+//! std::env::set_var("EXAMPLE_WORKERS", "1000");
+//!
+//! let port: u16 = env_try("EXAMPLE_PORT").unwrap();
+//! assert_eq!(port, 8080);
+//! let workers: u8 = env_shrink("EXAMPLE_WORKERS").unwrap();
+//! assert_eq!(workers, 255);
+//!
+//! let err = env_try::<u8>("EXAMPLE_PORT").unwrap_err();
+//! assert_eq!(err.to_string(), "environment variable EXAMPLE_PORT=\"8080\": integer overflow");
+//! ```
+
+use crate::wide::{Primitive, Wide};
+use crate::TruncateError;
+use core::fmt;
+use core::num::ParseIntError;
+use std::env::{self, VarError};
+use std::string::String;
+
+/// What went wrong reading an environment variable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EnvErrorKind {
+    /// The variable isn't set.
+    Missing,
+    /// The variable isn't valid unicode.
+    NotUnicode,
+    /// The variable isn't an integer.
+    Parse(ParseIntError),
+    /// The variable is an integer, but doesn't fit the requested type.
+    Truncate(TruncateError),
+}
+
+/// The error returned by [`env_try`] and [`env_shrink`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvError {
+    name: String,
+    value: Option<String>,
+    kind: EnvErrorKind,
+}
+
+impl EnvError {
+    /// The name of the variable.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value of the variable, if it was valid unicode.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> &EnvErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "environment variable {}", self.name)?;
+        match (&self.kind, &self.value) {
+            (EnvErrorKind::Missing, _) => f.write_str(" is not set"),
+            (EnvErrorKind::NotUnicode, _) => f.write_str(" is not valid unicode"),
+            (EnvErrorKind::Parse(e), Some(value)) => write!(f, "={:?}: {}", value, e),
+            (EnvErrorKind::Truncate(e), Some(value)) => write!(f, "={:?}: {}", value, e),
+            (EnvErrorKind::Parse(e), None) => write!(f, ": {}", e),
+            (EnvErrorKind::Truncate(e), None) => write!(f, ": {}", e),
+        }
+    }
+}
+
+impl std::error::Error for EnvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            EnvErrorKind::Parse(e) => Some(e),
+            EnvErrorKind::Truncate(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Read and parse a variable, before narrowing.
+fn read_wide(name: &str) -> Result<(Wide, String), EnvError> {
+    let error = |value, kind| EnvError {
+        name: name.into(),
+        value,
+        kind,
+    };
+    let value = match env::var(name) {
+        Ok(value) => value,
+        Err(VarError::NotPresent) => return Err(error(None, EnvErrorKind::Missing)),
+        Err(VarError::NotUnicode(_)) => return Err(error(None, EnvErrorKind::NotUnicode)),
+    };
+    let trimmed = value.trim();
+    let wide = if trimmed.starts_with('-') {
+        trimmed.parse::<i128>().map(|x| {
+            if x < 0 {
+                Wide::Negative(x)
+            } else {
+                Wide::NonNegative(x as u128)
+            }
+        })
+    } else {
+        trimmed.parse::<u128>().map(Wide::NonNegative)
+    };
+    match wide {
+        Ok(wide) => Ok((wide, value)),
+        Err(e) => Err(error(Some(value), EnvErrorKind::Parse(e))),
+    }
+}
+
+/// Read an integer from an environment variable, failing if it doesn't
+/// fit in `T`.
+///
+/// Leading and trailing whitespace is ignored.
+pub fn env_try<T: Primitive>(name: &str) -> Result<T, EnvError> {
+    let (wide, value) = read_wide(name)?;
+    T::from_wide_checked(wide).ok_or_else(|| {
+        let e = if wide < T::MIN_WIDE {
+            TruncateError::Underflow
+        } else {
+            TruncateError::Overflow
+        };
+        EnvError {
+            name: name.into(),
+            value: Some(value),
+            kind: EnvErrorKind::Truncate(e),
+        }
+    })
+}
+
+/// Read an integer from an environment variable, saturating if it doesn't
+/// fit in `T`.
+///
+/// Leading and trailing whitespace is ignored. This only fails if the
+/// variable is missing or isn't an integer.
+pub fn env_shrink<T: Primitive>(name: &str) -> Result<T, EnvError> {
+    let (wide, _) = read_wide(name)?;
+    Ok(T::from_wide_saturating(wide))
+}
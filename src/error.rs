@@ -0,0 +1,47 @@
+use core::fmt;
+
+/// The error returned when a value doesn't fit into the destination type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum TruncateError {
+    /// The value was larger than the destination type's maximum.
+    Overflow,
+    /// The value was smaller than the destination type's minimum.
+    Underflow,
+    /// The value fit into the destination's representation, but isn't
+    /// a valid value of the destination type.
+    Invalid,
+}
+
+impl TruncateError {
+    /// Describe the failure to truncate `value`.
+    ///
+    /// Every integer type can hold zero, so a value that didn't fit
+    /// must have been either too large or too small.
+    pub(crate) fn for_value<T: PartialOrd + Default>(value: &T) -> Self {
+        if *value < T::default() {
+            TruncateError::Underflow
+        } else {
+            TruncateError::Overflow
+        }
+    }
+}
+
+impl fmt::Display for TruncateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TruncateError::Overflow => f.write_str("integer overflow"),
+            TruncateError::Underflow => f.write_str("integer underflow"),
+            TruncateError::Invalid => f.write_str("invalid value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TruncateError {}
+
+// rkyv errors need a `core::error::Error`, which is only in newer compilers;
+// rkyv itself requires one of those.
+#[cfg(all(feature = "rkyv", not(feature = "std")))]
+impl core::error::Error for TruncateError {}
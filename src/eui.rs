@@ -0,0 +1,191 @@
+//! EUI-48 (MAC) and EUI-64 identifiers.
+//!
+//! A MAC address is a 48-bit integer, usually carried in a `u64`.
+//! [`Eui48`] and [`Eui64`] hold identifiers that are known to fit, and
+//! the truncation traits are implemented from every integer type into
+//! them, so a `u64` with any of its upper 16 bits set is rejected
+//! rather than quietly becoming a different address.
+//!
+//! ```rust
+//! use truncate_integer::eui::{Eui48, Eui64};
+//! use truncate_integer::TryTruncate;
+//!
+//! let mac = Eui48::new(0x0011_2233_4455).unwrap();
+//! assert_eq!(mac.oui(), 0x001122);
+//! assert_eq!(mac.to_string(), "00:11:22:33:44:55");
+//! assert_eq!(mac.to_eui64(), Eui64::new(0x0011_22FF_FE33_4455).unwrap());
+//!
+//! let mac: Option<Eui48> = 0x1_0000_0000_0000u64.try_truncate();
+//! assert!(mac.is_none());
+//! ```
+
+use crate::bitsint::U;
+use crate::wide::{FromWide, Widen};
+use crate::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+use core::fmt;
+
+macro_rules! make_eui {
+    ($Eui:ident, $N:literal, $($Into:ty),*) => {
+        impl $Eui {
+            /// The width in bits.
+            pub const BITS: u32 = $N;
+            /// The smallest identifier, zero.
+            pub const MIN: Self = $Eui(U::<$N>::MIN);
+            /// The largest identifier.
+            pub const MAX: Self = $Eui(U::<$N>::MAX);
+
+            /// Create an identifier.
+            ///
+            /// Returns `None` if `raw` doesn't fit.
+            #[inline]
+            pub fn new(raw: u64) -> Option<Self> {
+                raw.try_truncate()
+            }
+
+            /// Get the identifier.
+            #[inline]
+            pub fn raw(self) -> u64 {
+                self.0.get()
+            }
+
+            /// The organizationally unique identifier: the first three
+            /// octets.
+            #[inline]
+            pub fn oui(self) -> u32 {
+                (self.raw() >> ($N - 24)) as u32
+            }
+
+            /// Returns `true` if this is a group (multicast) identifier.
+            #[inline]
+            pub fn is_multicast(self) -> bool {
+                self.raw() >> ($N - 8) & 1 != 0
+            }
+
+            /// Returns `true` if this is a locally administered identifier.
+            #[inline]
+            pub fn is_local(self) -> bool {
+                self.raw() >> ($N - 8) & 2 != 0
+            }
+
+            /// The octets, in transmission order.
+            #[inline]
+            pub fn to_bytes(self) -> [u8; $N / 8] {
+                let mut bytes = [0; $N / 8];
+                bytes.copy_from_slice(&self.raw().to_be_bytes()[8 - $N / 8..]);
+                bytes
+            }
+
+            /// Create an identifier from its octets, in transmission order.
+            #[inline]
+            pub fn from_bytes(bytes: [u8; $N / 8]) -> Self {
+                let mut raw = [0; 8];
+                raw[8 - $N / 8..].copy_from_slice(&bytes);
+                Self::from_raw_wrapping(u64::from_be_bytes(raw))
+            }
+
+            // The callers know that `raw` fits, so this is never recorded
+            // by the audit.
+            #[inline]
+            fn from_raw_wrapping(raw: u64) -> Self {
+                $Eui(FromWide::from_wide_wrapping(Widen::widen(raw)))
+            }
+        }
+
+        impl<Source: Widen> TryTruncate<$Eui> for Source {
+            #[inline]
+            fn try_truncate(self) -> Option<$Eui> {
+                TryTruncate::<U<$N>>::try_truncate(self).map($Eui)
+            }
+        }
+
+        impl<Source: Widen> Chop<$Eui> for Source {
+            #[track_caller]
+            #[inline]
+            fn chop(self) -> $Eui {
+                $Eui(Chop::<U<$N>>::chop(self))
+            }
+        }
+
+        impl<Source: Widen> Shrink<$Eui> for Source {
+            #[inline]
+            fn shrink(self) -> $Eui {
+                $Eui(Shrink::<U<$N>>::shrink(self))
+            }
+        }
+
+        impl<Source: Widen> TruncateUnchecked<$Eui> for Source {
+            #[track_caller]
+            #[inline]
+            fn truncate_unchecked(self) -> $Eui {
+                $Eui(TruncateUnchecked::<U<$N>>::truncate_unchecked(self))
+            }
+        }
+
+        $(
+            impl From<$Eui> for $Into {
+                #[inline]
+                fn from(eui: $Eui) -> Self {
+                    eui.raw().into()
+                }
+            }
+        )*
+
+        impl fmt::Display for $Eui {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for (i, byte) in self.to_bytes().iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(":")?;
+                    }
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+/// A 48-bit MAC address.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Eui48(U<48>);
+
+/// A 64-bit extended unique identifier.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Eui64(U<64>);
+
+make_eui!(Eui48, 48, u64, u128, i128);
+make_eui!(Eui64, 64, u64, u128, i128);
+
+impl Eui48 {
+    /// Expand into an EUI-64, by inserting `FF:FE` after the OUI.
+    #[inline]
+    pub fn to_eui64(self) -> Eui64 {
+        let raw = self.raw();
+        let expanded = (raw >> 24) << 40 | 0xFF_FE << 24 | (raw & 0xFF_FFFF);
+        Eui64::from_raw_wrapping(expanded)
+    }
+
+    /// The modified EUI-64 used for IPv6 interface identifiers.
+    ///
+    /// This is [`to_eui64`](Self::to_eui64) with the universal/local bit
+    /// inverted.
+    #[inline]
+    pub fn to_modified_eui64(self) -> u64 {
+        self.to_eui64().raw() ^ 1 << 57
+    }
+}
+
+impl Eui64 {
+    /// Recover the EUI-48 this was expanded from.
+    ///
+    /// Returns `None` if the middle octets aren't `FF:FE`.
+    #[inline]
+    pub fn to_eui48(self) -> Option<Eui48> {
+        let raw = self.raw();
+        if raw >> 24 & 0xFFFF != 0xFF_FE {
+            return None;
+        }
+        Some(Eui48::from_raw_wrapping(
+            (raw >> 40) << 24 | (raw & 0xFF_FFFF),
+        ))
+    }
+}
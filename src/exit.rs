@@ -0,0 +1,83 @@
+//! Narrow status codes into process exit statuses.
+//!
+//! An exit status is a `u8`, but programs often carry a wider error code
+//! internally. These functions narrow one into a `u8` or an
+//! [`ExitCode`](std::process::ExitCode):
+//!
+//! - `exit_*_try` functions fail if the code isn't in `0..=255`.
+//! - `exit_*_shrink` functions map a code that isn't in `0..=255` to
+//!   255. Unlike [`Shrink`], a negative code doesn't become 0, so a
+//!   failure is never reported as success.
+//!
+//! ```rust
+//! use truncate_integer::exit::{exit_status_shrink, exit_status_try};
+//! use truncate_integer::TruncateError;
+//!
+//! assert_eq!(exit_status_try(2i32), Ok(2));
+//! assert_eq!(exit_status_try(256i64), Err(TruncateError::Overflow));
+//! assert_eq!(exit_status_shrink(256i64), 255);
+//! assert_eq!(exit_status_shrink(-1i32), 255);
+//! ```
+//!
+//! # Platform caveats
+//!
+//! - On Unix, only the low 8 bits of the value passed to `exit` reach
+//!   the parent, so `exit(256)` looks like success. Narrowing here
+//!   avoids that.
+//! - Shells give some statuses their own meaning: 126 and 127 are
+//!   reported when a command can't be run, and 128 + `n` when it was
+//!   killed by signal `n`. Codes that collide with these may confuse
+//!   scripts.
+//! - On Windows, a process can exit with any 32-bit value, but
+//!   `ExitCode` can only carry a `u8`; use
+//!   [`std::process::exit`] to report a wider code there.
+//! - `ExitCode` needs Rust 1.61, which is newer than this crate's
+//!   minimum of 1.51, so the functions that return one need the
+//!   `exit-code` feature. Enabling it raises the minimum to 1.61.
+
+use crate::{Shrink, TruncateError, TryTruncate};
+#[cfg(feature = "exit-code")]
+use std::process::ExitCode;
+
+/// Narrow `code` into an exit status, failing if it isn't in `0..=255`.
+pub fn exit_status_try<V>(code: V) -> Result<u8, TruncateError>
+where
+    V: TryTruncate<u8> + Copy + PartialOrd + Default,
+{
+    code.try_truncate()
+        .ok_or_else(|| TruncateError::for_value(&code))
+}
+
+/// Narrow `code` into an exit status, mapping a code that isn't in
+/// `0..=255` to 255.
+pub fn exit_status_shrink<V>(code: V) -> u8
+where
+    V: Shrink<u8> + Copy + PartialOrd + Default,
+{
+    if code < V::default() {
+        u8::MAX
+    } else {
+        code.shrink()
+    }
+}
+
+/// Narrow `code` into an [`ExitCode`], failing if it isn't in `0..=255`.
+#[cfg(feature = "exit-code")]
+#[clippy::msrv = "1.61"]
+pub fn exit_code_try<V>(code: V) -> Result<ExitCode, TruncateError>
+where
+    V: TryTruncate<u8> + Copy + PartialOrd + Default,
+{
+    exit_status_try(code).map(ExitCode::from)
+}
+
+/// Narrow `code` into an [`ExitCode`], mapping a code that isn't in
+/// `0..=255` to 255.
+#[cfg(feature = "exit-code")]
+#[clippy::msrv = "1.61"]
+pub fn exit_code_shrink<V>(code: V) -> ExitCode
+where
+    V: Shrink<u8> + Copy + PartialOrd + Default,
+{
+    ExitCode::from(exit_status_shrink(code))
+}
@@ -0,0 +1,103 @@
+//! Forcing truncations to overflow, for testing error handling.
+//!
+//! With the `fault-injection` feature enabled, a configurable fraction
+//! of [`TryTruncate`] and [`Shrink`] calls between primitive types take
+//! their overflow path whatever the input: `try_truncate` returns
+//! `None`, and `shrink` returns the destination's maximum (or minimum,
+//! for a negative input). This makes the code that handles those cases
+//! reachable from ordinary integration tests. Identity and widening
+//! conversions, which can't overflow, are never affected.
+//!
+//! Injection is off until [`set_rate`] is called. The choice of which
+//! calls fail comes from a generator seeded by [`set_seed`], so a
+//! single-threaded test sees the same failures on every run. The
+//! settings are global, so tests that change them shouldn't run in
+//! parallel with tests that depend on exact results.
+//!
+//! This feature is meant for test builds only.
+//!
+//! ```rust
+//! use truncate_integer::{fault, Shrink, TryTruncate};
+//!
+//! fault::set_rate(1, 1);
+//! assert_eq!(TryTruncate::<u8>::try_truncate(5u32), None);
+//! assert_eq!(Shrink::<u8>::shrink(5u32), 255);
+//! assert_eq!(Shrink::<i8>::shrink(-5i32), -128);
+//! assert_eq!(fault::injected(), 3);
+//!
+//! fault::disable();
+//! assert_eq!(TryTruncate::<u8>::try_truncate(5u32), Some(5));
+//! ```
+//!
+//! [`TryTruncate`]: crate::TryTruncate
+//! [`Shrink`]: crate::Shrink
+
+use crate::ConversionKindOf;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// The chance of injecting a fault, out of 2^32.
+static THRESHOLD: AtomicU64 = AtomicU64::new(0);
+static STATE: AtomicU64 = AtomicU64::new(0);
+static INJECTED: AtomicU64 = AtomicU64::new(0);
+
+/// Make `numerator` out of every `denominator` truncations overflow.
+///
+/// # Panics
+///
+/// Panics if `denominator` is zero, or `numerator` is larger than
+/// `denominator`.
+#[track_caller]
+pub fn set_rate(numerator: u32, denominator: u32) {
+    assert!(
+        denominator != 0 && numerator <= denominator,
+        "invalid fault injection rate"
+    );
+    let threshold = (u64::from(numerator) << 32) / u64::from(denominator);
+    THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+/// Stop injecting faults.
+pub fn disable() {
+    THRESHOLD.store(0, Ordering::Relaxed);
+}
+
+/// Restart the sequence of injected faults from `seed`, and reset the
+/// count returned by [`injected`].
+pub fn set_seed(seed: u64) {
+    STATE.store(seed, Ordering::Relaxed);
+    INJECTED.store(0, Ordering::Relaxed);
+}
+
+/// The number of faults injected so far.
+pub fn injected() -> u64 {
+    INJECTED.load(Ordering::Relaxed)
+}
+
+/// Decide whether this truncation should overflow.
+#[inline]
+pub(crate) fn inject() -> bool {
+    let threshold = THRESHOLD.load(Ordering::Relaxed);
+    if threshold == 0 {
+        return false;
+    }
+    // splitmix64
+    let mut z = STATE
+        .fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+        .wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    let hit = z >> 32 < threshold;
+    if hit {
+        INJECTED.fetch_add(1, Ordering::Relaxed);
+    }
+    hit
+}
+
+/// Decide whether a truncation from `S` to `D` should overflow.
+///
+/// Conversions that can't overflow never do, and don't use up a fault.
+#[inline]
+pub(crate) fn inject_for<S: ConversionKindOf<D>, D>() -> bool {
+    !S::KIND.is_lossless() && inject()
+}
@@ -0,0 +1,171 @@
+//! Frame-of-reference encoding.
+//!
+//! A sequence of integers that are close together (such as timestamps)
+//! is stored as a base value plus a list of deltas. The deltas are
+//! narrowed into the smallest unsigned type that can hold all of them.
+//!
+//! ```rust
+//! use truncate_integer::frame::{Deltas, FrameOfReference};
+//!
+//! let timestamps = [1_600_000_000_123u64, 1_600_000_000_000, 1_600_000_001_000];
+//! let frame = FrameOfReference::encode(&timestamps);
+//! assert_eq!(frame.base(), 1_600_000_000_000);
+//! assert_eq!(frame.deltas(), &Deltas::U16(vec![123, 0, 1000]));
+//! assert_eq!(frame.decode(), timestamps);
+//! ```
+
+use crate::{Chop, MinBits};
+use alloc::vec::Vec;
+
+/// Narrowed deltas from a [`FrameOfReference`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Deltas {
+    /// Deltas that fit in 8 bits.
+    U8(Vec<u8>),
+    /// Deltas that fit in 16 bits.
+    U16(Vec<u16>),
+    /// Deltas that fit in 32 bits.
+    U32(Vec<u32>),
+    /// Deltas that fit in 64 bits.
+    U64(Vec<u64>),
+}
+
+impl Deltas {
+    /// Narrow each delta into the type chosen by `max_bits`.
+    fn narrow(deltas: impl ExactSizeIterator<Item = u64>, max_bits: u32) -> Self {
+        if max_bits <= 8 {
+            Deltas::U8(deltas.map(Chop::chop).collect())
+        } else if max_bits <= 16 {
+            Deltas::U16(deltas.map(Chop::chop).collect())
+        } else if max_bits <= 32 {
+            Deltas::U32(deltas.map(Chop::chop).collect())
+        } else {
+            Deltas::U64(deltas.collect())
+        }
+    }
+
+    /// The width of each delta, in bits.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Deltas::U8(_) => 8,
+            Deltas::U16(_) => 16,
+            Deltas::U32(_) => 32,
+            Deltas::U64(_) => 64,
+        }
+    }
+
+    /// The number of deltas.
+    pub fn len(&self) -> usize {
+        match self {
+            Deltas::U8(d) => d.len(),
+            Deltas::U16(d) => d.len(),
+            Deltas::U32(d) => d.len(),
+            Deltas::U64(d) => d.len(),
+        }
+    }
+
+    /// Returns `true` if there are no deltas.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get one delta, widened to `u64`.
+    pub fn get(&self, index: usize) -> Option<u64> {
+        match self {
+            Deltas::U8(d) => d.get(index).map(|&x| u64::from(x)),
+            Deltas::U16(d) => d.get(index).map(|&x| u64::from(x)),
+            Deltas::U32(d) => d.get(index).map(|&x| u64::from(x)),
+            Deltas::U64(d) => d.get(index).copied(),
+        }
+    }
+}
+
+/// A sequence of integers, stored as a base value plus narrowed deltas.
+///
+/// The base is the minimum value, so every delta is non-negative.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FrameOfReference<T> {
+    base: T,
+    deltas: Deltas,
+}
+
+impl<T: Copy> FrameOfReference<T> {
+    /// The base value.
+    ///
+    /// This is the minimum of the encoded values (or zero, if there are none).
+    pub fn base(&self) -> T {
+        self.base
+    }
+
+    /// The deltas from the base value.
+    pub fn deltas(&self) -> &Deltas {
+        &self.deltas
+    }
+
+    /// The number of encoded values.
+    pub fn len(&self) -> usize {
+        self.deltas.len()
+    }
+
+    /// Returns `true` if there are no encoded values.
+    pub fn is_empty(&self) -> bool {
+        self.deltas.is_empty()
+    }
+}
+
+mod private {
+    pub trait FrameValue: Copy + Ord {
+        const ZERO: Self;
+        /// Compute `self - base`, where `base <= self`.
+        fn delta_from(self, base: Self) -> u64;
+        /// Compute `base + delta`.
+        fn add_delta(base: Self, delta: u64) -> Self;
+    }
+}
+
+use private::FrameValue;
+
+impl<T: FrameValue> FrameOfReference<T> {
+    /// Encode a slice of values.
+    pub fn encode(values: &[T]) -> Self {
+        let base = values.iter().copied().min().unwrap_or(T::ZERO);
+        let deltas = values.iter().map(|x| x.delta_from(base));
+        let max_bits = deltas.clone().max().unwrap_or(0).min_bits();
+        let deltas = Deltas::narrow(deltas, max_bits);
+        FrameOfReference { base, deltas }
+    }
+
+    /// Get one value.
+    pub fn get(&self, index: usize) -> Option<T> {
+        let delta = self.deltas.get(index)?;
+        Some(T::add_delta(self.base, delta))
+    }
+
+    /// Decode all of the values.
+    pub fn decode(&self) -> Vec<T> {
+        (0..self.len()).filter_map(|i| self.get(i)).collect()
+    }
+}
+
+macro_rules! make_frame {
+    ($Type:ty, $Unsigned:ty) => {
+        impl FrameValue for $Type {
+            const ZERO: Self = 0;
+
+            #[inline]
+            fn delta_from(self, base: Self) -> u64 {
+                (self as $Unsigned).wrapping_sub(base as $Unsigned) as u64
+            }
+
+            #[inline]
+            fn add_delta(base: Self, delta: u64) -> Self {
+                (base as $Unsigned).wrapping_add(delta as $Unsigned) as $Type
+            }
+        }
+    };
+}
+
+make_frame!(u32, u32);
+make_frame!(i32, u32);
+make_frame!(u64, u64);
+make_frame!(i64, u64);
@@ -0,0 +1,139 @@
+//! Component-wise narrowing for [`glam`](https://docs.rs/glam) vectors.
+//!
+//! The truncation traits are implemented between glam's integer vector
+//! types, and from them into arrays, whenever they're implemented for
+//! the component types. A checked conversion fails if any component
+//! doesn't fit.
+//!
+//! ```rust
+//! use glam::{IVec4, U16Vec2, UVec2};
+//! use truncate_integer::{Shrink, TryTruncate};
+//!
+//! let color = IVec4::new(255, 128, -20, 300);
+//! let rgba: [u8; 4] = color.shrink();
+//! assert_eq!(rgba, [255, 128, 0, 255]);
+//! let rgba: Option<[u8; 4]> = color.try_truncate();
+//! assert_eq!(rgba, None);
+//!
+//! let size: Option<U16Vec2> = UVec2::new(1920, 1080).try_truncate();
+//! assert_eq!(size, Some(U16Vec2::new(1920, 1080)));
+//! ```
+
+use crate::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+use ::glam::*;
+
+macro_rules! make_glam_vec {
+    ($Src:ident => $Dest:ident, $D:ty, $N:literal; $($f:ident),*) => {
+        impl TryTruncate<$Dest> for $Src {
+            #[inline]
+            fn try_truncate(self) -> Option<$Dest> {
+                Some($Dest::new($(self.$f.try_truncate()?),*))
+            }
+        }
+
+        impl TryTruncate<[$D; $N]> for $Src {
+            #[inline]
+            fn try_truncate(self) -> Option<[$D; $N]> {
+                Some([$(self.$f.try_truncate()?),*])
+            }
+        }
+
+        impl Chop<$Dest> for $Src {
+            #[track_caller]
+            #[inline]
+            fn chop(self) -> $Dest {
+                $Dest::new($(self.$f.chop()),*)
+            }
+        }
+
+        impl Chop<[$D; $N]> for $Src {
+            #[track_caller]
+            #[inline]
+            fn chop(self) -> [$D; $N] {
+                [$(self.$f.chop()),*]
+            }
+        }
+
+        impl Shrink<$Dest> for $Src {
+            #[inline]
+            fn shrink(self) -> $Dest {
+                $Dest::new($(self.$f.shrink()),*)
+            }
+        }
+
+        impl Shrink<[$D; $N]> for $Src {
+            #[inline]
+            fn shrink(self) -> [$D; $N] {
+                [$(self.$f.shrink()),*]
+            }
+        }
+    };
+}
+
+macro_rules! make_glam_vec_unchecked {
+    ($Src:ident => $Dest:ident, $D:ty, $N:literal; $($f:ident),*) => {
+        impl TruncateUnchecked<$Dest> for $Src {
+            #[track_caller]
+            #[inline]
+            fn truncate_unchecked(self) -> $Dest {
+                $Dest::new($(self.$f.truncate_unchecked()),*)
+            }
+        }
+
+        impl TruncateUnchecked<[$D; $N]> for $Src {
+            #[track_caller]
+            #[inline]
+            fn truncate_unchecked(self) -> [$D; $N] {
+                [$(self.$f.truncate_unchecked()),*]
+            }
+        }
+    };
+}
+
+macro_rules! make_glam {
+    ($S:ty => $D:ty; $Src2:ident, $Src3:ident, $Src4:ident => $Dest2:ident, $Dest3:ident, $Dest4:ident) => {
+        make_glam_vec!($Src2 => $Dest2, $D, 2; x, y);
+        make_glam_vec!($Src3 => $Dest3, $D, 3; x, y, z);
+        make_glam_vec!($Src4 => $Dest4, $D, 4; x, y, z, w);
+    };
+}
+
+macro_rules! make_glam_all {
+    ($S:ty => $D:ty; $Src2:ident, $Src3:ident, $Src4:ident => $Dest2:ident, $Dest3:ident, $Dest4:ident) => {
+        make_glam!($S => $D; $Src2, $Src3, $Src4 => $Dest2, $Dest3, $Dest4);
+        make_glam_vec_unchecked!($Src2 => $Dest2, $D, 2; x, y);
+        make_glam_vec_unchecked!($Src3 => $Dest3, $D, 3; x, y, z);
+        make_glam_vec_unchecked!($Src4 => $Dest4, $D, 4; x, y, z, w);
+    };
+}
+
+make_glam_all!(u64 => u8; U64Vec2, U64Vec3, U64Vec4 => U8Vec2, U8Vec3, U8Vec4);
+make_glam_all!(u64 => u16; U64Vec2, U64Vec3, U64Vec4 => U16Vec2, U16Vec3, U16Vec4);
+make_glam_all!(u64 => u32; U64Vec2, U64Vec3, U64Vec4 => UVec2, UVec3, UVec4);
+make_glam_all!(u32 => u8; UVec2, UVec3, UVec4 => U8Vec2, U8Vec3, U8Vec4);
+make_glam_all!(u32 => u16; UVec2, UVec3, UVec4 => U16Vec2, U16Vec3, U16Vec4);
+make_glam_all!(u16 => u8; U16Vec2, U16Vec3, U16Vec4 => U8Vec2, U8Vec3, U8Vec4);
+make_glam_all!(u64 => i8; U64Vec2, U64Vec3, U64Vec4 => I8Vec2, I8Vec3, I8Vec4);
+make_glam_all!(u64 => i16; U64Vec2, U64Vec3, U64Vec4 => I16Vec2, I16Vec3, I16Vec4);
+make_glam_all!(u64 => i32; U64Vec2, U64Vec3, U64Vec4 => IVec2, IVec3, IVec4);
+make_glam_all!(u32 => i8; UVec2, UVec3, UVec4 => I8Vec2, I8Vec3, I8Vec4);
+make_glam_all!(u32 => i16; UVec2, UVec3, UVec4 => I16Vec2, I16Vec3, I16Vec4);
+make_glam_all!(u16 => i8; U16Vec2, U16Vec3, U16Vec4 => I8Vec2, I8Vec3, I8Vec4);
+make_glam_all!(i64 => i8; I64Vec2, I64Vec3, I64Vec4 => I8Vec2, I8Vec3, I8Vec4);
+make_glam_all!(i64 => i16; I64Vec2, I64Vec3, I64Vec4 => I16Vec2, I16Vec3, I16Vec4);
+make_glam_all!(i64 => i32; I64Vec2, I64Vec3, I64Vec4 => IVec2, IVec3, IVec4);
+make_glam_all!(i32 => i8; IVec2, IVec3, IVec4 => I8Vec2, I8Vec3, I8Vec4);
+make_glam_all!(i32 => i16; IVec2, IVec3, IVec4 => I16Vec2, I16Vec3, I16Vec4);
+make_glam_all!(i16 => i8; I16Vec2, I16Vec3, I16Vec4 => I8Vec2, I8Vec3, I8Vec4);
+make_glam_all!(i64 => u8; I64Vec2, I64Vec3, I64Vec4 => U8Vec2, U8Vec3, U8Vec4);
+make_glam_all!(i64 => u16; I64Vec2, I64Vec3, I64Vec4 => U16Vec2, U16Vec3, U16Vec4);
+make_glam_all!(i64 => u32; I64Vec2, I64Vec3, I64Vec4 => UVec2, UVec3, UVec4);
+make_glam_all!(i32 => u8; IVec2, IVec3, IVec4 => U8Vec2, U8Vec3, U8Vec4);
+make_glam_all!(i32 => u16; IVec2, IVec3, IVec4 => U16Vec2, U16Vec3, U16Vec4);
+make_glam_all!(i16 => u8; I16Vec2, I16Vec3, I16Vec4 => U8Vec2, U8Vec3, U8Vec4);
+make_glam_all!(i32 => u64; IVec2, IVec3, IVec4 => U64Vec2, U64Vec3, U64Vec4);
+make_glam_all!(i16 => u64; I16Vec2, I16Vec3, I16Vec4 => U64Vec2, U64Vec3, U64Vec4);
+make_glam_all!(i16 => u32; I16Vec2, I16Vec3, I16Vec4 => UVec2, UVec3, UVec4);
+make_glam_all!(i8 => u64; I8Vec2, I8Vec3, I8Vec4 => U64Vec2, U64Vec3, U64Vec4);
+make_glam_all!(i8 => u32; I8Vec2, I8Vec3, I8Vec4 => UVec2, UVec3, UVec4);
+make_glam_all!(i8 => u16; I8Vec2, I8Vec3, I8Vec4 => U16Vec2, U16Vec3, U16Vec4);
@@ -0,0 +1,165 @@
+//! Hashers with short outputs.
+//!
+//! [`TruncatingHasher`] wraps any [`Hasher`], and narrows its 64-bit
+//! result into a `u8`, `u16`, or `u32`. This is useful for short
+//! fingerprints, e.g. in bloom filters or for picking a shard.
+//!
+//! ```rust
+//! use std::collections::hash_map::DefaultHasher;
+//! use std::hash::{Hash, Hasher};
+//! use truncate_integer::hash::{Fold, TruncatingHasher};
+//!
+//! let mut hasher = TruncatingHasher::<_, u16>::new(DefaultHasher::new(), Fold::Xor);
+//! "hello".hash(&mut hasher);
+//! let fingerprint: u16 = hasher.finish_narrow();
+//! assert_eq!(u64::from(fingerprint), hasher.finish());
+//! ```
+
+use crate::TruncateUnchecked;
+use core::hash::{BuildHasher, Hasher};
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+/// How a [`TruncatingHasher`] narrows the inner hasher's result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Fold {
+    /// Keep only the low-order bits.
+    LowBits,
+    /// XOR the high half of the bits into the low half, repeatedly,
+    /// until the value fits.
+    ///
+    /// This is a better choice if the inner hasher doesn't mix its
+    /// output well.
+    Xor,
+}
+
+impl Fold {
+    /// Narrow a 64-bit hash into `T`.
+    #[track_caller]
+    pub fn fold<T>(self, hash: u64) -> T
+    where
+        u64: TruncateUnchecked<T>,
+    {
+        let bits = (size_of::<T>() * 8) as u32;
+        let mut hash = hash;
+        if self == Fold::Xor {
+            let mut width = 64;
+            while width > bits {
+                width /= 2;
+                hash ^= hash >> width;
+            }
+        }
+        hash.truncate_unchecked()
+    }
+}
+
+/// A [`Hasher`] whose result is narrowed into `T`.
+///
+/// [`Hasher::finish`] still returns a `u64`, but its value will always
+/// fit into `T`. Use [`finish_narrow`](Self::finish_narrow) to get the
+/// narrowed value directly.
+#[derive(Clone, Debug)]
+pub struct TruncatingHasher<H, T> {
+    inner: H,
+    fold: Fold,
+    _narrow: PhantomData<T>,
+}
+
+impl<H, T> TruncatingHasher<H, T> {
+    /// Wrap a `Hasher`.
+    pub fn new(inner: H, fold: Fold) -> Self {
+        TruncatingHasher {
+            inner,
+            fold,
+            _narrow: PhantomData,
+        }
+    }
+
+    /// Unwrap the inner `Hasher`.
+    pub fn into_inner(self) -> H {
+        self.inner
+    }
+}
+
+impl<H, T> TruncatingHasher<H, T>
+where
+    H: Hasher,
+    u64: TruncateUnchecked<T>,
+{
+    /// Return the narrowed hash value.
+    #[track_caller]
+    pub fn finish_narrow(&self) -> T {
+        self.fold.fold(self.inner.finish())
+    }
+}
+
+impl<H, T> Hasher for TruncatingHasher<H, T>
+where
+    H: Hasher,
+    u64: TruncateUnchecked<T>,
+    T: Into<u64>,
+{
+    fn finish(&self) -> u64 {
+        self.finish_narrow().into()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.inner.write(bytes);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.inner.write_u8(i);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.inner.write_u16(i);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.inner.write_u32(i);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.inner.write_u64(i);
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.inner.write_u128(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.inner.write_usize(i);
+    }
+}
+
+/// A [`BuildHasher`] that creates [`TruncatingHasher`]s.
+#[derive(Clone, Debug)]
+pub struct BuildTruncatingHasher<B, T> {
+    inner: B,
+    fold: Fold,
+    _narrow: PhantomData<T>,
+}
+
+impl<B, T> BuildTruncatingHasher<B, T> {
+    /// Wrap a `BuildHasher`.
+    pub fn new(inner: B, fold: Fold) -> Self {
+        BuildTruncatingHasher {
+            inner,
+            fold,
+            _narrow: PhantomData,
+        }
+    }
+}
+
+impl<B, T> BuildHasher for BuildTruncatingHasher<B, T>
+where
+    B: BuildHasher,
+    u64: TruncateUnchecked<T>,
+    T: Into<u64>,
+{
+    type Hasher = TruncatingHasher<B::Hasher, T>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        TruncatingHasher::new(self.inner.build_hasher(), self.fold)
+    }
+}
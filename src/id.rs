@@ -0,0 +1,205 @@
+//! Handing out dense, narrow ids for sparse keys.
+//!
+//! Graph and ECS serializers replace wide keys (pointers, hashes, entity
+//! handles) with small ids that index into a table. [`IdAllocator`]
+//! interns each key, hands out the lowest free id of type `U`, and looks
+//! keys up again by id.
+//!
+//! When every id is in use, [`OnExhausted`] chooses whether interning a
+//! new key fails, or recycles an id that's in use, forgetting its key.
+//!
+//! ```rust
+//! use truncate_integer::id::{IdAllocator, OnExhausted};
+//!
+//! let mut ids = IdAllocator::<u8>::new(OnExhausted::Error);
+//! assert_eq!(ids.intern(0xDEAD_BEEF), Ok(0));
+//! assert_eq!(ids.intern(0x1234), Ok(1));
+//! assert_eq!(ids.intern(0xDEAD_BEEF), Ok(0));
+//! assert_eq!(ids.key(1), Some(0x1234));
+//!
+//! for key in 2..256 {
+//!     ids.intern(key).unwrap();
+//! }
+//! assert_eq!(ids.intern(1000).unwrap_err().to_string(), "all 256 ids are in use");
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An unsigned integer type used for ids.
+pub trait Id: private::Sealed + Copy + Ord {
+    /// The number of distinct ids.
+    #[doc(hidden)]
+    const COUNT: u64;
+    #[doc(hidden)]
+    fn to_index(self) -> usize;
+    #[doc(hidden)]
+    fn from_index(index: usize) -> Self;
+}
+
+macro_rules! make_id {
+    ($($Type:ty),*) => {
+        $(
+            impl private::Sealed for $Type {}
+
+            impl Id for $Type {
+                const COUNT: u64 = <$Type>::MAX as u64 + 1;
+
+                #[inline]
+                fn to_index(self) -> usize {
+                    self as usize
+                }
+
+                #[inline]
+                fn from_index(index: usize) -> Self {
+                    index as $Type
+                }
+            }
+        )*
+    };
+}
+
+make_id!(u8, u16, u32);
+
+/// What [`IdAllocator::intern`] does when every id is in use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OnExhausted {
+    /// Return an [`IdError`].
+    Error,
+    /// Reuse an id that's in use, in round-robin order, and forget the
+    /// key that had it.
+    Recycle,
+}
+
+/// The error returned when every id is in use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IdError {
+    capacity: u64,
+}
+
+impl IdError {
+    /// Create an `IdError` for an id space of `capacity` ids.
+    pub fn new(capacity: u64) -> Self {
+        IdError { capacity }
+    }
+
+    /// The number of ids.
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+}
+
+impl fmt::Display for IdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "all {} ids are in use", self.capacity)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IdError {}
+
+/// Interns keys of type `K`, and hands out ids of type `U`.
+#[derive(Clone, Debug)]
+pub struct IdAllocator<U, K = u64> {
+    ids: BTreeMap<K, U>,
+    keys: Vec<Option<K>>,
+    free: Vec<U>,
+    policy: OnExhausted,
+    next_victim: usize,
+}
+
+impl<U: Id, K: Ord + Copy> IdAllocator<U, K> {
+    /// Create an empty allocator.
+    pub fn new(policy: OnExhausted) -> Self {
+        IdAllocator {
+            ids: BTreeMap::new(),
+            keys: Vec::new(),
+            free: Vec::new(),
+            policy,
+            next_victim: 0,
+        }
+    }
+
+    /// The number of keys interned.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if no keys are interned.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Get the id of `key`, giving it one if it doesn't have one.
+    ///
+    /// A new key gets the lowest id that was removed, if any, or else the
+    /// next id that hasn't been used yet. If every id is in use, this
+    /// follows the [`OnExhausted`] policy.
+    pub fn intern(&mut self, key: K) -> Result<U, IdError> {
+        if let Some(&id) = self.ids.get(&key) {
+            return Ok(id);
+        }
+        let id = if let Some(id) = self.free.pop() {
+            id
+        } else if (self.keys.len() as u64) < U::COUNT {
+            self.keys.push(None);
+            U::from_index(self.keys.len() - 1)
+        } else {
+            match self.policy {
+                OnExhausted::Error => return Err(IdError::new(U::COUNT)),
+                OnExhausted::Recycle => self.recycle(),
+            }
+        };
+        self.keys[id.to_index()] = Some(key);
+        self.ids.insert(key, id);
+        Ok(id)
+    }
+
+    /// Take the next id in round-robin order away from its key.
+    fn recycle(&mut self) -> U {
+        let index = self.next_victim;
+        self.next_victim = (index + 1) % self.keys.len();
+        if let Some(old) = self.keys[index].take() {
+            self.ids.remove(&old);
+        }
+        U::from_index(index)
+    }
+
+    /// Get the id of `key`, if it has one.
+    pub fn get(&self, key: &K) -> Option<U> {
+        self.ids.get(key).copied()
+    }
+
+    /// Get the key that has `id`, if any.
+    pub fn key(&self, id: U) -> Option<K> {
+        self.keys.get(id.to_index()).copied().flatten()
+    }
+
+    /// Forget `key`, and free its id to be used again.
+    ///
+    /// Returns the id it had.
+    pub fn remove(&mut self, key: &K) -> Option<U> {
+        let id = self.ids.remove(key)?;
+        self.keys[id.to_index()] = None;
+        // Keep the free list sorted so the lowest id is reused first.
+        let pos = self
+            .free
+            .binary_search_by(|x| id.cmp(x))
+            .unwrap_or_else(|e| e);
+        self.free.insert(pos, id);
+        Some(id)
+    }
+
+    /// Iterate over the interned keys in id order.
+    pub fn iter(&self) -> impl Iterator<Item = (U, K)> + '_ {
+        self.keys
+            .iter()
+            .enumerate()
+            .filter_map(|(index, key)| key.map(|key| (U::from_index(index), key)))
+    }
+}
@@ -0,0 +1,112 @@
+//! Bit depth conversion for the [`image`](https://docs.rs/image) crate.
+//!
+//! [`reduce_depth`] converts a 16-bit image to 8 bits per channel,
+//! rescaling each channel with rounding, so that `65535` maps to `255`
+//! and the error is never more than half a step. (Dropping the low byte
+//! instead rounds everything down.) [`reduce_depth_dithered`] adds an
+//! ordered dither, which hides the banding that would otherwise be
+//! visible in smooth gradients.
+//!
+//! [`expand_depth`] converts back to 16 bits; reducing an expanded image
+//! returns the original image.
+//!
+//! ```rust
+//! use image::{ImageBuffer, Rgb};
+//! use truncate_integer::image::{expand_depth, reduce_depth};
+//!
+//! let img: ImageBuffer<Rgb<u16>, _> = ImageBuffer::from_pixel(2, 2, Rgb([0, 32896, 65535]));
+//! let small = reduce_depth(&img);
+//! assert_eq!(small.get_pixel(0, 0), &Rgb([0u8, 128, 255]));
+//! assert_eq!(reduce_depth(&expand_depth(&small)), small);
+//! ```
+
+use ::image::{ImageBuffer, Luma, LumaA, Pixel, Rgb, Rgba};
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+/// A 16-bit pixel type that has an 8-bit equivalent.
+pub trait WidePixel: Pixel<Subpixel = u16> {
+    /// The same pixel type, with 8-bit channels.
+    type Narrow: Pixel<Subpixel = u8>;
+}
+
+/// An 8-bit pixel type that has a 16-bit equivalent.
+pub trait NarrowPixel: Pixel<Subpixel = u8> {
+    /// The same pixel type, with 16-bit channels.
+    type Wide: Pixel<Subpixel = u16>;
+}
+
+macro_rules! make_pixel {
+    ($($Pixel:ident),*) => {
+        $(
+            impl WidePixel for $Pixel<u16> {
+                type Narrow = $Pixel<u8>;
+            }
+
+            impl NarrowPixel for $Pixel<u8> {
+                type Wide = $Pixel<u16>;
+            }
+        )*
+    };
+}
+
+make_pixel!(Luma, LumaA, Rgb, Rgba);
+
+/// A 4x4 Bayer matrix, for ordered dithering.
+const BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Rescale a 16-bit channel to 8 bits, rounding up if the discarded
+/// fraction is at least `bias / 65535`.
+#[inline]
+fn narrow(value: u16, bias: u32) -> u8 {
+    ((u32::from(value) * 255 + bias) / 65535) as u8
+}
+
+/// Convert a 16-bit image to 8 bits per channel, with rounding.
+pub fn reduce_depth<P, C>(img: &ImageBuffer<P, C>) -> ImageBuffer<P::Narrow, Vec<u8>>
+where
+    P: WidePixel,
+    C: Deref<Target = [u16]>,
+{
+    let raw = img.as_raw().iter().map(|&x| narrow(x, 65535 / 2)).collect();
+    ImageBuffer::from_raw(img.width(), img.height(), raw).expect("buffer size mismatch")
+}
+
+/// Convert a 16-bit image to 8 bits per channel, with an ordered dither.
+///
+/// Each pixel's rounding threshold varies with its position, so areas
+/// whose value falls between two 8-bit steps are rendered as a fine
+/// pattern of both, rather than a flat band of one.
+pub fn reduce_depth_dithered<P, C>(img: &ImageBuffer<P, C>) -> ImageBuffer<P::Narrow, Vec<u8>>
+where
+    P: WidePixel,
+    C: Deref<Target = [u16]>,
+{
+    let channels = usize::from(P::CHANNEL_COUNT);
+    let width = img.width() as usize;
+    let raw = img
+        .as_raw()
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let pixel = i / channels;
+            let (col, row) = (pixel % width, pixel / width);
+            let threshold = u32::from(BAYER[row % 4][col % 4]);
+            // Thresholds are evenly spaced across (0, 1).
+            narrow(x, (2 * threshold + 1) * 65535 / 32)
+        })
+        .collect();
+    ImageBuffer::from_raw(img.width(), img.height(), raw).expect("buffer size mismatch")
+}
+
+/// Convert an 8-bit image to 16 bits per channel.
+///
+/// This is exact: `255` maps to `65535`, and each step is the same size.
+pub fn expand_depth<P, C>(img: &ImageBuffer<P, C>) -> ImageBuffer<P::Wide, Vec<u16>>
+where
+    P: NarrowPixel,
+    C: Deref<Target = [u8]>,
+{
+    let raw = img.as_raw().iter().map(|&x| u16::from(x) * 257).collect();
+    ImageBuffer::from_raw(img.width(), img.height(), raw).expect("buffer size mismatch")
+}
@@ -0,0 +1,189 @@
+//! Immediate fields for assemblers and JIT compilers.
+//!
+//! Instruction formats encode constants in narrow immediate fields,
+//! often scaled: an AArch64 `ldr` offset is a 12-bit unsigned field
+//! counted in units of the access size, and a RISC-V branch offset is a
+//! 12-bit signed field counted in units of two bytes. An immediate with
+//! a `shift` stores `value >> shift`, so `value` must be a multiple of
+//! `1 << shift`.
+//!
+//! ```rust
+//! use truncate_integer::imm::{encode_signed_imm, fits_signed_imm, fits_unsigned_imm, ImmError};
+//!
+//! // RISC-V B-type: 12 bits, scaled by 2.
+//! assert!(fits_signed_imm(-4096, 12, 1));
+//! assert!(!fits_signed_imm(4096, 12, 1));
+//! assert!(!fits_signed_imm(3, 12, 1));
+//! assert_eq!(encode_signed_imm(-2, 12, 1), Ok(0xFFF));
+//!
+//! // AArch64 64-bit ldr: 12 bits, scaled by 8.
+//! assert!(fits_unsigned_imm(32760, 12, 3));
+//! assert_eq!(encode_signed_imm(5000, 8, 0), Err(ImmError::OutOfRange { value: 5000, bits: 8 }));
+//! ```
+
+use core::fmt;
+
+/// The error returned when a constant doesn't fit in an immediate field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImmError {
+    /// The value isn't a multiple of `1 << shift`.
+    Misaligned {
+        /// The value being encoded.
+        value: i128,
+        /// The field's shift.
+        shift: u32,
+    },
+    /// The scaled value doesn't fit in the field.
+    OutOfRange {
+        /// The value being encoded.
+        value: i128,
+        /// The width of the field.
+        bits: u32,
+    },
+}
+
+impl fmt::Display for ImmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImmError::Misaligned { value, shift } => {
+                write!(
+                    f,
+                    "immediate {} is not a multiple of {}",
+                    value,
+                    1u64 << shift
+                )
+            }
+            ImmError::OutOfRange { value, bits } => {
+                write!(f, "immediate {} doesn't fit in {} bits", value, bits)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ImmError {}
+
+#[track_caller]
+#[inline]
+fn check_width(bits: u32, shift: u32) {
+    assert!(
+        bits != 0 && bits <= 64 && shift < 64,
+        "invalid immediate width"
+    );
+}
+
+/// Returns `true` if `value` can be encoded in a `bits`-bit signed
+/// immediate field, scaled by `1 << shift`.
+///
+/// # Panics
+///
+/// Panics if `bits` isn't in the range `1..=64`, or `shift` is 64 or
+/// more.
+#[track_caller]
+#[inline]
+pub fn fits_signed_imm(value: i64, bits: u32, shift: u32) -> bool {
+    encode_signed_imm(value, bits, shift).is_ok()
+}
+
+/// Returns `true` if `value` can be encoded in a `bits`-bit unsigned
+/// immediate field, scaled by `1 << shift`.
+///
+/// # Panics
+///
+/// Panics if `bits` isn't in the range `1..=64`, or `shift` is 64 or
+/// more.
+#[track_caller]
+#[inline]
+pub fn fits_unsigned_imm(value: u64, bits: u32, shift: u32) -> bool {
+    encode_unsigned_imm(value, bits, shift).is_ok()
+}
+
+/// Encode `value` as a `bits`-bit signed immediate, scaled by
+/// `1 << shift`.
+///
+/// Returns the two's complement field bits, in the low `bits` bits.
+///
+/// # Panics
+///
+/// Panics if `bits` isn't in the range `1..=64`, or `shift` is 64 or
+/// more.
+#[track_caller]
+pub fn encode_signed_imm(value: i64, bits: u32, shift: u32) -> Result<u64, ImmError> {
+    check_width(bits, shift);
+    if (value as u64) & ((1u64 << shift) - 1) != 0 {
+        return Err(ImmError::Misaligned {
+            value: value as i128,
+            shift,
+        });
+    }
+    let scaled = value >> shift;
+    let min = i64::MIN >> (64 - bits);
+    let max = i64::MAX >> (64 - bits);
+    if !(min..=max).contains(&scaled) {
+        return Err(ImmError::OutOfRange {
+            value: value as i128,
+            bits,
+        });
+    }
+    Ok(scaled as u64 & (u64::MAX >> (64 - bits)))
+}
+
+/// Encode `value` as a `bits`-bit unsigned immediate, scaled by
+/// `1 << shift`.
+///
+/// # Panics
+///
+/// Panics if `bits` isn't in the range `1..=64`, or `shift` is 64 or
+/// more.
+#[track_caller]
+pub fn encode_unsigned_imm(value: u64, bits: u32, shift: u32) -> Result<u64, ImmError> {
+    check_width(bits, shift);
+    if value & ((1u64 << shift) - 1) != 0 {
+        return Err(ImmError::Misaligned {
+            value: value as i128,
+            shift,
+        });
+    }
+    let scaled = value >> shift;
+    if scaled > u64::MAX >> (64 - bits) {
+        return Err(ImmError::OutOfRange {
+            value: value as i128,
+            bits,
+        });
+    }
+    Ok(scaled)
+}
+
+/// Decode a `bits`-bit signed immediate field, scaled by `1 << shift`.
+///
+/// Bits above the field are ignored. The result wraps if it doesn't fit
+/// in an `i64`.
+///
+/// # Panics
+///
+/// Panics if `bits` isn't in the range `1..=64`, or `shift` is 64 or
+/// more.
+#[track_caller]
+#[inline]
+pub fn decode_signed_imm(field: u64, bits: u32, shift: u32) -> i64 {
+    check_width(bits, shift);
+    // Sign-extend from `bits` bits.
+    let extended = ((field << (64 - bits)) as i64) >> (64 - bits);
+    extended.wrapping_shl(shift)
+}
+
+/// Decode a `bits`-bit unsigned immediate field, scaled by `1 << shift`.
+///
+/// Bits above the field are ignored. The result wraps if it doesn't fit
+/// in a `u64`.
+///
+/// # Panics
+///
+/// Panics if `bits` isn't in the range `1..=64`, or `shift` is 64 or
+/// more.
+#[track_caller]
+#[inline]
+pub fn decode_unsigned_imm(field: u64, bits: u32, shift: u32) -> u64 {
+    check_width(bits, shift);
+    (field & (u64::MAX >> (64 - bits))).wrapping_shl(shift)
+}
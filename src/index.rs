@@ -0,0 +1,136 @@
+//! Converting mesh indices into 16-bit index buffers.
+//!
+//! GPUs can draw from 16-bit index buffers, which are half the size of
+//! 32-bit ones, but only if every index fits. [`try_to_u16`] converts a
+//! whole buffer, and reports the first index that doesn't fit.
+//! [`shrink_to_u16`] saturates instead, which is useful for debug
+//! visualizations where a mangled mesh is better than none.
+//!
+//! ```rust
+//! use truncate_integer::index::{try_to_u16, IndexError};
+//!
+//! let indices = [0u32, 1, 2, 2, 1, 70000];
+//! let mut out = [0u16; 6];
+//! let err = try_to_u16(&indices, &mut out).unwrap_err();
+//! assert_eq!(err, IndexError::new(5, 70000));
+//! assert_eq!(err.to_string(), "index 70000 at position 5 doesn't fit in u16");
+//! ```
+//!
+//! Note that many graphics APIs reserve `0xFFFF` as a primitive restart
+//! marker when it's enabled.
+
+use crate::{Shrink, TryTruncate};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An integer type used for mesh indices.
+pub trait MeshIndex: private::Sealed + Copy + TryTruncate<u16> + Shrink<u16> {
+    /// Widen the index, for error reporting.
+    fn to_u64(self) -> u64;
+}
+
+macro_rules! make_mesh_index {
+    ($($Type:ty),*) => {
+        $(
+            impl private::Sealed for $Type {}
+
+            impl MeshIndex for $Type {
+                #[inline]
+                fn to_u64(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+}
+
+make_mesh_index!(u32, u64, usize);
+
+/// The error returned when an index doesn't fit in a `u16`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct IndexError {
+    position: usize,
+    value: u64,
+}
+
+impl IndexError {
+    /// Create an error for the index `value` at `position`.
+    pub fn new(position: usize, value: u64) -> Self {
+        IndexError { position, value }
+    }
+
+    /// The position of the first index that didn't fit.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The value of the first index that didn't fit.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} at position {} doesn't fit in u16",
+            self.value, self.position
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexError {}
+
+/// Convert indices into `u16`, failing if any of them doesn't fit.
+///
+/// If an index doesn't fit, the contents of `dst` are unspecified.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+#[track_caller]
+pub fn try_to_u16<T: MeshIndex>(src: &[T], dst: &mut [u16]) -> Result<(), IndexError> {
+    assert_eq!(src.len(), dst.len(), "index buffer length mismatch");
+    for (position, (out, &index)) in dst.iter_mut().zip(src).enumerate() {
+        match index.try_truncate() {
+            Some(index) => *out = index,
+            None => return Err(IndexError::new(position, index.to_u64())),
+        }
+    }
+    Ok(())
+}
+
+/// Convert indices into `u16`, saturating any that don't fit.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+#[track_caller]
+pub fn shrink_to_u16<T: MeshIndex>(src: &[T], dst: &mut [u16]) {
+    assert_eq!(src.len(), dst.len(), "index buffer length mismatch");
+    for (out, &index) in dst.iter_mut().zip(src) {
+        *out = index.shrink();
+    }
+}
+
+/// Convert indices into a new `u16` buffer, failing if any of them
+/// doesn't fit.
+#[cfg(feature = "alloc")]
+pub fn try_to_u16_vec<T: MeshIndex>(src: &[T]) -> Result<Vec<u16>, IndexError> {
+    src.iter()
+        .enumerate()
+        .map(|(position, &index)| {
+            index
+                .try_truncate()
+                .ok_or_else(|| IndexError::new(position, index.to_u64()))
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+//! Order-preserving prefixes of big-endian keys.
+//!
+//! Key-value stores usually encode integer keys in big-endian order, so
+//! that comparing the encoded bytes gives the same answer as comparing
+//! the integers. Keeping only the first few bytes of each key gives a
+//! shorter key with a weaker guarantee:
+//!
+//! - If `a <= b`, then `prefix(a) <= prefix(b)`.
+//! - If `prefix(a) < prefix(b)`, then `a < b`.
+//!
+//! Two different keys can have the same prefix, so a prefix can't be
+//! used as a strict bound. To turn a key into an exclusive upper bound
+//! on prefixes, round up with the `*_ceil` functions or [`successor`].
+//! These return `None` if the prefix is all `0xFF` bytes, since no
+//! prefix of that length is larger; the range is unbounded.
+//!
+//! ```rust
+//! use truncate_integer::key::{prefix_bytes, prefix_u64, prefix_u64_ceil, successor_in_place};
+//!
+//! let key = 0x1234_5678_9ABC_DEF0u64;
+//! assert_eq!(prefix_u64(key, 2), 0x1234);
+//! assert_eq!(prefix_u64_ceil(key, 2), Some(0x1235));
+//! assert_eq!(prefix_u64_ceil(0x1234_0000_0000_0000, 2), Some(0x1234));
+//! assert_eq!(prefix_u64_ceil(u64::MAX, 2), None);
+//!
+//! assert_eq!(prefix_bytes(b"user:1234", 5), b"user:");
+//! let mut bound = *b"ab\xFF\xFF";
+//! assert_eq!(successor_in_place(&mut bound), Some(2));
+//! assert_eq!(&bound[..2], b"ac");
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+macro_rules! make_key_prefix {
+    ($Type:ty, $prefix:ident, $ceil:ident) => {
+        #[doc = concat!("The first `len` bytes of the big-endian encoding of a `", stringify!($Type), "`.")]
+        ///
+        /// # Panics
+        ///
+        /// Panics if `len` is zero or larger than the size of the key.
+        #[track_caller]
+        #[inline]
+        pub fn $prefix(key: $Type, len: usize) -> $Type {
+            const SIZE: usize = core::mem::size_of::<$Type>();
+            assert!(len != 0 && len <= SIZE, "invalid prefix length");
+            key >> (8 * (SIZE - len))
+        }
+
+        #[doc = concat!("The smallest `len`-byte prefix of a `", stringify!($Type), "` that is at least as large as `key`.")]
+        ///
+        /// This is the prefix of `key` if the discarded bytes are all
+        /// zero, and the next prefix otherwise. Returns `None` if there
+        /// is no larger prefix.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `len` is zero or larger than the size of the key.
+        #[track_caller]
+        #[inline]
+        pub fn $ceil(key: $Type, len: usize) -> Option<$Type> {
+            const SIZE: usize = core::mem::size_of::<$Type>();
+            let prefix = $prefix(key, len);
+            if len == SIZE || key << (8 * len) == 0 {
+                return Some(prefix);
+            }
+            let next = prefix + 1;
+            if $prefix(<$Type>::MAX, len) < next {
+                None
+            } else {
+                Some(next)
+            }
+        }
+    };
+}
+
+make_key_prefix!(u64, prefix_u64, prefix_u64_ceil);
+make_key_prefix!(u128, prefix_u128, prefix_u128_ceil);
+
+/// The first `len` bytes of a key, or the whole key if it's shorter.
+#[inline]
+pub fn prefix_bytes(key: &[u8], len: usize) -> &[u8] {
+    &key[..key.len().min(len)]
+}
+
+/// Replace `prefix` with the smallest byte string that is larger than
+/// every string starting with `prefix`.
+///
+/// This drops any trailing `0xFF` bytes, then increments the last byte.
+/// Returns the length of the result, which is no longer than the
+/// original, or `None` if `prefix` is empty or all `0xFF`.
+#[inline]
+pub fn successor_in_place(prefix: &mut [u8]) -> Option<usize> {
+    let last = prefix.iter().rposition(|&b| b != 0xFF)?;
+    prefix[last] += 1;
+    Some(last + 1)
+}
+
+/// The smallest byte string that is larger than every string starting
+/// with `prefix`.
+///
+/// Returns `None` if `prefix` is empty or all `0xFF`.
+#[cfg(feature = "alloc")]
+pub fn successor(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut next = prefix.to_vec();
+    let len = successor_in_place(&mut next)?;
+    next.truncate(len);
+    Some(next)
+}
@@ -0,0 +1,121 @@
+/// The cheapest way to perform a conversion between two types.
+///
+/// See [`conversion_kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConversionKind {
+    /// Every source value is exactly representable in the destination type.
+    Lossless,
+    /// The conversion only fails if the source value is negative.
+    SignCheckOnly,
+    /// The conversion fails if the source value is outside the
+    /// destination type's range.
+    RangeChecked,
+    /// Some source values that are within the destination's range can't
+    /// be represented exactly, e.g. `u64` to `f64`.
+    Lossy,
+}
+
+impl ConversionKind {
+    /// Returns `true` if the conversion can't fail or change the value.
+    pub const fn is_lossless(self) -> bool {
+        matches!(self, ConversionKind::Lossless)
+    }
+
+    const fn between_integers(
+        src_bits: u32,
+        src_signed: bool,
+        dest_bits: u32,
+        dest_signed: bool,
+    ) -> Self {
+        match (src_signed, dest_signed) {
+            (false, false) | (true, true) if dest_bits >= src_bits => ConversionKind::Lossless,
+            (false, true) if dest_bits > src_bits => ConversionKind::Lossless,
+            (true, false) if dest_bits >= src_bits => ConversionKind::SignCheckOnly,
+            _ => ConversionKind::RangeChecked,
+        }
+    }
+
+    const fn integer_to_float(src_bits: u32, src_signed: bool, mantissa_digits: u32) -> Self {
+        // The sign bit doesn't need to be stored in the mantissa.
+        let magnitude_bits = if src_signed { src_bits - 1 } else { src_bits };
+        if magnitude_bits <= mantissa_digits {
+            ConversionKind::Lossless
+        } else {
+            ConversionKind::Lossy
+        }
+    }
+}
+
+mod private {
+    pub trait IntLayout {
+        const BITS: u32;
+        const SIGNED: bool;
+    }
+}
+
+use private::IntLayout;
+
+macro_rules! make_layout {
+    ($signed:expr, $($Type:ty),*) => {
+        $(
+            impl IntLayout for $Type {
+                const BITS: u32 = (core::mem::size_of::<$Type>() * 8) as u32;
+                const SIGNED: bool = $signed;
+            }
+        )*
+    };
+}
+
+make_layout!(false, u8, u16, u32, u64, u128, usize);
+make_layout!(true, i8, i16, i32, i64, i128, isize);
+
+/// Describes the conversion from `Self` into `Dest`.
+///
+/// This is implemented between every pair of primitive integer types,
+/// and from every integer type into `f32` and `f64`.
+///
+/// Because `KIND` is an associated const, it can be used in const
+/// contexts and will be evaluated at compile time.
+///
+/// ```rust
+/// use truncate_integer::{ConversionKind, ConversionKindOf};
+///
+/// const KIND: ConversionKind = <i32 as ConversionKindOf<u64>>::KIND;
+/// assert_eq!(KIND, ConversionKind::SignCheckOnly);
+/// ```
+pub trait ConversionKindOf<Dest> {
+    /// The kind of conversion from `Self` into `Dest`.
+    const KIND: ConversionKind;
+}
+
+impl<Source: IntLayout, Dest: IntLayout> ConversionKindOf<Dest> for Source {
+    const KIND: ConversionKind =
+        ConversionKind::between_integers(Source::BITS, Source::SIGNED, Dest::BITS, Dest::SIGNED);
+}
+
+impl<Source: IntLayout> ConversionKindOf<f32> for Source {
+    const KIND: ConversionKind =
+        ConversionKind::integer_to_float(Source::BITS, Source::SIGNED, f32::MANTISSA_DIGITS);
+}
+
+impl<Source: IntLayout> ConversionKindOf<f64> for Source {
+    const KIND: ConversionKind =
+        ConversionKind::integer_to_float(Source::BITS, Source::SIGNED, f64::MANTISSA_DIGITS);
+}
+
+/// Describe the conversion from `Source` into `Dest`.
+///
+/// ```rust
+/// use truncate_integer::{conversion_kind, ConversionKind};
+///
+/// assert_eq!(conversion_kind::<u8, i16>(), ConversionKind::Lossless);
+/// assert_eq!(conversion_kind::<i8, u8>(), ConversionKind::SignCheckOnly);
+/// assert_eq!(conversion_kind::<u16, i16>(), ConversionKind::RangeChecked);
+/// assert_eq!(conversion_kind::<u64, f64>(), ConversionKind::Lossy);
+/// ```
+pub fn conversion_kind<Source, Dest>() -> ConversionKind
+where
+    Source: ConversionKindOf<Dest>,
+{
+    Source::KIND
+}
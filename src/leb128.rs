@@ -0,0 +1,196 @@
+//! Decoding LEB128 integers, then narrowing them.
+//!
+//! DWARF and WebAssembly store integers as LEB128 varints, and parsers
+//! decode each one and then narrow it into a field type in two steps.
+//! These functions do both: the varint is read into a 128-bit
+//! accumulator, and then narrowed into `T` with the chosen truncation
+//! mode.
+//!
+//! - `*_try` functions use [`TryTruncate`](crate::TryTruncate)
+//!   semantics, reporting a value that doesn't fit as
+//!   [`LebError::Truncate`].
+//! - `*_shrink` functions saturate, like [`Shrink`](crate::Shrink).
+//! - `*_unchecked` functions keep the low-order bits, like
+//!   [`TruncateUnchecked`](crate::TruncateUnchecked).
+//!
+//! Every mode reports an encoding that ends in the middle of a value as
+//! [`LebError::UnexpectedEnd`], so a malformed input is never confused
+//! with an overflow. Values too large for 128 bits are still decoded
+//! exactly enough to narrow them, and padding bytes are accepted.
+//!
+//! Each function returns the value and the number of bytes read.
+//!
+//! ```rust
+//! use truncate_integer::leb128::{sleb128_try, uleb128_shrink, uleb128_try, LebError};
+//! use truncate_integer::TruncateError;
+//!
+//! let buf = [0xE5, 0x8E, 0x26];
+//! assert_eq!(uleb128_try::<u32>(&buf), Ok((624_485, 3)));
+//! assert_eq!(uleb128_try::<u16>(&buf), Err(LebError::Truncate(TruncateError::Overflow)));
+//! assert_eq!(uleb128_shrink::<u16>(&buf), Ok((u16::MAX, 3)));
+//! assert_eq!(sleb128_try::<i8>(&[0x7F]), Ok((-1, 1)));
+//! assert_eq!(uleb128_try::<u8>(&[0x80, 0x80]), Err(LebError::UnexpectedEnd));
+//! ```
+
+use crate::wide::{Primitive, Wide};
+use crate::TruncateError;
+use core::fmt;
+
+/// The error returned when decoding fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LebError {
+    /// The input ended in the middle of a value, so the encoding is
+    /// malformed.
+    UnexpectedEnd,
+    /// The value doesn't fit into the destination type.
+    Truncate(TruncateError),
+}
+
+impl fmt::Display for LebError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LebError::UnexpectedEnd => f.write_str("unterminated LEB128 value"),
+            LebError::Truncate(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LebError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LebError::Truncate(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<TruncateError> for LebError {
+    fn from(e: TruncateError) -> Self {
+        LebError::Truncate(e)
+    }
+}
+
+/// A decoded value.
+struct Decoded {
+    /// The low-order 128 bits.
+    bits: u128,
+    /// The value, or whether it's above or below every 128-bit integer.
+    value: Result<Wide, TruncateError>,
+    /// The number of bytes read.
+    len: usize,
+}
+
+/// The 7-bit groups of a varint: the low 128 bits, whether any
+/// explicit bits above them are set or clear, and the length.
+fn groups(buf: &[u8]) -> Result<(u128, bool, bool, u32, usize), LebError> {
+    let mut bits = 0u128;
+    let mut any_high_set = false;
+    let mut any_high_clear = false;
+    let mut shift = 0u32;
+    for (i, &byte) in buf.iter().enumerate() {
+        let group = u128::from(byte & 0x7F);
+        if shift < 128 {
+            bits |= group << shift;
+        }
+        if shift + 7 > 128 {
+            // The part of this group at bit 128 and above.
+            let skip = 128u32.saturating_sub(shift);
+            let high = group >> skip;
+            let width = 7 - skip;
+            any_high_set |= high != 0;
+            any_high_clear |= high != (1 << width) - 1;
+        }
+        shift = shift.saturating_add(7);
+        if byte & 0x80 == 0 {
+            return Ok((bits, any_high_set, any_high_clear, shift, i + 1));
+        }
+    }
+    Err(LebError::UnexpectedEnd)
+}
+
+fn decode_unsigned(buf: &[u8]) -> Result<Decoded, LebError> {
+    let (bits, any_high_set, _, _, len) = groups(buf)?;
+    let value = if any_high_set {
+        Err(TruncateError::Overflow)
+    } else {
+        Ok(Wide::NonNegative(bits))
+    };
+    Ok(Decoded { bits, value, len })
+}
+
+fn decode_signed(buf: &[u8]) -> Result<Decoded, LebError> {
+    let (mut bits, any_high_set, any_high_clear, shift, len) = groups(buf)?;
+    let negative = buf[len - 1] & 0x40 != 0;
+    if negative && shift < 128 {
+        bits |= u128::MAX << shift;
+    }
+    let value = if !negative {
+        if any_high_set {
+            Err(TruncateError::Overflow)
+        } else {
+            Ok(Wide::NonNegative(bits))
+        }
+    } else if any_high_clear || (bits as i128) >= 0 {
+        Err(TruncateError::Underflow)
+    } else {
+        Ok(Wide::Negative(bits as i128))
+    };
+    Ok(Decoded { bits, value, len })
+}
+
+fn narrow_try<T: Primitive>(decoded: Decoded) -> Result<(T, usize), LebError> {
+    let wide = decoded.value?;
+    let value = T::from_wide_checked(wide).ok_or(if wide < T::MIN_WIDE {
+        TruncateError::Underflow
+    } else {
+        TruncateError::Overflow
+    })?;
+    Ok((value, decoded.len))
+}
+
+fn narrow_shrink<T: Primitive>(decoded: Decoded) -> (T, usize) {
+    let value = match decoded.value {
+        Ok(wide) => T::from_wide_saturating(wide),
+        Err(TruncateError::Underflow) => T::from_wide_wrapping(T::MIN_WIDE),
+        Err(_) => T::from_wide_wrapping(T::MAX_WIDE),
+    };
+    (value, decoded.len)
+}
+
+fn narrow_unchecked<T: Primitive>(decoded: Decoded) -> (T, usize) {
+    let value = T::from_wide_wrapping(Wide::NonNegative(decoded.bits));
+    (value, decoded.len)
+}
+
+/// Decode an unsigned LEB128 value, failing if it doesn't fit into `T`.
+pub fn uleb128_try<T: Primitive>(buf: &[u8]) -> Result<(T, usize), LebError> {
+    narrow_try(decode_unsigned(buf)?)
+}
+
+/// Decode an unsigned LEB128 value, saturating if it doesn't fit into
+/// `T`.
+pub fn uleb128_shrink<T: Primitive>(buf: &[u8]) -> Result<(T, usize), LebError> {
+    Ok(narrow_shrink(decode_unsigned(buf)?))
+}
+
+/// Decode an unsigned LEB128 value, keeping the bits that fit into `T`.
+pub fn uleb128_unchecked<T: Primitive>(buf: &[u8]) -> Result<(T, usize), LebError> {
+    Ok(narrow_unchecked(decode_unsigned(buf)?))
+}
+
+/// Decode a signed LEB128 value, failing if it doesn't fit into `T`.
+pub fn sleb128_try<T: Primitive>(buf: &[u8]) -> Result<(T, usize), LebError> {
+    narrow_try(decode_signed(buf)?)
+}
+
+/// Decode a signed LEB128 value, saturating if it doesn't fit into `T`.
+pub fn sleb128_shrink<T: Primitive>(buf: &[u8]) -> Result<(T, usize), LebError> {
+    Ok(narrow_shrink(decode_signed(buf)?))
+}
+
+/// Decode a signed LEB128 value, keeping the bits that fit into `T`.
+pub fn sleb128_unchecked<T: Primitive>(buf: &[u8]) -> Result<(T, usize), LebError> {
+    Ok(narrow_unchecked(decode_signed(buf)?))
+}
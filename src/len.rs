@@ -0,0 +1,171 @@
+//! Narrowing collection lengths into length fields.
+//!
+//! Wire formats usually prefix a collection with its length in a small
+//! integer. [`try_len_u16`] and friends return the length of a
+//! collection as that integer, or a [`LenError`] naming the limit that
+//! was exceeded. [`len_u16`] and friends panic instead.
+//!
+//! ```rust
+//! use truncate_integer::len::{len_u8, try_len_u16};
+//!
+//! let name = "example.com";
+//! assert_eq!(len_u8(name), 11);
+//!
+//! let payload = [0u8; 70000];
+//! let err = try_len_u16(&payload).unwrap_err();
+//! assert_eq!(err.length(), 70000);
+//! assert_eq!(err.limit(), 65535);
+//! assert_eq!(err.to_string(), "length 70000 exceeds the limit of 65535");
+//! ```
+
+use core::fmt;
+
+/// A collection with a length.
+pub trait Len {
+    /// The number of elements in the collection.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the collection is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Len for [T] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+}
+
+impl<T, const N: usize> Len for [T; N] {
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+impl Len for str {
+    #[inline]
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! make_len {
+    ($($Type:ty),* $(,)?) => {
+        $(
+            impl<T> Len for $Type {
+                #[inline]
+                fn len(&self) -> usize {
+                    <$Type>::len(self)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl Len for alloc::string::String {
+    #[inline]
+    fn len(&self) -> usize {
+        alloc::string::String::len(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+make_len!(
+    alloc::vec::Vec<T>,
+    alloc::collections::VecDeque<T>,
+    alloc::collections::BTreeSet<T>,
+    alloc::collections::BinaryHeap<T>,
+);
+
+#[cfg(feature = "alloc")]
+impl<K, V> Len for alloc::collections::BTreeMap<K, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        alloc::collections::BTreeMap::len(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> Len for std::collections::HashSet<T, S> {
+    #[inline]
+    fn len(&self) -> usize {
+        std::collections::HashSet::len(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> Len for std::collections::HashMap<K, V, S> {
+    #[inline]
+    fn len(&self) -> usize {
+        std::collections::HashMap::len(self)
+    }
+}
+
+/// The error returned when a length doesn't fit in a length field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct LenError {
+    len: usize,
+    limit: u64,
+}
+
+impl LenError {
+    /// Create an error for a `len` that exceeds `limit`.
+    pub fn new(len: usize, limit: u64) -> Self {
+        LenError { len, limit }
+    }
+
+    /// The length that didn't fit.
+    pub fn length(&self) -> usize {
+        self.len
+    }
+
+    /// The largest length the field can hold.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+}
+
+impl fmt::Display for LenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "length {} exceeds the limit of {}", self.len, self.limit)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LenError {}
+
+macro_rules! make_len_fn {
+    ($Dest:ident, $try_fn:ident, $fn:ident) => {
+        #[doc = concat!("The length of `collection`, as a `", stringify!($Dest), "`.")]
+        #[inline]
+        pub fn $try_fn<C: Len + ?Sized>(collection: &C) -> Result<$Dest, LenError> {
+            let len = collection.len();
+            narrow!(usize, $Dest, len).ok_or_else(|| LenError::new(len, $Dest::MAX as u64))
+        }
+
+        #[doc = concat!("The length of `collection`, as a `", stringify!($Dest), "`.")]
+        ///
+        /// # Panics
+        ///
+        /// Panics if the length doesn't fit.
+        #[track_caller]
+        #[inline]
+        pub fn $fn<C: Len + ?Sized>(collection: &C) -> $Dest {
+            match $try_fn(collection) {
+                Ok(len) => len,
+                Err(err) => panic!("{}", err),
+            }
+        }
+    };
+}
+
+make_len_fn!(u8, try_len_u8, len_u8);
+make_len_fn!(u16, try_len_u16, len_u16);
+make_len_fn!(u32, try_len_u32, len_u32);
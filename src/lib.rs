@@ -3,14 +3,14 @@
 //! There are several ways one might want to do integer truncation in Rust:
 //!
 //! - Unchecked: truncation may result in a changed value. You only get
-//! the low-order N bits.
+//!   the low-order N bits.
 //! - Checked: if truncation would result in a changed value, return `None`,
-//! otherwise `Some(value)`.
+//!   otherwise `Some(value)`.
 //! - Panicking: if truncation would result in a changed value, 'panic!'
-//! This is equivalent to checked truncation with `.unwrap()`, but with a nicer
-//! panic message.
+//!   This is equivalent to checked truncation with `.unwrap()`, but with a nicer
+//!   panic message.
 //! - Saturating: if truncation would result in a changed value, return
-//! the maximum value that would fit in the target type.
+//!   the maximum value that would fit in the target type.
 //!
 //! It's possible to get all of these in Rust without importing additional
 //! crates or writing much code, for example:
@@ -52,10 +52,246 @@
 //! - [`ShrinkFrom`]
 //!
 //! All of the truncations are implemented for both signed and unsigned
-//! integers (including signed-to-unsigned and vice versa), except
-//! `TruncateFromUnchecked`, because it's not immediately clear what the
-//! correct output would be when then input is outside the output bounds.
+//! integers (including signed-to-unsigned and vice versa). Unchecked
+//! truncation of a signed value keeps the low-order bits of its two's
+//! complement representation, just like `as`.
+//!
+//! The traits are implemented between every pair of primitive integer
+//! types. Widening conversions, and conversions from a type to itself,
+//! always succeed, so generic code can be bounded on these traits
+//! without knowing which of its types is wider.
 #![no_std]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+
+#[cfg(any(feature = "alloc", feature = "image", feature = "quickcheck"))]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod bitcast;
+mod bits;
+#[macro_use]
+mod bulk_kernel;
+mod cast;
+mod cmp;
+mod common;
+mod error;
+mod kind;
+#[macro_use]
+mod limb;
+mod narrow_expr;
+mod narrow_struct;
+#[cfg(feature = "alloc")]
+mod narrow_vec;
+mod narrowest;
+mod repr;
+mod wide;
+pub use bitcast::{BitCast, BitCastFrom};
+pub use bits::MinBits;
+pub use cmp::{max_shrink, min_shrink, CrossCmp};
+pub use common::{CommonWider, Wider};
+pub use error::TruncateError;
+pub use kind::{conversion_kind, ConversionKind, ConversionKindOf};
+#[doc(hidden)]
+pub use narrow_expr::WideExpr;
+pub use narrow_struct::NarrowStructError;
+#[cfg(feature = "alloc")]
+pub use narrow_vec::NarrowVec;
+#[doc(hidden)]
+pub use narrowest::{
+    narrowest_signed_bytes, narrowest_unsigned_bytes, NarrowestType, NarrowestWidth,
+};
+pub use repr::{IntegerRepr, TruncateInto};
+/// Generate constructors that narrow each field of a struct.
+///
+/// `#[narrowing]` adds two constructors to a struct with named fields,
+/// taking one argument per field, in order:
+///
+/// - `try_new` narrows each argument with [`TryTruncate`], and returns a
+///   [`NarrowStructError`] listing every field that didn't fit.
+/// - `new_saturating` narrows each argument with [`Shrink`].
+///
+/// Each argument can be any type that narrows into its field, so callers
+/// don't need to convert anything first.
+///
+/// This needs the `derive` feature, which needs Rust 1.61 or later,
+/// rather than the crate's usual 1.51, for `syn` 2.
+///
+/// ```rust
+/// use truncate_integer::narrowing;
+///
+/// #[narrowing]
+/// #[derive(Debug)]
+/// struct Header {
+///     len: u16,
+///     id: u8,
+/// }
+///
+/// let payload = [0u8; 1000];
+/// let header = Header::try_new(payload.len(), 7u64).unwrap();
+/// assert_eq!(header.len, 1000);
+///
+/// let err = Header::try_new(70000u32, 300i32).unwrap_err();
+/// assert_eq!(err.to_string(), "fields out of range: len, id");
+///
+/// let header = Header::new_saturating(70000u32, -1i32);
+/// assert_eq!((header.len, header.id), (u16::MAX, 0));
+/// ```
+#[cfg(feature = "derive")]
+pub use truncate_integer_derive::narrowing;
+/// Derive a `TryFrom` conversion from a struct with wider fields.
+///
+/// `#[narrow(from = Raw)]` names the source struct. Each field is read
+/// from the source field with the same name, or the one named by
+/// `#[narrow(from = name)]`, and narrowed with the mode chosen by its
+/// attribute:
+///
+/// - `#[narrow(checked)]`, the default, uses [`TryTruncate`]. If any
+///   checked field doesn't fit, the conversion fails with a
+///   [`NarrowStructError`] listing every field that didn't.
+/// - `#[narrow(saturate)]` uses [`Shrink`].
+/// - `#[narrow(panic)]` uses [`Chop`].
+/// - `#[narrow(unchecked)]` uses [`TruncateUnchecked`].
+///
+/// This needs the `derive` feature, and so Rust 1.61, like
+/// [`narrowing`].
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use truncate_integer::ShrinkFromStruct;
+///
+/// struct RawHeader {
+///     len: u64,
+///     ttl: i64,
+///     flags: u32,
+/// }
+///
+/// #[derive(Debug, ShrinkFromStruct)]
+/// #[narrow(from = RawHeader)]
+/// struct Header {
+///     len: u16,
+///     #[narrow(saturate)]
+///     ttl: u8,
+///     #[narrow(unchecked, from = flags)]
+///     flag_bits: u8,
+/// }
+///
+/// let raw = RawHeader { len: 1500, ttl: 1000, flags: 0x1_0003 };
+/// let header = Header::try_from(raw).unwrap();
+/// assert_eq!((header.len, header.ttl, header.flag_bits), (1500, 255, 3));
+///
+/// let raw = RawHeader { len: 70000, ttl: -1, flags: 0 };
+/// let err = Header::try_from(raw).unwrap_err();
+/// assert_eq!(err.to_string(), "fields out of range: len");
+/// ```
+#[cfg(feature = "derive")]
+pub use truncate_integer_derive::ShrinkFromStruct;
+
+pub mod align;
+pub mod analyze;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "dasp")]
+pub mod audio;
+#[cfg(feature = "audit")]
+pub mod audit;
+#[cfg(feature = "binrw")]
+pub mod binrw;
+pub mod bitfield;
+#[cfg(feature = "bitflags")]
+pub mod bitflags;
+pub mod bitsint;
+pub mod bucket;
+pub mod bulk;
+#[cfg(feature = "byteorder")]
+pub mod byteorder;
+pub mod bytes;
+pub mod can;
+pub mod compact;
+pub mod companding;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+pub mod counter;
+pub mod decimal;
+#[cfg(feature = "deku")]
+pub mod deku;
+pub mod delta;
+pub mod display;
+pub mod dma;
+#[cfg(feature = "std")]
+pub mod env;
+pub mod eui;
+#[cfg(feature = "std")]
+pub mod exit;
+#[cfg(feature = "fault-injection")]
+pub mod fault;
+#[cfg(feature = "alloc")]
+pub mod frame;
+#[cfg(feature = "glam")]
+pub mod glam;
+pub mod hash;
+#[cfg(feature = "alloc")]
+pub mod id;
+#[cfg(feature = "image")]
+pub mod image;
+pub mod imm;
+pub mod index;
+pub mod key;
+pub mod leb128;
+pub mod len;
+#[cfg(feature = "libc")]
+pub mod libc;
+pub mod lossy;
+pub mod midi;
+pub mod morris;
+#[cfg(feature = "std")]
+pub mod net;
+pub mod nibble;
+#[cfg(feature = "nom")]
+pub mod nom;
+pub mod norm;
+pub mod normalize;
+pub mod percent;
+pub mod pixel;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+pub mod quantize;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+pub mod radix;
+pub mod ranged;
+#[cfg(feature = "num-rational")]
+pub mod rational;
+pub mod reg;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "rug")]
+pub mod rug;
+pub mod sentinel;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde_compact;
+#[cfg(feature = "serde_with")]
+pub mod serde_with;
+pub mod serial;
+pub mod sign;
+#[cfg(feature = "portable_simd")]
+pub mod simd;
+
+#[cfg(all(feature = "serde_with", not(feature = "serde")))]
+compile_error!("the `serde_with` feature requires the `serde` feature");
+#[cfg(all(feature = "byteorder", not(feature = "std")))]
+compile_error!("the `byteorder` feature requires the `std` feature");
+pub mod transport;
+#[cfg(feature = "ufmt")]
+pub mod ufmt;
+pub mod varwidth;
+pub mod wasm;
+pub mod wgsl;
+#[cfg(feature = "windows")]
+pub mod windows;
+pub mod y2038;
 
 pub trait TryTruncate<T> {
     /// Try to truncate an integer to fit into a smaller type.
@@ -63,6 +299,38 @@ pub trait TryTruncate<T> {
     /// If the value fits into the target type, return `Ok(value)`
     /// Otherwise, return `None`.
     fn try_truncate(self) -> Option<T>;
+
+    /// Try to truncate an integer to fit into a smaller type.
+    ///
+    /// If the value fits into the target type, return `Ok(value)`.
+    /// Otherwise, return the original value in `Err`, so that the caller
+    /// can fall back to a wider representation.
+    ///
+    /// ```rust
+    /// use truncate_integer::TryTruncate;
+    ///
+    /// assert_eq!(TryTruncate::<u8>::try_truncate_or_keep(200u32), Ok(200u8));
+    /// assert_eq!(TryTruncate::<u8>::try_truncate_or_keep(300u32), Err(300u32));
+    /// ```
+    #[inline]
+    fn try_truncate_or_keep(self) -> Result<T, Self>
+    where
+        Self: Sized + Copy,
+    {
+        self.try_truncate().ok_or(self)
+    }
+
+    /// Narrow each lane of `src` into `dst` with `kernel`; this is how
+    /// [`bulk`] reaches the vectorized kernel for a pair of types.
+    #[doc(hidden)]
+    #[inline]
+    fn try_truncate_bulk(src: &[Self], dst: &mut [T], mask: &mut [u64], kernel: bulk::Kernel) -> usize
+    where
+        Self: Sized + Copy + Shrink<T>,
+    {
+        let _ = kernel;
+        bulk::masked_by(dst, mask, |i| src[i])
+    }
 }
 
 pub trait TryTruncateFrom<T>: Sized {
@@ -114,6 +382,8 @@ pub trait TruncateUnchecked<T> {
     /// Otherwise, return the low-order bits that do fit.
     ///
     /// This has the same result as using `as` to truncate (e.g. `foo as u8`).
+    /// Signed values wrap in two's complement, so `-1i32` becomes `255u8`,
+    /// and `200u8` becomes `-56i8`.
     fn truncate_unchecked(self) -> T;
 }
 
@@ -131,6 +401,7 @@ impl<Source, Dest> TruncateFromUnchecked<Source> for Dest
 where
     Source: TruncateUnchecked<Dest>,
 {
+    #[track_caller]
     fn truncate_from_unchecked(x: Source) -> Self {
         x.truncate_unchecked()
     }
@@ -164,13 +435,28 @@ where
 }
 
 macro_rules! make_truncate {
-    ($Source: ty, $Dest:ty) => {
+    ($Source:ident, $Dest:ident) => {
         impl TryTruncate<$Dest> for $Source {
             #[track_caller]
             #[inline]
             fn try_truncate(self) -> Option<$Dest> {
-                use ::core::convert::TryFrom;
-                <$Dest>::try_from(self).ok()
+                #[cfg(feature = "fault-injection")]
+                {
+                    if crate::fault::inject_for::<$Source, $Dest>() {
+                        return None;
+                    }
+                }
+                narrow!($Source, $Dest, self)
+            }
+
+            #[inline]
+            fn try_truncate_bulk(
+                src: &[$Source],
+                dst: &mut [$Dest],
+                mask: &mut [u64],
+                kernel: bulk::Kernel,
+            ) -> usize {
+                bulk_kernel!($Source, $Dest, src, dst, mask, kernel)
             }
         }
 
@@ -178,11 +464,9 @@ macro_rules! make_truncate {
             #[track_caller]
             #[inline]
             fn chop(self) -> $Dest {
-                use ::core::convert::TryFrom;
-
-                match <$Dest>::try_from(self) {
-                    Ok(val) => val,
-                    Err(_) => panic!("chop overflow"),
+                match narrow!($Source, $Dest, self) {
+                    Some(val) => val,
+                    None => panic!("chop overflow"),
                 }
             }
         }
@@ -191,12 +475,16 @@ macro_rules! make_truncate {
             #[track_caller]
             #[inline]
             fn shrink(self) -> $Dest {
-                use ::core::convert::TryFrom;
+                #[cfg(feature = "fault-injection")]
+                let forced = crate::fault::inject_for::<$Source, $Dest>();
+                #[cfg(not(feature = "fault-injection"))]
+                let forced = false;
 
-                match <$Dest>::try_from(self) {
-                    Ok(val) => val,
-                    Err(_) => {
-                        if self < (<$Dest>::MIN) as $Source {
+                match narrow!($Source, $Dest, self) {
+                    Some(val) if !forced => val,
+                    _ => {
+                        // Only a negative value can be below the minimum.
+                        if self < <$Source>::default() {
                             <$Dest>::MIN
                         } else {
                             <$Dest>::MAX
@@ -210,13 +498,22 @@ macro_rules! make_truncate {
 }
 
 macro_rules! make_truncate_all {
-    ($Source: ty, $Dest:ty) => {
-        // FIXME: don't implement this for negative numbers!
+    ($Source:ident, $Dest:ident) => {
         impl TruncateUnchecked<$Dest> for $Source {
             #[track_caller]
             #[inline]
             fn truncate_unchecked(self) -> $Dest {
-                self as $Dest
+                let result = self as $Dest;
+                #[cfg(feature = "audit")]
+                {
+                    // A value that changed sign round-trips, so check that too.
+                    let sign_changed =
+                        (self < <$Source>::default()) != (result < <$Dest>::default());
+                    if result as $Source != self || sign_changed {
+                        crate::audit::record(core::panic::Location::caller());
+                    }
+                }
+                result
             }
         }
 
@@ -224,50 +521,177 @@ macro_rules! make_truncate_all {
     }
 }
 
-make_truncate_all!(usize, u8);
-make_truncate_all!(usize, u16);
-make_truncate_all!(usize, u32);
-
-make_truncate_all!(u128, u8);
-make_truncate_all!(u128, u16);
-make_truncate_all!(u128, u32);
-make_truncate_all!(u128, u64);
-make_truncate_all!(u64, u8);
-make_truncate_all!(u64, u16);
-make_truncate_all!(u64, u32);
-make_truncate_all!(u32, u8);
-make_truncate_all!(u32, u16);
-make_truncate_all!(u16, u8);
-
-make_truncate_all!(u128, i8);
-make_truncate_all!(u128, i16);
-make_truncate_all!(u128, i32);
-make_truncate_all!(u128, i64);
-make_truncate_all!(u64, i8);
-make_truncate_all!(u64, i16);
-make_truncate_all!(u64, i32);
-make_truncate_all!(u32, i8);
-make_truncate_all!(u32, i16);
-make_truncate_all!(u16, i8);
-
-make_truncate!(i128, i64);
-make_truncate!(i128, i32);
-make_truncate!(i128, i16);
-make_truncate!(i128, i8);
-make_truncate!(i64, i8);
-make_truncate!(i64, i16);
-make_truncate!(i64, i32);
-make_truncate!(i32, i8);
-make_truncate!(i32, i16);
-make_truncate!(i16, i8);
-
-make_truncate!(i128, u64);
-make_truncate!(i128, u32);
-make_truncate!(i128, u16);
-make_truncate!(i128, u8);
-make_truncate!(i64, u8);
-make_truncate!(i64, u16);
-make_truncate!(i64, u32);
-make_truncate!(i32, u8);
-make_truncate!(i32, u16);
-make_truncate!(i16, u8);
+// Every pair of primitive types, which `$pair` is called for; the
+// conformance table walks the same list.
+macro_rules! truncate_pairs {
+    ($pair:ident) => {
+        // Identity conversions, which always succeed, so that generic code
+        // doesn't need a special case when the types are the same.
+        $pair!(u8, u8);
+        $pair!(u16, u16);
+        $pair!(u32, u32);
+        $pair!(u64, u64);
+        $pair!(u128, u128);
+        $pair!(usize, usize);
+        $pair!(i8, i8);
+        $pair!(i16, i16);
+        $pair!(i32, i32);
+        $pair!(i64, i64);
+        $pair!(i128, i128);
+        $pair!(isize, isize);
+
+        // Widening conversions, which always succeed, so that generic code
+        // can use these traits whatever the widths of the types.
+        $pair!(u8, u16);
+        $pair!(u8, u32);
+        $pair!(u8, u64);
+        $pair!(u8, u128);
+        $pair!(u8, usize);
+        $pair!(u16, u32);
+        $pair!(u16, u64);
+        $pair!(u16, u128);
+        $pair!(u16, usize);
+        $pair!(u32, u64);
+        $pair!(u32, u128);
+        $pair!(u64, u128);
+        $pair!(u8, i16);
+        $pair!(u8, i32);
+        $pair!(u8, i64);
+        $pair!(u8, i128);
+        $pair!(u8, isize);
+        $pair!(u16, i32);
+        $pair!(u16, i64);
+        $pair!(u16, i128);
+        $pair!(u32, i64);
+        $pair!(u32, i128);
+        $pair!(u64, i128);
+
+        $pair!(i8, i16);
+        $pair!(i8, i32);
+        $pair!(i8, i64);
+        $pair!(i8, i128);
+        $pair!(i8, isize);
+        $pair!(i16, i32);
+        $pair!(i16, i64);
+        $pair!(i16, i128);
+        $pair!(i16, isize);
+        $pair!(i32, i64);
+        $pair!(i32, i128);
+        $pair!(i64, i128);
+
+        // Same-width conversions between signed and unsigned types.
+        $pair!(u8, i8);
+        $pair!(u16, i16);
+        $pair!(u32, i32);
+        $pair!(u64, i64);
+        $pair!(u128, i128);
+        $pair!(i8, u8);
+        $pair!(i16, u16);
+        $pair!(i32, u32);
+        $pair!(i64, u64);
+        $pair!(i128, u128);
+
+        // Pairs with a pointer-sized side. Whether these lose information
+        // depends on `target_pointer_width`, so `core` only has `TryFrom`
+        // for them; the checks are exact on 16, 32 and 64-bit targets.
+        $pair!(usize, u8);
+        $pair!(usize, u16);
+        $pair!(usize, u32);
+        $pair!(usize, u64);
+        $pair!(usize, u128);
+        $pair!(usize, i8);
+        $pair!(usize, i16);
+        $pair!(usize, i32);
+        $pair!(usize, i64);
+        $pair!(usize, i128);
+        $pair!(usize, isize);
+
+        $pair!(u128, usize);
+        $pair!(u64, usize);
+        $pair!(u32, usize);
+        $pair!(u128, isize);
+        $pair!(u64, isize);
+        $pair!(u32, isize);
+        $pair!(u16, isize);
+
+        $pair!(isize, i8);
+        $pair!(isize, i16);
+        $pair!(isize, i32);
+        $pair!(isize, i64);
+        $pair!(isize, i128);
+        $pair!(isize, u8);
+        $pair!(isize, u16);
+        $pair!(isize, u32);
+        $pair!(isize, u64);
+        $pair!(isize, u128);
+        $pair!(isize, usize);
+
+        $pair!(i128, usize);
+        $pair!(i64, usize);
+        $pair!(i32, usize);
+        $pair!(i16, usize);
+        $pair!(i8, usize);
+        $pair!(i128, isize);
+        $pair!(i64, isize);
+        $pair!(i32, isize);
+
+        $pair!(u128, u8);
+        $pair!(u128, u16);
+        $pair!(u128, u32);
+        $pair!(u128, u64);
+        $pair!(u64, u8);
+        $pair!(u64, u16);
+        $pair!(u64, u32);
+        $pair!(u32, u8);
+        $pair!(u32, u16);
+        $pair!(u16, u8);
+
+        $pair!(u128, i8);
+        $pair!(u128, i16);
+        $pair!(u128, i32);
+        $pair!(u128, i64);
+        $pair!(u64, i8);
+        $pair!(u64, i16);
+        $pair!(u64, i32);
+        $pair!(u32, i8);
+        $pair!(u32, i16);
+        $pair!(u16, i8);
+
+        $pair!(i128, i64);
+        $pair!(i128, i32);
+        $pair!(i128, i16);
+        $pair!(i128, i8);
+        $pair!(i64, i8);
+        $pair!(i64, i16);
+        $pair!(i64, i32);
+        $pair!(i32, i8);
+        $pair!(i32, i16);
+        $pair!(i16, i8);
+
+        $pair!(i128, u64);
+        $pair!(i128, u32);
+        $pair!(i128, u16);
+        $pair!(i128, u8);
+        $pair!(i64, u8);
+        $pair!(i64, u16);
+        $pair!(i64, u32);
+        $pair!(i32, u8);
+        $pair!(i32, u16);
+        $pair!(i16, u8);
+
+        $pair!(i64, u128);
+        $pair!(i32, u128);
+        $pair!(i32, u64);
+        $pair!(i16, u128);
+        $pair!(i16, u64);
+        $pair!(i16, u32);
+        $pair!(i8, u128);
+        $pair!(i8, u64);
+        $pair!(i8, u32);
+        $pair!(i8, u16);
+    };
+}
+
+truncate_pairs!(make_truncate_all);
+#[cfg(feature = "conformance")]
+pub(crate) use truncate_pairs;
@@ -0,0 +1,29 @@
+//! POSIX integer type aliases, from the [`libc`](https://docs.rs/libc) crate.
+//!
+//! The widths of these types vary by target (`off_t` is 32 bits on some
+//! platforms and 64 bits on others), so a conversion that can't fail on
+//! one target may fail on another. Narrowing them with the truncation
+//! traits keeps the checks in place everywhere.
+//!
+//! The pointer-sized aliases (`size_t`, `ssize_t`) are `usize` and
+//! `isize`, so they use the crate's impls for those types.
+//!
+//! ```rust
+//! # #[cfg(unix)] {
+//! use truncate_integer::libc::{off_t, size_t, ssize_t};
+//! use truncate_integer::{Shrink, TryTruncate};
+//!
+//! // A read(2) result: negative means error.
+//! let ret: ssize_t = -1;
+//! let count: Option<size_t> = ret.try_truncate();
+//! assert_eq!(count, None);
+//!
+//! // Clamp a file offset to a buffer length.
+//! let offset: off_t = 1 << 20;
+//! let len: u16 = offset.shrink();
+//! assert_eq!(len, u16::MAX);
+//! # }
+//! ```
+
+#[cfg(unix)]
+pub use ::libc::{off_t, pid_t, size_t, ssize_t, time_t};
@@ -0,0 +1,64 @@
+// Range checks for 128-bit values that look at one 64-bit half at a time.
+//
+// On targets without native 128-bit compares (32-bit Arm, wasm32), a
+// `TryFrom<u128>` check is a chain of four-limb subtractions. A value
+// that fits in 64 bits has a high half of all zeros or all ones, so
+// testing that half first, then comparing the low half, is much cheaper.
+
+/// Whether `value` is at most `max`.
+#[inline]
+pub(crate) fn u128_fits(value: u128, max: u64) -> bool {
+    (value >> 64) as u64 == 0 && value as u64 <= max
+}
+
+/// Whether `value` is in `min..=max`, where `min` is at most zero.
+#[inline]
+pub(crate) fn i128_fits(value: i128, min: i64, max: u64) -> bool {
+    let high = (value >> 64) as i64;
+    let low = value as u64;
+    match high {
+        0 => low <= max,
+        // The value is `low - 2^64`, which fits in an `i64` only if the
+        // top bit of `low` is set.
+        -1 => (low as i64) < 0 && low as i64 >= min,
+        _ => false,
+    }
+}
+
+// Narrow `$value` from `$Source` into `$Dest`, returning an `Option`.
+// This is `TryFrom`, except that 128-bit sources use the checks above,
+// which assume a destination of at most 64 bits.
+macro_rules! narrow {
+    (u128, u128, $value:expr) => {
+        Some::<u128>($value)
+    };
+    (i128, i128, $value:expr) => {
+        Some::<i128>($value)
+    };
+    // The checks below don't apply to a 128-bit destination.
+    (u128, i128, $value:expr) => {
+        <i128 as ::core::convert::TryFrom<u128>>::try_from($value).ok()
+    };
+    (i128, u128, $value:expr) => {
+        <u128 as ::core::convert::TryFrom<i128>>::try_from($value).ok()
+    };
+    (u128, $Dest:ty, $value:expr) => {{
+        let value: u128 = $value;
+        if crate::limb::u128_fits(value, <$Dest>::MAX as u64) {
+            Some(value as $Dest)
+        } else {
+            None
+        }
+    }};
+    (i128, $Dest:ty, $value:expr) => {{
+        let value: i128 = $value;
+        if crate::limb::i128_fits(value, <$Dest>::MIN as i64, <$Dest>::MAX as u64) {
+            Some(value as $Dest)
+        } else {
+            None
+        }
+    }};
+    ($Source:ident, $Dest:ty, $value:expr) => {
+        <$Dest as ::core::convert::TryFrom<$Source>>::try_from($value).ok()
+    };
+}
@@ -0,0 +1,119 @@
+//! Narrowing that remembers whether anything was lost.
+//!
+//! [`Lossy<T>`] carries a value along with a sticky flag, like the IEEE
+//! floating point "inexact" flag. Narrowing operations on a `Lossy`
+//! always succeed, but set the flag if the value changed. The flag is
+//! carried through every later operation, so it can be checked once at
+//! the end of a computation.
+//!
+//! ```rust
+//! use truncate_integer::lossy::Lossy;
+//!
+//! let samples = [100i32, -20, 70000];
+//! let mut out = Lossy::new(0u64);
+//! for &s in &samples {
+//!     let s = Lossy::new(s).shrink::<i16>();
+//!     out = out.zip(s, |acc, s| acc + (s as u16) as u64);
+//! }
+//! assert!(out.is_lossy());
+//! ```
+
+use crate::wide::{FromWide, Widen};
+
+/// A value, and whether it was approximated to get it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Lossy<T> {
+    value: T,
+    lossy: bool,
+}
+
+impl<T> Lossy<T> {
+    /// Wrap an exact value.
+    pub fn new(value: T) -> Self {
+        Lossy {
+            value,
+            lossy: false,
+        }
+    }
+
+    /// Get the value.
+    pub fn get(self) -> T {
+        self.value
+    }
+
+    /// Returns `true` if any operation leading to this value was inexact.
+    pub fn is_lossy(&self) -> bool {
+        self.lossy
+    }
+
+    /// Get the value and the loss flag.
+    pub fn into_parts(self) -> (T, bool) {
+        (self.value, self.lossy)
+    }
+
+    /// Get the value, or `None` if it was approximated.
+    pub fn exact(self) -> Option<T> {
+        if self.lossy {
+            None
+        } else {
+            Some(self.value)
+        }
+    }
+
+    /// Set the loss flag.
+    pub fn mark_lossy(&mut self) {
+        self.lossy = true;
+    }
+
+    /// Apply a function to the value, keeping the loss flag.
+    pub fn map<U, F>(self, f: F) -> Lossy<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Lossy {
+            value: f(self.value),
+            lossy: self.lossy,
+        }
+    }
+
+    /// Combine two values; the result is lossy if either input was.
+    pub fn zip<U, V, F>(self, other: Lossy<U>, f: F) -> Lossy<V>
+    where
+        F: FnOnce(T, U) -> V,
+    {
+        Lossy {
+            value: f(self.value, other.value),
+            lossy: self.lossy || other.lossy,
+        }
+    }
+}
+
+impl<T: Widen> Lossy<T> {
+    /// Perform saturating truncation, setting the flag if the value
+    /// didn't fit.
+    pub fn shrink<Dest: FromWide + Widen>(self) -> Lossy<Dest> {
+        let wide = self.value.widen();
+        let value = Dest::from_wide_saturating(wide);
+        Lossy {
+            value,
+            lossy: self.lossy || value.widen() != wide,
+        }
+    }
+
+    /// Perform unchecked (wrapping) truncation, setting the flag if the
+    /// value didn't fit.
+    pub fn wrap<Dest: FromWide + Widen>(self) -> Lossy<Dest> {
+        let wide = self.value.widen();
+        let value = Dest::from_wide_wrapping(wide);
+        Lossy {
+            value,
+            lossy: self.lossy || value.widen() != wide,
+        }
+    }
+}
+
+impl<T> From<T> for Lossy<T> {
+    fn from(value: T) -> Self {
+        Lossy::new(value)
+    }
+}
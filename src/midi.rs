@@ -0,0 +1,82 @@
+//! MIDI data bytes and 14-bit values.
+//!
+//! MIDI data bytes hold 7 bits; the high bit is reserved for status
+//! bytes. 14-bit values (pitch bend, NRPN and high-resolution controllers)
+//! are sent as two data bytes, least significant first.
+//!
+//! Pitch bend is centered on `8192`, so it's converted to and from a
+//! signed value in `-8192..=8191`.
+//!
+//! ```rust
+//! use truncate_integer::midi::{pitch_bend_combine, pitch_bend_split, shrink_data_byte, split_14};
+//!
+//! assert_eq!(shrink_data_byte(200u8), 127);
+//! assert_eq!(split_14(0x2001), Some([0x01, 0x40]));
+//! assert_eq!(pitch_bend_split(0), [0x00, 0x40]);
+//! assert_eq!(pitch_bend_split(i16::MAX), [0x7F, 0x7F]);
+//! assert_eq!(pitch_bend_combine([0x00, 0x00]), -8192);
+//! ```
+
+use crate::bitsint::U;
+use crate::{Shrink, TryTruncate};
+
+/// The center (no bend) value of a pitch bend message.
+pub const PITCH_BEND_CENTER: u16 = 8192;
+
+/// Narrow a value into a data byte, if it fits in 7 bits.
+#[inline]
+pub fn try_data_byte<T: TryTruncate<U<7>>>(value: T) -> Option<u8> {
+    value.try_truncate().map(U::get)
+}
+
+/// Narrow a value into a data byte, saturating to `0..=127`.
+#[inline]
+pub fn shrink_data_byte<T: Shrink<U<7>>>(value: T) -> u8 {
+    value.shrink().get()
+}
+
+/// Split a 14-bit value into `[lsb, msb]` data bytes.
+///
+/// Returns `None` if the value doesn't fit in 14 bits.
+#[inline]
+pub fn split_14(value: u16) -> Option<[u8; 2]> {
+    if value >> 14 != 0 {
+        None
+    } else {
+        Some(shrink_split_14(value))
+    }
+}
+
+/// Split a 14-bit value into `[lsb, msb]` data bytes, saturating to
+/// `0..=16383`.
+#[inline]
+pub fn shrink_split_14(value: u16) -> [u8; 2] {
+    let value = value.min(0x3FFF);
+    [(value & 0x7F) as u8, (value >> 7) as u8]
+}
+
+/// Combine `[lsb, msb]` data bytes into a 14-bit value.
+///
+/// The high bit of each byte is ignored.
+#[inline]
+pub fn combine_14(bytes: [u8; 2]) -> u16 {
+    let [lsb, msb] = bytes;
+    u16::from(msb & 0x7F) << 7 | u16::from(lsb & 0x7F)
+}
+
+/// Convert a signed pitch bend into `[lsb, msb]` data bytes, saturating
+/// to `-8192..=8191`.
+#[inline]
+pub fn pitch_bend_split(bend: i16) -> [u8; 2] {
+    let value = (i32::from(bend) + i32::from(PITCH_BEND_CENTER)).clamp(0, 0x3FFF) as u16;
+    shrink_split_14(value)
+}
+
+/// Convert `[lsb, msb]` data bytes into a signed pitch bend in
+/// `-8192..=8191`.
+///
+/// The high bit of each byte is ignored.
+#[inline]
+pub fn pitch_bend_combine(bytes: [u8; 2]) -> i16 {
+    combine_14(bytes) as i16 - PITCH_BEND_CENTER as i16
+}
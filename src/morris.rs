@@ -0,0 +1,193 @@
+//! Approximate counters that fit in one or two bytes.
+//!
+//! A [`MorrisCounter`] stores a count logarithmically, so an unbounded
+//! count fits in a `u8` or `u16` at the cost of precision. The register
+//! is a tiny floating point number: small counts are stored exactly, and
+//! larger ones keep a few significant bits. Above the exact range, each
+//! increment only takes effect with a probability that balances the
+//! step to the next representable count, so the estimate is unbiased.
+//!
+//! | Register | Exact up to | Largest estimate |
+//! | -------- | ----------- | ---------------- |
+//! | `u8`     | 15          | about 1.6 × 10¹⁰ |
+//! | `u16`    | 2047        | about 9.4 × 10²¹ |
+//!
+//! The counter saturates at the largest register value. The caller
+//! supplies the randomness, as uniformly random `u64`s, so the counter
+//! works without a random number generator dependency.
+//!
+//! ```rust
+//! use truncate_integer::morris::MorrisCounter;
+//!
+//! // Any source of random bits will do; this is xorshift.
+//! let mut state = 0x2545_F491_4F6C_DD1Du64;
+//! let mut random = || {
+//!     state ^= state << 13;
+//!     state ^= state >> 7;
+//!     state ^= state << 17;
+//!     state
+//! };
+//!
+//! let mut counter = MorrisCounter::<u8>::new();
+//! for _ in 0..10 {
+//!     counter.increment(random());
+//! }
+//! assert!(counter.is_exact());
+//! assert_eq!(counter.estimate(), 10);
+//!
+//! for _ in 0..100_000 {
+//!     counter.increment(random());
+//! }
+//! let estimate = counter.estimate();
+//! assert!(estimate > 50_000 && estimate < 200_000);
+//! ```
+
+use crate::Shrink;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An unsigned integer type used as the register of a [`MorrisCounter`].
+pub trait Register: private::Sealed + Copy {
+    #[doc(hidden)]
+    const MANTISSA_BITS: u32;
+    #[doc(hidden)]
+    const MAX_RAW: u32;
+    #[doc(hidden)]
+    fn to_raw(self) -> u32;
+    #[doc(hidden)]
+    fn from_raw(raw: u32) -> Self;
+}
+
+macro_rules! make_register {
+    ($($Type:ty => $mantissa:expr),*) => {
+        $(
+            impl private::Sealed for $Type {}
+
+            impl Register for $Type {
+                const MANTISSA_BITS: u32 = $mantissa;
+                const MAX_RAW: u32 = <$Type>::MAX as u32;
+
+                #[inline]
+                fn to_raw(self) -> u32 {
+                    u32::from(self)
+                }
+
+                #[inline]
+                fn from_raw(raw: u32) -> Self {
+                    raw as $Type
+                }
+            }
+        )*
+    };
+}
+
+make_register!(u8 => 3, u16 => 10);
+
+/// An approximate counter, stored in a `u8` or `u16`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MorrisCounter<T> {
+    register: T,
+}
+
+impl<T: Register> MorrisCounter<T> {
+    /// Create a counter at zero.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_raw(T::from_raw(0))
+    }
+
+    /// Create a counter from its raw register value.
+    #[inline]
+    pub fn from_raw(register: T) -> Self {
+        MorrisCounter { register }
+    }
+
+    /// The raw register value.
+    #[inline]
+    pub fn raw(&self) -> T {
+        self.register
+    }
+
+    /// Returns `true` if the estimate is the exact count.
+    ///
+    /// This stays true until the count leaves the exact range.
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        self.register.to_raw() >> T::MANTISSA_BITS <= 1
+    }
+
+    /// Returns `true` if the counter can't count any higher.
+    #[inline]
+    pub fn is_saturated(&self) -> bool {
+        self.register.to_raw() == T::MAX_RAW
+    }
+
+    /// The estimated count.
+    ///
+    /// If the estimate doesn't fit in a `u64`, it saturates.
+    #[inline]
+    pub fn estimate(&self) -> u64 {
+        value::<T>(self.register.to_raw()).shrink()
+    }
+
+    /// Count one event, with the help of some random bits.
+    ///
+    /// `random` should be uniformly random, and different for each call.
+    #[inline]
+    pub fn increment(&mut self, random: u64) {
+        let raw = self.register.to_raw();
+        if raw == T::MAX_RAW {
+            return;
+        }
+        let exponent = raw >> T::MANTISSA_BITS;
+        // The next count is 2^(exponent - 1) away.
+        let mask = (1u64 << exponent.saturating_sub(1)) - 1;
+        if random & mask == 0 {
+            self.register = T::from_raw(raw + 1);
+        }
+    }
+
+    /// Add the count of `other` into this counter, with the help of some
+    /// random bits.
+    ///
+    /// `random` should be uniformly random. If the sum falls between two
+    /// representable counts, it's rounded up or down at random, so the
+    /// estimate stays unbiased.
+    pub fn merge(&mut self, other: &Self, random: u64) {
+        let total = value::<T>(self.register.to_raw()) + value::<T>(other.register.to_raw());
+        let mantissa = T::MANTISSA_BITS;
+        let raw = if total < 1 << mantissa {
+            total
+        } else {
+            let exponent = 128 - total.leading_zeros() - mantissa;
+            let fraction = (total >> (exponent - 1)) - (1 << mantissa);
+            u128::from(exponent) << mantissa | fraction
+        };
+        if raw >= u128::from(T::MAX_RAW) {
+            self.register = T::from_raw(T::MAX_RAW);
+            return;
+        }
+        let raw = raw as u32;
+        let remainder = (total - value::<T>(raw)) as u64;
+        let mask = (1u64 << (raw >> mantissa).saturating_sub(1)) - 1;
+        let raw = if random & mask < remainder {
+            raw + 1
+        } else {
+            raw
+        };
+        self.register = T::from_raw(raw);
+    }
+}
+
+/// The count represented by a register value.
+#[inline]
+fn value<T: Register>(raw: u32) -> u128 {
+    let exponent = raw >> T::MANTISSA_BITS;
+    let fraction = u128::from(raw & ((1 << T::MANTISSA_BITS) - 1));
+    match exponent {
+        0 => fraction,
+        _ => ((1 << T::MANTISSA_BITS) + fraction) << (exponent - 1),
+    }
+}
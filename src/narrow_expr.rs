@@ -0,0 +1,299 @@
+use crate::wide::{Primitive, Wide, Widen};
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Evaluate integer arithmetic in a wide type, and narrow the result
+/// once at the end.
+///
+/// `narrow_expr!((expr) => T, mode)` widens every operand of `expr` to a
+/// sign and a `u128` magnitude, evaluates it exactly, and then narrows
+/// the result into `T` with the same modes as [`cast!`](crate::cast):
+///
+/// - `checked`: returns `Option<T>`.
+/// - `panic`: panics if the result doesn't fit.
+/// - `saturate`: returns the closest value that fits.
+///
+/// Operands can be of any integer type, and can be mixed. The operators
+/// `+`, `-`, `*`, `/`, `%` and unary `-` are supported, with the usual
+/// precedence, along with parentheses. An operand is a variable or a
+/// literal; anything else, like a method call, goes in braces.
+///
+/// An intermediate result whose magnitude doesn't fit in a `u128` isn't
+/// an error by itself: it remembers its sign, so a later multiplication
+/// by zero or a saturating narrow still gives the right answer. If its
+/// value is needed (say, it's divided, or something of the opposite sign
+/// is added to it), `checked` returns `None` and the other modes panic.
+///
+/// ```rust
+/// use truncate_integer::narrow_expr;
+///
+/// let (a, b, c) = (40_000u16, 3u8, 10_000u32);
+/// assert_eq!(narrow_expr!((a + b * c) => u16, saturate), u16::MAX);
+/// assert_eq!(narrow_expr!((a - b * c) => u16, checked), Some(10_000));
+/// assert_eq!(narrow_expr!((b - a) => u16, checked), None);
+///
+/// let samples = [1i16, -7, 4];
+/// assert_eq!(narrow_expr!(({ samples.len() } * 100 / 3) => u8, panic), 100);
+/// ```
+#[macro_export]
+macro_rules! narrow_expr {
+    (($($e:tt)*) => $T:ty, checked) => {
+        $crate::narrow_expr!(@munch [] $($e)*).checked::<$T>()
+    };
+    (($($e:tt)*) => $T:ty, panic) => {
+        $crate::narrow_expr!(@munch [] $($e)*).panic::<$T>()
+    };
+    (($($e:tt)*) => $T:ty, saturate) => {
+        $crate::narrow_expr!(@munch [] $($e)*).saturate::<$T>()
+    };
+
+    (@munch [$($out:tt)*]) => {
+        $($out)*
+    };
+    (@munch [$($out:tt)*] ($($inner:tt)*) $($rest:tt)*) => {
+        $crate::narrow_expr!(@munch [$($out)* ($crate::narrow_expr!(@munch [] $($inner)*))] $($rest)*)
+    };
+    (@munch [$($out:tt)*] + $($rest:tt)*) => {
+        $crate::narrow_expr!(@munch [$($out)* +] $($rest)*)
+    };
+    (@munch [$($out:tt)*] - $($rest:tt)*) => {
+        $crate::narrow_expr!(@munch [$($out)* -] $($rest)*)
+    };
+    (@munch [$($out:tt)*] * $($rest:tt)*) => {
+        $crate::narrow_expr!(@munch [$($out)* *] $($rest)*)
+    };
+    (@munch [$($out:tt)*] / $($rest:tt)*) => {
+        $crate::narrow_expr!(@munch [$($out)* /] $($rest)*)
+    };
+    (@munch [$($out:tt)*] % $($rest:tt)*) => {
+        $crate::narrow_expr!(@munch [$($out)* %] $($rest)*)
+    };
+    (@munch [$($out:tt)*] $operand:tt $($rest:tt)*) => {
+        $crate::narrow_expr!(@munch [$($out)* $crate::WideExpr::new($operand)] $($rest)*)
+    };
+}
+
+/// An intermediate value of [`narrow_expr!`].
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WideExpr {
+    /// A value with a magnitude that fits in a `u128`. Zero is never
+    /// negative.
+    Value { negative: bool, magnitude: u128 },
+    /// Too large for a `u128` magnitude; true if negative.
+    Overflow(bool),
+    /// Too large for a `u128` magnitude, and the sign isn't known either.
+    Unknown,
+}
+
+use WideExpr::{Overflow, Unknown, Value};
+
+const ZERO: WideExpr = Value {
+    negative: false,
+    magnitude: 0,
+};
+
+/// A value with this sign and magnitude.
+#[inline]
+fn value(negative: bool, magnitude: u128) -> WideExpr {
+    Value {
+        negative: negative && magnitude != 0,
+        magnitude,
+    }
+}
+
+impl WideExpr {
+    #[inline]
+    pub fn new<T: Primitive>(value: T) -> Self {
+        match Widen::widen(value) {
+            Wide::Negative(x) => self::value(true, x.unsigned_abs()),
+            Wide::NonNegative(x) => self::value(false, x),
+        }
+    }
+
+    /// The sign, and the value if it fits in a `Wide`. Returns `None` if
+    /// the sign isn't known.
+    #[inline]
+    fn to_wide(self) -> Option<(bool, Option<Wide>)> {
+        match self {
+            Value {
+                negative: false,
+                magnitude,
+            } => Some((false, Some(Wide::NonNegative(magnitude)))),
+            Value {
+                negative: true,
+                magnitude,
+            } if magnitude <= 1 << 127 => Some((
+                true,
+                Some(Wide::Negative((magnitude as i128).wrapping_neg())),
+            )),
+            Value { negative, .. } => Some((negative, None)),
+            Overflow(negative) => Some((negative, None)),
+            Unknown => None,
+        }
+    }
+
+    #[inline]
+    pub fn checked<T: Primitive>(self) -> Option<T> {
+        match self.to_wide() {
+            Some((_, Some(x))) => T::from_wide_checked(x),
+            _ => None,
+        }
+    }
+
+    #[track_caller]
+    #[inline]
+    pub fn panic<T: Primitive>(self) -> T {
+        match self.checked() {
+            Some(x) => x,
+            None => panic!("chop overflow"),
+        }
+    }
+
+    #[track_caller]
+    #[inline]
+    pub fn saturate<T: Primitive>(self) -> T {
+        match self.to_wide() {
+            Some((_, Some(x))) => T::from_wide_saturating(x),
+            Some((true, None)) => T::from_wide_wrapping(T::MIN_WIDE),
+            Some((false, None)) => T::from_wide_wrapping(T::MAX_WIDE),
+            None => panic!("narrow_expr! intermediate out of range"),
+        }
+    }
+}
+
+impl Neg for WideExpr {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        match self {
+            Value {
+                negative,
+                magnitude,
+            } => value(!negative, magnitude),
+            Overflow(negative) => Overflow(!negative),
+            Unknown => Unknown,
+        }
+    }
+}
+
+impl Add for WideExpr {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (
+                Value {
+                    negative: s,
+                    magnitude: x,
+                },
+                Value {
+                    negative: t,
+                    magnitude: y,
+                },
+            ) => {
+                if s == t {
+                    x.checked_add(y).map_or(Overflow(s), |m| value(s, m))
+                } else if x >= y {
+                    value(s, x - y)
+                } else {
+                    value(t, y - x)
+                }
+            }
+            // An overflowed value is at least 2^128 in magnitude, so adding
+            // a value of the opposite sign may bring it back into range.
+            (Overflow(s), ZERO) | (ZERO, Overflow(s)) => Overflow(s),
+            (Overflow(s), Value { negative: t, .. }) | (Value { negative: t, .. }, Overflow(s))
+                if s == t =>
+            {
+                Overflow(s)
+            }
+            (Overflow(s), Overflow(t)) if s == t => Overflow(s),
+            _ => Unknown,
+        }
+    }
+}
+
+impl Sub for WideExpr {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for WideExpr {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (
+                Value {
+                    negative: s,
+                    magnitude: x,
+                },
+                Value {
+                    negative: t,
+                    magnitude: y,
+                },
+            ) => x
+                .checked_mul(y)
+                .map_or(Overflow(s != t), |m| value(s != t, m)),
+            (Overflow(_), ZERO) | (ZERO, Overflow(_)) => ZERO,
+            (Overflow(s), Value { negative: t, .. }) | (Value { negative: t, .. }, Overflow(s)) => {
+                Overflow(s != t)
+            }
+            (Overflow(s), Overflow(t)) => Overflow(s != t),
+            _ => Unknown,
+        }
+    }
+}
+
+impl Div for WideExpr {
+    type Output = Self;
+
+    #[track_caller]
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (_, ZERO) => panic!("attempt to divide by zero"),
+            (
+                Value {
+                    negative: s,
+                    magnitude: x,
+                },
+                Value {
+                    negative: t,
+                    magnitude: y,
+                },
+            ) => value(s != t, x / y),
+            (Value { .. }, Overflow(_)) => ZERO,
+            _ => Unknown,
+        }
+    }
+}
+
+impl Rem for WideExpr {
+    type Output = Self;
+
+    #[track_caller]
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (_, ZERO) => panic!("attempt to calculate the remainder with a divisor of zero"),
+            // The remainder has the sign of the dividend.
+            (
+                Value {
+                    negative,
+                    magnitude: x,
+                },
+                Value { magnitude: y, .. },
+            ) => value(negative, x % y),
+            (Value { .. }, Overflow(_)) => self,
+            _ => Unknown,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use core::fmt;
+
+/// Build a struct from wider values, narrowing each field with
+/// [`TryTruncate`](crate::TryTruncate).
+///
+/// If any field doesn't fit, returns a [`NarrowStructError`] listing
+/// every field that failed, not just the first one.
+///
+/// ```rust
+/// use truncate_integer::narrow_struct;
+///
+/// #[derive(Debug)]
+/// struct Header {
+///     len: u16,
+///     id: u8,
+///     offset: i8,
+/// }
+///
+/// let (raw_len, raw_id, raw_offset) = (1000u32, 7u64, -3i32);
+/// let header = narrow_struct!(Header { len: raw_len, id: raw_id, offset: raw_offset }).unwrap();
+/// assert_eq!(header.len, 1000);
+///
+/// let err = narrow_struct!(Header { len: 70000u32, id: 7u64, offset: 200i32 }).unwrap_err();
+/// assert_eq!(err.to_string(), "fields out of range: len, offset");
+/// ```
+///
+/// A struct can have at most 64 fields.
+#[macro_export]
+macro_rules! narrow_struct {
+    ($($Struct:ident)::+ { $($field:ident : $value:expr),* $(,)? }) => {{
+        const FIELDS: &[&str] = &[$(stringify!($field)),*];
+        assert!(FIELDS.len() <= 64, "narrow_struct! supports at most 64 fields");
+
+        // Evaluate every value before binding any field names, so that a
+        // value expression can't see an earlier field's result.
+        let values = ($($crate::TryTruncate::try_truncate($value),)*);
+        let ($($field,)*) = values;
+
+        let mut failed = 0u64;
+        let mut bit = 1u64;
+        $(
+            if $field.is_none() {
+                failed |= bit;
+            }
+            bit = bit.wrapping_shl(1);
+        )*
+        let _ = bit;
+
+        if failed == 0 {
+            ::core::result::Result::Ok($($Struct)::+ { $($field: $field.unwrap()),* })
+        } else {
+            ::core::result::Result::Err($crate::NarrowStructError::new(FIELDS, failed))
+        }
+    }};
+}
+
+/// The error returned by [`narrow_struct!`] when some fields don't fit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NarrowStructError {
+    fields: &'static [&'static str],
+    failed: u64,
+}
+
+impl NarrowStructError {
+    #[doc(hidden)]
+    pub fn new(fields: &'static [&'static str], failed: u64) -> Self {
+        NarrowStructError { fields, failed }
+    }
+
+    /// The names of the fields that didn't fit, in declaration order.
+    pub fn fields(&self) -> impl Iterator<Item = &'static str> + '_ {
+        let failed = self.failed;
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| failed & (1 << i) != 0)
+            .map(|(_, name)| *name)
+    }
+
+    /// Returns `true` if the named field didn't fit.
+    pub fn contains(&self, field: &str) -> bool {
+        self.fields().any(|name| name == field)
+    }
+}
+
+impl fmt::Display for NarrowStructError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("fields out of range: ")?;
+        for (i, name) in self.fields().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NarrowStructError {}
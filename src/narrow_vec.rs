@@ -0,0 +1,163 @@
+use crate::wide::{FromWide, Widen};
+use crate::{MinBits, TruncateUnchecked};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Storage {
+    U8(Vec<u8>),
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+    U64(Vec<u64>),
+}
+
+/// A growable array of integers, stored in the narrowest width that fits.
+///
+/// All values are stored with the same width. When a value is pushed
+/// that doesn't fit, the existing values are widened.
+///
+/// ```rust
+/// use truncate_integer::NarrowVec;
+///
+/// let mut v = NarrowVec::new();
+/// v.push(1);
+/// v.push(200);
+/// assert_eq!(v.bits(), 8);
+/// v.push(70_000);
+/// assert_eq!(v.bits(), 32);
+/// assert_eq!(v.get(1), Some(200));
+/// assert_eq!(v.iter().collect::<Vec<_>>(), [1, 200, 70_000]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NarrowVec {
+    storage: Storage,
+}
+
+impl Default for NarrowVec {
+    fn default() -> Self {
+        NarrowVec::new()
+    }
+}
+
+impl NarrowVec {
+    /// Create an empty `NarrowVec`.
+    ///
+    /// It starts out storing 8-bit values.
+    pub fn new() -> Self {
+        NarrowVec {
+            storage: Storage::U8(Vec::new()),
+        }
+    }
+
+    /// The number of values.
+    pub fn len(&self) -> usize {
+        match &self.storage {
+            Storage::U8(v) => v.len(),
+            Storage::U16(v) => v.len(),
+            Storage::U32(v) => v.len(),
+            Storage::U64(v) => v.len(),
+        }
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The width that each value is stored with, in bits.
+    pub fn bits(&self) -> u32 {
+        match &self.storage {
+            Storage::U8(_) => 8,
+            Storage::U16(_) => 16,
+            Storage::U32(_) => 32,
+            Storage::U64(_) => 64,
+        }
+    }
+
+    /// Get one value.
+    pub fn get(&self, index: usize) -> Option<u64> {
+        match &self.storage {
+            Storage::U8(v) => v.get(index).map(|&x| u64::from(x)),
+            Storage::U16(v) => v.get(index).map(|&x| u64::from(x)),
+            Storage::U32(v) => v.get(index).map(|&x| u64::from(x)),
+            Storage::U64(v) => v.get(index).copied(),
+        }
+    }
+
+    /// Iterate over the values.
+    pub fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+
+    /// Remove all values.
+    ///
+    /// This doesn't change the storage width.
+    pub fn clear(&mut self) {
+        match &mut self.storage {
+            Storage::U8(v) => v.clear(),
+            Storage::U16(v) => v.clear(),
+            Storage::U32(v) => v.clear(),
+            Storage::U64(v) => v.clear(),
+        }
+    }
+
+    /// Append a value, widening the storage if needed.
+    pub fn push(&mut self, value: u64) {
+        let pushed = match &mut self.storage {
+            Storage::U8(v) => push_narrow(v, value),
+            Storage::U16(v) => push_narrow(v, value),
+            Storage::U32(v) => push_narrow(v, value),
+            Storage::U64(v) => {
+                v.push(value);
+                true
+            }
+        };
+        if !pushed {
+            self.widen(value.min_bits());
+            self.push(value);
+        }
+    }
+
+    /// Widen the storage so that it can hold values of `bits` bits.
+    fn widen(&mut self, bits: u32) {
+        let values = self.iter();
+        let storage = if bits <= 16 {
+            Storage::U16(values.map(TruncateUnchecked::truncate_unchecked).collect())
+        } else if bits <= 32 {
+            Storage::U32(values.map(TruncateUnchecked::truncate_unchecked).collect())
+        } else {
+            Storage::U64(values.collect())
+        };
+        self.storage = storage;
+    }
+}
+
+/// Push `value` if it fits into `T`, returning `false` if it doesn't.
+///
+/// This doesn't go through `TryTruncate`, so that an injected fault
+/// can't make `push` widen forever.
+fn push_narrow<T: FromWide>(v: &mut Vec<T>, value: u64) -> bool {
+    match T::from_wide_checked(Widen::widen(value)) {
+        Some(x) => {
+            v.push(x);
+            true
+        }
+        None => false,
+    }
+}
+
+impl Extend<u64> for NarrowVec {
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl FromIterator<u64> for NarrowVec {
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        let mut v = NarrowVec::new();
+        v.extend(iter);
+        v
+    }
+}
@@ -0,0 +1,108 @@
+/// Type an integer constant as the narrowest unsigned primitive that
+/// holds it.
+///
+/// `narrowest!(300)` is `300u16`, and `narrowest!(255)` is `255u8`. The
+/// value must be a constant expression that fits in a `u128`. This is
+/// useful in tables where each entry should be no wider than it needs
+/// to be, without picking each width by hand.
+///
+/// ```rust
+/// use truncate_integer::narrowest;
+///
+/// let small = narrowest!(200);
+/// let large = narrowest!(70000);
+/// let opcode = narrowest!(0x3F << 2);
+/// assert_eq!(core::mem::size_of_val(&small), 1);
+/// assert_eq!(core::mem::size_of_val(&large), 4);
+/// assert_eq!(opcode, 0xFCu8);
+/// ```
+#[macro_export]
+macro_rules! narrowest {
+    ($value:expr) => {{
+        const VALUE: u128 = $value;
+        VALUE as <$crate::NarrowestWidth<{ $crate::narrowest_unsigned_bytes(VALUE) }, false> as $crate::NarrowestType>::Type
+    }};
+}
+
+/// Type an integer constant as the narrowest signed primitive that
+/// holds it.
+///
+/// `narrowest_signed!(-200)` is `-200i16`, and `narrowest_signed!(-128)`
+/// is `-128i8`. The value must be a constant expression that fits in an
+/// `i128`.
+///
+/// ```rust
+/// use truncate_integer::narrowest_signed;
+///
+/// let delta = narrowest_signed!(-128);
+/// let offset = narrowest_signed!(128);
+/// assert_eq!(core::mem::size_of_val(&delta), 1);
+/// assert_eq!(offset, 128i16);
+/// ```
+#[macro_export]
+macro_rules! narrowest_signed {
+    ($value:expr) => {{
+        const VALUE: i128 = $value;
+        VALUE as <$crate::NarrowestWidth<{ $crate::narrowest_signed_bytes(VALUE) }, true> as $crate::NarrowestType>::Type
+    }};
+}
+
+#[doc(hidden)]
+pub struct NarrowestWidth<const BYTES: usize, const SIGNED: bool>;
+
+#[doc(hidden)]
+pub trait NarrowestType {
+    type Type;
+}
+
+macro_rules! make_narrowest_type {
+    ($($bytes:literal => $Unsigned:ty, $Signed:ty;)*) => {
+        $(
+            impl NarrowestType for NarrowestWidth<$bytes, false> {
+                type Type = $Unsigned;
+            }
+
+            impl NarrowestType for NarrowestWidth<$bytes, true> {
+                type Type = $Signed;
+            }
+        )*
+    };
+}
+
+make_narrowest_type! {
+    1 => u8, i8;
+    2 => u16, i16;
+    4 => u32, i32;
+    8 => u64, i64;
+    16 => u128, i128;
+}
+
+#[doc(hidden)]
+pub const fn narrowest_unsigned_bytes(value: u128) -> usize {
+    if value <= u8::MAX as u128 {
+        1
+    } else if value <= u16::MAX as u128 {
+        2
+    } else if value <= u32::MAX as u128 {
+        4
+    } else if value <= u64::MAX as u128 {
+        8
+    } else {
+        16
+    }
+}
+
+#[doc(hidden)]
+pub const fn narrowest_signed_bytes(value: i128) -> usize {
+    if value >= i8::MIN as i128 && value <= i8::MAX as i128 {
+        1
+    } else if value >= i16::MIN as i128 && value <= i16::MAX as i128 {
+        2
+    } else if value >= i32::MIN as i128 && value <= i32::MAX as i128 {
+        4
+    } else if value >= i64::MIN as i128 && value <= i64::MAX as i128 {
+        8
+    } else {
+        16
+    }
+}
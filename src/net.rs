@@ -0,0 +1,88 @@
+//! Splitting IPv6 addresses into prefixes and interface identifiers.
+//!
+//! An [`Ipv6Addr`] is a 128-bit integer, and most of its structure is
+//! bit ranges of that integer: the high 64 bits are the routing prefix,
+//! the low 64 bits are the interface identifier, and a `/N` network
+//! keeps only the high `N` bits.
+//!
+//! ```rust
+//! use std::net::Ipv6Addr;
+//! use truncate_integer::net::{interface_id, mask_prefix, prefix_value, routing_prefix};
+//!
+//! let addr: Ipv6Addr = "2001:db8:1234:5678:abcd::1".parse().unwrap();
+//! assert_eq!(routing_prefix(addr), 0x2001_0db8_1234_5678);
+//! assert_eq!(interface_id(addr), 0xabcd_0000_0000_0001);
+//! assert_eq!(mask_prefix(addr, 48), "2001:db8:1234::".parse::<Ipv6Addr>().unwrap());
+//!
+//! // A /48 site prefix fits in a u64, but not a u32.
+//! assert_eq!(prefix_value::<u64>(addr, 48), Some(0x2001_0db8_1234));
+//! assert_eq!(prefix_value::<u32>(addr, 48), None);
+//! ```
+
+use crate::wide::{Primitive, Wide};
+use std::net::Ipv6Addr;
+
+/// The high 64 bits of `addr`.
+#[inline]
+pub fn routing_prefix(addr: Ipv6Addr) -> u64 {
+    (u128::from(addr) >> 64) as u64
+}
+
+/// The low 64 bits of `addr`.
+#[inline]
+pub fn interface_id(addr: Ipv6Addr) -> u64 {
+    u128::from(addr) as u64
+}
+
+/// Build an address from a routing prefix and an interface identifier.
+#[inline]
+pub fn from_parts(prefix: u64, interface_id: u64) -> Ipv6Addr {
+    Ipv6Addr::from(u128::from(prefix) << 64 | u128::from(interface_id))
+}
+
+/// A mask with the high `len` bits set.
+#[track_caller]
+#[inline]
+fn netmask_bits(len: u32) -> u128 {
+    assert!(len <= 128, "invalid prefix length");
+    u128::MAX.checked_shl(128 - len).unwrap_or(0)
+}
+
+/// The netmask of a `/len` network.
+///
+/// # Panics
+///
+/// Panics if `len` is more than 128.
+#[track_caller]
+#[inline]
+pub fn netmask(len: u32) -> Ipv6Addr {
+    Ipv6Addr::from(netmask_bits(len))
+}
+
+/// Keep the high `len` bits of `addr`, and clear the rest.
+///
+/// This is the network address of the `/len` network containing `addr`.
+///
+/// # Panics
+///
+/// Panics if `len` is more than 128.
+#[track_caller]
+#[inline]
+pub fn mask_prefix(addr: Ipv6Addr, len: u32) -> Ipv6Addr {
+    Ipv6Addr::from(u128::from(addr) & netmask_bits(len))
+}
+
+/// The high `len` bits of `addr` as an integer, narrowed into `T`.
+///
+/// Returns `None` if the prefix doesn't fit in `T`.
+///
+/// # Panics
+///
+/// Panics if `len` is more than 128.
+#[track_caller]
+#[inline]
+pub fn prefix_value<T: Primitive>(addr: Ipv6Addr, len: u32) -> Option<T> {
+    let bits = u128::from(addr) & netmask_bits(len);
+    let value = bits.checked_shr(128 - len).unwrap_or(0);
+    T::from_wide_checked(Wide::NonNegative(value))
+}
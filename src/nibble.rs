@@ -0,0 +1,148 @@
+//! Splitting bytes into 4-bit nibbles, and joining them back.
+//!
+//! BCD, hex digits, and many packed formats store two 4-bit values in
+//! each byte, with the high nibble first. Joining nibbles can either
+//! keep only the low four bits of each input ([`join_nibbles`]) or check
+//! that each one fits ([`try_join_nibbles`]).
+//!
+//! ```rust
+//! use truncate_integer::nibble::{join_nibbles, try_join_nibbles, SplitNibbles};
+//!
+//! assert_eq!(0x4Au8.split_nibbles(), (0x4, 0xA));
+//! assert_eq!(join_nibbles(0x4, 0xA), 0x4A);
+//! assert_eq!(try_join_nibbles(0x4, 0xA), Some(0x4A));
+//! assert_eq!(try_join_nibbles(0x14, 0xA), None);
+//! ```
+//!
+//! The slice versions convert whole buffers:
+//!
+//! ```rust
+//! use truncate_integer::nibble::{split_nibbles_into, try_join_nibbles_into, NibbleError};
+//!
+//! let mut digits = [0u8; 4];
+//! split_nibbles_into(&[0x12, 0x34], &mut digits);
+//! assert_eq!(digits, [1, 2, 3, 4]);
+//!
+//! let mut packed = [0u8; 2];
+//! try_join_nibbles_into(&digits, &mut packed).unwrap();
+//! assert_eq!(packed, [0x12, 0x34]);
+//!
+//! let err = try_join_nibbles_into(&[1, 2, 3, 16], &mut packed).unwrap_err();
+//! assert_eq!(err, NibbleError::new(3, 16));
+//! ```
+
+use core::fmt;
+
+/// Split a byte into its high and low nibbles.
+pub trait SplitNibbles {
+    /// Split into `(high, low)`, each in the range `0..=15`.
+    fn split_nibbles(self) -> (u8, u8);
+}
+
+impl SplitNibbles for u8 {
+    #[inline]
+    fn split_nibbles(self) -> (u8, u8) {
+        (self >> 4, self & 0xF)
+    }
+}
+
+/// Join two nibbles into a byte, keeping only the low four bits of each.
+#[inline]
+pub fn join_nibbles(high: u8, low: u8) -> u8 {
+    (high << 4) | (low & 0xF)
+}
+
+/// Join two nibbles into a byte, if both are in the range `0..=15`.
+#[inline]
+pub fn try_join_nibbles(high: u8, low: u8) -> Option<u8> {
+    if high > 0xF || low > 0xF {
+        None
+    } else {
+        Some(join_nibbles(high, low))
+    }
+}
+
+/// The error returned when a value doesn't fit in a nibble.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct NibbleError {
+    position: usize,
+    value: u8,
+}
+
+impl NibbleError {
+    /// Create an error for the value `value` at `position`.
+    pub fn new(position: usize, value: u8) -> Self {
+        NibbleError { position, value }
+    }
+
+    /// The position of the first value that didn't fit.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The first value that didn't fit.
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl fmt::Display for NibbleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value {} at position {} doesn't fit in a nibble",
+            self.value, self.position
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NibbleError {}
+
+/// Split each byte of `src` into two nibbles in `dst`, high nibble first.
+///
+/// # Panics
+///
+/// Panics if `dst` isn't twice as long as `src`.
+#[track_caller]
+pub fn split_nibbles_into(src: &[u8], dst: &mut [u8]) {
+    assert_eq!(src.len() * 2, dst.len(), "nibble buffer length mismatch");
+    for (out, &byte) in dst.chunks_exact_mut(2).zip(src) {
+        let (high, low) = byte.split_nibbles();
+        out[0] = high;
+        out[1] = low;
+    }
+}
+
+/// Join pairs of nibbles from `src` into bytes in `dst`, keeping only the
+/// low four bits of each.
+///
+/// # Panics
+///
+/// Panics if `src` isn't twice as long as `dst`.
+#[track_caller]
+pub fn join_nibbles_into(src: &[u8], dst: &mut [u8]) {
+    assert_eq!(src.len(), dst.len() * 2, "nibble buffer length mismatch");
+    for (out, pair) in dst.iter_mut().zip(src.chunks_exact(2)) {
+        *out = join_nibbles(pair[0], pair[1]);
+    }
+}
+
+/// Join pairs of nibbles from `src` into bytes in `dst`, failing if any
+/// of them doesn't fit.
+///
+/// If a value doesn't fit, the contents of `dst` are unspecified.
+///
+/// # Panics
+///
+/// Panics if `src` isn't twice as long as `dst`.
+#[track_caller]
+pub fn try_join_nibbles_into(src: &[u8], dst: &mut [u8]) -> Result<(), NibbleError> {
+    assert_eq!(src.len(), dst.len() * 2, "nibble buffer length mismatch");
+    if let Some(position) = src.iter().position(|&x| x > 0xF) {
+        return Err(NibbleError::new(position, src[position]));
+    }
+    join_nibbles_into(src, dst);
+    Ok(())
+}
@@ -0,0 +1,128 @@
+//! Parser combinators for the [`nom`](https://docs.rs/nom) crate.
+//!
+//! Each parser reads a fixed-width integer from the input, then narrows
+//! it into the (usually smaller) type the caller is storing it in.
+//!
+//! - `*_try` parsers use [`TryTruncate`]; a value that doesn't fit is
+//!   reported as a parse error with ErrorKind `Verify` and the context
+//!   string `"integer overflow"`.
+//! - `*_shrink` parsers use [`Shrink`], so they only fail if the input
+//!   is too short.
+//!
+//! The parsers are available in [`complete`] and [`streaming`] flavors,
+//! mirroring `nom::number`.
+//!
+//! ```rust
+//! use truncate_integer::nom::complete::{be_u32_shrink, le_u64_try};
+//!
+//! let input: &[u8] = &[0x00, 0x01, 0x00, 0x00, 0xFF, 0, 0, 0, 0, 0, 0, 0];
+//! type Error<'a> = nom::error::Error<&'a [u8]>;
+//!
+//! let (rest, len) = be_u32_shrink::<u16, _, Error>(input).unwrap();
+//! assert_eq!(len, u16::MAX);
+//! let (_, id) = le_u64_try::<u8, _, Error>(rest).unwrap();
+//! assert_eq!(id, 255);
+//! ```
+
+use crate::{Shrink, TryTruncate};
+use ::nom::error::{ContextError, ErrorKind, ParseError};
+use ::nom::{IResult, Input};
+
+/// The context string attached to errors from the `*_try` parsers.
+pub const OVERFLOW_CONTEXT: &str = "integer overflow";
+
+fn overflow<I, E>(input: I) -> ::nom::Err<E>
+where
+    I: Clone,
+    E: ParseError<I> + ContextError<I>,
+{
+    let error = E::from_error_kind(input.clone(), ErrorKind::Verify);
+    ::nom::Err::Error(E::add_context(input, OVERFLOW_CONTEXT, error))
+}
+
+macro_rules! nom_try {
+    ($mode:ident, $name:ident, $parser:ident, $Wide:ty) => {
+        #[doc = concat!("Parse a `", stringify!($Wide), "` with `nom::number::", stringify!($mode), "::", stringify!($parser), "`, then narrow it with [`TryTruncate`].")]
+        ///
+        /// If the value doesn't fit, return a `Verify` error.
+        pub fn $name<T, I, E>(input: I) -> IResult<I, T, E>
+        where
+            I: Input<Item = u8> + Clone,
+            E: ParseError<I> + ContextError<I>,
+            $Wide: TryTruncate<T>,
+        {
+            let (rest, value) = ::nom::number::$mode::$parser(input.clone())?;
+            match value.try_truncate() {
+                Some(value) => Ok((rest, value)),
+                None => Err(overflow(input)),
+            }
+        }
+    };
+}
+
+macro_rules! nom_shrink {
+    ($mode:ident, $name:ident, $parser:ident, $Wide:ty) => {
+        #[doc = concat!("Parse a `", stringify!($Wide), "` with `nom::number::", stringify!($mode), "::", stringify!($parser), "`, then narrow it with [`Shrink`].")]
+        pub fn $name<T, I, E>(input: I) -> IResult<I, T, E>
+        where
+            I: Input<Item = u8>,
+            E: ParseError<I>,
+            $Wide: Shrink<T>,
+        {
+            let (rest, value) = ::nom::number::$mode::$parser(input)?;
+            Ok((rest, value.shrink()))
+        }
+    };
+}
+
+macro_rules! nom_all {
+    ($mode:ident) => {
+        nom_try!($mode, be_u16_try, be_u16, u16);
+        nom_try!($mode, be_u32_try, be_u32, u32);
+        nom_try!($mode, be_u64_try, be_u64, u64);
+        nom_try!($mode, be_u128_try, be_u128, u128);
+        nom_try!($mode, be_i16_try, be_i16, i16);
+        nom_try!($mode, be_i32_try, be_i32, i32);
+        nom_try!($mode, be_i64_try, be_i64, i64);
+        nom_try!($mode, be_i128_try, be_i128, i128);
+        nom_try!($mode, le_u16_try, le_u16, u16);
+        nom_try!($mode, le_u32_try, le_u32, u32);
+        nom_try!($mode, le_u64_try, le_u64, u64);
+        nom_try!($mode, le_u128_try, le_u128, u128);
+        nom_try!($mode, le_i16_try, le_i16, i16);
+        nom_try!($mode, le_i32_try, le_i32, i32);
+        nom_try!($mode, le_i64_try, le_i64, i64);
+        nom_try!($mode, le_i128_try, le_i128, i128);
+
+        nom_shrink!($mode, be_u16_shrink, be_u16, u16);
+        nom_shrink!($mode, be_u32_shrink, be_u32, u32);
+        nom_shrink!($mode, be_u64_shrink, be_u64, u64);
+        nom_shrink!($mode, be_u128_shrink, be_u128, u128);
+        nom_shrink!($mode, be_i16_shrink, be_i16, i16);
+        nom_shrink!($mode, be_i32_shrink, be_i32, i32);
+        nom_shrink!($mode, be_i64_shrink, be_i64, i64);
+        nom_shrink!($mode, be_i128_shrink, be_i128, i128);
+        nom_shrink!($mode, le_u16_shrink, le_u16, u16);
+        nom_shrink!($mode, le_u32_shrink, le_u32, u32);
+        nom_shrink!($mode, le_u64_shrink, le_u64, u64);
+        nom_shrink!($mode, le_u128_shrink, le_u128, u128);
+        nom_shrink!($mode, le_i16_shrink, le_i16, i16);
+        nom_shrink!($mode, le_i32_shrink, le_i32, i32);
+        nom_shrink!($mode, le_i64_shrink, le_i64, i64);
+        nom_shrink!($mode, le_i128_shrink, le_i128, i128);
+    };
+}
+
+/// Parsers that treat a short input as an error.
+pub mod complete {
+    use super::*;
+
+    nom_all!(complete);
+}
+
+/// Parsers that treat a short input as `Incomplete`.
+pub mod streaming {
+    use super::*;
+
+    nom_all!(streaming);
+}
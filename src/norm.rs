@@ -0,0 +1,114 @@
+//! Conversions between `f32` and normalized integers.
+//!
+//! These follow the conventions GPUs use for "unorm" and "snorm"
+//! texture and vertex formats:
+//!
+//! - A unorm value maps `0.0..=1.0` onto the full range of an unsigned integer.
+//! - A snorm value maps `-1.0..=1.0` onto a signed integer, excluding
+//!   its minimum value. Both `MIN` and `MIN + 1` convert to `-1.0`.
+//!
+//! When converting from `f32`, the input is first clamped into the
+//! valid range, then scaled and rounded to the nearest integer (with
+//! ties rounding up). NaN converts to zero.
+//!
+//! ```rust
+//! use truncate_integer::norm::{FloatToNorm, SnormToFloat, UnormToFloat};
+//!
+//! assert_eq!(0.5f32.to_unorm8(), 128);
+//! assert_eq!(2.0f32.to_unorm8(), 255);
+//! assert_eq!((-1.0f32).to_snorm8(), -127);
+//! assert_eq!(255u8.to_unorm_f32(), 1.0);
+//! assert_eq!(i8::MIN.to_snorm_f32(), -1.0);
+//! ```
+
+/// Compute `floor(0.5 + value)`.
+///
+/// `value` must be within the range of an `i32`.
+#[inline]
+pub(crate) fn round(value: f32) -> f32 {
+    let value = value + 0.5;
+    let truncated = value as i32 as f32;
+    if truncated > value {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+#[inline]
+fn unorm(value: f32, max: f32) -> f32 {
+    // NaN survives `clamp`, but `round` turns it into zero.
+    round(value.clamp(0.0, 1.0) * max)
+}
+
+#[inline]
+fn snorm(value: f32, max: f32) -> f32 {
+    round(value.clamp(-1.0, 1.0) * max)
+}
+
+/// Convert an `f32` into a normalized integer.
+pub trait FloatToNorm {
+    /// Convert a value in `0.0..=1.0` into a `u8` in `0..=255`.
+    fn to_unorm8(self) -> u8;
+    /// Convert a value in `0.0..=1.0` into a `u16` in `0..=65535`.
+    fn to_unorm16(self) -> u16;
+    /// Convert a value in `-1.0..=1.0` into an `i8` in `-127..=127`.
+    fn to_snorm8(self) -> i8;
+    /// Convert a value in `-1.0..=1.0` into an `i16` in `-32767..=32767`.
+    fn to_snorm16(self) -> i16;
+}
+
+impl FloatToNorm for f32 {
+    #[inline]
+    fn to_unorm8(self) -> u8 {
+        unorm(self, 255.0) as u8
+    }
+
+    #[inline]
+    fn to_unorm16(self) -> u16 {
+        unorm(self, 65535.0) as u16
+    }
+
+    #[inline]
+    fn to_snorm8(self) -> i8 {
+        snorm(self, 127.0) as i8
+    }
+
+    #[inline]
+    fn to_snorm16(self) -> i16 {
+        snorm(self, 32767.0) as i16
+    }
+}
+
+/// Convert an unsigned normalized integer into an `f32` in `0.0..=1.0`.
+pub trait UnormToFloat {
+    /// Convert an unsigned normalized integer into an `f32` in `0.0..=1.0`.
+    fn to_unorm_f32(self) -> f32;
+}
+
+/// Convert a signed normalized integer into an `f32` in `-1.0..=1.0`.
+pub trait SnormToFloat {
+    /// Convert a signed normalized integer into an `f32` in `-1.0..=1.0`.
+    fn to_snorm_f32(self) -> f32;
+}
+
+macro_rules! make_norm {
+    ($Unsigned:ty, $Signed:ty) => {
+        impl UnormToFloat for $Unsigned {
+            #[inline]
+            fn to_unorm_f32(self) -> f32 {
+                f32::from(self) / f32::from(<$Unsigned>::MAX)
+            }
+        }
+
+        impl SnormToFloat for $Signed {
+            #[inline]
+            fn to_snorm_f32(self) -> f32 {
+                (f32::from(self) / f32::from(<$Signed>::MAX)).max(-1.0)
+            }
+        }
+    };
+}
+
+make_norm!(u8, i8);
+make_norm!(u16, i16);
@@ -0,0 +1,94 @@
+//! Remapping a runtime range onto the full range of a narrow type.
+//!
+//! Heatmaps, plots and sensor calibration know the range of their input
+//! only at runtime. [`normalize_shrink`] maps `src_min..=src_max`
+//! linearly onto `0..=T::MAX`, rounding to the nearest step, and clamps
+//! values outside the range to the ends.
+//!
+//! If `src_min` is greater than `src_max`, the mapping is reversed, so
+//! `src_min` still maps to 0. If they're equal, values up to `src_min`
+//! map to 0 and larger values map to `T::MAX`.
+//!
+//! ```rust
+//! use truncate_integer::normalize::normalize_shrink;
+//!
+//! // A thermometer calibrated from -40 to 125 degrees.
+//! assert_eq!(normalize_shrink::<u8, _>(-40i32, -40, 125), 0);
+//! assert_eq!(normalize_shrink::<u8, _>(42i32, -40, 125), 127);
+//! assert_eq!(normalize_shrink::<u8, _>(200i32, -40, 125), 255);
+//! assert_eq!(normalize_shrink::<u16, _>(25u64, 100, 0), 49151);
+//! ```
+
+use crate::wide::{Primitive, Wide};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An unsigned integer type to normalize into.
+pub trait Level: private::Sealed + Copy {
+    #[doc(hidden)]
+    const MAX_LEVEL: u128;
+    #[doc(hidden)]
+    fn from_level(level: u128) -> Self;
+}
+
+macro_rules! make_level {
+    ($($Type:ty),*) => {
+        $(
+            impl private::Sealed for $Type {}
+
+            impl Level for $Type {
+                const MAX_LEVEL: u128 = <$Type>::MAX as u128;
+
+                #[inline]
+                fn from_level(level: u128) -> Self {
+                    level as $Type
+                }
+            }
+        )*
+    };
+}
+
+make_level!(u8, u16);
+
+/// `a - b`, for `a >= b`.
+#[inline]
+fn distance(a: Wide, b: Wide) -> u128 {
+    a.to_bits().wrapping_sub(b.to_bits())
+}
+
+/// Compute `offset * max / range`, rounded to nearest.
+#[inline]
+fn scale(mut offset: u128, mut range: u128, max: u128) -> u128 {
+    // Keep the product within 128 bits. The error this introduces is
+    // far below one step of the output.
+    let excess = (128 - range.leading_zeros()).saturating_sub(111);
+    offset >>= excess;
+    range >>= excess;
+    (offset * max + range / 2) / range
+}
+
+/// Map `value` from `src_min..=src_max` onto `0..=T::MAX`, rounding to
+/// nearest and clamping.
+pub fn normalize_shrink<T: Level, V: Primitive>(value: V, src_min: V, src_max: V) -> T {
+    let (value, min, max) = (value.widen(), src_min.widen(), src_max.widen());
+    let level = if min <= max {
+        if value <= min {
+            0
+        } else if value >= max {
+            T::MAX_LEVEL
+        } else {
+            scale(distance(value, min), distance(max, min), T::MAX_LEVEL)
+        }
+    } else if value >= min {
+        0
+    } else if value <= max {
+        T::MAX_LEVEL
+    } else {
+        // Measure from `src_min`, so ties round the same way in both
+        // directions.
+        scale(distance(min, value), distance(min, max), T::MAX_LEVEL)
+    };
+    T::from_level(level)
+}
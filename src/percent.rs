@@ -0,0 +1,51 @@
+//! Saturating percentages and ratios.
+//!
+//! [`percent_of`], [`permille_of`] and [`basis_points_of`] compute
+//! `part / whole` on a scale of 100, 1000 or 10000, in a 128-bit
+//! intermediate so the multiplication can't overflow. The ratio is
+//! rounded to the nearest unit, with halves rounded up, and then
+//! saturated into the output type, so a part larger than the whole
+//! gives a result above the full scale, up to the type's maximum.
+//!
+//! A `whole` of zero is handled explicitly: `0 / 0` is zero, and any
+//! other part of an empty whole saturates to the maximum.
+//!
+//! ```rust
+//! use truncate_integer::percent::{basis_points_of, percent_of};
+//!
+//! assert_eq!(percent_of(1, 3), 33);
+//! assert_eq!(percent_of(2, 3), 67);
+//! assert_eq!(percent_of(3, 2), 150);
+//! assert_eq!(percent_of(u64::MAX, 1), 255);
+//! assert_eq!(percent_of(0, 0), 0);
+//! assert_eq!(basis_points_of(1, 8), 1250);
+//! ```
+
+use crate::Shrink;
+
+#[inline]
+fn scaled(part: u64, whole: u64, scale: u128) -> u128 {
+    if whole == 0 {
+        return if part == 0 { 0 } else { u128::MAX };
+    }
+    let whole = u128::from(whole);
+    (u128::from(part) * scale + whole / 2) / whole
+}
+
+/// Compute `part` as a percentage of `whole`.
+#[inline]
+pub fn percent_of(part: u64, whole: u64) -> u8 {
+    scaled(part, whole, 100).shrink()
+}
+
+/// Compute `part` in parts per thousand of `whole`.
+#[inline]
+pub fn permille_of(part: u64, whole: u64) -> u16 {
+    scaled(part, whole, 1000).shrink()
+}
+
+/// Compute `part` in basis points (parts per ten thousand) of `whole`.
+#[inline]
+pub fn basis_points_of(part: u64, whole: u64) -> u16 {
+    scaled(part, whole, 10_000).shrink()
+}
@@ -0,0 +1,81 @@
+//! Packed 16-bit pixel formats.
+//!
+//! Each channel is rescaled with rounding, rather than simply dropping
+//! the low-order bits, so that `0` and `255` map to the smallest and
+//! largest channel values, and unpacking a packed pixel and packing it
+//! again always returns the original value.
+//!
+//! ```rust
+//! use truncate_integer::pixel::{pack_rgb565, unpack_rgb565};
+//!
+//! let pixel = pack_rgb565([255, 128, 0]);
+//! assert_eq!(pixel, 0xFC00);
+//! assert_eq!(unpack_rgb565(pixel), [255, 130, 0]);
+//! ```
+
+/// Rescale an 8-bit channel value into `bits` bits.
+#[inline]
+fn narrow(value: u8, bits: u32) -> u16 {
+    let max = (1u32 << bits) - 1;
+    ((u32::from(value) * max + 127) / 255) as u16
+}
+
+/// Rescale a `bits`-bit channel value into 8 bits.
+#[inline]
+fn widen(value: u16, bits: u32) -> u8 {
+    let max = (1u32 << bits) - 1;
+    let value = u32::from(value) & max;
+    ((value * 255 + max / 2) / max) as u8
+}
+
+/// Pack an `[r, g, b]` pixel into RGB565 format.
+///
+/// Red is stored in the high-order 5 bits, followed by 6 bits of green
+/// and 5 bits of blue.
+pub fn pack_rgb565(rgb: [u8; 3]) -> u16 {
+    let [r, g, b] = rgb;
+    narrow(r, 5) << 11 | narrow(g, 6) << 5 | narrow(b, 5)
+}
+
+/// Unpack an RGB565 pixel into `[r, g, b]`.
+pub fn unpack_rgb565(pixel: u16) -> [u8; 3] {
+    [widen(pixel >> 11, 5), widen(pixel >> 5, 6), widen(pixel, 5)]
+}
+
+/// Pack an `[a, r, g, b]` pixel into ARGB1555 format.
+///
+/// Alpha is stored in the high-order bit, and is set if `a >= 128`.
+pub fn pack_argb1555(argb: [u8; 4]) -> u16 {
+    let [a, r, g, b] = argb;
+    narrow(a, 1) << 15 | narrow(r, 5) << 10 | narrow(g, 5) << 5 | narrow(b, 5)
+}
+
+/// Unpack an ARGB1555 pixel into `[a, r, g, b]`.
+///
+/// Alpha will be either `0` or `255`.
+pub fn unpack_argb1555(pixel: u16) -> [u8; 4] {
+    [
+        widen(pixel >> 15, 1),
+        widen(pixel >> 10, 5),
+        widen(pixel >> 5, 5),
+        widen(pixel, 5),
+    ]
+}
+
+/// Pack an `[r, g, b, a]` pixel into RGBA4444 format.
+///
+/// Red is stored in the high-order 4 bits, and alpha in the low-order 4 bits.
+pub fn pack_rgba4444(rgba: [u8; 4]) -> u16 {
+    let [r, g, b, a] = rgba;
+    narrow(r, 4) << 12 | narrow(g, 4) << 8 | narrow(b, 4) << 4 | narrow(a, 4)
+}
+
+/// Unpack an RGBA4444 pixel into `[r, g, b, a]`.
+pub fn unpack_rgba4444(pixel: u16) -> [u8; 4] {
+    [
+        widen(pixel >> 12, 4),
+        widen(pixel >> 8, 4),
+        widen(pixel >> 4, 4),
+        widen(pixel, 4),
+    ]
+}
@@ -0,0 +1,127 @@
+//! Narrowing protobuf fields into domain types.
+//!
+//! Protobuf only has 32- and 64-bit integers, so code generated by
+//! `prost` (and other generators) often uses a wider type than the
+//! domain model. These helpers narrow a field and attach its name to
+//! the error, so that a bad message can be reported usefully.
+//!
+//! ```rust
+//! use truncate_integer::protobuf::{field, optional_field, FieldError};
+//!
+//! // As generated by prost.
+//! struct ProtoConfig {
+//!     port: u32,
+//!     retries: Option<i64>,
+//! }
+//!
+//! struct Config {
+//!     port: u16,
+//!     retries: Option<u8>,
+//! }
+//!
+//! fn convert(proto: ProtoConfig) -> Result<Config, FieldError> {
+//!     Ok(Config {
+//!         port: field("port", proto.port)?,
+//!         retries: optional_field("retries", proto.retries)?,
+//!     })
+//! }
+//!
+//! let err = convert(ProtoConfig { port: 80, retries: Some(-1) }).err().unwrap();
+//! assert_eq!(err.to_string(), "field retries: integer underflow");
+//! ```
+//!
+//! `sint32` and `sint64` fields are zigzag encoded on the wire; if
+//! they're handled as raw varints, [`sint_field`] decodes and narrows in
+//! one step.
+
+use crate::{TruncateError, TryTruncate};
+use core::fmt;
+
+/// The error returned when a protobuf field doesn't fit its domain type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldError {
+    field: &'static str,
+    error: TruncateError,
+}
+
+impl FieldError {
+    /// Create an error for the named field.
+    pub fn new(field: &'static str, error: TruncateError) -> Self {
+        FieldError { field, error }
+    }
+
+    /// The name of the field.
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// Why the field didn't fit.
+    pub fn error(&self) -> TruncateError {
+        self.error
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "field {}: {}", self.field, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Narrow a field into its domain type.
+#[inline]
+pub fn field<S, D>(name: &'static str, value: S) -> Result<D, FieldError>
+where
+    S: TryTruncate<D> + PartialOrd + Default + Copy,
+{
+    value
+        .try_truncate()
+        .ok_or_else(|| FieldError::new(name, TruncateError::for_value(&value)))
+}
+
+/// Narrow an optional field into its domain type.
+///
+/// A missing field is not an error.
+#[inline]
+pub fn optional_field<S, D>(name: &'static str, value: Option<S>) -> Result<Option<D>, FieldError>
+where
+    S: TryTruncate<D> + PartialOrd + Default + Copy,
+{
+    value.map(|value| field(name, value)).transpose()
+}
+
+/// Zigzag-encode a signed value, as for a `sint64` field.
+#[inline]
+pub fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Decode a zigzag-encoded value, as from a `sint64` field.
+#[inline]
+pub fn zigzag_decode(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// Decode a zigzag-encoded varint, and narrow it into its domain type.
+///
+/// ```rust
+/// use truncate_integer::protobuf::{sint_field, zigzag_encode};
+///
+/// let raw = zigzag_encode(-100);
+/// assert_eq!(raw, 199);
+/// assert_eq!(sint_field::<i8>("delta", raw), Ok(-100));
+/// assert!(sint_field::<u8>("delta", raw).is_err());
+/// ```
+#[inline]
+pub fn sint_field<D>(name: &'static str, raw: u64) -> Result<D, FieldError>
+where
+    i64: TryTruncate<D>,
+{
+    field(name, zigzag_decode(raw))
+}
@@ -0,0 +1,177 @@
+//! Rounding to a multiple of an arbitrary step, then narrowing.
+//!
+//! Sensor readings are binned, and UI positions snapped, to steps that
+//! aren't powers of two. Each value is rounded to the nearest multiple
+//! of `step`, with halfway cases away from zero, and then narrowed.
+//!
+//! - `quantize_*` functions narrow the quotient: the number of steps.
+//! - `snap_*` functions narrow the quantized value: the multiple itself.
+//!
+//! Both come in `try`, `shrink` and `chop` forms, like [`TryTruncate`],
+//! [`Shrink`] and [`Chop`]. The arithmetic is exact for every source
+//! type.
+//!
+//! [`TryTruncate`]: crate::TryTruncate
+//! [`Shrink`]: crate::Shrink
+//! [`Chop`]: crate::Chop
+//!
+//! ```rust
+//! use truncate_integer::quantize::{quantize_shrink, quantize_try, snap_shrink};
+//!
+//! // 0.1 degree readings, in bins of 2.5 degrees.
+//! assert_eq!(quantize_try::<u8, _>(1237u32, 25), Some(49));
+//! assert_eq!(quantize_try::<i8, _>(-1237i32, 25), Some(-49));
+//! assert_eq!(quantize_shrink::<u8, _>(100_000u32, 25), 255);
+//!
+//! // Snap to a 15 pixel grid.
+//! assert_eq!(snap_shrink::<u8, _>(38u32, 15), 45);
+//! assert_eq!(snap_shrink::<u8, _>(260u32, 15), 255);
+//! ```
+
+use crate::wide::{Primitive, Wide};
+
+/// Divide `value` by `step`, rounding to nearest, returning the sign
+/// and magnitude of the quotient.
+#[track_caller]
+#[inline]
+fn quotient(value: Wide, step: u64) -> (bool, u128) {
+    assert!(step != 0, "quantize step is zero");
+    let step = u128::from(step);
+    let (negative, magnitude) = match value {
+        Wide::Negative(x) => (true, (x as u128).wrapping_neg()),
+        Wide::NonNegative(x) => (false, x),
+    };
+    let mut q = magnitude / step;
+    let r = magnitude % step;
+    if r >= step - r {
+        // Can't overflow: a remainder means the step is at least 2.
+        q += 1;
+    }
+    (negative, q)
+}
+
+/// Rebuild a widened value from its sign and magnitude.
+///
+/// Returns `None` if it doesn't fit in a `Wide`.
+#[inline]
+fn signed(negative: bool, magnitude: u128) -> Option<Wide> {
+    if !negative || magnitude == 0 {
+        Some(Wide::NonNegative(magnitude))
+    } else if magnitude <= 1 << 127 {
+        Some(Wide::Negative(magnitude.wrapping_neg() as i128))
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn quantized(value: Wide, step: u64) -> Wide {
+    let (negative, q) = quotient(value, step);
+    // The quotient is no larger than the value, so it always fits.
+    if negative && q != 0 {
+        Wide::Negative((q as i128).wrapping_neg())
+    } else {
+        Wide::NonNegative(q)
+    }
+}
+
+#[inline]
+fn snapped(value: Wide, step: u64) -> Result<Wide, bool> {
+    let (negative, q) = quotient(value, step);
+    q.checked_mul(u128::from(step))
+        .and_then(|x| signed(negative, x))
+        .ok_or(negative)
+}
+
+#[inline]
+fn saturate<T: Primitive>(value: Result<Wide, bool>) -> T {
+    match value {
+        Ok(x) => T::from_wide_saturating(x),
+        Err(true) => T::from_wide_wrapping(T::MIN_WIDE),
+        Err(false) => T::from_wide_wrapping(T::MAX_WIDE),
+    }
+}
+
+/// Round `value` to the nearest multiple of `step`, and narrow the
+/// number of steps into `T`.
+///
+/// Returns `None` if it doesn't fit.
+///
+/// # Panics
+///
+/// Panics if `step` is zero.
+#[track_caller]
+#[inline]
+pub fn quantize_try<T: Primitive, V: Primitive>(value: V, step: u64) -> Option<T> {
+    T::from_wide_checked(quantized(value.widen(), step))
+}
+
+/// Round `value` to the nearest multiple of `step`, and narrow the
+/// number of steps into `T`, saturating if it doesn't fit.
+///
+/// # Panics
+///
+/// Panics if `step` is zero.
+#[track_caller]
+#[inline]
+pub fn quantize_shrink<T: Primitive, V: Primitive>(value: V, step: u64) -> T {
+    T::from_wide_saturating(quantized(value.widen(), step))
+}
+
+/// Round `value` to the nearest multiple of `step`, and narrow the
+/// number of steps into `T`.
+///
+/// # Panics
+///
+/// Panics if `step` is zero, or the number of steps doesn't fit.
+#[track_caller]
+#[inline]
+pub fn quantize_chop<T: Primitive, V: Primitive>(value: V, step: u64) -> T {
+    match quantize_try(value, step) {
+        Some(x) => x,
+        None => panic!("chop overflow"),
+    }
+}
+
+/// Round `value` to the nearest multiple of `step`, and narrow that
+/// multiple into `T`.
+///
+/// Returns `None` if it doesn't fit.
+///
+/// # Panics
+///
+/// Panics if `step` is zero.
+#[track_caller]
+#[inline]
+pub fn snap_try<T: Primitive, V: Primitive>(value: V, step: u64) -> Option<T> {
+    snapped(value.widen(), step)
+        .ok()
+        .and_then(T::from_wide_checked)
+}
+
+/// Round `value` to the nearest multiple of `step`, and narrow that
+/// multiple into `T`, saturating if it doesn't fit.
+///
+/// # Panics
+///
+/// Panics if `step` is zero.
+#[track_caller]
+#[inline]
+pub fn snap_shrink<T: Primitive, V: Primitive>(value: V, step: u64) -> T {
+    saturate(snapped(value.widen(), step))
+}
+
+/// Round `value` to the nearest multiple of `step`, and narrow that
+/// multiple into `T`.
+///
+/// # Panics
+///
+/// Panics if `step` is zero, or the multiple doesn't fit.
+#[track_caller]
+#[inline]
+pub fn snap_chop<T: Primitive, V: Primitive>(value: V, step: u64) -> T {
+    match snap_try(value, step) {
+        Some(x) => x,
+        None => panic!("chop overflow"),
+    }
+}
@@ -0,0 +1,225 @@
+//! Support for the [`quickcheck`](https://docs.rs/quickcheck) crate.
+//!
+//! [`Fits<Src, Dest>`](Fits) generates values of `Src` that fit into
+//! `Dest`, and [`Overflows<Src, Dest>`](Overflows) generates values that
+//! don't. Both generate the values at the edges of the range more often
+//! than a uniform choice would, since that's where narrowing bugs are,
+//! and both shrink failing values while staying in their class.
+//!
+//! [`Boundary<T>`](Boundary) generates only the boundary values of `T`:
+//! its minimum and maximum, the values next to them, and `-1`, `0` and
+//! `1`.
+//!
+//! ```rust
+//! use quickcheck::quickcheck;
+//! use truncate_integer::quickcheck::{Fits, Overflows};
+//! use truncate_integer::TryTruncate;
+//!
+//! fn fits(x: Fits<u64, u16>) -> bool {
+//!     TryTruncate::<u16>::try_truncate(x.get()).is_some()
+//! }
+//!
+//! fn overflows(x: Overflows<i32, i8>) -> bool {
+//!     TryTruncate::<i8>::try_truncate(x.get()).is_none()
+//! }
+//!
+//! quickcheck(fits as fn(Fits<u64, u16>) -> bool);
+//! quickcheck(overflows as fn(Overflows<i32, i8>) -> bool);
+//! ```
+
+use crate::wide::{Primitive, Wide};
+use ::quickcheck::{Arbitrary, Gen};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// The position of a value in the ordering of `T`, as a `u128`.
+///
+/// Every value of a single primitive type has a distinct key, and keys
+/// compare the same way as the values.
+fn key<T: Primitive>(value: Wide) -> u128 {
+    if T::MIN_WIDE < Wide::NonNegative(0) {
+        value.to_bits() ^ (1 << 127)
+    } else {
+        value.to_bits()
+    }
+}
+
+fn from_key<T: Primitive>(key: u128) -> T {
+    // Inverse of `key`, given that the result is a `T`.
+    let bits = if T::MIN_WIDE < Wide::NonNegative(0) {
+        key ^ (1 << 127)
+    } else {
+        key
+    };
+    T::from_wide_wrapping(Wide::NonNegative(bits))
+}
+
+/// Pick a key in `min..=max`, preferring the ends of the range.
+fn pick(g: &mut Gen, min: u128, max: u128) -> u128 {
+    let span = max - min;
+    let offset = match u8::arbitrary(g) % 8 {
+        0 => 0,
+        1 => span.min(1),
+        2 => span - span.min(1),
+        3 => span,
+        _ => match span.checked_add(1) {
+            Some(len) => u128::arbitrary(g) % len,
+            None => u128::arbitrary(g),
+        },
+    };
+    min + offset
+}
+
+/// Shrink `value` toward `target`, by halving the distance between
+/// them.
+fn shrink_toward<T: Primitive + 'static>(value: T, target: u128) -> Box<dyn Iterator<Item = T>> {
+    let value = key::<T>(value.widen());
+    let mut candidates = Vec::new();
+    if value > target {
+        let mut distance = value - target;
+        while distance != 0 {
+            candidates.push(from_key(value - distance));
+            distance /= 2;
+        }
+    } else {
+        let mut distance = target - value;
+        while distance != 0 {
+            candidates.push(from_key(value + distance));
+            distance /= 2;
+        }
+    }
+    Box::new(candidates.into_iter())
+}
+
+/// The range of keys of `Src` values that fit into `Dest`.
+fn fit_range<Src: Primitive, Dest: Primitive>() -> (u128, u128) {
+    let min = Dest::MIN_WIDE.max(Src::MIN_WIDE);
+    let max = Dest::MAX_WIDE.min(Src::MAX_WIDE);
+    (key::<Src>(min), key::<Src>(max))
+}
+
+/// A value of `Src` that fits into `Dest`.
+pub struct Fits<Src, Dest>(Src, PhantomData<fn() -> Dest>);
+
+/// A value of `Src` that doesn't fit into `Dest`.
+///
+/// Generating one panics if every `Src` fits into `Dest`.
+pub struct Overflows<Src, Dest>(Src, PhantomData<fn() -> Dest>);
+
+macro_rules! make_wrapper {
+    ($Wrapper:ident) => {
+        impl<Src: Copy, Dest> $Wrapper<Src, Dest> {
+            /// Get the value.
+            pub fn get(&self) -> Src {
+                self.0
+            }
+        }
+
+        impl<Src: Copy, Dest> Clone for $Wrapper<Src, Dest> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<Src: Copy, Dest> Copy for $Wrapper<Src, Dest> {}
+
+        impl<Src: fmt::Debug, Dest> fmt::Debug for $Wrapper<Src, Dest> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($Wrapper)).field(&self.0).finish()
+            }
+        }
+    };
+}
+
+make_wrapper!(Fits);
+make_wrapper!(Overflows);
+
+impl<Src, Dest> Arbitrary for Fits<Src, Dest>
+where
+    Src: Primitive + fmt::Debug + 'static,
+    Dest: Primitive + 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        let (min, max) = fit_range::<Src, Dest>();
+        Fits(from_key(pick(g, min, max)), PhantomData)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // Shrink toward zero, which always fits.
+        let zero = key::<Src>(Wide::NonNegative(0));
+        Box::new(shrink_toward(self.0, zero).map(|x| Fits(x, PhantomData)))
+    }
+}
+
+impl<Src, Dest> Arbitrary for Overflows<Src, Dest>
+where
+    Src: Primitive + fmt::Debug + 'static,
+    Dest: Primitive + 'static,
+{
+    #[track_caller]
+    fn arbitrary(g: &mut Gen) -> Self {
+        let (fit_min, fit_max) = fit_range::<Src, Dest>();
+        let (min, max) = (key::<Src>(Src::MIN_WIDE), key::<Src>(Src::MAX_WIDE));
+        let below = fit_min > min;
+        let above = fit_max < max;
+        let use_below = match (below, above) {
+            (true, true) => bool::arbitrary(g),
+            (true, false) => true,
+            (false, true) => false,
+            (false, false) => panic!("every value fits in the destination type"),
+        };
+        let value = if use_below {
+            pick(g, min, fit_min - 1)
+        } else {
+            pick(g, fit_max + 1, max)
+        };
+        Overflows(from_key(value), PhantomData)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // Shrink toward the nearest value that still doesn't fit.
+        let (fit_min, fit_max) = fit_range::<Src, Dest>();
+        let value = key::<Src>(self.0.widen());
+        let target = if value < fit_min {
+            fit_min - 1
+        } else {
+            fit_max + 1
+        };
+        Box::new(shrink_toward(self.0, target).map(|x| Overflows(x, PhantomData)))
+    }
+}
+
+/// A boundary value of `T`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Boundary<T>(pub T);
+
+impl<T> Arbitrary for Boundary<T>
+where
+    T: Primitive + 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        let (min, max) = (key::<T>(T::MIN_WIDE), key::<T>(T::MAX_WIDE));
+        let zero = key::<T>(Wide::NonNegative(0));
+        let keys = [
+            min,
+            min + 1,
+            zero.max(min + 1) - 1,
+            zero,
+            zero + 1,
+            max - 1,
+            max,
+        ];
+        Boundary(from_key(*g.choose(&keys).unwrap()))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let zero = key::<T>(Wide::NonNegative(0));
+        if key::<T>(self.0.widen()) == zero {
+            Box::new(core::iter::empty())
+        } else {
+            Box::new(core::iter::once(Boundary(from_key(zero))))
+        }
+    }
+}
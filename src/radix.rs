@@ -0,0 +1,119 @@
+//! Extracting radix digits from integers.
+//!
+//! Radix sorts and tries split each key into fixed-width digits. A
+//! digit here is a group of `bits` bits of the key's two's complement
+//! representation; with [`digit`] the group is as wide as the result
+//! type, so `digit::<u8, _>` extracts bytes.
+//!
+//! Digits are numbered from the least significant end with
+//! [`Order::Lsb`], or from the most significant end with [`Order::Msb`].
+//! If `bits` doesn't divide the width of the key, the most significant
+//! digit is the short one.
+//!
+//! ```rust
+//! use truncate_integer::radix::{digit, digit_bits, digits, Order};
+//!
+//! let key = 0x1234_5678u32;
+//! assert_eq!(digit::<u8, _>(key, 0, Order::Lsb), 0x78);
+//! assert_eq!(digit::<u8, _>(key, 0, Order::Msb), 0x12);
+//! assert_eq!(digit_bits::<u8, _>(key, 4, 1, Order::Msb), 0x2);
+//!
+//! let keys = [0x0102u16, 0x0304, 0x0506];
+//! let mut column = [0u8; 3];
+//! digits(&keys, 1, Order::Lsb, &mut column);
+//! assert_eq!(column, [0x01, 0x03, 0x05]);
+//! ```
+
+use crate::wide::{Primitive, Wide};
+use core::mem::size_of;
+
+/// Which end of the integer digit indices count from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// Digit 0 is the least significant.
+    Lsb,
+    /// Digit 0 is the most significant.
+    Msb,
+}
+
+#[inline]
+fn width<T>() -> u32 {
+    (size_of::<T>() * 8) as u32
+}
+
+/// The number of `bits`-bit digits in a `V`.
+///
+/// # Panics
+///
+/// Panics if `bits` is zero.
+#[track_caller]
+#[inline]
+pub fn digit_count<V>(bits: u32) -> u32 {
+    assert!(bits != 0, "invalid digit width");
+    (width::<V>() + bits - 1) / bits
+}
+
+/// Extract digit `k` of `value`, where digits are as wide as `T`.
+///
+/// # Panics
+///
+/// Panics if `k` is out of range.
+#[track_caller]
+#[inline]
+pub fn digit<T: Primitive, V: Primitive>(value: V, k: u32, order: Order) -> T {
+    digit_bits(value, width::<T>(), k, order)
+}
+
+/// Extract digit `k` of `value`, where digits are `bits` bits wide.
+///
+/// # Panics
+///
+/// Panics if `bits` is zero or wider than `T`, or if `k` is out of range.
+#[track_caller]
+#[inline]
+pub fn digit_bits<T: Primitive, V: Primitive>(value: V, bits: u32, k: u32, order: Order) -> T {
+    assert!(bits <= width::<T>(), "invalid digit width");
+    let count = digit_count::<V>(bits);
+    assert!(k < count, "digit index out of range");
+    let index = match order {
+        Order::Lsb => k,
+        Order::Msb => count - 1 - k,
+    };
+    // Keep only the key's own bits, not its sign extension.
+    let key = value.widen().to_bits() & (u128::MAX >> (128 - width::<V>()));
+    let digit = (key >> (index * bits)) & (u128::MAX >> (128 - bits));
+    T::from_wide_wrapping(Wide::NonNegative(digit))
+}
+
+/// Extract digit `k` of every value in `src` into `dst`, where digits
+/// are as wide as `T`.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths, or if `k` is out of
+/// range.
+#[track_caller]
+pub fn digits<T: Primitive, V: Primitive>(src: &[V], k: u32, order: Order, dst: &mut [T]) {
+    digits_bits(src, width::<T>(), k, order, dst)
+}
+
+/// Extract digit `k` of every value in `src` into `dst`, where digits
+/// are `bits` bits wide.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths, if `bits` is zero
+/// or wider than `T`, or if `k` is out of range.
+#[track_caller]
+pub fn digits_bits<T: Primitive, V: Primitive>(
+    src: &[V],
+    bits: u32,
+    k: u32,
+    order: Order,
+    dst: &mut [T],
+) {
+    assert_eq!(src.len(), dst.len(), "digit buffer length mismatch");
+    for (&value, out) in src.iter().zip(dst) {
+        *out = digit_bits(value, bits, k, order);
+    }
+}
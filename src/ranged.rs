@@ -0,0 +1,256 @@
+//! Integers that track the range of values they might hold.
+//!
+//! A [`Ranged<T>`] is a value along with an interval that it's known
+//! to be in. Arithmetic on `Ranged` values updates the interval, so
+//! at the end of a computation the interval says what the result
+//! could possibly be, regardless of the inputs.
+//!
+//! If the interval fits in a smaller type, the result can be
+//! converted with [`into_infallible`](Ranged::into_infallible). That
+//! check depends only on the operations and initial bounds, not on the
+//! data, so it either always passes or always fails.
+//!
+//! ```rust
+//! use truncate_integer::ranged::Ranged;
+//!
+//! // Average two 8-bit pixel values.
+//! let a = Ranged::full(200u8).widen::<u16>();
+//! let b = Ranged::full(100u8).widen::<u16>();
+//! let avg = (a + b) >> 1;
+//! assert_eq!((avg.min(), avg.max()), (0, 255));
+//! let avg: u8 = avg.into_infallible();
+//! assert_eq!(avg, 150);
+//! ```
+//!
+//! An operation panics if the interval would overflow `T`, even if the
+//! value itself doesn't, because some other input could have.
+
+use crate::wide::{Primitive, Wide, Widen};
+use core::ops::{Add, BitAnd, Mul, Shl, Shr, Sub};
+
+/// An integer with a known minimum and maximum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ranged<T> {
+    value: T,
+    min: T,
+    max: T,
+}
+
+impl<T: Primitive + Ord> Ranged<T> {
+    /// Create a value known to be in `min..=max`.
+    ///
+    /// Returns `None` if `value` isn't in that range.
+    pub fn new(value: T, min: T, max: T) -> Option<Self> {
+        if min <= value && value <= max {
+            Some(Ranged { value, min, max })
+        } else {
+            None
+        }
+    }
+
+    /// Create a value that could be anything in `T`.
+    pub fn full(value: T) -> Self {
+        Ranged {
+            value,
+            min: T::from_wide_wrapping(T::MIN_WIDE),
+            max: T::from_wide_wrapping(T::MAX_WIDE),
+        }
+    }
+
+    /// Create a constant, whose range is just its value.
+    pub fn exact(value: T) -> Self {
+        Ranged {
+            value,
+            min: value,
+            max: value,
+        }
+    }
+
+    /// Get the value.
+    pub fn get(self) -> T {
+        self.value
+    }
+
+    /// The smallest value this could be.
+    pub fn min(self) -> T {
+        self.min
+    }
+
+    /// The largest value this could be.
+    pub fn max(self) -> T {
+        self.max
+    }
+
+    /// Clamp the value into `min..=max`, and narrow the range to match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    #[track_caller]
+    pub fn clamp(self, min: T, max: T) -> Self {
+        assert!(min <= max, "Ranged::clamp min > max");
+        Ranged {
+            value: self.value.clamp(min, max),
+            min: self.min.clamp(min, max),
+            max: self.max.clamp(min, max),
+        }
+    }
+
+    /// Convert into a larger type, keeping the range.
+    pub fn widen<U>(self) -> Ranged<U>
+    where
+        U: From<T>,
+    {
+        Ranged {
+            value: self.value.into(),
+            min: self.min.into(),
+            max: self.max.into(),
+        }
+    }
+
+    /// Returns `true` if every value in the range fits in `Dest`.
+    pub fn fits<Dest: Primitive>(self) -> bool {
+        self.min.widen() >= Dest::MIN_WIDE && self.max.widen() <= Dest::MAX_WIDE
+    }
+
+    /// Convert into `Dest`, which must be able to hold every value in
+    /// the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range doesn't fit in `Dest`, even if the value does.
+    #[track_caller]
+    pub fn into_infallible<Dest: Primitive>(self) -> Dest {
+        if !self.fits::<Dest>() {
+            panic!("Ranged interval does not fit");
+        }
+        Dest::from_wide_wrapping(self.value.widen())
+    }
+}
+
+#[track_caller]
+fn bound<T>(value: Option<T>) -> T {
+    match value {
+        Some(value) => value,
+        None => panic!("Ranged overflow"),
+    }
+}
+
+fn is_negative<T: Widen>(value: T) -> bool {
+    matches!(value.widen(), Wide::Negative(_))
+}
+
+macro_rules! make_ranged {
+    ($($Type:ty),*) => {
+        $(
+            impl Add for Ranged<$Type> {
+                type Output = Self;
+
+                #[track_caller]
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    Ranged {
+                        value: self.value + rhs.value,
+                        min: bound(self.min.checked_add(rhs.min)),
+                        max: bound(self.max.checked_add(rhs.max)),
+                    }
+                }
+            }
+
+            impl Sub for Ranged<$Type> {
+                type Output = Self;
+
+                #[track_caller]
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    Ranged {
+                        value: self.value - rhs.value,
+                        min: bound(self.min.checked_sub(rhs.max)),
+                        max: bound(self.max.checked_sub(rhs.min)),
+                    }
+                }
+            }
+
+            impl Mul for Ranged<$Type> {
+                type Output = Self;
+
+                #[track_caller]
+                #[inline]
+                fn mul(self, rhs: Self) -> Self {
+                    let products = [
+                        bound(self.min.checked_mul(rhs.min)),
+                        bound(self.min.checked_mul(rhs.max)),
+                        bound(self.max.checked_mul(rhs.min)),
+                        bound(self.max.checked_mul(rhs.max)),
+                    ];
+                    Ranged {
+                        value: self.value * rhs.value,
+                        min: products.iter().copied().fold(<$Type>::MAX, Ord::min),
+                        max: products.iter().copied().fold(<$Type>::MIN, Ord::max),
+                    }
+                }
+            }
+
+            impl Shl<u32> for Ranged<$Type> {
+                type Output = Self;
+
+                #[track_caller]
+                #[inline]
+                fn shl(self, rhs: u32) -> Self {
+                    // Shifting back recovers the bound only if no bits,
+                    // including the sign, were shifted out.
+                    let shl = |x: $Type| x.checked_shl(rhs).filter(|y| y.checked_shr(rhs) == Some(x));
+                    Ranged {
+                        value: self.value << rhs,
+                        min: bound(shl(self.min)),
+                        max: bound(shl(self.max)),
+                    }
+                }
+            }
+
+            impl Shr<u32> for Ranged<$Type> {
+                type Output = Self;
+
+                #[track_caller]
+                #[inline]
+                fn shr(self, rhs: u32) -> Self {
+                    Ranged {
+                        value: self.value >> rhs,
+                        min: self.min >> rhs,
+                        max: self.max >> rhs,
+                    }
+                }
+            }
+
+            impl BitAnd<$Type> for Ranged<$Type> {
+                type Output = Self;
+
+                #[inline]
+                fn bitand(self, mask: $Type) -> Self {
+                    // A non-negative value masked can only get smaller, but
+                    // a negative value masked can become any non-negative
+                    // value in the mask.
+                    let min = if is_negative(self.min) && is_negative(mask) {
+                        <$Type>::MIN
+                    } else {
+                        0
+                    };
+                    let max = match (is_negative(self.max), is_negative(mask)) {
+                        (false, false) if is_negative(self.min) => mask,
+                        (false, false) => self.max.min(mask),
+                        (false, true) => self.max,
+                        (true, false) => mask,
+                        (true, true) => self.max.min(mask),
+                    };
+                    Ranged {
+                        value: self.value & mask,
+                        min,
+                        max,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+make_ranged!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
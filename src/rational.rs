@@ -0,0 +1,124 @@
+//! Rounding [`num-rational`](https://docs.rs/num-rational) ratios into
+//! integers.
+//!
+//! [`RoundTruncate`] rounds a `Ratio` to an integer with an explicit
+//! [`Rounding`] mode, then narrows it into the destination type with
+//! one of the usual truncation policies.
+//!
+//! ```rust
+//! use num_rational::Ratio;
+//! use truncate_integer::rational::{RoundTruncate, Rounding};
+//! use truncate_integer::TruncateError;
+//!
+//! let rate = Ratio::new(1000i64, 3);
+//! assert_eq!(rate.round_try::<u8>(Rounding::Floor), Err(TruncateError::Overflow));
+//! assert_eq!(rate.round_try::<u16>(Rounding::Nearest), Ok(333));
+//! assert_eq!(rate.round_try::<u16>(Rounding::Ceil), Ok(334));
+//! assert_eq!(rate.round_shrink::<u8>(Rounding::Nearest), 255);
+//! ```
+
+use crate::wide::{Primitive, Wide, Widen};
+use crate::TruncateError;
+use ::num_rational::{BigRational, Ratio};
+use core::convert::TryFrom;
+
+/// How to round a ratio to an integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero.
+    Trunc,
+    /// Round to the nearest integer, with halfway cases away from zero.
+    Nearest,
+}
+
+mod private {
+    use super::*;
+
+    /// A ratio that can be rounded to a widened integer.
+    pub trait RoundWide {
+        fn round_wide(&self, rounding: Rounding) -> Result<Wide, TruncateError>;
+    }
+}
+
+use private::RoundWide;
+
+macro_rules! rounded {
+    ($ratio:expr, $rounding:expr) => {
+        match $rounding {
+            Rounding::Floor => $ratio.floor(),
+            Rounding::Ceil => $ratio.ceil(),
+            Rounding::Trunc => $ratio.trunc(),
+            Rounding::Nearest => $ratio.round(),
+        }
+        .to_integer()
+    };
+}
+
+macro_rules! make_round_wide {
+    ($($Type:ty),*) => {
+        $(
+            impl RoundWide for Ratio<$Type> {
+                #[inline]
+                fn round_wide(&self, rounding: Rounding) -> Result<Wide, TruncateError> {
+                    Ok(rounded!(self, rounding).widen())
+                }
+            }
+        )*
+    };
+}
+
+make_round_wide!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl RoundWide for BigRational {
+    fn round_wide(&self, rounding: Rounding) -> Result<Wide, TruncateError> {
+        let value = rounded!(self, rounding);
+        if let Ok(x) = i128::try_from(&value) {
+            Ok(x.widen())
+        } else if let Ok(x) = u128::try_from(&value) {
+            Ok(Wide::NonNegative(x))
+        } else {
+            Err(TruncateError::for_value(&value))
+        }
+    }
+}
+
+/// Round a ratio to an integer, then narrow it.
+pub trait RoundTruncate {
+    /// Round, then narrow with checked truncation.
+    fn round_try<Dest: Primitive>(&self, rounding: Rounding) -> Result<Dest, TruncateError>;
+
+    /// Round, then narrow with saturating truncation.
+    fn round_shrink<Dest: Primitive>(&self, rounding: Rounding) -> Dest;
+
+    /// Round, then narrow with panicking truncation.
+    #[track_caller]
+    fn round_chop<Dest: Primitive>(&self, rounding: Rounding) -> Dest {
+        match self.round_try(rounding) {
+            Ok(value) => value,
+            Err(_) => panic!("chop overflow"),
+        }
+    }
+}
+
+impl<R: RoundWide> RoundTruncate for R {
+    fn round_try<Dest: Primitive>(&self, rounding: Rounding) -> Result<Dest, TruncateError> {
+        let wide = self.round_wide(rounding)?;
+        Dest::from_wide_checked(wide).ok_or(if wide < Dest::MIN_WIDE {
+            TruncateError::Underflow
+        } else {
+            TruncateError::Overflow
+        })
+    }
+
+    fn round_shrink<Dest: Primitive>(&self, rounding: Rounding) -> Dest {
+        match self.round_wide(rounding) {
+            Ok(wide) => Dest::from_wide_saturating(wide),
+            Err(TruncateError::Underflow) => Dest::from_wide_wrapping(Dest::MIN_WIDE),
+            Err(_) => Dest::from_wide_wrapping(Dest::MAX_WIDE),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Emulated CPU registers of any width.
+//!
+//! A [`RegValue<N>`](RegValue) holds the contents of an `N`-bit register.
+//! Writing a wider value keeps only its low `N` bits, the way the
+//! emulated hardware would. The arithmetic helpers wrap to `N` bits, and
+//! also return the [`Flags`] an emulated ALU would set.
+//!
+//! ```rust
+//! use truncate_integer::reg::RegValue;
+//!
+//! // An 8-bit accumulator.
+//! let a = RegValue::<8>::new(0xF0u32);
+//! let (sum, flags) = a.overflowing_add(RegValue::new(0x20u32));
+//! assert_eq!(sum.bits(), 0x10);
+//! assert!(flags.carry);
+//! assert!(!flags.overflow);
+//!
+//! let (sum, flags) = RegValue::<8>::new(0x7Fu32).overflowing_add(RegValue::new(1u32));
+//! assert_eq!(sum.signed().get(), -128);
+//! assert!(flags.overflow && flags.negative);
+//!
+//! // Writes wrap to the register width.
+//! let mut r = RegValue::<12>::default();
+//! r.set(-1i32);
+//! assert_eq!(r.bits(), 0xFFF);
+//! ```
+
+use crate::bitsint::{Bits, Storage, I, U};
+use crate::wide::{FromWide, Wide, Widen};
+use core::fmt;
+
+/// The status flags set by an arithmetic operation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Flags {
+    /// An unsigned carry out of the top bit, or a borrow for subtraction.
+    pub carry: bool,
+    /// A signed overflow: the result has the wrong sign.
+    pub overflow: bool,
+    /// The result is zero.
+    pub zero: bool,
+    /// The top bit of the result is set.
+    pub negative: bool,
+}
+
+/// The contents of an `N`-bit register.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RegValue<const N: u32>(U<N>)
+where
+    Bits<N>: Storage;
+
+impl<const N: u32> RegValue<N>
+where
+    Bits<N>: Storage,
+{
+    const MASK: u128 = u128::MAX >> (128 - N);
+    const SIGN: u128 = 1 << (N - 1);
+
+    /// Create a register value, keeping only the low `N` bits of `value`.
+    #[inline]
+    pub fn new<T: Widen>(value: T) -> Self {
+        RegValue(U::from_wide_wrapping(value.widen()))
+    }
+
+    #[inline]
+    fn from_bits(bits: u128) -> Self {
+        RegValue(U::from_wide_wrapping(Wide::NonNegative(bits)))
+    }
+
+    /// Write a new value, keeping only the low `N` bits of `value`.
+    #[inline]
+    pub fn set<T: Widen>(&mut self, value: T) {
+        *self = Self::new(value);
+    }
+
+    /// The value, as an unsigned integer.
+    #[inline]
+    pub fn get(self) -> U<N> {
+        self.0
+    }
+
+    /// The value, as an unsigned `u128`.
+    #[inline]
+    pub fn bits(self) -> u128 {
+        self.0.widen().to_bits()
+    }
+
+    /// The value, as a two's complement signed integer.
+    #[inline]
+    pub fn signed(self) -> I<N> {
+        I::from_wide_wrapping(Wide::NonNegative(self.bits()))
+    }
+
+    fn flags(result: u128, carry: bool, overflow: bool) -> Flags {
+        Flags {
+            carry,
+            overflow,
+            zero: result == 0,
+            negative: result & Self::SIGN != 0,
+        }
+    }
+
+    /// Add, wrapping to `N` bits.
+    #[inline]
+    pub fn overflowing_add(self, rhs: Self) -> (Self, Flags) {
+        self.carrying_add(rhs, false)
+    }
+
+    /// Add, plus one if `carry` is set, wrapping to `N` bits.
+    pub fn carrying_add(self, rhs: Self, carry: bool) -> (Self, Flags) {
+        let (a, b) = (self.bits(), rhs.bits());
+        let (sum, c1) = a.overflowing_add(b);
+        let (sum, c2) = sum.overflowing_add(carry as u128);
+        // A full-width register carries out of the u128; a narrower one
+        // carries into the bits above it.
+        let carry = c1 || c2 || sum > Self::MASK;
+        let result = sum & Self::MASK;
+        let overflow = (a ^ result) & (b ^ result) & Self::SIGN != 0;
+        (
+            Self::from_bits(result),
+            Self::flags(result, carry, overflow),
+        )
+    }
+
+    /// Subtract, wrapping to `N` bits. The carry flag is set if there
+    /// was a borrow.
+    #[inline]
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, Flags) {
+        self.borrowing_sub(rhs, false)
+    }
+
+    /// Subtract, minus one if `borrow` is set, wrapping to `N` bits. The
+    /// carry flag is set if there was a borrow.
+    pub fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, Flags) {
+        let (a, b) = (self.bits(), rhs.bits());
+        let (diff, b1) = a.overflowing_sub(b);
+        let (diff, b2) = diff.overflowing_sub(borrow as u128);
+        let result = diff & Self::MASK;
+        let overflow = (a ^ b) & (a ^ result) & Self::SIGN != 0;
+        (
+            Self::from_bits(result),
+            Self::flags(result, b1 || b2, overflow),
+        )
+    }
+
+    /// Multiply, wrapping to `N` bits.
+    ///
+    /// The carry and overflow flags are both set if the unsigned product
+    /// doesn't fit in `N` bits.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, Flags) {
+        let (product, wrapped) = self.bits().overflowing_mul(rhs.bits());
+        let lost = wrapped || product > Self::MASK;
+        let result = product & Self::MASK;
+        (Self::from_bits(result), Self::flags(result, lost, lost))
+    }
+}
+
+impl<const N: u32> fmt::Display for RegValue<N>
+where
+    Bits<N>: Storage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: u32> fmt::LowerHex for RegValue<N>
+where
+    Bits<N>: Storage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.bits(), f)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Chop, TruncateError, TryTruncate};
+use core::convert::TryFrom;
+
+/// A type whose values are represented by an integer, such as a fieldless enum.
+///
+/// This allows [`TruncateInto`] to pick the integer type to narrow into
+/// before converting it with `TryFrom`.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use truncate_integer::IntegerRepr;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Opcode {
+///     Nop = 0,
+///     Halt = 1,
+/// }
+///
+/// impl TryFrom<u8> for Opcode {
+///     type Error = ();
+///
+///     fn try_from(x: u8) -> Result<Self, ()> {
+///         match x {
+///             0 => Ok(Opcode::Nop),
+///             1 => Ok(Opcode::Halt),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// impl IntegerRepr for Opcode {
+///     type Repr = u8;
+/// }
+/// ```
+pub trait IntegerRepr: TryFrom<<Self as IntegerRepr>::Repr> {
+    /// The integer type that represents this type.
+    type Repr;
+}
+
+/// Truncate an integer directly into an [`IntegerRepr`] type.
+///
+/// This combines the integer truncation and the `TryFrom` conversion
+/// into one call.
+///
+/// ```rust
+/// # use std::convert::TryFrom;
+/// # use truncate_integer::IntegerRepr;
+/// # #[derive(Debug, PartialEq)]
+/// # enum Opcode { Nop = 0, Halt = 1 }
+/// # impl TryFrom<u8> for Opcode {
+/// #     type Error = ();
+/// #     fn try_from(x: u8) -> Result<Self, ()> {
+/// #         match x { 0 => Ok(Opcode::Nop), 1 => Ok(Opcode::Halt), _ => Err(()) }
+/// #     }
+/// # }
+/// # impl IntegerRepr for Opcode { type Repr = u8; }
+/// use truncate_integer::{TruncateError, TruncateInto};
+///
+/// assert_eq!(1u32.chop_into::<Opcode>(), Opcode::Halt);
+/// assert_eq!(2u32.try_truncate_into::<Opcode>(), Err(TruncateError::Invalid));
+/// assert_eq!(256u32.try_truncate_into::<Opcode>(), Err(TruncateError::Overflow));
+/// ```
+pub trait TruncateInto: Sized {
+    /// Try to truncate an integer into an [`IntegerRepr`] type.
+    ///
+    /// If the value doesn't fit into `T::Repr`, or isn't a valid `T`,
+    /// return an error.
+    fn try_truncate_into<T>(self) -> Result<T, TruncateError>
+    where
+        T: IntegerRepr,
+        Self: TryTruncate<T::Repr> + Copy + PartialOrd + Default,
+    {
+        match self.try_truncate() {
+            Some(repr) => T::try_from(repr).map_err(|_| TruncateError::Invalid),
+            None => Err(TruncateError::for_value(&self)),
+        }
+    }
+
+    /// Perform panicking truncation into an [`IntegerRepr`] type.
+    ///
+    /// If the value doesn't fit into `T::Repr`, or isn't a valid `T`, panic.
+    #[track_caller]
+    fn chop_into<T>(self) -> T
+    where
+        T: IntegerRepr,
+        Self: Chop<T::Repr>,
+    {
+        match T::try_from(self.chop()) {
+            Ok(val) => val,
+            Err(_) => panic!("chop invalid value"),
+        }
+    }
+}
+
+impl<T> TruncateInto for T {}
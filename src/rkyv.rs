@@ -0,0 +1,112 @@
+//! Wrappers for the [`rkyv`](https://docs.rs/rkyv) crate.
+//!
+//! These are used with `#[rkyv(with = ...)]` to archive a field in a
+//! narrower type than it has in memory. Deserializing widens it again.
+//!
+//! - [`ShrinkAs<T>`] archives the field as a `T` using [`Shrink`], so
+//!   out-of-range values are saturated.
+//! - [`TryTruncateAs<T>`] archives the field as a `T` using
+//!   [`TryTruncate`], so serialization fails if a value doesn't fit.
+//!
+//! ```rust
+//! use rkyv::rancor::Error;
+//! use rkyv::{Archive, Deserialize, Serialize};
+//! use truncate_integer::rkyv::{ShrinkAs, TryTruncateAs};
+//!
+//! #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+//! struct Record {
+//!     #[rkyv(with = ShrinkAs<u16>)]
+//!     len: u64,
+//!     #[rkyv(with = TryTruncateAs<u8>)]
+//!     id: u32,
+//! }
+//!
+//! let record = Record { len: 100_000, id: 7 };
+//! let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+//! let archived = rkyv::access::<ArchivedRecord, Error>(&bytes).unwrap();
+//! assert_eq!(archived.len, 65535);
+//! let record: Record = rkyv::deserialize::<_, Error>(archived).unwrap();
+//! assert_eq!(record, Record { len: 65535, id: 7 });
+//!
+//! let bad = Record { len: 0, id: 256 };
+//! assert!(rkyv::to_bytes::<Error>(&bad).is_err());
+//! ```
+
+use crate::{Shrink, TruncateError, TryTruncate};
+use ::rkyv::rancor::{Fallible, Source};
+use ::rkyv::with::{ArchiveWith, DeserializeWith, SerializeWith};
+use ::rkyv::{Archive, Deserialize, Place, Serialize};
+use core::marker::PhantomData;
+
+/// Archive a field as a `T`, saturating values that don't fit.
+#[derive(Debug)]
+pub struct ShrinkAs<T>(PhantomData<T>);
+
+/// Archive a field as a `T`, failing if a value doesn't fit.
+#[derive(Debug)]
+pub struct TryTruncateAs<T>(PhantomData<T>);
+
+macro_rules! make_rkyv_with {
+    ($Wrapper:ident) => {
+        impl<F, T: Archive> ArchiveWith<F> for $Wrapper<T> {
+            type Archived = T::Archived;
+            // The narrowed value is computed during serialization, so
+            // that `TryTruncateAs` can report an error.
+            type Resolver = (T, T::Resolver);
+
+            #[inline]
+            fn resolve_with(_: &F, resolver: Self::Resolver, out: Place<Self::Archived>) {
+                let (value, resolver) = resolver;
+                value.resolve(resolver, out);
+            }
+        }
+
+        impl<F, T, D> DeserializeWith<T::Archived, F, D> for $Wrapper<T>
+        where
+            F: From<T>,
+            T: Archive,
+            T::Archived: Deserialize<T, D>,
+            D: Fallible + ?Sized,
+        {
+            #[inline]
+            fn deserialize_with(field: &T::Archived, deserializer: &mut D) -> Result<F, D::Error> {
+                field.deserialize(deserializer).map(F::from)
+            }
+        }
+    };
+}
+
+make_rkyv_with!(ShrinkAs);
+make_rkyv_with!(TryTruncateAs);
+
+impl<F, T, S> SerializeWith<F, S> for ShrinkAs<T>
+where
+    F: Shrink<T> + Copy,
+    T: Serialize<S>,
+    S: Fallible + ?Sized,
+{
+    #[inline]
+    fn serialize_with(field: &F, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let value: T = field.shrink();
+        let resolver = value.serialize(serializer)?;
+        Ok((value, resolver))
+    }
+}
+
+impl<F, T, S> SerializeWith<F, S> for TryTruncateAs<T>
+where
+    F: TryTruncate<T> + Copy + PartialOrd + Default,
+    T: Serialize<S>,
+    S: Fallible + ?Sized,
+    S::Error: Source,
+{
+    #[inline]
+    fn serialize_with(field: &F, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let value: T = match field.try_truncate() {
+            Some(value) => value,
+            None => return Err(S::Error::new(TruncateError::for_value(field))),
+        };
+        let resolver = value.serialize(serializer)?;
+        Ok((value, resolver))
+    }
+}
@@ -0,0 +1,117 @@
+//! Narrowing [`rug`](https://docs.rs/rug) big integers.
+//!
+//! The truncation traits are implemented from [`Integer`], by value or
+//! by reference, into every primitive integer type:
+//!
+//! - [`TryTruncate`] returns `None` if the value doesn't fit.
+//! - [`Shrink`] saturates.
+//! - [`TruncateUnchecked`] wraps, keeping the low bits of the two's
+//!   complement value, like an `as` cast.
+//! - [`Chop`] panics if the value doesn't fit.
+//!
+//! ```rust
+//! use rug::Integer;
+//! use truncate_integer::{Shrink, TruncateUnchecked, TryTruncate};
+//!
+//! let big = Integer::from(1) << 100u32;
+//! let x: Option<u64> = (&big).try_truncate();
+//! assert_eq!(x, None);
+//! let x: i32 = (&big).shrink();
+//! assert_eq!(x, i32::MAX);
+//!
+//! let small = Integer::from(-300);
+//! let x: u8 = (&small).truncate_unchecked();
+//! assert_eq!(x, 212);
+//! let x: Option<i16> = small.try_truncate();
+//! assert_eq!(x, Some(-300));
+//! ```
+
+use crate::wide::{FromWide, Wide, Widen};
+use crate::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+use ::rug::Integer;
+use core::cmp::Ordering;
+
+/// Widen `value`, if it fits in 128 bits.
+fn to_wide(value: &Integer) -> Option<Wide> {
+    match value.to_u128() {
+        Some(x) => Some(Wide::NonNegative(x)),
+        None => value.to_i128().map(Widen::widen),
+    }
+}
+
+/// Widen `value`, clamping it into the range of 128-bit integers.
+fn to_wide_saturating(value: &Integer) -> Wide {
+    to_wide(value).unwrap_or(match value.cmp0() {
+        Ordering::Less => Wide::Negative(i128::MIN),
+        _ => Wide::NonNegative(u128::MAX),
+    })
+}
+
+macro_rules! make_rug {
+    ($($Dest:ty),*) => {
+        $(
+            impl TryTruncate<$Dest> for &Integer {
+                #[inline]
+                fn try_truncate(self) -> Option<$Dest> {
+                    to_wide(self).and_then(<$Dest>::from_wide_checked)
+                }
+            }
+
+            impl Chop<$Dest> for &Integer {
+                #[track_caller]
+                #[inline]
+                fn chop(self) -> $Dest {
+                    match TryTruncate::<$Dest>::try_truncate(self) {
+                        Some(val) => val,
+                        None => panic!("chop overflow"),
+                    }
+                }
+            }
+
+            impl Shrink<$Dest> for &Integer {
+                #[inline]
+                fn shrink(self) -> $Dest {
+                    <$Dest>::from_wide_saturating(to_wide_saturating(self))
+                }
+            }
+
+            impl TruncateUnchecked<$Dest> for &Integer {
+                #[inline]
+                fn truncate_unchecked(self) -> $Dest {
+                    <$Dest>::from_wide_wrapping(Wide::NonNegative(self.to_u128_wrapping()))
+                }
+            }
+
+            impl TryTruncate<$Dest> for Integer {
+                #[inline]
+                fn try_truncate(self) -> Option<$Dest> {
+                    (&self).try_truncate()
+                }
+            }
+
+            impl Chop<$Dest> for Integer {
+                #[track_caller]
+                #[inline]
+                fn chop(self) -> $Dest {
+                    (&self).chop()
+                }
+            }
+
+            impl Shrink<$Dest> for Integer {
+                #[inline]
+                fn shrink(self) -> $Dest {
+                    (&self).shrink()
+                }
+            }
+
+            impl TruncateUnchecked<$Dest> for Integer {
+                #[inline]
+                fn truncate_unchecked(self) -> $Dest {
+                    (&self).truncate_unchecked()
+                }
+            }
+        )*
+    };
+}
+
+make_rug!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,117 @@
+//! Narrowing into fields with a reserved "overflowed" value.
+//!
+//! Many wire formats reserve one value of a field, often the largest, to
+//! mean "too big to represent". A [`WithSentinel<T>`] is a value of `T`
+//! along with the sentinel reserved in it. It can only be built from a
+//! value that isn't the sentinel, so a valid value is never mistaken for
+//! an overflow.
+//!
+//! [`shrink_with_sentinel`](ShrinkWithSentinel::shrink_with_sentinel)
+//! narrows a value into such a field, storing the sentinel if it doesn't
+//! fit.
+//!
+//! ```rust
+//! use truncate_integer::sentinel::{ShrinkWithSentinel, WithSentinel};
+//!
+//! let x = 200u32.shrink_with_sentinel(u8::MAX);
+//! assert_eq!(x.get(), Some(200));
+//! let x = 300u32.shrink_with_sentinel(u8::MAX);
+//! assert_eq!(x.get(), None);
+//! assert_eq!(x.raw(), 255);
+//!
+//! // 255 fits in a u8, but it's reserved.
+//! assert_eq!(WithSentinel::new(255u8, u8::MAX), None);
+//! assert!(255u32.shrink_with_sentinel(u8::MAX).is_overflowed());
+//! ```
+
+use crate::TryTruncate;
+
+/// A value of `T` in which one value is reserved to mean "overflowed".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WithSentinel<T> {
+    raw: T,
+    sentinel: T,
+}
+
+impl<T: Copy + PartialEq> WithSentinel<T> {
+    /// Create a valid value.
+    ///
+    /// Returns `None` if `value` is the sentinel.
+    #[inline]
+    pub fn new(value: T, sentinel: T) -> Option<Self> {
+        if value == sentinel {
+            None
+        } else {
+            Some(WithSentinel {
+                raw: value,
+                sentinel,
+            })
+        }
+    }
+
+    /// Create a value that means "overflowed".
+    #[inline]
+    pub fn overflowed(sentinel: T) -> Self {
+        WithSentinel {
+            raw: sentinel,
+            sentinel,
+        }
+    }
+
+    /// Interpret a value read from a field with this sentinel.
+    #[inline]
+    pub fn from_raw(raw: T, sentinel: T) -> Self {
+        WithSentinel { raw, sentinel }
+    }
+
+    /// Get the value, or `None` if it overflowed.
+    #[inline]
+    pub fn get(self) -> Option<T> {
+        if self.is_overflowed() {
+            None
+        } else {
+            Some(self.raw)
+        }
+    }
+
+    /// Get the value to store in the field, which is the sentinel if it
+    /// overflowed.
+    #[inline]
+    pub fn raw(self) -> T {
+        self.raw
+    }
+
+    /// Get the sentinel.
+    #[inline]
+    pub fn sentinel(self) -> T {
+        self.sentinel
+    }
+
+    /// Returns `true` if this is the sentinel.
+    #[inline]
+    pub fn is_overflowed(self) -> bool {
+        self.raw == self.sentinel
+    }
+}
+
+/// Narrow an integer into a field with a reserved sentinel.
+pub trait ShrinkWithSentinel<T> {
+    /// Narrow into `T`, or return the sentinel if the value doesn't fit.
+    ///
+    /// A value that fits but is equal to the sentinel can't be stored
+    /// either, so it also becomes the sentinel.
+    fn shrink_with_sentinel(self, sentinel: T) -> WithSentinel<T>;
+}
+
+impl<Source, T> ShrinkWithSentinel<T> for Source
+where
+    Source: TryTruncate<T>,
+    T: Copy + PartialEq,
+{
+    #[inline]
+    fn shrink_with_sentinel(self, sentinel: T) -> WithSentinel<T> {
+        self.try_truncate()
+            .and_then(|value| WithSentinel::new(value, sentinel))
+            .unwrap_or_else(|| WithSentinel::overflowed(sentinel))
+    }
+}
@@ -0,0 +1,123 @@
+//! `serialize_with` helpers for the [`serde`](https://docs.rs/serde) crate.
+//!
+//! These narrow a wide in-memory value as it's serialized, so that it's
+//! written to the output as a smaller integer type.
+//!
+//! - `shrink_to_*` functions use [`Shrink`], so out-of-range values are
+//!   saturated.
+//! - `try_to_*` functions use [`TryTruncate`], so out-of-range values
+//!   cause a serialization error.
+//!
+//! ```rust
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Stats {
+//!     #[serde(serialize_with = "truncate_integer::serde::shrink_to_u16")]
+//!     count: u64,
+//!     #[serde(serialize_with = "truncate_integer::serde::try_to_i8")]
+//!     delta: i32,
+//! }
+//!
+//! let json = serde_json::to_string(&Stats { count: 100_000, delta: -3 }).unwrap();
+//! assert_eq!(json, r#"{"count":65535,"delta":-3}"#);
+//!
+//! let err = serde_json::to_string(&Stats { count: 0, delta: 200 }).unwrap_err();
+//! assert_eq!(err.to_string(), "integer overflow");
+//! ```
+//!
+//! [`TruncateError`], [`IndexError`], [`LenError`], [`NibbleError`] and
+//! [`IntType`] also implement `Serialize` and `Deserialize`, and
+//! [`NarrowStructError`] and [`WidthReport`] implement `Serialize`, so
+//! that a service can report a failed conversion as structured data
+//! rather than a message:
+//!
+//! ```rust
+//! use truncate_integer::len::LenError;
+//! use truncate_integer::TruncateError;
+//!
+//! let json = serde_json::to_string(&TruncateError::Overflow).unwrap();
+//! assert_eq!(json, r#""Overflow""#);
+//!
+//! let json = serde_json::to_string(&LenError::new(70000, 65535)).unwrap();
+//! assert_eq!(json, r#"{"len":70000,"limit":65535}"#);
+//! let err: LenError = serde_json::from_str(&json).unwrap();
+//! assert_eq!(err, LenError::new(70000, 65535));
+//! ```
+
+use crate::analyze::WidthReport;
+use crate::{NarrowStructError, Shrink, TruncateError, TryTruncate};
+use ::serde::ser::{Error, SerializeStruct};
+use ::serde::{Serialize, Serializer};
+
+macro_rules! make_serialize_with {
+    ($shrink:ident, $try:ident, $Dest:ty) => {
+        #[doc = concat!("Serialize a value as a `", stringify!($Dest), "`, saturating if it doesn't fit.")]
+        pub fn $shrink<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Shrink<$Dest> + Copy,
+            S: Serializer,
+        {
+            let value: $Dest = value.shrink();
+            value.serialize(serializer)
+        }
+
+        #[doc = concat!("Serialize a value as a `", stringify!($Dest), "`, failing if it doesn't fit.")]
+        pub fn $try<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: TryTruncate<$Dest> + Copy + PartialOrd + Default,
+            S: Serializer,
+        {
+            match value.try_truncate() {
+                Some(narrow) => <$Dest>::serialize(&narrow, serializer),
+                None => Err(S::Error::custom(TruncateError::for_value(value))),
+            }
+        }
+    };
+}
+
+make_serialize_with!(shrink_to_u8, try_to_u8, u8);
+make_serialize_with!(shrink_to_u16, try_to_u16, u16);
+make_serialize_with!(shrink_to_u32, try_to_u32, u32);
+make_serialize_with!(shrink_to_u64, try_to_u64, u64);
+make_serialize_with!(shrink_to_i8, try_to_i8, i8);
+make_serialize_with!(shrink_to_i16, try_to_i16, i16);
+make_serialize_with!(shrink_to_i32, try_to_i32, i32);
+make_serialize_with!(shrink_to_i64, try_to_i64, i64);
+
+impl Serialize for NarrowStructError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Fields<'a>(&'a NarrowStructError);
+
+        impl Serialize for Fields<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.0.fields())
+            }
+        }
+
+        let mut state = serializer.serialize_struct("NarrowStructError", 1)?;
+        state.serialize_field("fields", &Fields(self))?;
+        state.end()
+    }
+}
+
+impl Serialize for WidthReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Failures<'a>(&'a WidthReport);
+
+        impl Serialize for Failures<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_map(self.0.candidates())
+            }
+        }
+
+        let mut state = serializer.serialize_struct("WidthReport", 6)?;
+        state.serialize_field("len", &self.len())?;
+        state.serialize_field("min", &self.min())?;
+        state.serialize_field("max", &self.max())?;
+        state.serialize_field("bits", &self.bits())?;
+        state.serialize_field("narrowest", &self.narrowest())?;
+        state.serialize_field("failures", &Failures(self))?;
+        state.end()
+    }
+}
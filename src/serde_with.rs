@@ -0,0 +1,117 @@
+//! Adapters for the [`serde_with`](https://docs.rs/serde_with) crate.
+//!
+//! These are used with `#[serde_as(as = ...)]`, where the type parameter
+//! is the type on the wire. Unlike `serialize_with` functions, they can
+//! be nested inside containers, e.g. `Vec<ShrinkAs<u8>>`.
+//!
+//! - [`ShrinkAs<T>`] uses [`Shrink`], so out-of-range values are saturated.
+//! - [`ChopAs<T>`] uses [`Chop`], so out-of-range values panic.
+//! - [`TryAs<T>`] uses [`TryTruncate`], so out-of-range values are an
+//!   error.
+//!
+//! Serializing narrows the field into `T`; deserializing reads a `T` and
+//! narrows it into the field's type.
+//!
+//! This module needs both the `serde` and `serde_with` features.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_with::serde_as;
+//! use truncate_integer::serde_with::{ShrinkAs, TryAs};
+//!
+//! #[serde_as]
+//! #[derive(Deserialize)]
+//! struct Levels {
+//!     #[serde_as(as = "Vec<ShrinkAs<i64>>")]
+//!     levels: Vec<u8>,
+//!     #[serde_as(as = "Option<TryAs<u64>>")]
+//!     limit: Option<u16>,
+//! }
+//!
+//! let levels: Levels = serde_json::from_str(r#"{"levels":[1,-1,300],"limit":null}"#).unwrap();
+//! assert_eq!(levels.levels, [1, 0, 255]);
+//!
+//! let err = serde_json::from_str::<Levels>(r#"{"levels":[],"limit":70000}"#).err().unwrap();
+//! assert!(err.to_string().starts_with("integer overflow"));
+//! ```
+
+use crate::{Chop, Shrink, TruncateError, TryTruncate};
+use ::serde::de::{Deserialize, Deserializer, Error as _};
+use ::serde::ser::{Error as _, Serialize, Serializer};
+use ::serde_with::{DeserializeAs, SerializeAs};
+use core::marker::PhantomData;
+
+/// Convert with [`Shrink`], saturating values that don't fit.
+#[derive(Debug)]
+pub struct ShrinkAs<T>(PhantomData<T>);
+
+/// Convert with [`Chop`], panicking if a value doesn't fit.
+#[derive(Debug)]
+pub struct ChopAs<T>(PhantomData<T>);
+
+/// Convert with [`TryTruncate`], failing if a value doesn't fit.
+#[derive(Debug)]
+pub struct TryAs<T>(PhantomData<T>);
+
+impl<Field, Wire> SerializeAs<Field> for ShrinkAs<Wire>
+where
+    Field: Shrink<Wire> + Copy,
+    Wire: Serialize,
+{
+    fn serialize_as<S: Serializer>(source: &Field, serializer: S) -> Result<S::Ok, S::Error> {
+        source.shrink().serialize(serializer)
+    }
+}
+
+impl<'de, Field, Wire> DeserializeAs<'de, Field> for ShrinkAs<Wire>
+where
+    Wire: Deserialize<'de> + Shrink<Field>,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Field, D::Error> {
+        Ok(Wire::deserialize(deserializer)?.shrink())
+    }
+}
+
+impl<Field, Wire> SerializeAs<Field> for ChopAs<Wire>
+where
+    Field: Chop<Wire> + Copy,
+    Wire: Serialize,
+{
+    fn serialize_as<S: Serializer>(source: &Field, serializer: S) -> Result<S::Ok, S::Error> {
+        source.chop().serialize(serializer)
+    }
+}
+
+impl<'de, Field, Wire> DeserializeAs<'de, Field> for ChopAs<Wire>
+where
+    Wire: Deserialize<'de> + Chop<Field>,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Field, D::Error> {
+        Ok(Wire::deserialize(deserializer)?.chop())
+    }
+}
+
+impl<Field, Wire> SerializeAs<Field> for TryAs<Wire>
+where
+    Field: TryTruncate<Wire> + Copy + PartialOrd + Default,
+    Wire: Serialize,
+{
+    fn serialize_as<S: Serializer>(source: &Field, serializer: S) -> Result<S::Ok, S::Error> {
+        match source.try_truncate() {
+            Some(value) => value.serialize(serializer),
+            None => Err(S::Error::custom(TruncateError::for_value(source))),
+        }
+    }
+}
+
+impl<'de, Field, Wire> DeserializeAs<'de, Field> for TryAs<Wire>
+where
+    Wire: Deserialize<'de> + TryTruncate<Field> + Copy + PartialOrd + Default,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Field, D::Error> {
+        let value = Wire::deserialize(deserializer)?;
+        value
+            .try_truncate()
+            .ok_or_else(|| D::Error::custom(TruncateError::for_value(&value)))
+    }
+}
@@ -0,0 +1,123 @@
+//! Serial number arithmetic, as defined by [RFC 1982].
+//!
+//! Counters like DNS zone serials and TCP sequence numbers are stored in
+//! a fixed number of bits, so they eventually wrap around to zero.
+//! Comparing them with `<` gives the wrong answer across the wrap.
+//!
+//! [`Serial`] compares two values by looking at the shortest distance
+//! between them: `a < b` if `b` can be reached by adding less than half
+//! the counter's range to `a`. If the two values are exactly half the
+//! range apart, they can't be compared.
+//!
+//! ```rust
+//! use truncate_integer::serial::Serial;
+//!
+//! assert!(Serial(250u8) < Serial(5u8));
+//! assert_eq!(Serial(250u8).distance(Serial(5u8)), Some(11));
+//! assert_eq!(Serial(0u8).partial_cmp(&Serial(128u8)), None);
+//! ```
+//!
+//! [RFC 1982]: https://www.rfc-editor.org/rfc/rfc1982
+
+use core::cmp::Ordering;
+
+/// A wrapping counter with RFC 1982 comparison.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Serial<T>(pub T);
+
+macro_rules! make_serial {
+    ($Type:ty, $Signed:ty) => {
+        impl Serial<$Type> {
+            /// The largest value that can be added in one step.
+            pub const MAX_ADD: $Type = <$Signed>::MAX as $Type;
+
+            /// Compute the signed distance from `self` to `other`.
+            ///
+            /// Returns `None` if the values are exactly half the range
+            /// apart, since the direction is undefined.
+            pub fn distance(self, other: Self) -> Option<$Signed> {
+                let diff = other.0.wrapping_sub(self.0) as $Signed;
+                if diff == <$Signed>::MIN {
+                    None
+                } else {
+                    Some(diff)
+                }
+            }
+
+            /// Add `n` to the counter, wrapping around if necessary.
+            ///
+            /// RFC 1982 only defines addition for `n <= MAX_ADD`, so larger
+            /// values return `None`.
+            pub fn checked_add(self, n: $Type) -> Option<Self> {
+                if n > Self::MAX_ADD {
+                    None
+                } else {
+                    Some(Serial(self.0.wrapping_add(n)))
+                }
+            }
+
+            /// Add `n` to the counter, wrapping around if necessary.
+            pub fn wrapping_add(self, n: $Type) -> Self {
+                Serial(self.0.wrapping_add(n))
+            }
+        }
+
+        impl PartialOrd for Serial<$Type> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.distance(*other).map(|d| 0.cmp(&d))
+            }
+        }
+    };
+}
+
+make_serial!(u8, i8);
+make_serial!(u16, i16);
+make_serial!(u32, i32);
+make_serial!(u64, i64);
+make_serial!(u128, i128);
+
+/// Recover a full counter value from its low-order `bits` bits.
+///
+/// Returns the value closest to `reference` whose low-order bits match
+/// `truncated`. Any higher bits of `truncated` are ignored.
+///
+/// This is the packet number decoding algorithm from [RFC 9000]
+/// (QUIC), where `reference` is one more than the largest packet number
+/// received so far. It works equally well for RTP sequence numbers and
+/// other counters that are sent truncated.
+///
+/// ```rust
+/// use truncate_integer::serial::expand_truncated;
+///
+/// // The example from RFC 9000, Appendix A.3.
+/// assert_eq!(expand_truncated(0x9b32u16, 16, 0xa82f30eb), 0xa82f9b32);
+/// // Values just past a wrap of the low bits.
+/// assert_eq!(expand_truncated(2u8, 8, 0x1fe), 0x202);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bits` is zero or greater than 64.
+///
+/// [RFC 9000]: https://www.rfc-editor.org/rfc/rfc9000#appendix-A.3
+pub fn expand_truncated<T: Into<u64>>(truncated: T, bits: u32, reference: u64) -> u64 {
+    assert!((1..=64).contains(&bits), "invalid bit count");
+    if bits == 64 {
+        return truncated.into();
+    }
+    let window = 1u64 << bits;
+    let half_window = window / 2;
+    let mask = window - 1;
+
+    let candidate = (reference & !mask) | (truncated.into() & mask);
+    let too_low = reference
+        .checked_sub(half_window)
+        .map_or(false, |low| candidate <= low);
+    if too_low && candidate <= u64::MAX - window {
+        candidate + window
+    } else if candidate > reference.saturating_add(half_window) && candidate >= window {
+        candidate - window
+    } else {
+        candidate
+    }
+}
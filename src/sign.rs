@@ -0,0 +1,132 @@
+//! Splitting integers into a sign and a magnitude.
+//!
+//! Some formats store the sign of an integer separately from its
+//! magnitude. The magnitude of `i8::MIN` is 128, which doesn't fit in an
+//! `i8`, so [`SignMagnitude::split_sign`] returns an unsigned magnitude
+//! of the same width. Going the other way, a negative magnitude can be
+//! one larger than a positive one.
+//!
+//! ```rust
+//! use truncate_integer::sign::{Sign, SignMagnitude};
+//!
+//! assert_eq!(i8::MIN.split_sign(), (Sign::Negative, 128u8));
+//! assert_eq!(i8::try_from_sign_magnitude(Sign::Negative, 128u32), Some(i8::MIN));
+//! assert_eq!(i8::try_from_sign_magnitude(Sign::Positive, 128u32), None);
+//! assert_eq!(i8::shrink_from_sign_magnitude(Sign::Positive, 128u32), i8::MAX);
+//!
+//! // The magnitude can also be narrowed.
+//! assert_eq!((-300i32).try_split_sign::<u8>(), None);
+//! assert_eq!((-300i32).shrink_split_sign::<u8>(), (Sign::Negative, 255));
+//! ```
+
+use crate::wide::{Primitive, Wide, Widen};
+
+/// The sign of an integer.
+///
+/// Zero is [`Positive`](Sign::Positive).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// Greater than or equal to zero.
+    Positive,
+    /// Less than zero.
+    Negative,
+}
+
+/// Convert between an integer and its sign and magnitude.
+pub trait SignMagnitude: Primitive {
+    /// The unsigned type of the same width.
+    type Magnitude: Primitive;
+
+    /// Split into a sign and a magnitude.
+    fn split_sign(self) -> (Sign, Self::Magnitude);
+
+    /// Split into a sign and a magnitude, narrowing the magnitude into
+    /// `M` if it fits.
+    #[inline]
+    fn try_split_sign<M: Primitive>(self) -> Option<(Sign, M)> {
+        let (sign, magnitude) = self.split_sign();
+        Some((sign, M::from_wide_checked(magnitude.widen())?))
+    }
+
+    /// Split into a sign and a magnitude, saturating the magnitude into
+    /// `M`.
+    #[inline]
+    fn shrink_split_sign<M: Primitive>(self) -> (Sign, M) {
+        let (sign, magnitude) = self.split_sign();
+        (sign, M::from_wide_saturating(magnitude.widen()))
+    }
+
+    /// Combine a sign and a magnitude, if the result fits.
+    ///
+    /// A negative magnitude is never valid. A magnitude of zero is
+    /// zero, whatever the sign.
+    #[inline]
+    fn try_from_sign_magnitude<M: Primitive>(sign: Sign, magnitude: M) -> Option<Self> {
+        Self::from_wide_checked(combine(sign, magnitude.widen())?)
+    }
+
+    /// Combine a sign and a magnitude, saturating the result.
+    ///
+    /// A negative magnitude is treated as zero.
+    #[inline]
+    fn shrink_from_sign_magnitude<M: Primitive>(sign: Sign, magnitude: M) -> Self {
+        let magnitude = magnitude.widen().max(Wide::NonNegative(0));
+        match combine(sign, magnitude) {
+            Some(value) => Self::from_wide_saturating(value),
+            // Too large to be negated; smaller than any minimum.
+            None => Self::from_wide_wrapping(Self::MIN_WIDE),
+        }
+    }
+}
+
+/// Apply a sign to a magnitude, or `None` if the magnitude is negative
+/// or too large to negate.
+#[inline]
+fn combine(sign: Sign, magnitude: Wide) -> Option<Wide> {
+    let magnitude = match magnitude {
+        Wide::NonNegative(x) => x,
+        Wide::Negative(_) => return None,
+    };
+    match sign {
+        Sign::Positive => Some(Wide::NonNegative(magnitude)),
+        Sign::Negative if magnitude == 0 => Some(Wide::NonNegative(0)),
+        Sign::Negative if magnitude <= i128::MIN.unsigned_abs() => {
+            Some(Wide::Negative((magnitude as i128).wrapping_neg()))
+        }
+        Sign::Negative => None,
+    }
+}
+
+macro_rules! make_sign_magnitude_signed {
+    ($($Type:ty => $Magnitude:ty),*) => {
+        $(
+            impl SignMagnitude for $Type {
+                type Magnitude = $Magnitude;
+
+                #[inline]
+                fn split_sign(self) -> (Sign, $Magnitude) {
+                    let sign = if self < 0 { Sign::Negative } else { Sign::Positive };
+                    (sign, self.unsigned_abs())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! make_sign_magnitude_unsigned {
+    ($($Type:ty),*) => {
+        $(
+            impl SignMagnitude for $Type {
+                type Magnitude = $Type;
+
+                #[inline]
+                fn split_sign(self) -> (Sign, $Type) {
+                    (Sign::Positive, self)
+                }
+            }
+        )*
+    };
+}
+
+make_sign_magnitude_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+make_sign_magnitude_unsigned!(u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,126 @@
+//! Narrowing [`core::simd`] vectors.
+//!
+//! The truncation traits are implemented between `Simd` vectors of the
+//! same length, for each pair of lane types from 8 to 64 bits where the
+//! destination can't hold every source value. Each lane is narrowed the
+//! same way a scalar would be:
+//!
+//! - [`TryTruncate`] returns `None` if any lane doesn't fit, and
+//!   [`TryTruncateLanes`] returns a mask of the lanes that did.
+//! - [`Shrink`] saturates each lane.
+//! - [`TruncateUnchecked`] keeps the low bits of each lane, like an `as`
+//!   cast.
+//! - [`Chop`] panics if any lane doesn't fit.
+//!
+//! This needs the unstable `portable_simd` feature, so it only builds
+//! with a nightly compiler.
+//!
+//! ```rust
+//! #![feature(portable_simd)]
+//! use core::simd::Simd;
+//! use truncate_integer::simd::TryTruncateLanes;
+//! use truncate_integer::{Shrink, TruncateUnchecked, TryTruncate};
+//!
+//! let v = Simd::from_array([1u32, 300, 255, 256]);
+//! let x: Simd<u8, 4> = v.shrink();
+//! assert_eq!(x.to_array(), [1, 255, 255, 255]);
+//! let x: Simd<u8, 4> = v.truncate_unchecked();
+//! assert_eq!(x.to_array(), [1, 44, 255, 0]);
+//! let x: Option<Simd<u8, 4>> = v.try_truncate();
+//! assert_eq!(x, None);
+//!
+//! let (_, fits) = TryTruncateLanes::<Simd<u8, 4>>::try_truncate_lanes(v);
+//! assert_eq!(fits.to_array(), [true, false, true, false]);
+//! ```
+
+use crate::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+use core::simd::cmp::{SimdOrd, SimdPartialOrd};
+use core::simd::num::{SimdInt, SimdUint};
+use core::simd::{Mask, Simd, SimdElement};
+
+/// Narrow each lane of a vector, reporting which lanes fit.
+pub trait TryTruncateLanes<T> {
+    /// A mask with one lane per lane of `T`.
+    type Mask;
+
+    /// Narrow each lane, saturating the lanes that don't fit.
+    ///
+    /// Returns the narrowed vector and a mask that is set for each lane
+    /// that fit without saturating.
+    fn try_truncate_lanes(self) -> (T, Self::Mask);
+}
+
+macro_rules! make_simd {
+    ($Source:ty => $($Dest:ty),*) => {
+        $(
+            impl<const N: usize> TryTruncateLanes<Simd<$Dest, N>> for Simd<$Source, N> {
+                type Mask = Mask<<$Dest as SimdElement>::Mask, N>;
+
+                #[inline]
+                fn try_truncate_lanes(self) -> (Simd<$Dest, N>, Self::Mask) {
+                    // The range of the destination, in the source type.
+                    const MIN: $Source = if (<$Dest>::MIN as i128) < (<$Source>::MIN as i128) {
+                        <$Source>::MIN
+                    } else {
+                        <$Dest>::MIN as $Source
+                    };
+                    const MAX: $Source = if (<$Dest>::MAX as i128) > (<$Source>::MAX as i128) {
+                        <$Source>::MAX
+                    } else {
+                        <$Dest>::MAX as $Source
+                    };
+                    let (min, max) = (Simd::splat(MIN), Simd::splat(MAX));
+                    let fits = self.simd_ge(min) & self.simd_le(max);
+                    (self.simd_clamp(min, max).cast(), fits.cast())
+                }
+            }
+
+            impl<const N: usize> TryTruncate<Simd<$Dest, N>> for Simd<$Source, N> {
+                #[inline]
+                fn try_truncate(self) -> Option<Simd<$Dest, N>> {
+                    let (value, fits) =
+                        TryTruncateLanes::<Simd<$Dest, N>>::try_truncate_lanes(self);
+                    if fits.all() {
+                        Some(value)
+                    } else {
+                        None
+                    }
+                }
+            }
+
+            impl<const N: usize> Chop<Simd<$Dest, N>> for Simd<$Source, N> {
+                #[track_caller]
+                #[inline]
+                fn chop(self) -> Simd<$Dest, N> {
+                    match TryTruncate::<Simd<$Dest, N>>::try_truncate(self) {
+                        Some(val) => val,
+                        None => panic!("chop overflow"),
+                    }
+                }
+            }
+
+            impl<const N: usize> Shrink<Simd<$Dest, N>> for Simd<$Source, N> {
+                #[inline]
+                fn shrink(self) -> Simd<$Dest, N> {
+                    TryTruncateLanes::<Simd<$Dest, N>>::try_truncate_lanes(self).0
+                }
+            }
+
+            impl<const N: usize> TruncateUnchecked<Simd<$Dest, N>> for Simd<$Source, N> {
+                #[inline]
+                fn truncate_unchecked(self) -> Simd<$Dest, N> {
+                    self.cast()
+                }
+            }
+        )*
+    };
+}
+
+make_simd!(u8 => i8);
+make_simd!(u16 => u8, i8, i16);
+make_simd!(u32 => u8, u16, i8, i16, i32);
+make_simd!(u64 => u8, u16, u32, i8, i16, i32, i64);
+make_simd!(i8 => u8);
+make_simd!(i16 => i8, u8, u16);
+make_simd!(i32 => i8, i16, u8, u16, u32);
+make_simd!(i64 => i8, i16, i32, u8, u16, u32, u64);
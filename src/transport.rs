@@ -0,0 +1,164 @@
+//! Field widths from HTTP/2 and QUIC.
+//!
+//! - [`FrameLength`] is the 24-bit length of an HTTP/2 frame.
+//! - [`StreamId`] is a 31-bit HTTP/2 stream identifier. On the wire it
+//!   shares a 32-bit word with a reserved bit, which
+//!   [`StreamId::from_wire`] ignores and [`StreamId::to_wire`] leaves
+//!   clear, as [RFC 9113] requires.
+//! - [`VarInt`] is a QUIC variable-length integer, which holds values up
+//!   to 2<sup>62</sup> - 1 ([RFC 9000]).
+//!
+//! The truncation traits are implemented from every integer type into
+//! them, so a value that's too wide for the field is caught (or
+//! saturated) where it's narrowed, instead of being masked.
+//!
+//! ```rust
+//! use truncate_integer::transport::{FrameLength, StreamId, VarInt};
+//! use truncate_integer::{Shrink, TryTruncate};
+//!
+//! let len: Option<FrameLength> = 16_384usize.try_truncate();
+//! assert_eq!(len.map(FrameLength::raw), Some(16_384));
+//! let len: Option<FrameLength> = (1u32 << 24).try_truncate();
+//! assert!(len.is_none());
+//!
+//! // The reserved bit isn't part of the stream identifier.
+//! let id: Option<StreamId> = 0x8000_0001u32.try_truncate();
+//! assert!(id.is_none());
+//! assert_eq!(StreamId::from_wire(0x8000_0001).raw(), 1);
+//!
+//! let n: VarInt = u64::MAX.shrink();
+//! assert_eq!(n, VarInt::MAX);
+//! assert_eq!(n.encoded_len(), 8);
+//! ```
+//!
+//! [RFC 9113]: https://www.rfc-editor.org/rfc/rfc9113#section-4.1
+//! [RFC 9000]: https://www.rfc-editor.org/rfc/rfc9000#section-16
+
+use crate::bitsint::U;
+use crate::wide::{FromWide, Widen};
+use crate::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+use core::fmt;
+
+macro_rules! make_field {
+    ($Field:ident, $N:literal, $Raw:ty, $($Into:ty),*) => {
+        impl $Field {
+            /// The width in bits.
+            pub const BITS: u32 = $N;
+            /// The smallest value, zero.
+            pub const MIN: Self = $Field(U::<$N>::MIN);
+            /// The largest value.
+            pub const MAX: Self = $Field(U::<$N>::MAX);
+
+            /// Create a value.
+            ///
+            /// Returns `None` if `raw` doesn't fit.
+            #[inline]
+            pub fn new(raw: $Raw) -> Option<Self> {
+                raw.try_truncate()
+            }
+
+            /// Get the value.
+            #[inline]
+            pub fn raw(self) -> $Raw {
+                self.0.get()
+            }
+        }
+
+        impl<Source: Widen> TryTruncate<$Field> for Source {
+            #[inline]
+            fn try_truncate(self) -> Option<$Field> {
+                TryTruncate::<U<$N>>::try_truncate(self).map($Field)
+            }
+        }
+
+        impl<Source: Widen> Chop<$Field> for Source {
+            #[track_caller]
+            #[inline]
+            fn chop(self) -> $Field {
+                $Field(Chop::<U<$N>>::chop(self))
+            }
+        }
+
+        impl<Source: Widen> Shrink<$Field> for Source {
+            #[inline]
+            fn shrink(self) -> $Field {
+                $Field(Shrink::<U<$N>>::shrink(self))
+            }
+        }
+
+        impl<Source: Widen> TruncateUnchecked<$Field> for Source {
+            #[track_caller]
+            #[inline]
+            fn truncate_unchecked(self) -> $Field {
+                $Field(TruncateUnchecked::<U<$N>>::truncate_unchecked(self))
+            }
+        }
+
+        $(
+            impl From<$Field> for $Into {
+                #[inline]
+                fn from(value: $Field) -> Self {
+                    value.raw().into()
+                }
+            }
+        )*
+
+        impl fmt::Display for $Field {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.raw().fmt(f)
+            }
+        }
+    };
+}
+
+/// The 24-bit length of an HTTP/2 frame payload.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrameLength(U<24>);
+
+/// A 31-bit HTTP/2 stream identifier.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StreamId(U<31>);
+
+/// A QUIC variable-length integer, up to 2<sup>62</sup> - 1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarInt(U<62>);
+
+make_field!(FrameLength, 24, u32, u32, u64, u128, i64, i128);
+make_field!(StreamId, 31, u32, u32, u64, u128, i64, i128);
+make_field!(VarInt, 62, u64, u64, u128, i128);
+
+impl StreamId {
+    /// Read a stream identifier from a 32-bit word, ignoring the reserved
+    /// bit.
+    #[inline]
+    pub fn from_wire(word: u32) -> Self {
+        let id = word & 0x7FFF_FFFF;
+        StreamId(FromWide::from_wide_wrapping(Widen::widen(id)))
+    }
+
+    /// The 32-bit word for this identifier, with the reserved bit clear.
+    #[inline]
+    pub fn to_wire(self) -> u32 {
+        self.raw()
+    }
+
+    /// Whether the stream was opened by the client; client streams have
+    /// odd identifiers.
+    #[inline]
+    pub fn is_client_initiated(self) -> bool {
+        self.raw() & 1 == 1
+    }
+}
+
+impl VarInt {
+    /// The number of bytes needed to encode this value: 1, 2, 4 or 8.
+    #[inline]
+    pub fn encoded_len(self) -> usize {
+        match self.raw() {
+            0..=0x3F => 1,
+            0x40..=0x3FFF => 2,
+            0x4000..=0x3FFF_FFFF => 4,
+            _ => 8,
+        }
+    }
+}
@@ -0,0 +1,408 @@
+//! [`ufmt`](https://docs.rs/ufmt) support for the error types.
+//!
+//! `core::fmt` can add several kilobytes to a small firmware image, so
+//! embedded code often formats with `ufmt` instead. This module
+//! implements `uDisplay` and `uDebug` for [`TruncateError`] and the
+//! other error types in this crate, with the same messages as their
+//! `Display` impls.
+//!
+//! ```rust
+//! use core::convert::Infallible;
+//! use truncate_integer::{TruncateError, TryTruncate};
+//! use ufmt::{uWrite, uwrite};
+//!
+//! struct Buf([u8; 32], usize);
+//!
+//! impl uWrite for Buf {
+//!     type Error = Infallible;
+//!
+//!     fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+//!         self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+//!         self.1 += s.len();
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let err: Result<u8, _> = 300u32.try_truncate().ok_or(TruncateError::Overflow);
+//! let mut buf = Buf([0; 32], 0);
+//! uwrite!(buf, "{}", err.unwrap_err()).unwrap();
+//! assert_eq!(&buf.0[..buf.1], b"integer overflow");
+//! ```
+
+use crate::align::AlignError;
+use crate::bitfield::BitfieldError;
+use crate::compact::CompactError;
+use crate::dma::DmaError;
+#[cfg(feature = "alloc")]
+use crate::id::IdError;
+use crate::imm::ImmError;
+use crate::index::IndexError;
+use crate::leb128::LebError;
+use crate::len::LenError;
+use crate::nibble::NibbleError;
+use crate::wasm::OutOfAddressableRange;
+use crate::y2038::Time32Error;
+use crate::{NarrowStructError, TruncateError};
+use ::ufmt::{uDebug, uDisplay, uWrite, uwrite, Formatter};
+
+impl uDisplay for TruncateError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            TruncateError::Overflow => f.write_str("integer overflow"),
+            TruncateError::Underflow => f.write_str("integer underflow"),
+            TruncateError::Invalid => f.write_str("invalid value"),
+        }
+    }
+}
+
+impl uDebug for TruncateError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            TruncateError::Overflow => f.write_str("Overflow"),
+            TruncateError::Underflow => f.write_str("Underflow"),
+            TruncateError::Invalid => f.write_str("Invalid"),
+        }
+    }
+}
+
+impl uDisplay for AlignError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            AlignError::NotPowerOfTwo => f.write_str("alignment is not a power of two"),
+            AlignError::Overflow => f.write_str("integer overflow"),
+        }
+    }
+}
+
+impl uDebug for AlignError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            AlignError::NotPowerOfTwo => f.write_str("NotPowerOfTwo"),
+            AlignError::Overflow => f.write_str("Overflow"),
+        }
+    }
+}
+
+impl uDisplay for BitfieldError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uwrite!(f, "{} in a {}-bit field", self.error(), self.bits())
+    }
+}
+
+impl uDebug for BitfieldError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_struct("BitfieldError")?
+            .field("error", &self.error())?
+            .field("bits", &self.bits())?
+            .finish()
+    }
+}
+
+impl uDisplay for CompactError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            CompactError::BufferTooShort => f.write_str("buffer too short"),
+            CompactError::InvalidHeader(header) => {
+                uwrite!(f, "invalid header byte {:#04x}", *header)
+            }
+            CompactError::Truncate(e) => uDisplay::fmt(e, f),
+        }
+    }
+}
+
+impl uDebug for CompactError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            CompactError::BufferTooShort => f.write_str("BufferTooShort"),
+            CompactError::InvalidHeader(header) => {
+                f.debug_tuple("InvalidHeader")?.field(header)?.finish()
+            }
+            CompactError::Truncate(e) => f.debug_tuple("Truncate")?.field(e)?.finish(),
+        }
+    }
+}
+
+impl uDisplay for DmaError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match *self {
+            DmaError::Misaligned { addr, align } => {
+                uwrite!(
+                    f,
+                    "DMA address {:#x} is not aligned to {} bytes",
+                    addr,
+                    align
+                )
+            }
+            DmaError::OutOfRange {
+                addr,
+                len,
+                max_address,
+            } => uwrite!(
+                f,
+                "DMA buffer {:#x}+{:#x} is beyond {:#x}",
+                addr,
+                len,
+                max_address
+            ),
+            DmaError::AddressField { addr } => {
+                uwrite!(f, "DMA address {:#x} doesn't fit in the descriptor", addr)
+            }
+            DmaError::LengthField { len } => {
+                uwrite!(f, "DMA length {:#x} doesn't fit in the descriptor", len)
+            }
+        }
+    }
+}
+
+impl uDebug for DmaError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            DmaError::Misaligned { addr, align } => f
+                .debug_struct("Misaligned")?
+                .field("addr", addr)?
+                .field("align", align)?
+                .finish(),
+            DmaError::OutOfRange {
+                addr,
+                len,
+                max_address,
+            } => f
+                .debug_struct("OutOfRange")?
+                .field("addr", addr)?
+                .field("len", len)?
+                .field("max_address", max_address)?
+                .finish(),
+            DmaError::AddressField { addr } => f
+                .debug_struct("AddressField")?
+                .field("addr", addr)?
+                .finish(),
+            DmaError::LengthField { len } => {
+                f.debug_struct("LengthField")?.field("len", len)?.finish()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl uDisplay for IdError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uwrite!(f, "all {} ids are in use", self.capacity())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl uDebug for IdError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_struct("IdError")?
+            .field("capacity", &self.capacity())?
+            .finish()
+    }
+}
+
+impl uDisplay for ImmError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match *self {
+            ImmError::Misaligned { value, shift } => {
+                uwrite!(
+                    f,
+                    "immediate {} is not a multiple of {}",
+                    value,
+                    1u64 << shift
+                )
+            }
+            ImmError::OutOfRange { value, bits } => {
+                uwrite!(f, "immediate {} doesn't fit in {} bits", value, bits)
+            }
+        }
+    }
+}
+
+impl uDebug for ImmError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            ImmError::Misaligned { value, shift } => f
+                .debug_struct("Misaligned")?
+                .field("value", value)?
+                .field("shift", shift)?
+                .finish(),
+            ImmError::OutOfRange { value, bits } => f
+                .debug_struct("OutOfRange")?
+                .field("value", value)?
+                .field("bits", bits)?
+                .finish(),
+        }
+    }
+}
+
+impl uDisplay for IndexError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uwrite!(
+            f,
+            "index {} at position {} doesn't fit in u16",
+            self.value(),
+            self.position()
+        )
+    }
+}
+
+impl uDebug for IndexError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_struct("IndexError")?
+            .field("position", &self.position())?
+            .field("value", &self.value())?
+            .finish()
+    }
+}
+
+impl uDisplay for LebError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            LebError::UnexpectedEnd => f.write_str("unterminated LEB128 value"),
+            LebError::Truncate(e) => uDisplay::fmt(e, f),
+        }
+    }
+}
+
+impl uDebug for LebError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            LebError::UnexpectedEnd => f.write_str("UnexpectedEnd"),
+            LebError::Truncate(e) => f.debug_tuple("Truncate")?.field(e)?.finish(),
+        }
+    }
+}
+
+impl uDisplay for LenError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uwrite!(
+            f,
+            "length {} exceeds the limit of {}",
+            self.length(),
+            self.limit()
+        )
+    }
+}
+
+impl uDebug for LenError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_struct("LenError")?
+            .field("len", &self.length())?
+            .field("limit", &self.limit())?
+            .finish()
+    }
+}
+
+impl uDisplay for NarrowStructError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str("fields out of range: ")?;
+        for (i, name) in self.fields().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+impl uDebug for NarrowStructError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_struct("NarrowStructError")?
+            .field("fields", &FieldList(self))?
+            .finish()
+    }
+}
+
+/// The failed fields of a [`NarrowStructError`], as a debug list.
+struct FieldList<'a>(&'a NarrowStructError);
+
+impl uDebug for FieldList<'_> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        let mut list = f.debug_list()?;
+        for name in self.0.fields() {
+            list.entry(&Name(name))?;
+        }
+        list.finish()
+    }
+}
+
+/// A field name, debug-formatted as a quoted string.
+struct Name(&'static str);
+
+impl uDebug for Name {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_char('"')?;
+        f.write_str(self.0)?;
+        f.write_char('"')
+    }
+}
+
+impl uDisplay for NibbleError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uwrite!(
+            f,
+            "value {} at position {} doesn't fit in a nibble",
+            self.value(),
+            self.position()
+        )
+    }
+}
+
+impl uDebug for NibbleError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_struct("NibbleError")?
+            .field("position", &self.position())?
+            .field("value", &self.value())?
+            .finish()
+    }
+}
+
+impl uDisplay for OutOfAddressableRange {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        if self.is_empty() {
+            uwrite!(
+                f,
+                "address {:#x} is out of the addressable range",
+                self.address()
+            )
+        } else {
+            uwrite!(
+                f,
+                "address range {:#x}+{:#x} is out of the addressable range",
+                self.address(),
+                self.len()
+            )
+        }
+    }
+}
+
+impl uDebug for OutOfAddressableRange {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.debug_struct("OutOfAddressableRange")?
+            .field("address", &self.address())?
+            .field("len", &self.len())?
+            .finish()
+    }
+}
+
+impl uDisplay for Time32Error {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            Time32Error::BeyondY2038 => f.write_str("time is after 2038-01-19T03:14:07Z"),
+            Time32Error::BeforeY1901 => f.write_str("time is before 1901-12-13T20:45:52Z"),
+            Time32Error::BeyondY2106 => f.write_str("time is after 2106-02-07T06:28:15Z"),
+            Time32Error::BeforeEpoch => f.write_str("time is before 1970-01-01T00:00:00Z"),
+        }
+    }
+}
+
+impl uDebug for Time32Error {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            Time32Error::BeyondY2038 => f.write_str("BeyondY2038"),
+            Time32Error::BeforeY1901 => f.write_str("BeforeY1901"),
+            Time32Error::BeyondY2106 => f.write_str("BeyondY2106"),
+            Time32Error::BeforeEpoch => f.write_str("BeforeEpoch"),
+        }
+    }
+}
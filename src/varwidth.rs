@@ -0,0 +1,207 @@
+//! Integers with a byte width chosen at runtime.
+//!
+//! File formats like ELF and DWARF store integers whose width is only
+//! known once a header has been parsed. A [`VarWidth`] reads and writes
+//! integers of 1 to 16 bytes, in either byte order.
+//!
+//! Values are read into a `u128` or `i128`, which can then be narrowed
+//! with any of the truncation traits. When writing, the caller chooses
+//! what happens if the value doesn't fit into the width:
+//!
+//! - `try_write_*` returns an error.
+//! - `shrink_write_*` writes the closest value that does fit.
+//! - `write_*_unchecked` writes the low-order bytes.
+//!
+//! ```rust
+//! use truncate_integer::varwidth::{Endian, VarWidth};
+//! use truncate_integer::TryTruncate;
+//!
+//! let width = VarWidth::new(3, Endian::Little).unwrap();
+//! let mut buf = [0u8; 3];
+//! width.try_write_unsigned(&mut buf, 0x123456u32).unwrap();
+//! assert_eq!(buf, [0x56, 0x34, 0x12]);
+//! assert!(width.try_write_unsigned(&mut buf, 0x1000000u32).is_err());
+//!
+//! let value: Option<u16> = width.read_unsigned(&buf).try_truncate();
+//! assert_eq!(value, None);
+//! ```
+
+use crate::TruncateError;
+
+/// Byte order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Most significant byte first.
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+/// An integer format with a runtime byte width and byte order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VarWidth {
+    width: usize,
+    endian: Endian,
+}
+
+impl VarWidth {
+    /// Create a new `VarWidth`.
+    ///
+    /// Returns `None` unless `width` is in the range `1..=16`.
+    pub fn new(width: usize, endian: Endian) -> Option<Self> {
+        if (1..=16).contains(&width) {
+            Some(VarWidth { width, endian })
+        } else {
+            None
+        }
+    }
+
+    /// The width in bytes.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The byte order.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    fn bits(&self) -> u32 {
+        self.width as u32 * 8
+    }
+
+    fn unsigned_max(&self) -> u128 {
+        u128::MAX >> (128 - self.bits())
+    }
+
+    fn signed_max(&self) -> i128 {
+        i128::MAX >> (128 - self.bits())
+    }
+
+    fn signed_min(&self) -> i128 {
+        i128::MIN >> (128 - self.bits())
+    }
+
+    /// Read an unsigned integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than the width.
+    pub fn read_unsigned(&self, bytes: &[u8]) -> u128 {
+        let bytes = &bytes[..self.width];
+        let mut value = 0u128;
+        match self.endian {
+            Endian::Big => {
+                for &byte in bytes {
+                    value = value << 8 | u128::from(byte);
+                }
+            }
+            Endian::Little => {
+                for &byte in bytes.iter().rev() {
+                    value = value << 8 | u128::from(byte);
+                }
+            }
+        }
+        value
+    }
+
+    /// Read a signed (two's complement) integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than the width.
+    pub fn read_signed(&self, bytes: &[u8]) -> i128 {
+        let shift = 128 - self.bits();
+        (self.read_unsigned(bytes) << shift) as i128 >> shift
+    }
+
+    /// Write the low-order bytes of `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than the width.
+    pub fn write_unsigned_unchecked<T: Into<u128>>(&self, bytes: &mut [u8], value: T) {
+        let value = value.into();
+        let bytes = &mut bytes[..self.width];
+        let width = self.width;
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            let shift = match self.endian {
+                Endian::Big => 8 * (width - 1 - i),
+                Endian::Little => 8 * i,
+            };
+            *byte = (value >> shift) as u8;
+        }
+    }
+
+    /// Write an unsigned integer, or return an error if it doesn't fit.
+    ///
+    /// Nothing is written if the value doesn't fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than the width.
+    pub fn try_write_unsigned<T: Into<u128>>(
+        &self,
+        bytes: &mut [u8],
+        value: T,
+    ) -> Result<(), TruncateError> {
+        let value = value.into();
+        if value > self.unsigned_max() {
+            return Err(TruncateError::Overflow);
+        }
+        self.write_unsigned_unchecked(bytes, value);
+        Ok(())
+    }
+
+    /// Write an unsigned integer, saturating if it doesn't fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than the width.
+    pub fn shrink_write_unsigned<T: Into<u128>>(&self, bytes: &mut [u8], value: T) {
+        let value = value.into().min(self.unsigned_max());
+        self.write_unsigned_unchecked(bytes, value);
+    }
+
+    /// Write the low-order bytes of a signed integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than the width.
+    pub fn write_signed_unchecked<T: Into<i128>>(&self, bytes: &mut [u8], value: T) {
+        self.write_unsigned_unchecked(bytes, value.into() as u128);
+    }
+
+    /// Write a signed integer, or return an error if it doesn't fit.
+    ///
+    /// Nothing is written if the value doesn't fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than the width.
+    pub fn try_write_signed<T: Into<i128>>(
+        &self,
+        bytes: &mut [u8],
+        value: T,
+    ) -> Result<(), TruncateError> {
+        let value = value.into();
+        if value > self.signed_max() {
+            return Err(TruncateError::Overflow);
+        }
+        if value < self.signed_min() {
+            return Err(TruncateError::Underflow);
+        }
+        self.write_signed_unchecked(bytes, value);
+        Ok(())
+    }
+
+    /// Write a signed integer, saturating if it doesn't fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than the width.
+    pub fn shrink_write_signed<T: Into<i128>>(&self, bytes: &mut [u8], value: T) {
+        let value = value.into().max(self.signed_min()).min(self.signed_max());
+        self.write_signed_unchecked(bytes, value);
+    }
+}
@@ -0,0 +1,120 @@
+//! Conversions for WebAssembly memory addresses.
+//!
+//! With the memory64 proposal, guest addresses and lengths are `u64`,
+//! but a linear memory using 32-bit indexes (memory32) can only address
+//! 4 GiB, and a wasm32 host can only address as much as fits in its `usize`.
+//! Narrowing an address with `as` at this boundary silently wraps it to
+//! some other location in memory.
+//!
+//! ```rust
+//! use truncate_integer::wasm::{memory32_range, memory32_address};
+//!
+//! assert_eq!(memory32_address(0xFFFF_FFFF), Ok(0xFFFF_FFFF));
+//! assert!(memory32_address(0x1_0000_0000).is_err());
+//!
+//! // A range may end exactly at the 4 GiB limit.
+//! assert_eq!(memory32_range(0xFFFF_FF00, 0x100), Ok(0xFFFF_FF00..0x1_0000_0000));
+//! assert!(memory32_range(0xFFFF_FF00, 0x101).is_err());
+//! ```
+
+use crate::Shrink;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Range;
+
+/// The size of a memory32 address space.
+pub const MEMORY32_SIZE: u64 = 1 << 32;
+
+/// The error returned when an address is outside the addressable range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OutOfAddressableRange {
+    address: u64,
+    len: u64,
+}
+
+impl OutOfAddressableRange {
+    /// The start of the address range that didn't fit.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// The length of the address range that didn't fit.
+    ///
+    /// This is zero if a single address was converted.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if a single address was converted.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl fmt::Display for OutOfAddressableRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.len == 0 {
+            write!(
+                f,
+                "address {:#x} is out of the addressable range",
+                self.address
+            )
+        } else {
+            write!(
+                f,
+                "address range {:#x}+{:#x} is out of the addressable range",
+                self.address, self.len
+            )
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfAddressableRange {}
+
+/// Compute `address + len`, if it's no more than `limit`.
+fn range_end(address: u64, len: u64, limit: u64) -> Result<u64, OutOfAddressableRange> {
+    match address.checked_add(len) {
+        Some(end) if end <= limit => Ok(end),
+        _ => Err(OutOfAddressableRange { address, len }),
+    }
+}
+
+/// Convert a 64-bit address into a memory32 address.
+pub fn memory32_address(address: u64) -> Result<u32, OutOfAddressableRange> {
+    u32::try_from(address).map_err(|_| OutOfAddressableRange { address, len: 0 })
+}
+
+/// Convert a 64-bit address into a memory32 address, saturating at `u32::MAX`.
+pub fn memory32_address_saturating(address: u64) -> u32 {
+    address.shrink()
+}
+
+/// Convert a 64-bit address range into a memory32 address range.
+///
+/// The end of the range is returned as a `u64`, because a range may
+/// extend to the very end of a memory32 address space.
+pub fn memory32_range(address: u64, len: u64) -> Result<Range<u64>, OutOfAddressableRange> {
+    let end = range_end(address, len, MEMORY32_SIZE)?;
+    Ok(address..end)
+}
+
+/// Convert a 64-bit address into a host `usize`.
+pub fn host_address(address: u64) -> Result<usize, OutOfAddressableRange> {
+    usize::try_from(address).map_err(|_| OutOfAddressableRange { address, len: 0 })
+}
+
+/// Convert a 64-bit address into a host `usize`, saturating at `usize::MAX`.
+pub fn host_address_saturating(address: u64) -> usize {
+    usize::try_from(address).unwrap_or(usize::MAX)
+}
+
+/// Convert a 64-bit address range into a host `usize` range.
+///
+/// Every address in the range, and its end, must fit in a `usize`.
+pub fn host_range(address: u64, len: u64) -> Result<Range<usize>, OutOfAddressableRange> {
+    let limit = u64::try_from(usize::MAX).unwrap_or(u64::MAX);
+    let end = range_end(address, len, limit)?;
+    // Both values are no more than `limit`, so they fit.
+    Ok(address as usize..end as usize)
+}
@@ -0,0 +1,223 @@
+//! The WGSL data packing built-in functions.
+//!
+//! These are CPU implementations of the packing and unpacking functions
+//! from the [WGSL specification], so that vertex and uniform data
+//! prepared on the CPU matches what a shader computes bit-for-bit.
+//!
+//! - The normalized functions round as the spec defines, with
+//!   `floor(0.5 + x)`, using the [`norm`](crate::norm) conversions.
+//! - `pack2x16float` rounds to the nearest `f16`, with ties to even.
+//!   WGSL leaves the result undefined for values outside the finite
+//!   range of `f16`; here they become infinity.
+//! - The `*_clamp` integer functions saturate each component, and the
+//!   others keep its low 8 bits.
+//!
+//! Component `i` of the input is stored in bits `8 * i` through
+//! `8 * i + 7` (or `16 * i` through `16 * i + 15`) of the result.
+//!
+//! ```rust
+//! use truncate_integer::wgsl::{pack2x16float, pack4x8unorm, pack4x_u8_clamp, unpack4x8unorm};
+//!
+//! assert_eq!(pack4x8unorm([1.0, 0.5, 0.0, 2.0]), 0xFF00_80FF);
+//! assert_eq!(unpack4x8unorm(0xFF00_80FF)[0], 1.0);
+//! assert_eq!(pack2x16float([1.0, -2.0]), 0xC000_3C00);
+//! assert_eq!(pack4x_u8_clamp([0, 1, 255, 1000]), 0xFFFF_0100);
+//! ```
+//!
+//! [WGSL specification]: https://www.w3.org/TR/WGSL/#pack-builtin-functions
+
+use crate::norm::{FloatToNorm, SnormToFloat, UnormToFloat};
+use crate::Shrink;
+
+#[inline]
+fn pack4(bytes: [u8; 4]) -> u32 {
+    u32::from_le_bytes(bytes)
+}
+
+#[inline]
+fn pack2(halves: [u16; 2]) -> u32 {
+    u32::from(halves[0]) | u32::from(halves[1]) << 16
+}
+
+#[inline]
+fn unpack2(value: u32) -> [u16; 2] {
+    [value as u16, (value >> 16) as u16]
+}
+
+/// Pack four `f32`s into 8-bit signed normalized integers.
+pub fn pack4x8snorm(e: [f32; 4]) -> u32 {
+    pack4([
+        e[0].to_snorm8() as u8,
+        e[1].to_snorm8() as u8,
+        e[2].to_snorm8() as u8,
+        e[3].to_snorm8() as u8,
+    ])
+}
+
+/// Pack four `f32`s into 8-bit unsigned normalized integers.
+pub fn pack4x8unorm(e: [f32; 4]) -> u32 {
+    pack4([
+        e[0].to_unorm8(),
+        e[1].to_unorm8(),
+        e[2].to_unorm8(),
+        e[3].to_unorm8(),
+    ])
+}
+
+/// Pack two `f32`s into 16-bit signed normalized integers.
+pub fn pack2x16snorm(e: [f32; 2]) -> u32 {
+    pack2([e[0].to_snorm16() as u16, e[1].to_snorm16() as u16])
+}
+
+/// Pack two `f32`s into 16-bit unsigned normalized integers.
+pub fn pack2x16unorm(e: [f32; 2]) -> u32 {
+    pack2([e[0].to_unorm16(), e[1].to_unorm16()])
+}
+
+/// Pack two `f32`s into `f16`s.
+pub fn pack2x16float(e: [f32; 2]) -> u32 {
+    pack2([f32_to_f16(e[0]), f32_to_f16(e[1])])
+}
+
+/// Unpack four 8-bit signed normalized integers into `f32`s.
+pub fn unpack4x8snorm(e: u32) -> [f32; 4] {
+    let b = e.to_le_bytes();
+    [
+        (b[0] as i8).to_snorm_f32(),
+        (b[1] as i8).to_snorm_f32(),
+        (b[2] as i8).to_snorm_f32(),
+        (b[3] as i8).to_snorm_f32(),
+    ]
+}
+
+/// Unpack four 8-bit unsigned normalized integers into `f32`s.
+pub fn unpack4x8unorm(e: u32) -> [f32; 4] {
+    let b = e.to_le_bytes();
+    [
+        b[0].to_unorm_f32(),
+        b[1].to_unorm_f32(),
+        b[2].to_unorm_f32(),
+        b[3].to_unorm_f32(),
+    ]
+}
+
+/// Unpack two 16-bit signed normalized integers into `f32`s.
+pub fn unpack2x16snorm(e: u32) -> [f32; 2] {
+    let h = unpack2(e);
+    [(h[0] as i16).to_snorm_f32(), (h[1] as i16).to_snorm_f32()]
+}
+
+/// Unpack two 16-bit unsigned normalized integers into `f32`s.
+pub fn unpack2x16unorm(e: u32) -> [f32; 2] {
+    let h = unpack2(e);
+    [h[0].to_unorm_f32(), h[1].to_unorm_f32()]
+}
+
+/// Unpack two `f16`s into `f32`s.
+pub fn unpack2x16float(e: u32) -> [f32; 2] {
+    let h = unpack2(e);
+    [f16_to_f32(h[0]), f16_to_f32(h[1])]
+}
+
+/// Pack the low 8 bits of four integers.
+pub fn pack4x_i8(e: [i32; 4]) -> u32 {
+    pack4([e[0] as u8, e[1] as u8, e[2] as u8, e[3] as u8])
+}
+
+/// Pack the low 8 bits of four integers.
+pub fn pack4x_u8(e: [u32; 4]) -> u32 {
+    pack4([e[0] as u8, e[1] as u8, e[2] as u8, e[3] as u8])
+}
+
+/// Pack four integers, each clamped into `-128..=127`.
+pub fn pack4x_i8_clamp(e: [i32; 4]) -> u32 {
+    let clamp = |x: i32| Shrink::<i8>::shrink(x) as u8;
+    pack4([clamp(e[0]), clamp(e[1]), clamp(e[2]), clamp(e[3])])
+}
+
+/// Pack four integers, each clamped into `0..=255`.
+pub fn pack4x_u8_clamp(e: [u32; 4]) -> u32 {
+    pack4([e[0].shrink(), e[1].shrink(), e[2].shrink(), e[3].shrink()])
+}
+
+/// Unpack four signed 8-bit integers.
+pub fn unpack4x_i8(e: u32) -> [i32; 4] {
+    let b = e.to_le_bytes();
+    [
+        i32::from(b[0] as i8),
+        i32::from(b[1] as i8),
+        i32::from(b[2] as i8),
+        i32::from(b[3] as i8),
+    ]
+}
+
+/// Unpack four unsigned 8-bit integers.
+pub fn unpack4x_u8(e: u32) -> [u32; 4] {
+    let b = e.to_le_bytes();
+    [
+        u32::from(b[0]),
+        u32::from(b[1]),
+        u32::from(b[2]),
+        u32::from(b[3]),
+    ]
+}
+
+/// Shift `value` right by `shift` bits, rounding to nearest, ties to
+/// even.
+#[inline]
+fn round_shift(value: u32, shift: u32) -> u32 {
+    let half = 1 << (shift - 1);
+    let rem = value & ((1 << shift) - 1);
+    let quotient = value >> shift;
+    if rem > half || (rem == half && quotient & 1 == 1) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// Convert an `f32` into the bits of the nearest `f16`.
+fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) as u16 & 0x8000;
+    let exp = (bits >> 23) as i32 & 0xFF;
+    let man = bits & 0x7F_FFFF;
+    if exp == 0xFF {
+        // Infinity stays infinity, and NaN stays a quiet NaN.
+        let nan = if man != 0 { 0x200 } else { 0 };
+        return sign | 0x7C00 | nan | (man >> 13) as u16;
+    }
+    let exp = exp - 127 + 15;
+    if exp >= 0x1F {
+        return sign | 0x7C00;
+    }
+    if exp <= 0 {
+        // A subnormal f16, in units of 2^-24.
+        let shift = (14 - exp) as u32;
+        if shift > 24 {
+            return sign;
+        }
+        return sign | round_shift(man | 0x80_0000, shift) as u16;
+    }
+    // Rounding may carry into the exponent, up to infinity.
+    sign | round_shift((exp as u32) << 23 | man, 13) as u16
+}
+
+/// Convert the bits of an `f16` into an `f32`.
+fn f16_to_f32(half: u16) -> f32 {
+    let sign = u32::from(half & 0x8000) << 16;
+    let exp = u32::from(half >> 10) & 0x1F;
+    let man = u32::from(half & 0x3FF);
+    match exp {
+        0 => {
+            let value = man as f32 / 16_777_216.0;
+            if sign != 0 {
+                -value
+            } else {
+                value
+            }
+        }
+        0x1F => f32::from_bits(sign | 0x7F80_0000 | man << 13),
+        _ => f32::from_bits(sign | (exp + 112) << 23 | man << 13),
+    }
+}
@@ -0,0 +1,114 @@
+//! Private machinery for converting between any two integer types.
+//!
+//! Every integer can be widened into a [`Wide`] without loss, which can
+//! then be compared with any other `Wide`, or narrowed into another type.
+
+/// An integer value widened so that it can be compared with any other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Wide {
+    // Variant order matters: every negative value is less than
+    // every non-negative value.
+    Negative(i128),
+    NonNegative(u128),
+}
+
+impl Wide {
+    /// The two's complement bits of this value.
+    #[inline]
+    pub fn to_bits(self) -> u128 {
+        match self {
+            Wide::Negative(x) => x as u128,
+            Wide::NonNegative(x) => x,
+        }
+    }
+}
+
+pub trait Widen: Copy {
+    fn widen(self) -> Wide;
+}
+
+pub trait FromWide: Sized {
+    /// The minimum value of this type.
+    const MIN_WIDE: Wide;
+    /// The maximum value of this type.
+    const MAX_WIDE: Wide;
+
+    /// Convert a widened value back, keeping only the low-order bits.
+    fn from_wide_wrapping(value: Wide) -> Self;
+
+    /// Convert a widened value back, if it fits.
+    #[inline]
+    fn from_wide_checked(value: Wide) -> Option<Self> {
+        if value < Self::MIN_WIDE || value > Self::MAX_WIDE {
+            None
+        } else {
+            Some(Self::from_wide_wrapping(value))
+        }
+    }
+
+    /// Convert a widened value back, saturating if it doesn't fit.
+    #[inline]
+    fn from_wide_saturating(value: Wide) -> Self {
+        Self::from_wide_wrapping(value.max(Self::MIN_WIDE).min(Self::MAX_WIDE))
+    }
+}
+
+/// Implemented only for the primitive integer types.
+pub trait Primitive: Widen + FromWide {}
+
+macro_rules! make_wide_unsigned {
+    ($($Type:ty),*) => {
+        $(
+            impl Widen for $Type {
+                #[inline]
+                fn widen(self) -> Wide {
+                    Wide::NonNegative(self as u128)
+                }
+            }
+
+            impl FromWide for $Type {
+                const MIN_WIDE: Wide = Wide::NonNegative(0);
+                const MAX_WIDE: Wide = Wide::NonNegative(<$Type>::MAX as u128);
+
+                #[inline]
+                fn from_wide_wrapping(value: Wide) -> Self {
+                    value.to_bits() as $Type
+                }
+            }
+
+            impl Primitive for $Type {}
+        )*
+    };
+}
+
+macro_rules! make_wide_signed {
+    ($($Type:ty),*) => {
+        $(
+            impl Widen for $Type {
+                #[inline]
+                fn widen(self) -> Wide {
+                    if self < 0 {
+                        Wide::Negative(self as i128)
+                    } else {
+                        Wide::NonNegative(self as u128)
+                    }
+                }
+            }
+
+            impl FromWide for $Type {
+                const MIN_WIDE: Wide = Wide::Negative(<$Type>::MIN as i128);
+                const MAX_WIDE: Wide = Wide::NonNegative(<$Type>::MAX as u128);
+
+                #[inline]
+                fn from_wide_wrapping(value: Wide) -> Self {
+                    value.to_bits() as $Type
+                }
+            }
+
+            impl Primitive for $Type {}
+        )*
+    };
+}
+
+make_wide_unsigned!(u8, u16, u32, u64, u128, usize);
+make_wide_signed!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,68 @@
+//! Win32 integer type aliases.
+//!
+//! These match the definitions in the Windows SDK (and the `windows-sys`
+//! crate), so code calling Windows APIs can narrow lengths, handles and
+//! flags with an explicit policy instead of an `as` cast.
+//!
+//! The pointer-sized aliases (`SIZE_T`, `LONG_PTR`, etc.) are `usize`
+//! and `isize`, so they use the crate's impls for those types.
+//!
+//! ```rust
+//! use truncate_integer::windows::{DWORD, LONG, LONG_PTR, SIZE_T, ULONGLONG};
+//! use truncate_integer::{Shrink, TryTruncate};
+//!
+//! let len: SIZE_T = 0x1_0000_0000;
+//! let n: Option<DWORD> = len.try_truncate();
+//! # #[cfg(target_pointer_width = "64")]
+//! assert_eq!(n, None);
+//!
+//! let result: LONG_PTR = -1;
+//! let x: LONG = result.shrink();
+//! assert_eq!(x, -1);
+//!
+//! let file_size: ULONGLONG = u64::MAX;
+//! let size: SIZE_T = file_size.shrink();
+//! assert_eq!(size, usize::MAX);
+//! ```
+#![allow(non_camel_case_types)]
+
+/// An 8-bit unsigned integer.
+pub type BYTE = u8;
+/// A 16-bit unsigned integer.
+pub type WORD = u16;
+/// A 32-bit unsigned integer.
+pub type DWORD = u32;
+/// A 64-bit unsigned integer.
+pub type DWORD64 = u64;
+/// A 64-bit unsigned integer.
+pub type DWORDLONG = u64;
+/// A 16-bit signed integer.
+pub type SHORT = i16;
+/// A 16-bit unsigned integer.
+pub type USHORT = u16;
+/// A 32-bit signed integer.
+pub type INT = i32;
+/// A 32-bit unsigned integer.
+pub type UINT = u32;
+/// A 32-bit signed integer.
+pub type LONG = i32;
+/// A 32-bit unsigned integer.
+pub type ULONG = u32;
+/// A 64-bit signed integer.
+pub type LONGLONG = i64;
+/// A 64-bit unsigned integer.
+pub type ULONGLONG = u64;
+/// A pointer-sized signed integer.
+pub type INT_PTR = isize;
+/// A pointer-sized unsigned integer.
+pub type UINT_PTR = usize;
+/// A pointer-sized signed integer.
+pub type LONG_PTR = isize;
+/// A pointer-sized unsigned integer.
+pub type ULONG_PTR = usize;
+/// A pointer-sized unsigned integer.
+pub type DWORD_PTR = usize;
+/// A pointer-sized unsigned integer, used for sizes.
+pub type SIZE_T = usize;
+/// A pointer-sized signed integer, used for sizes.
+pub type SSIZE_T = isize;
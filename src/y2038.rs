@@ -0,0 +1,87 @@
+//! Narrowing timestamps into 32-bit `time_t` fields.
+//!
+//! Old file formats and ABIs store Unix time, in seconds, in 32 bits. A
+//! signed 32-bit `time_t` runs out at 2038-01-19T03:14:07Z, and an
+//! unsigned one at 2106-02-07T06:28:15Z. These functions narrow an `i64`
+//! timestamp into such a field, and name the way it failed, so a
+//! timestamp past the end isn't reported as a generic overflow.
+//!
+//! - `*_try` functions return a [`Time32Error`] if the timestamp doesn't
+//!   fit.
+//! - `*_shrink` functions saturate to the first or last representable
+//!   second, for best-effort interop with readers that can't do better.
+//!
+//! ```rust
+//! use truncate_integer::y2038::{time32_shrink, time32_try, Time32Error};
+//!
+//! // 2038-01-19T03:14:07Z
+//! assert_eq!(time32_try(2_147_483_647), Ok(i32::MAX));
+//! // One second later.
+//! assert_eq!(time32_try(2_147_483_648), Err(Time32Error::BeyondY2038));
+//! assert_eq!(time32_shrink(4_102_444_800), i32::MAX);
+//! ```
+
+use crate::wide::{FromWide, Widen};
+use core::fmt;
+
+/// The error returned when a timestamp doesn't fit in 32 bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Time32Error {
+    /// The timestamp is after 2038-01-19T03:14:07Z, the last second of a
+    /// signed 32-bit `time_t`.
+    BeyondY2038,
+    /// The timestamp is before 1901-12-13T20:45:52Z, the first second of
+    /// a signed 32-bit `time_t`.
+    BeforeY1901,
+    /// The timestamp is after 2106-02-07T06:28:15Z, the last second of
+    /// an unsigned 32-bit `time_t`.
+    BeyondY2106,
+    /// The timestamp is before the Unix epoch, which an unsigned 32-bit
+    /// `time_t` can't represent.
+    BeforeEpoch,
+}
+
+impl fmt::Display for Time32Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Time32Error::BeyondY2038 => f.write_str("time is after 2038-01-19T03:14:07Z"),
+            Time32Error::BeforeY1901 => f.write_str("time is before 1901-12-13T20:45:52Z"),
+            Time32Error::BeyondY2106 => f.write_str("time is after 2106-02-07T06:28:15Z"),
+            Time32Error::BeforeEpoch => f.write_str("time is before 1970-01-01T00:00:00Z"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Time32Error {}
+
+/// Narrow Unix time in seconds into a signed 32-bit `time_t`.
+pub fn time32_try(secs: i64) -> Result<i32, Time32Error> {
+    narrow!(i64, i32, secs).ok_or(if secs < 0 {
+        Time32Error::BeforeY1901
+    } else {
+        Time32Error::BeyondY2038
+    })
+}
+
+/// Narrow Unix time in seconds into a signed 32-bit `time_t`, saturating
+/// if it doesn't fit.
+pub fn time32_shrink(secs: i64) -> i32 {
+    i32::from_wide_saturating(Widen::widen(secs))
+}
+
+/// Narrow Unix time in seconds into an unsigned 32-bit `time_t`.
+pub fn time32_unsigned_try(secs: i64) -> Result<u32, Time32Error> {
+    narrow!(i64, u32, secs).ok_or(if secs < 0 {
+        Time32Error::BeforeEpoch
+    } else {
+        Time32Error::BeyondY2106
+    })
+}
+
+/// Narrow Unix time in seconds into an unsigned 32-bit `time_t`,
+/// saturating if it doesn't fit.
+pub fn time32_unsigned_shrink(secs: i64) -> u32 {
+    u32::from_wide_saturating(Widen::widen(secs))
+}
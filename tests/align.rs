@@ -0,0 +1,27 @@
+use truncate_integer::align::{Align, AlignError};
+
+#[test]
+fn test_align_down() {
+    assert_eq!(0usize.try_align_down(8), Ok(0));
+    assert_eq!(15usize.try_align_down(8), Ok(8));
+    assert_eq!(16usize.try_align_down(8), Ok(16));
+    assert_eq!(u64::MAX.try_align_down(1 << 63), Ok(1 << 63));
+    assert_eq!(7u32.try_align_down(1), Ok(7));
+    assert_eq!(7u32.try_align_down(0), Err(AlignError::NotPowerOfTwo));
+    assert_eq!(7u32.try_align_down(6), Err(AlignError::NotPowerOfTwo));
+}
+
+#[test]
+fn test_align_up() {
+    assert_eq!(0u64.try_align_up(4096), Ok(0));
+    assert_eq!(1u64.try_align_up(4096), Ok(4096));
+    assert_eq!(4096u64.try_align_up(4096), Ok(4096));
+    assert_eq!(u32::MAX.try_align_up(1), Ok(u32::MAX));
+    assert_eq!(u32::MAX.try_align_up(2), Err(AlignError::Overflow));
+    assert_eq!((u32::MAX - 7).try_align_up(8), Ok(u32::MAX - 7));
+    assert_eq!(1usize.try_align_up(3), Err(AlignError::NotPowerOfTwo));
+
+    assert_eq!(u32::MAX.saturating_align_up(2), Ok(u32::MAX - 1));
+    assert_eq!(5u32.saturating_align_up(4), Ok(8));
+    assert_eq!(5u32.saturating_align_up(5), Err(AlignError::NotPowerOfTwo));
+}
@@ -0,0 +1,56 @@
+use truncate_integer::analyze::{analyze, IntType};
+
+#[test]
+fn test_analyze() {
+    let report = analyze(&[0, 127, 128, 255, 256, 40000, 1 << 40, u64::MAX]);
+    assert_eq!(report.len(), 8);
+    assert_eq!(report.min(), Some(0));
+    assert_eq!(report.max(), Some(u64::MAX));
+    assert_eq!(report.bits(), 64);
+    assert_eq!(report.narrowest(), IntType::U64);
+    let failures: Vec<_> = report.candidates().collect();
+    assert_eq!(
+        failures,
+        [
+            (IntType::U8, 4),
+            (IntType::I8, 6),
+            (IntType::U16, 2),
+            (IntType::I16, 3),
+            (IntType::U32, 2),
+            (IntType::I32, 2),
+            (IntType::U64, 0),
+            (IntType::I64, 1),
+        ]
+    );
+}
+
+#[test]
+fn test_narrowest() {
+    assert_eq!(analyze(&[1, 2, 3]).narrowest(), IntType::U8);
+    assert_eq!(analyze(&[255]).narrowest(), IntType::U8);
+    assert_eq!(analyze(&[256]).narrowest(), IntType::U16);
+    assert_eq!(analyze(&[u32::MAX as u64]).narrowest(), IntType::U32);
+    assert_eq!(analyze(&[u32::MAX as u64 + 1]).bits(), 33);
+}
+
+#[test]
+fn test_empty() {
+    let report = analyze(&[]);
+    assert!(report.is_empty());
+    assert_eq!(report.min(), None);
+    assert_eq!(report.max(), None);
+    assert_eq!(report.bits(), 0);
+    assert_eq!(report.narrowest(), IntType::U8);
+    assert!(report.candidates().all(|(_, failures)| failures == 0));
+}
+
+#[test]
+fn test_int_type() {
+    assert_eq!(IntType::I8.max_value(), 127);
+    assert_eq!(IntType::U16.max_value(), 65535);
+    assert_eq!(IntType::I64.max_value(), i64::MAX as u64);
+    assert_eq!(IntType::U64.max_value(), u64::MAX);
+    assert_eq!(IntType::I32.to_string(), "i32");
+    assert!(IntType::I16.is_signed());
+    assert_eq!(IntType::U32.bits(), 32);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use truncate_integer::arbitrary::{that_fits_in, that_overflows};
+use truncate_integer::bitsint::{I, U};
+use truncate_integer::{TruncateError, TryTruncate};
+
+const DATA: &[u8] = &[
+    0x00, 0xFF, 0x12, 0x80, 0x7F, 0x34, 0x56, 0x01, 0xFE, 0x9A, 0xBC, 0xDE, 0xF0, 0x11, 0x22, 0x33,
+    0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0x21, 0x43, 0x65,
+];
+
+fn inputs() -> impl Iterator<Item = &'static [u8]> {
+    (0..DATA.len()).map(|i| &DATA[i..])
+}
+
+#[test]
+fn test_that_fits_in() {
+    for data in inputs() {
+        let mut u = Unstructured::new(data);
+        let x: u64 = that_fits_in::<u64, u8>(&mut u).unwrap();
+        assert!(TryTruncate::<u8>::try_truncate(x).is_some());
+        let x: i64 = that_fits_in::<i64, u16>(&mut u).unwrap();
+        assert!(TryTruncate::<u16>::try_truncate(x).is_some());
+        let x: u8 = that_fits_in::<u8, i8>(&mut u).unwrap();
+        assert!(x <= 127);
+        let x: i8 = that_fits_in::<i8, i64>(&mut u).unwrap();
+        let _ = x;
+    }
+}
+
+#[test]
+fn test_that_overflows() {
+    for data in inputs() {
+        let mut u = Unstructured::new(data);
+        let x: u64 = that_overflows::<u64, u8>(&mut u).unwrap();
+        assert!(TryTruncate::<u8>::try_truncate(x).is_none());
+        let x: i32 = that_overflows::<i32, i8>(&mut u).unwrap();
+        assert!(TryTruncate::<i8>::try_truncate(x).is_none());
+        let x: i16 = that_overflows::<i16, u32>(&mut u).unwrap();
+        assert!(x < 0);
+        let x: i128 = that_overflows::<i128, u128>(&mut u).unwrap();
+        assert!(x < 0);
+        let x: u128 = that_overflows::<u128, i128>(&mut u).unwrap();
+        assert!(x > i128::MAX as u128);
+    }
+}
+
+#[test]
+#[should_panic(expected = "every value fits")]
+fn test_that_overflows_impossible() {
+    let mut u = Unstructured::new(DATA);
+    let _ = that_overflows::<u8, u16>(&mut u);
+}
+
+#[test]
+fn test_arbitrary_impls() {
+    for data in inputs() {
+        let mut u = Unstructured::new(data);
+        let _ = U::<12>::arbitrary(&mut u).unwrap();
+        let x = I::<5>::arbitrary(&mut u).unwrap();
+        assert!(x >= I::<5>::MIN && x <= I::<5>::MAX);
+        let x = U::<3>::arbitrary(&mut u).unwrap();
+        assert!(x.get() <= 7);
+        let _ = U::<128>::arbitrary(&mut u).unwrap();
+        let _ = I::<128>::arbitrary(&mut u).unwrap();
+        let _ = TruncateError::arbitrary(&mut u).unwrap();
+    }
+}
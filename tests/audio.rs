@@ -0,0 +1,63 @@
+#![cfg(feature = "dasp")]
+
+use truncate_integer::audio::{shrink_f32_i16, shrink_i32_i16, to_i16, write_i16, Dither};
+
+#[test]
+fn test_shrink_f32_i16() {
+    assert_eq!(shrink_f32_i16(0.0), 0);
+    assert_eq!(shrink_f32_i16(-1.0), i16::MIN);
+    assert_eq!(shrink_f32_i16(1.0), i16::MAX);
+    assert_eq!(shrink_f32_i16(-7.0), i16::MIN);
+    assert_eq!(shrink_f32_i16(f32::INFINITY), i16::MAX);
+    assert_eq!(shrink_f32_i16(f32::NAN), 0);
+    // dasp truncates this to 0.
+    assert_eq!(shrink_f32_i16(0.9 / 32768.0), 1);
+}
+
+#[test]
+fn test_shrink_i32_i16() {
+    assert_eq!(shrink_i32_i16(0x1234_7FFF), 0x1234);
+    assert_eq!(shrink_i32_i16(0x1234_8000), 0x1235);
+    assert_eq!(shrink_i32_i16(i32::MAX), i16::MAX);
+    assert_eq!(shrink_i32_i16(i32::MIN), i16::MIN);
+    assert_eq!(shrink_i32_i16(-0x8001), -1);
+}
+
+#[test]
+fn test_to_i16_generic() {
+    assert_eq!(to_i16(0.5f64), 16384);
+    assert_eq!(to_i16(i32::MIN), i16::MIN);
+    assert_eq!(to_i16(-128i8), i16::MIN);
+    assert_eq!(to_i16(255u8), 32512);
+
+    let mut out = [0i16; 2];
+    write_i16(&[0.25f64, 3.0], &mut out);
+    assert_eq!(out, [8192, i16::MAX]);
+}
+
+#[test]
+#[should_panic(expected = "sample buffer length mismatch")]
+fn test_write_i16_length() {
+    write_i16(&[0.0f32; 3], &mut [0i16; 2]);
+}
+
+#[test]
+fn test_dither() {
+    let mut dither = Dither::new(1);
+    // Clipping still applies.
+    assert_eq!(dither.f32_i16(2.0), i16::MAX);
+    assert!(dither.i32_i16(i32::MIN) <= i16::MIN + 1);
+
+    // A value halfway between two steps is rounded both ways.
+    let mut counts = [0; 3];
+    for _ in 0..1000 {
+        let x = dither.i32_i16(0x0000_8000);
+        counts[x as usize] += 1;
+    }
+    assert!(counts[0] > 300 && counts[1] > 300, "{:?}", counts);
+
+    // Dither never moves a sample by more than one step.
+    let mut out = [0i16; 100];
+    dither.write_i16(&[0.5f32; 100], &mut out);
+    assert!(out.iter().all(|&x| (16383..=16385).contains(&x)));
+}
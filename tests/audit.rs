@@ -0,0 +1,63 @@
+#![cfg(feature = "audit")]
+
+use truncate_integer::bitsint::U;
+use truncate_integer::display::LowHex;
+use truncate_integer::wgsl::pack4x_u8;
+use truncate_integer::{audit, TruncateUnchecked};
+
+// The audit table is global, so everything is checked in one test.
+#[test]
+fn test_audit() {
+    audit::reset();
+
+    let lossless_line = line!() + 2;
+    for x in 0..10u32 {
+        let _: u8 = x.truncate_unchecked();
+    }
+    let lossy_line = line!() + 2;
+    for x in 250..260u32 {
+        let _: u8 = x.truncate_unchecked();
+    }
+    let bitsint_line = line!() + 1;
+    let _: U<4> = 16u8.truncate_unchecked();
+    let sign_line = line!() + 2;
+    for &x in &[-1i8, 5] {
+        let _: u16 = x.truncate_unchecked();
+    }
+
+    let report = audit::report();
+    assert_eq!(report.len(), 3, "{:?}", report);
+    assert_eq!(report[0].file(), file!());
+    assert_eq!(report[0].line(), lossy_line);
+    assert_eq!(report[0].count(), 4);
+    assert_eq!(report[1].line(), bitsint_line);
+    assert_eq!(report[1].count(), 1);
+    assert_eq!(report[2].line(), sign_line);
+    assert_eq!(report[2].count(), 1);
+    assert!(report.iter().all(|e| e.line() != lossless_line));
+
+    let mut out = Vec::new();
+    audit::write_report(&mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.starts_with(&format!("{}:{}:", file!(), lossy_line)));
+    assert!(out
+        .lines()
+        .next()
+        .unwrap()
+        .ends_with(": 4 lossy truncations"));
+
+    audit::reset();
+    assert!(audit::report().is_empty());
+
+    // Truncations inside the crate are recorded at the caller, or not
+    // at all if they're part of what the helper does.
+    let helper_line = line!() + 1;
+    let _ = LowHex::<u8>::new(0x1234u16);
+    let _ = pack4x_u8([0x100, 0x201, 0x302, 0x403]);
+    let report = audit::report();
+    assert_eq!(report.len(), 1, "{:?}", report);
+    assert_eq!(report[0].file(), file!());
+    assert_eq!(report[0].line(), helper_line);
+
+    audit::reset();
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "binrw")]
+
+use binrw::{io::Cursor, BinRead, BinWrite};
+use truncate_integer::binrw::{shrink, try_truncate};
+use truncate_integer::TruncateError;
+
+#[derive(BinRead, BinWrite, Debug)]
+#[brw(little)]
+struct Record {
+    #[br(try_map = try_truncate::<i32, _>)]
+    #[bw(map = |x: &i8| i32::from(*x))]
+    delta: i8,
+    #[br(map = shrink::<u32, _>)]
+    #[bw(try_map = |x: &u16| try_truncate::<u16, u8>(*x))]
+    count: u16,
+}
+
+#[test]
+fn test_binrw_read() {
+    let mut input = Cursor::new([0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 1, 0]);
+    let record = Record::read(&mut input).unwrap();
+    assert_eq!(record.delta, -1);
+    assert_eq!(record.count, u16::MAX);
+
+    let mut input = Cursor::new([0x00, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]);
+    let err = Record::read(&mut input).unwrap_err();
+    match err {
+        binrw::Error::Custom { err, .. } => {
+            assert_eq!(
+                err.downcast_ref::<TruncateError>(),
+                Some(&TruncateError::Underflow)
+            );
+        }
+        _ => panic!("unexpected error {:?}", err),
+    }
+}
+
+#[test]
+fn test_binrw_write() {
+    let mut output = Cursor::new(Vec::new());
+    Record {
+        delta: -2,
+        count: 7,
+    }
+    .write(&mut output)
+    .unwrap();
+    assert_eq!(output.into_inner(), [0xFE, 0xFF, 0xFF, 0xFF, 7]);
+
+    let mut output = Cursor::new(Vec::new());
+    let err = Record {
+        delta: 0,
+        count: 256,
+    }
+    .write(&mut output)
+    .unwrap_err();
+    match err {
+        binrw::Error::Custom { err, .. } => {
+            assert_eq!(
+                err.downcast_ref::<TruncateError>(),
+                Some(&TruncateError::Overflow)
+            );
+        }
+        _ => panic!("unexpected error {:?}", err),
+    }
+}
@@ -0,0 +1,100 @@
+use truncate_integer::bitfield::{shrink_field, try_field, BitfieldError};
+use truncate_integer::TruncateError;
+
+#[test]
+fn test_try_field() {
+    assert_eq!(try_field::<u8, _>(0u64, 1), Ok(0));
+    assert_eq!(try_field::<u8, _>(1u64, 1), Ok(1));
+    assert_eq!(
+        try_field::<u8, _>(2u64, 1),
+        Err(BitfieldError::new(TruncateError::Overflow, 1))
+    );
+    assert_eq!(
+        try_field::<u16, _>(-1i32, 12),
+        Err(BitfieldError::new(TruncateError::Underflow, 12))
+    );
+    assert_eq!(try_field::<u16, _>(4095i32, 12), Ok(4095));
+    assert_eq!(try_field::<u128, _>(u128::MAX, 128), Ok(u128::MAX));
+    assert_eq!(try_field::<i8, _>(-16i32, 5), Ok(-16));
+    assert_eq!(try_field::<i8, _>(15u8, 5), Ok(15));
+    assert_eq!(
+        try_field::<i8, _>(16u8, 5),
+        Err(BitfieldError::new(TruncateError::Overflow, 5))
+    );
+    assert_eq!(
+        try_field::<i8, _>(-17i64, 5),
+        Err(BitfieldError::new(TruncateError::Underflow, 5))
+    );
+    assert_eq!(try_field::<i128, _>(i128::MIN, 128), Ok(i128::MIN));
+    assert_eq!(try_field::<i8, _>(-1i32, 1), Ok(-1));
+    assert_eq!(try_field::<i8, _>(0i32, 1), Ok(0));
+    assert_eq!(
+        try_field::<i8, _>(1i32, 1),
+        Err(BitfieldError::new(TruncateError::Overflow, 1))
+    );
+    assert_eq!(
+        try_field::<i64, _>(-2i32, 1),
+        Err(BitfieldError::new(TruncateError::Underflow, 1))
+    );
+}
+
+#[test]
+fn test_shrink_field() {
+    assert_eq!(shrink_field::<u8, _>(300u32, 8), 255);
+    assert_eq!(shrink_field::<u8, _>(300u32, 3), 7);
+    assert_eq!(shrink_field::<u32, _>(-5i8, 20), 0);
+    assert_eq!(shrink_field::<i16, _>(i64::MIN, 10), -512);
+    assert_eq!(shrink_field::<i16, _>(i64::MAX, 10), 511);
+    assert_eq!(shrink_field::<i16, _>(-3i8, 10), -3);
+    assert_eq!(shrink_field::<i8, _>(5i32, 1), 0);
+    assert_eq!(shrink_field::<i8, _>(-5i32, 1), -1);
+}
+
+#[test]
+#[should_panic(expected = "invalid field width")]
+fn test_field_too_wide() {
+    let _ = try_field::<u8, _>(0u8, 9);
+}
+
+#[test]
+#[should_panic(expected = "invalid field width")]
+fn test_field_zero_width() {
+    shrink_field::<i32, _>(0u8, 0);
+}
+
+#[test]
+fn test_bitfield_error() {
+    let err = BitfieldError::new(TruncateError::Overflow, 4);
+    assert_eq!(err.error(), TruncateError::Overflow);
+    assert_eq!(err.bits(), 4);
+    assert_eq!(err.to_string(), "integer overflow in a 4-bit field");
+}
+
+#[cfg(feature = "modular-bitfield")]
+mod modular {
+    use modular_bitfield::prelude::*;
+    use truncate_integer::bitfield::{shrink_specifier, try_specifier, BitfieldError};
+    use truncate_integer::TruncateError;
+
+    #[bitfield]
+    struct Header {
+        version: B4,
+        kind: B4,
+        len: B8,
+    }
+
+    #[test]
+    fn test_specifier() {
+        let mut header = Header::new();
+        header.set_version(try_specifier::<B4, _>(3u32).unwrap());
+        header.set_kind(shrink_specifier::<B4, _>(1000u32));
+        header.set_len(try_specifier::<B8, _>(200i64).unwrap());
+        assert_eq!(header.version(), 3);
+        assert_eq!(header.kind(), 15);
+        assert_eq!(header.len(), 200);
+        assert_eq!(
+            try_specifier::<B4, _>(16u32),
+            Err(BitfieldError::new(TruncateError::Overflow, 4))
+        );
+    }
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "bitflags")]
+
+use bitflags::bitflags;
+use truncate_integer::bitflags::{narrow_flags_truncate, try_narrow_flags, FlagsError};
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Opts: u16 {
+        const A = 0x0001;
+        const B = 0x0002;
+        const HIGH = 0x8000;
+    }
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Signed: i8 {
+        const LOW = 1;
+        const SIGN = -128;
+    }
+}
+
+#[test]
+fn test_try_narrow_flags() {
+    assert_eq!(try_narrow_flags::<Opts, _>(0u64), Ok(Opts::empty()));
+    assert_eq!(try_narrow_flags(0x8003u64), Ok(Opts::all()));
+    assert_eq!(
+        try_narrow_flags::<Opts, _>(0x8007u32),
+        Err(FlagsError::new(0x4))
+    );
+    // Bits above the backing integer are unknown too.
+    assert_eq!(
+        try_narrow_flags::<Opts, _>(0x1_0001u32),
+        Err(FlagsError::new(0x1_0000))
+    );
+    assert_eq!(
+        try_narrow_flags::<Opts, _>(-1i32).unwrap_err().unknown(),
+        !0x8003
+    );
+}
+
+#[test]
+fn test_try_narrow_flags_signed_bits() {
+    assert_eq!(try_narrow_flags(0x81u8), Ok(Signed::all()));
+    assert_eq!(
+        try_narrow_flags::<Signed, _>(-127i16),
+        Err(FlagsError::new(!0xFF))
+    );
+    assert_eq!(
+        try_narrow_flags::<Signed, _>(0x181u16),
+        Err(FlagsError::new(0x100))
+    );
+}
+
+#[test]
+fn test_narrow_flags_truncate() {
+    let opts: Opts = narrow_flags_truncate(0xFFFF_FFFFu32);
+    assert_eq!(opts, Opts::all());
+    let opts: Opts = narrow_flags_truncate(0x1_0002u64);
+    assert_eq!(opts, Opts::B);
+    let signed: Signed = narrow_flags_truncate(0x1FEu16);
+    assert_eq!(signed, Signed::SIGN);
+}
+
+#[test]
+fn test_flags_error_display() {
+    assert_eq!(FlagsError::new(0x30).to_string(), "unknown flag bits 0x30");
+}
@@ -0,0 +1,98 @@
+use truncate_integer::bitsint::{I, U};
+use truncate_integer::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+
+#[test]
+fn test_bitsint_consts() {
+    assert_eq!(U::<1>::MAX.get(), 1u8);
+    assert_eq!(U::<12>::MAX.get(), 4095u16);
+    assert_eq!(U::<128>::MAX.get(), u128::MAX);
+    assert_eq!(U::<7>::MIN.get(), 0u8);
+    assert_eq!(I::<1>::MIN.get(), -1i8);
+    assert_eq!(I::<1>::MAX.get(), 0i8);
+    assert_eq!(I::<24>::MIN.get(), -0x80_0000i32);
+    assert_eq!(I::<24>::MAX.get(), 0x7F_FFFFi32);
+    assert_eq!(I::<128>::MIN.get(), i128::MIN);
+    assert_eq!(U::<33>::BITS, 33);
+    assert_eq!(std::mem::size_of::<U<33>>(), 8);
+    assert_eq!(std::mem::size_of::<I<9>>(), 2);
+}
+
+#[test]
+fn test_bitsint_from_primitive() {
+    let x: Option<U<5>> = 31u64.try_truncate();
+    assert_eq!(x, Some(U::<5>::MAX));
+    let x: Option<U<5>> = 32u64.try_truncate();
+    assert_eq!(x, None);
+    let x: Option<U<5>> = (-1i8).try_truncate();
+    assert_eq!(x, None);
+
+    let x: I<5> = (-17i64).shrink();
+    assert_eq!(x, I::<5>::MIN);
+    let x: I<5> = 16u128.shrink();
+    assert_eq!(x, I::<5>::MAX);
+    let x: U<5> = (-1i32).shrink();
+    assert_eq!(x.get(), 0);
+
+    let x: U<5> = 0x3Fu8.truncate_unchecked();
+    assert_eq!(x.get(), 0x1F);
+    let x: I<5> = 0x1Fu8.truncate_unchecked();
+    assert_eq!(x.get(), -1);
+    let x: I<5> = (-17i16).truncate_unchecked();
+    assert_eq!(x.get(), 15);
+
+    let x: I<24> = (-5i32).chop();
+    assert_eq!(x.get(), -5);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_bitsint_chop_panic() {
+    let _x: U<3> = 8u8.chop();
+}
+
+#[test]
+fn test_bitsint_to_primitive() {
+    let x: U<12> = 300u16.chop();
+    let y: Option<u8> = x.try_truncate();
+    assert_eq!(y, None);
+    let y: u8 = x.shrink();
+    assert_eq!(y, 255);
+    let y: u8 = x.truncate_unchecked();
+    assert_eq!(y, 44);
+    let y: i64 = x.chop();
+    assert_eq!(y, 300);
+
+    let x: I<12> = (-300i16).chop();
+    let y: u32 = x.shrink();
+    assert_eq!(y, 0);
+    let y: i8 = x.shrink();
+    assert_eq!(y, -128);
+    let y: i128 = x.chop();
+    assert_eq!(y, -300);
+}
+
+#[test]
+fn test_bitsint_between_widths() {
+    let x: I<12> = (-300i16).chop();
+    let y: Option<I<9>> = x.try_truncate();
+    assert_eq!(y, None);
+    let y: I<10> = x.chop();
+    assert_eq!(y.get(), -300);
+    let y: U<20> = x.shrink();
+    assert_eq!(y.get(), 0);
+    let y: U<12> = x.truncate_unchecked();
+    assert_eq!(y.get(), 4096 - 300);
+
+    let x = U::<64>::MAX;
+    let y: I<65> = x.chop();
+    assert_eq!(y.get(), u64::MAX as i128);
+    let y: I<64> = x.shrink();
+    assert_eq!(y.get(), i64::MAX);
+}
+
+#[test]
+fn test_bitsint_fmt() {
+    let x: I<7> = (-3i8).chop();
+    assert_eq!(format!("{}", x), "-3");
+    assert_eq!(format!("{:?}", x), "I(-3)");
+}
@@ -0,0 +1,32 @@
+use truncate_integer::bucket::{bucket_index, bucket_index_shift};
+
+#[test]
+fn test_bucket_index_linear() {
+    assert_eq!(bucket_index(0, 100, 10u8), 0u8);
+    assert_eq!(bucket_index(99, 100, 10u8), 0u8);
+    assert_eq!(bucket_index(100, 100, 10u8), 1u8);
+    assert_eq!(bucket_index(999, 100, 10u8), 9u8);
+    assert_eq!(bucket_index(u64::MAX, 100, 10u8), 9u8);
+    assert_eq!(bucket_index(u64::MAX, 3, u32::MAX), u32::MAX - 1);
+}
+
+#[test]
+fn test_bucket_index_pow2() {
+    assert_eq!(bucket_index(255, 256, 300u16), 0u16);
+    assert_eq!(bucket_index(256, 256, 300u16), 1u16);
+    assert_eq!(bucket_index(u64::MAX, 1, 256u16), 255u16);
+    assert_eq!(bucket_index_shift(u64::MAX, 63, 10u8), 1u8);
+    assert_eq!(bucket_index_shift(1 << 20, 0, u8::MAX), 254u8);
+}
+
+#[test]
+#[should_panic(expected = "bucket width is zero")]
+fn test_bucket_index_zero_width() {
+    bucket_index(1, 0, 10u8);
+}
+
+#[test]
+#[should_panic(expected = "number of buckets is zero")]
+fn test_bucket_index_zero_buckets() {
+    bucket_index(1, 10, 0u8);
+}
@@ -0,0 +1,272 @@
+use std::fmt::Debug;
+use truncate_integer::bulk::{
+    failed_lanes, kernel, mask_len, try_truncate_field, try_truncate_masked,
+    try_truncate_masked_with, try_truncate_strided, Kernel,
+};
+use truncate_integer::{Shrink, TryTruncate};
+
+const KERNELS: [Kernel; 5] = [
+    Kernel::Scalar,
+    Kernel::Swar,
+    Kernel::Sse41,
+    Kernel::Avx2,
+    Kernel::Avx512,
+];
+
+#[test]
+fn test_mask_len() {
+    assert_eq!(mask_len(0), 0);
+    assert_eq!(mask_len(1), 1);
+    assert_eq!(mask_len(64), 1);
+    assert_eq!(mask_len(65), 2);
+}
+
+#[test]
+fn test_try_truncate_masked() {
+    let src: Vec<i32> = (-100..200).collect();
+    let mut dst = vec![0u8; src.len()];
+    let mut mask = vec![0u64; mask_len(src.len())];
+    assert_eq!(try_truncate_masked(&src, &mut dst, &mut mask), 100);
+    let failed: Vec<usize> = failed_lanes(&mask, src.len()).collect();
+    assert_eq!(failed, (0..100).collect::<Vec<_>>());
+    assert!(dst[..100].iter().all(|&x| x == 0));
+    assert_eq!(dst[100..], (0..200).collect::<Vec<u8>>()[..]);
+}
+
+#[test]
+fn test_try_truncate_masked_lanes() {
+    // Failures in different words, including the last partial one.
+    let mut src = vec![0u64; 200];
+    for &lane in &[0, 63, 64, 127, 150, 199] {
+        src[lane] = 1 << 40;
+    }
+    let mut dst = vec![0i16; 200];
+    let mut mask = [u64::MAX; 5];
+    assert_eq!(try_truncate_masked(&src, &mut dst, &mut mask), 6);
+    assert!(failed_lanes(&mask, 200).eq(vec![0, 63, 64, 127, 150, 199]));
+    assert_eq!(dst[63], i16::MAX);
+    // Words past the end are left alone.
+    assert_eq!(mask[4], u64::MAX);
+}
+
+#[test]
+fn test_failed_lanes_empty() {
+    assert_eq!(failed_lanes(&[], 0).next(), None);
+    assert_eq!(failed_lanes(&[0, 0], 128).next(), None);
+    assert!(failed_lanes(&[0, 1 << 5, 0, 1], 256).eq(vec![69, 192]));
+}
+
+#[test]
+fn test_failed_lanes_reused_mask() {
+    // Stale bits past the last lane, and in words past the end, are
+    // ignored.
+    let mask = [1 << 3 | 1 << 10, u64::MAX, u64::MAX];
+    assert!(failed_lanes(&mask, 10).eq(vec![3]));
+    assert!(failed_lanes(&mask, 11).eq(vec![3, 10]));
+    assert!(failed_lanes(&mask, 66).eq(vec![3, 10, 64, 65]));
+    assert_eq!(failed_lanes(&mask, 0).next(), None);
+}
+
+#[test]
+#[should_panic(expected = "mask buffer too short")]
+fn test_failed_lanes_short() {
+    failed_lanes(&[0], 65);
+}
+
+#[test]
+#[should_panic(expected = "bulk buffer length mismatch")]
+fn test_length_mismatch() {
+    let mut mask = [0u64; 1];
+    try_truncate_masked(&[1u32, 2], &mut [0u8; 3], &mut mask);
+}
+
+#[test]
+#[should_panic(expected = "mask buffer too short")]
+fn test_mask_too_short() {
+    let mut mask = [0u64; 1];
+    try_truncate_masked(&[1u32; 65], &mut [0u8; 65], &mut mask);
+}
+
+#[test]
+fn test_masked_matches_scalar() {
+    let mut x = 0x2545_F491_4F6C_DD1Du64;
+    let src: Vec<i64> = (0..1000)
+        .map(|_| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            (x as i64) >> (x % 64)
+        })
+        .collect();
+    let mut dst = vec![0i16; src.len()];
+    let mut mask = vec![0u64; mask_len(src.len())];
+    let failed = try_truncate_masked(&src, &mut dst, &mut mask);
+
+    let expected: Vec<usize> = (0..src.len())
+        .filter(|&i| TryTruncate::<i16>::try_truncate(src[i]).is_none())
+        .collect();
+    assert_eq!(failed, expected.len());
+    assert_eq!(failed_lanes(&mask, src.len()).collect::<Vec<_>>(), expected);
+    for (&s, &d) in src.iter().zip(&dst) {
+        assert_eq!(Shrink::<i16>::shrink(s), d);
+    }
+}
+
+#[test]
+fn test_try_truncate_strided() {
+    // Interleaved (x, y) pairs; take the y values.
+    let src = [0u32, 10, 1, 300, 2, 20, 3, 70000];
+    let mut dst = [0u8; 4];
+    let mut mask = [0u64; 1];
+    assert_eq!(try_truncate_strided(&src, 1, 2, &mut dst, &mut mask), 2);
+    assert_eq!(dst, [10, 255, 20, 255]);
+    assert_eq!(failed_lanes(&mask, 4).collect::<Vec<_>>(), [1, 3]);
+
+    let src: Vec<i32> = (0..1000).collect();
+    let mut dst = vec![0i8; 100];
+    let mut mask = vec![0u64; mask_len(100)];
+    assert_eq!(try_truncate_strided(&src, 7, 10, &mut dst, &mut mask), 87);
+    assert_eq!(dst[..3], [7, 17, 27]);
+    assert_eq!(failed_lanes(&mask, 100).next(), Some(13));
+
+    // An empty destination reads nothing.
+    assert_eq!(
+        try_truncate_strided::<u32, u8>(&[], 5, 3, &mut [], &mut []),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "strided source too short")]
+fn test_try_truncate_strided_short() {
+    let src = [0u32; 8];
+    try_truncate_strided::<u32, u8>(&src, 1, 2, &mut [0; 5], &mut [0]);
+}
+
+#[test]
+#[should_panic(expected = "invalid stride")]
+fn test_try_truncate_strided_zero() {
+    try_truncate_strided::<u32, u8>(&[0; 8], 0, 0, &mut [0; 2], &mut [0]);
+}
+
+#[test]
+fn test_try_truncate_field() {
+    // The green channel of packed 0xAARRGGBB pixels, as 6 bits.
+    let pixels = [0xFF11_2233u32, 0xFF00_3F00, 0x0000_FF00];
+    let mut green = [0u8; 3];
+    let mut mask = [0u64; 1];
+    let failed = try_truncate_field(
+        &pixels,
+        |&p| (p >> 8 & 0xFF) as i32 - 0x10,
+        &mut green,
+        &mut mask,
+    );
+    assert_eq!(failed, 0);
+    assert_eq!(green, [0x12, 0x2F, 0xEF]);
+
+    struct Record {
+        id: u64,
+        level: i32,
+    }
+    let records = [Record { id: 1, level: -5 }, Record { id: 2, level: 200 }];
+    let mut levels = [0i8; 2];
+    assert_eq!(
+        try_truncate_field(&records, |r| r.level, &mut levels, &mut mask),
+        1
+    );
+    assert_eq!(levels, [-5, 127]);
+    assert_eq!(records[1].id, 2);
+}
+
+#[test]
+fn test_kernel() {
+    assert!(kernel().is_supported());
+    assert_ne!(kernel(), Kernel::Scalar);
+    if !cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+        assert_eq!(kernel(), Kernel::Swar);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "avx512"))]
+#[should_panic(expected = "unsupported bulk kernel")]
+fn test_unsupported_kernel() {
+    let mut mask = [0u64; 1];
+    try_truncate_masked_with(Kernel::Avx512, &[1u32], &mut [0u8], &mut mask);
+}
+
+/// Check every supported kernel against `Shrink` and `TryTruncate`, one
+/// lane at a time.
+fn check_kernels<S, D>(src: &[S])
+where
+    S: TryTruncate<D> + Shrink<D> + Copy + Debug,
+    D: Copy + Default + PartialEq + Debug,
+{
+    let expected: Vec<usize> = (0..src.len())
+        .filter(|&i| TryTruncate::<D>::try_truncate(src[i]).is_none())
+        .collect();
+    for &kernel in KERNELS.iter().filter(|kernel| kernel.is_supported()) {
+        let mut dst = vec![D::default(); src.len()];
+        // Stale bits, which have to be overwritten.
+        let mut mask = vec![u64::MAX; mask_len(src.len())];
+        let failed = try_truncate_masked_with(kernel, src, &mut dst, &mut mask);
+        assert_eq!(failed, expected.len(), "{:?}", kernel);
+        assert!(
+            failed_lanes(&mask, src.len()).eq(expected.iter().copied()),
+            "{:?}",
+            kernel
+        );
+        for (&s, &d) in src.iter().zip(&dst) {
+            assert_eq!(Shrink::<D>::shrink(s), d, "{:?} {:?}", kernel, s);
+        }
+    }
+}
+
+#[test]
+fn test_kernels_match_scalar() {
+    macro_rules! check {
+        ($($Source:ident => $Dest:ident),*) => {$({
+            let edges = [
+                0,
+                1,
+                -1,
+                $Dest::MIN as i128 - 1,
+                $Dest::MIN as i128,
+                $Dest::MIN as i128 + 1,
+                $Dest::MAX as i128 - 1,
+                $Dest::MAX as i128,
+                $Dest::MAX as i128 + 1,
+                $Source::MIN as i128,
+                $Source::MAX as i128,
+            ];
+            let mut x = 0x2545_F491_4F6C_DD1Du64;
+            let src: Vec<$Source> = (0..1000)
+                .map(|i| {
+                    x ^= x << 13;
+                    x ^= x >> 7;
+                    x ^= x << 17;
+                    if x % 3 == 0 {
+                        edges[i % edges.len()] as $Source
+                    } else {
+                        (x as $Source) >> (x % u64::from($Source::BITS))
+                    }
+                })
+                .collect();
+            for &len in &[0, 1, 15, 16, 63, 64, 65, 128, 200, 1000] {
+                check_kernels::<$Source, $Dest>(&src[..len]);
+            }
+            // Every value, for the 16-bit sources.
+            if core::mem::size_of::<$Source>() == 2 {
+                let all: Vec<$Source> = ($Source::MIN..=$Source::MAX).collect();
+                check_kernels::<$Source, $Dest>(&all);
+            }
+        })*};
+    }
+    check!(
+        u32 => u16, u32 => i16, i32 => u16, i32 => i16,
+        u32 => u8, u32 => i8, i32 => u8, i32 => i8,
+        u16 => u8, u16 => i8, i16 => u8, i16 => i8
+    );
+    // Pairs without a kernel use the scalar loop with any of them.
+    check!(u64 => u32, i64 => i8, u8 => i8, i16 => u32);
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "byteorder")]
+
+use byteorder::{BigEndian, LittleEndian};
+use std::io::ErrorKind;
+use truncate_integer::byteorder::WriteNarrowExt;
+use truncate_integer::TruncateError;
+
+#[test]
+fn test_write_try() {
+    let mut out = Vec::new();
+    out.write_u8_try(200u64).unwrap();
+    out.write_i8_try(-1i32).unwrap();
+    out.write_u16_try::<BigEndian, _>(0x1234u32).unwrap();
+    out.write_u32_try::<LittleEndian, _>(0x1234_5678u64)
+        .unwrap();
+    out.write_i64_try::<BigEndian, _>(-2i128).unwrap();
+    assert_eq!(
+        out,
+        [
+            200, 0xFF, 0x12, 0x34, 0x78, 0x56, 0x34, 0x12, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+            0xFF, 0xFE
+        ]
+    );
+}
+
+#[test]
+fn test_write_try_error() {
+    let mut out = Vec::new();
+    let err = out.write_u16_try::<BigEndian, _>(-1i32).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    let inner = err.into_inner().unwrap();
+    assert_eq!(
+        inner.downcast_ref::<TruncateError>(),
+        Some(&TruncateError::Underflow)
+    );
+    let err = out.write_i32_try::<LittleEndian, _>(u64::MAX).unwrap_err();
+    assert_eq!(err.to_string(), "integer overflow");
+    // Nothing is written if the value doesn't fit.
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_write_shrink() {
+    let mut out = Vec::new();
+    out.write_u8_shrink(-5i16).unwrap();
+    out.write_i16_shrink::<LittleEndian, _>(40000u32).unwrap();
+    out.write_u64_shrink::<BigEndian, _>(u128::MAX).unwrap();
+    assert_eq!(
+        out,
+        [0, 0xFF, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+    );
+}
+
+#[test]
+fn test_write_chop() {
+    let mut out = Vec::new();
+    out.write_u32_chop::<BigEndian, _>(7u64).unwrap();
+    out.write_i8_chop(-7i64).unwrap();
+    assert_eq!(out, [0, 0, 0, 7, 0xF9]);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_write_chop_overflow() {
+    let mut out = Vec::new();
+    let _ = out.write_u16_chop::<LittleEndian, _>(65536u32);
+}
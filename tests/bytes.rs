@@ -0,0 +1,82 @@
+use std::convert::TryInto;
+use truncate_integer::bytes::FromBytesTruncate;
+
+#[test]
+fn test_unsigned_be() {
+    assert_eq!(u16::try_truncate_from_be_bytes(&[]), Some(0));
+    assert_eq!(u16::try_truncate_from_be_bytes(&[0x12]), Some(0x12));
+    assert_eq!(u16::try_truncate_from_be_bytes(&[0x12, 0x34]), Some(0x1234));
+    assert_eq!(
+        u16::try_truncate_from_be_bytes(&[0, 0, 0x12, 0x34]),
+        Some(0x1234)
+    );
+    assert_eq!(u16::try_truncate_from_be_bytes(&[1, 0, 0x12, 0x34]), None);
+    assert_eq!(u16::try_truncate_from_be_bytes(&[0xFF, 0xFF, 0xFF]), None);
+
+    assert_eq!(u16::shrink_from_be_bytes(&[0, 0, 0x12, 0x34]), 0x1234);
+    assert_eq!(u16::shrink_from_be_bytes(&[1, 0, 0x12, 0x34]), u16::MAX);
+    assert_eq!(u16::wrap_from_be_bytes(&[1, 0, 0x12, 0x34]), 0x1234);
+    assert_eq!(u128::wrap_from_be_bytes(&[0xFF; 20]), u128::MAX);
+}
+
+#[test]
+fn test_unsigned_le() {
+    assert_eq!(u16::try_truncate_from_le_bytes(&[]), Some(0));
+    assert_eq!(u16::try_truncate_from_le_bytes(&[0x12]), Some(0x12));
+    assert_eq!(
+        u16::try_truncate_from_le_bytes(&[0x34, 0x12, 0, 0]),
+        Some(0x1234)
+    );
+    assert_eq!(u16::try_truncate_from_le_bytes(&[0x34, 0x12, 0, 1]), None);
+
+    assert_eq!(u16::shrink_from_le_bytes(&[0x34, 0x12, 0, 1]), u16::MAX);
+    assert_eq!(u16::wrap_from_le_bytes(&[0x34, 0x12, 0, 1]), 0x1234);
+    assert_eq!(u32::try_truncate_from_le_bytes(&[0xFF; 24]), None);
+}
+
+#[test]
+fn test_signed_be() {
+    assert_eq!(
+        i8::try_truncate_from_be_bytes(&[0xFF, 0xFF, 0x80]),
+        Some(-128)
+    );
+    assert_eq!(
+        i8::try_truncate_from_be_bytes(&[0x00, 0x00, 0x7F]),
+        Some(127)
+    );
+    assert_eq!(i8::try_truncate_from_be_bytes(&[0x00, 0x00, 0x80]), None);
+    assert_eq!(i8::try_truncate_from_be_bytes(&[0xFF, 0xFF, 0x7F]), None);
+    assert_eq!(i32::try_truncate_from_be_bytes(&[0xFE]), Some(-2));
+    assert_eq!(i32::try_truncate_from_be_bytes(&[0x7F, 0xFE]), Some(0x7FFE));
+
+    assert_eq!(i8::shrink_from_be_bytes(&[0x00, 0x00, 0x80]), i8::MAX);
+    assert_eq!(i8::shrink_from_be_bytes(&[0xFF, 0xFF, 0x7F]), i8::MIN);
+    assert_eq!(i8::shrink_from_be_bytes(&[0x80, 0x00, 0x00]), i8::MIN);
+    assert_eq!(i8::wrap_from_be_bytes(&[0x00, 0x00, 0x80]), -128);
+}
+
+#[test]
+fn test_signed_le() {
+    assert_eq!(
+        i8::try_truncate_from_le_bytes(&[0x80, 0xFF, 0xFF]),
+        Some(-128)
+    );
+    assert_eq!(i8::try_truncate_from_le_bytes(&[0x80, 0x00, 0x00]), None);
+    assert_eq!(i32::try_truncate_from_le_bytes(&[0xFE]), Some(-2));
+
+    assert_eq!(i8::shrink_from_le_bytes(&[0x80, 0x00, 0x00]), i8::MAX);
+    assert_eq!(i8::shrink_from_le_bytes(&[0x7F, 0xFF, 0xFF]), i8::MIN);
+    assert_eq!(i8::wrap_from_le_bytes(&[0x7F, 0xFF, 0xFF]), 127);
+}
+
+#[test]
+fn test_round_trip() {
+    for &x in &[0i64, 1, -1, 1000, -1000, i64::MAX, i64::MIN] {
+        let be = x.to_be_bytes();
+        let le = x.to_le_bytes();
+        assert_eq!(i16::try_truncate_from_be_bytes(&be), x.try_into().ok());
+        assert_eq!(i16::try_truncate_from_le_bytes(&le), x.try_into().ok());
+        assert_eq!(i128::try_truncate_from_be_bytes(&be), Some(x as i128));
+        assert_eq!(i16::wrap_from_be_bytes(&be), x as i16);
+    }
+}
@@ -0,0 +1,45 @@
+use truncate_integer::can::{CanExtId, CanStdId};
+use truncate_integer::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+
+#[test]
+fn test_can_std_id() {
+    assert_eq!(CanStdId::new(0x7FF), Some(CanStdId::MAX));
+    assert_eq!(CanStdId::new(0x800), None);
+    assert_eq!(CanStdId::new(0).map(CanStdId::raw), Some(0));
+    assert_eq!(CanStdId::BITS, 11);
+
+    let id: Option<CanStdId> = (-1i32).try_truncate();
+    assert_eq!(id, None);
+    let id: CanStdId = (-1i32).shrink();
+    assert_eq!(id, CanStdId::MIN);
+    let id: CanStdId = 0x1234u16.shrink();
+    assert_eq!(id, CanStdId::MAX);
+    let id: CanStdId = 0x1234u16.truncate_unchecked();
+    assert_eq!(id.raw(), 0x234);
+    let id: CanStdId = 0x123u64.chop();
+    assert_eq!(u32::from(id), 0x123);
+    assert_eq!(i64::from(id), 0x123);
+}
+
+#[test]
+fn test_can_ext_id() {
+    assert_eq!(CanExtId::new(0x1FFF_FFFF), Some(CanExtId::MAX));
+    assert_eq!(CanExtId::new(0x2000_0000), None);
+    let id: Option<CanExtId> = 0x18DA_F110u64.try_truncate();
+    assert_eq!(id.map(u32::from), Some(0x18DA_F110));
+    let id: CanExtId = i128::MIN.shrink();
+    assert_eq!(id, CanExtId::MIN);
+    assert!(CanExtId::new(1) < CanExtId::new(2));
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_can_chop_overflow() {
+    let _: CanStdId = 0x800u32.chop();
+}
+
+#[test]
+fn test_can_display() {
+    assert_eq!(CanStdId::new(0x7DF).unwrap().to_string(), "0x7df");
+    assert_eq!(CanExtId::default().to_string(), "0x0");
+}
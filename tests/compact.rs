@@ -0,0 +1,85 @@
+use truncate_integer::compact::{decode, encode, encoded_len, CompactError, MAX_ENCODED_LEN};
+use truncate_integer::TruncateError;
+
+#[test]
+fn test_round_trip() {
+    let values = [
+        0i128,
+        1,
+        -1,
+        127,
+        128,
+        -128,
+        -129,
+        255,
+        256,
+        i64::MIN as i128,
+        u64::MAX as i128,
+        i128::MAX,
+        i128::MIN,
+    ];
+    let mut buf = [0u8; MAX_ENCODED_LEN];
+    for &x in &values {
+        let len = encode(x, &mut buf).unwrap();
+        assert_eq!(len, encoded_len(x));
+        assert_eq!(decode::<i128>(&buf), Ok((x, len)));
+    }
+
+    let len = encode(u128::MAX, &mut buf).unwrap();
+    assert_eq!(len, MAX_ENCODED_LEN);
+    assert_eq!(decode::<u128>(&buf), Ok((u128::MAX, len)));
+    assert_eq!(
+        decode::<i128>(&buf),
+        Err(CompactError::Truncate(TruncateError::Overflow))
+    );
+}
+
+#[test]
+fn test_lengths() {
+    assert_eq!(encoded_len(0u8), 1);
+    assert_eq!(encoded_len(0i64), 1);
+    assert_eq!(encoded_len(255u64), 2);
+    assert_eq!(encoded_len(255i64), 2);
+    assert_eq!(encoded_len(-128i64), 2);
+    assert_eq!(encoded_len(-129i64), 3);
+    assert_eq!(encoded_len(u32::MAX), 5);
+}
+
+#[test]
+fn test_cross_type() {
+    let mut buf = [0u8; MAX_ENCODED_LEN];
+    encode(200u64, &mut buf).unwrap();
+    assert_eq!(decode::<u8>(&buf), Ok((200, 2)));
+    assert_eq!(decode::<i16>(&buf), Ok((200, 2)));
+    assert_eq!(
+        decode::<i8>(&buf),
+        Err(CompactError::Truncate(TruncateError::Overflow))
+    );
+
+    encode(-5i8, &mut buf).unwrap();
+    assert_eq!(decode::<i64>(&buf), Ok((-5, 2)));
+    assert_eq!(
+        decode::<u64>(&buf),
+        Err(CompactError::Truncate(TruncateError::Underflow))
+    );
+}
+
+#[test]
+fn test_errors() {
+    let mut buf = [0u8; 2];
+    assert_eq!(encode(256u16, &mut buf), Err(CompactError::BufferTooShort));
+    assert_eq!(decode::<u8>(&[]), Err(CompactError::BufferTooShort));
+    assert_eq!(decode::<u16>(&[2, 0]), Err(CompactError::BufferTooShort));
+    assert_eq!(decode::<u8>(&[17]), Err(CompactError::InvalidHeader(17)));
+    assert_eq!(decode::<i16>(&[0x81, 0x05]), Ok((-251, 2)));
+    let mut positive = [0u8; 17];
+    positive[0] = 0x90;
+    assert_eq!(
+        decode::<i128>(&positive),
+        Err(CompactError::InvalidHeader(0x90))
+    );
+    assert_eq!(
+        CompactError::InvalidHeader(0x81).to_string(),
+        "invalid header byte 0x81"
+    );
+}
@@ -0,0 +1,93 @@
+use truncate_integer::companding::{
+    alaw_decode, alaw_encode, log_expand, log_shrink, mulaw_decode, mulaw_encode,
+};
+
+#[test]
+fn test_mulaw() {
+    assert_eq!(mulaw_encode(0), 0xFF);
+    assert_eq!(mulaw_encode(-1), 0x7F);
+    assert_eq!(mulaw_encode(i16::MAX), 0x80);
+    assert_eq!(mulaw_encode(i16::MIN), 0x00);
+    assert_eq!(mulaw_decode(0xFF), 0);
+    assert_eq!(mulaw_decode(0x80), 32124);
+    assert_eq!(mulaw_decode(0x00), -32124);
+
+    for code in 0..=255u8 {
+        let sample = mulaw_decode(code);
+        // 0x7F is negative zero.
+        let expected = if code == 0x7F { 0xFF } else { code };
+        assert_eq!(mulaw_encode(sample), expected, "{:#04x}", code);
+    }
+}
+
+#[test]
+fn test_alaw() {
+    assert_eq!(alaw_encode(0), 0xD5);
+    assert_eq!(alaw_encode(-1), 0x55);
+    assert_eq!(alaw_encode(i16::MAX), 0xAA);
+    assert_eq!(alaw_encode(i16::MIN), 0x2A);
+    assert_eq!(alaw_decode(0xD5), 8);
+    assert_eq!(alaw_decode(0x55), -8);
+    assert_eq!(alaw_decode(0xAA), 32256);
+    assert_eq!(alaw_decode(0x2A), -32256);
+
+    for code in 0..=255u8 {
+        assert_eq!(alaw_encode(alaw_decode(code)), code, "{:#04x}", code);
+    }
+}
+
+#[test]
+fn test_companding_is_monotonic() {
+    let mut last_mu = i16::MIN;
+    let mut last_a = i16::MIN;
+    for sample in i16::MIN..=i16::MAX {
+        let mu = mulaw_decode(mulaw_encode(sample));
+        let a = alaw_decode(alaw_encode(sample));
+        assert!(mu >= last_mu && a >= last_a, "{}", sample);
+        last_mu = mu;
+        last_a = a;
+    }
+}
+
+#[test]
+fn test_log_shrink_exact_range() {
+    for value in 0..16u64 {
+        let code: u8 = log_shrink(value, 3);
+        assert_eq!(u64::from(code), value);
+        assert_eq!(log_expand(code, 3), value);
+    }
+    let code: u8 = log_shrink(16, 3);
+    assert_eq!(log_expand(code, 3), 16);
+    let code: u8 = log_shrink(17, 3);
+    assert_eq!(log_expand(code, 3), 18);
+    let code: u8 = log_shrink(19, 3);
+    assert_eq!(log_expand(code, 3), 20);
+}
+
+#[test]
+fn test_log_shrink_relative_error() {
+    let mut x = 0x0123_4567_89AB_CDEFu64;
+    for _ in 0..100_000 {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        let value = x >> (x % 64);
+        for &bits in &[2u32, 5, 12] {
+            let code: u32 = log_shrink(value, bits);
+            let decoded = log_expand(code, bits);
+            let error = (decoded as f64 - value as f64).abs() / value.max(1) as f64;
+            assert!(error <= 0.5f64.powi(bits as i32 + 1), "{} {}", value, bits);
+        }
+    }
+}
+
+#[test]
+fn test_log_shrink_saturates() {
+    let code: u8 = log_shrink(u64::MAX, 3);
+    assert_eq!(code, u8::MAX);
+    let code: u8 = log_shrink(u64::MAX, 2);
+    assert_eq!(log_expand(code, 2), u64::MAX);
+    let code: u16 = log_shrink(u64::MAX, 3);
+    assert_eq!(log_expand(code, 3), u64::MAX);
+    assert_eq!(log_expand(u128::MAX, 3), u64::MAX);
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "conformance")]
+
+use truncate_integer::conformance::{table, write_csv, write_json, Mode, Outcome, Row};
+
+fn find<'a>(rows: &'a [Row], source: &str, dest: &str, mode: Mode, input: &str) -> &'a Row {
+    rows.iter()
+        .find(|r| {
+            r.source == source && r.dest == dest && r.mode == mode && r.input.to_string() == input
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_table() {
+    let rows = table();
+
+    let row = find(
+        &rows,
+        "i128",
+        "i8",
+        Mode::Shrink,
+        "-170141183460469231731687303715884105728",
+    );
+    assert_eq!(row.output, Outcome::Value(i8::MIN.into()));
+    let row = find(&rows, "u128", "u64", Mode::Try, "18446744073709551616");
+    assert_eq!(row.output, Outcome::None);
+    let row = find(&rows, "u32", "i16", Mode::Unchecked, "32768");
+    assert_eq!(row.output, Outcome::Value((-32768i16).into()));
+    let row = find(&rows, "i16", "u8", Mode::Chop, "-1");
+    assert_eq!(row.output, Outcome::Panic);
+
+    // Chop panics exactly where TryTruncate fails, and Shrink agrees
+    // with TryTruncate wherever it succeeds.
+    for row in rows.iter().filter(|r| r.mode == Mode::Try) {
+        let mode = |mode| find(&rows, row.source, row.dest, mode, &row.input.to_string()).output;
+        match row.output {
+            Outcome::None => assert_eq!(mode(Mode::Chop), Outcome::Panic),
+            value => {
+                assert_eq!(mode(Mode::Chop), value);
+                assert_eq!(mode(Mode::Shrink), value);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_write() {
+    let rows = table();
+
+    let mut csv = Vec::new();
+    write_csv(&mut csv, &rows).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(lines.next(), Some("source,dest,mode,input,output"));
+    assert_eq!(lines.next(), Some("u8,u8,try,0,0"));
+    assert_eq!(csv.lines().count(), rows.len() + 1);
+
+    let mut json = Vec::new();
+    write_json(&mut json, &rows).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    let json = json.as_array().unwrap();
+    assert_eq!(json.len(), rows.len());
+    assert_eq!(
+        json[0],
+        serde_json::json!({
+            "source": "u8",
+            "dest": "u8",
+            "mode": "try",
+            "input": "0",
+            "output": "0",
+        })
+    );
+}
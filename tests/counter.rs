@@ -0,0 +1,61 @@
+use truncate_integer::counter::SaturatingSlice;
+
+#[test]
+fn test_bump_saturating() {
+    let mut counts = [0u8, 254, 255];
+    for idx in 0..3 {
+        counts.bump_saturating(idx);
+    }
+    assert_eq!(counts, [1, 255, 255]);
+
+    let mut counts = [u64::MAX - 1; 2];
+    counts.bump_saturating(0);
+    counts.bump_saturating(0);
+    assert_eq!(counts, [u64::MAX, u64::MAX - 1]);
+}
+
+#[test]
+fn test_add_saturating() {
+    let mut counts = [0u16; 3];
+    counts.add_saturating(0, 65535);
+    counts.add_saturating(1, 65536);
+    counts.add_saturating(2, 7);
+    counts.add_saturating(2, 7);
+    assert_eq!(counts, [65535, 65535, 14]);
+
+    let mut counts = [u32::MAX - 5, 0];
+    counts.add_saturating(0, u32::MAX);
+    counts.add_saturating(1, u32::MAX);
+    assert_eq!(counts, [u32::MAX, u32::MAX]);
+
+    let mut counts = [u64::MAX - 5];
+    counts.add_saturating(0, 3);
+    assert_eq!(counts, [u64::MAX - 2]);
+    counts.add_saturating(0, 3);
+    assert_eq!(counts, [u64::MAX]);
+}
+
+#[test]
+fn test_merge_saturating() {
+    let mut counts = [1u8, 200, 255, 0];
+    counts.merge_saturating(&[1, 100, 1, 0]);
+    assert_eq!(counts, [2, 255, 255, 0]);
+
+    let mut counts = [u16::MAX, 1];
+    counts[..1].merge_saturating(&[5]);
+    assert_eq!(counts, [u16::MAX, 1]);
+}
+
+#[test]
+#[should_panic(expected = "counter slice length mismatch")]
+fn test_merge_length_mismatch() {
+    let mut counts = [0u32; 3];
+    counts.merge_saturating(&[0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn test_bump_out_of_bounds() {
+    let mut counts = [0u8; 3];
+    counts.bump_saturating(3);
+}
@@ -0,0 +1,52 @@
+use truncate_integer::decimal::{first_decimal_digits, last_decimal_digits};
+
+#[test]
+fn test_last_decimal_digits() {
+    let d = last_decimal_digits::<u16, _>(1_000_123u32, 4).unwrap();
+    assert_eq!((d.value(), d.width(), d.leading_zeros()), (123, 4, 1));
+    assert_eq!(d.to_string(), "0123");
+
+    let d = last_decimal_digits::<u32, _>(1_000_000u64, 6).unwrap();
+    assert_eq!((d.value(), d.leading_zeros()), (0, 6));
+    assert_eq!(d.to_string(), "000000");
+
+    let d = last_decimal_digits::<i8, _>(-1234i32, 2).unwrap();
+    assert_eq!(d.value(), 34);
+    let d = last_decimal_digits::<u128, _>(i128::MIN, 38).unwrap();
+    assert_eq!(d.value(), i128::MIN.unsigned_abs() % 10u128.pow(38));
+    let d = last_decimal_digits::<u8, _>(12345u32, 0).unwrap();
+    assert_eq!((d.value(), d.to_string()), (0, String::new()));
+}
+
+#[test]
+fn test_last_decimal_digits_capacity() {
+    assert!(last_decimal_digits::<u8, _>(0u8, 2).is_some());
+    assert!(last_decimal_digits::<u8, _>(0u8, 3).is_none());
+    assert!(last_decimal_digits::<i8, _>(0u8, 3).is_none());
+    assert!(last_decimal_digits::<u16, _>(0u8, 4).is_some());
+    assert!(last_decimal_digits::<u16, _>(0u8, 5).is_none());
+    assert!(last_decimal_digits::<u64, _>(0u8, 19).is_some());
+    assert!(last_decimal_digits::<u64, _>(0u8, 20).is_none());
+    assert!(last_decimal_digits::<u128, _>(0u8, 38).is_some());
+    assert!(last_decimal_digits::<u128, _>(u128::MAX, 39).is_none());
+}
+
+#[test]
+fn test_first_decimal_digits() {
+    let d = first_decimal_digits::<u16, _>(1_000_123u32, 3).unwrap();
+    assert_eq!((d.value(), d.width(), d.leading_zeros()), (100, 3, 0));
+
+    let d = first_decimal_digits::<u32, _>(42u8, 6).unwrap();
+    assert_eq!((d.value(), d.width()), (42, 2));
+    assert_eq!(d.to_string(), "42");
+
+    let d = first_decimal_digits::<u8, _>(0u64, 2).unwrap();
+    assert_eq!((d.value(), d.width(), d.leading_zeros()), (0, 1, 1));
+    assert_eq!(d.to_string(), "0");
+
+    let d = first_decimal_digits::<u8, _>(-98_765i64, 2).unwrap();
+    assert_eq!(d.value(), 98);
+    let d = first_decimal_digits::<u64, _>(u128::MAX, 19).unwrap();
+    assert_eq!(d.value(), 3_402_823_669_209_384_634);
+    assert!(first_decimal_digits::<u8, _>(1u8, 3).is_none());
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "deku")]
+
+use deku::prelude::*;
+
+#[derive(DekuRead, Debug)]
+#[deku(endian = "little")]
+struct Record {
+    #[deku(map = "truncate_integer::deku::try_truncate::<i32, _>")]
+    delta: i8,
+    #[deku(map = "truncate_integer::deku::shrink::<u32, _>")]
+    count: u16,
+}
+
+#[test]
+fn test_deku_read() {
+    let input = [0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 1, 0];
+    let (_, record) = Record::from_bytes((&input, 0)).unwrap();
+    assert_eq!(record.delta, -1);
+    assert_eq!(record.count, u16::MAX);
+
+    let input = [0x80, 0, 0, 0, 0, 0, 0, 0];
+    let err = Record::from_bytes((&input, 0)).unwrap_err();
+    assert!(matches!(err, DekuError::Parse(_)));
+}
@@ -0,0 +1,36 @@
+use truncate_integer::delta::{delta_chop, delta_shrink, try_delta};
+
+#[test]
+fn test_try_delta() {
+    assert_eq!(try_delta::<u8, _>(300u64, 45u64), Some(255));
+    assert_eq!(try_delta::<u8, _>(301u64, 45u64), None);
+    assert_eq!(try_delta::<u16, _>(45u64, 46u64), None);
+    assert_eq!(try_delta::<i16, _>(0usize, 32768usize), Some(i16::MIN));
+    assert_eq!(try_delta::<i16, _>(0usize, 32769usize), None);
+    assert_eq!(try_delta::<i16, _>(32767u32, 0u32), Some(i16::MAX));
+    assert_eq!(try_delta::<i64, _>(0u64, u64::MAX), None);
+    assert_eq!(try_delta::<i64, _>(u64::MAX, 1u64 << 63), Some(i64::MAX));
+    assert_eq!(try_delta::<u64, _>(u64::MAX, 0u64), Some(u64::MAX));
+    assert_eq!(try_delta::<i8, _>(7u8, 7u8), Some(0));
+}
+
+#[test]
+fn test_delta_shrink() {
+    assert_eq!(delta_shrink::<u8, _>(10u64, 20u64), 0);
+    assert_eq!(delta_shrink::<u8, _>(1000u64, 20u64), 255);
+    assert_eq!(delta_shrink::<i8, _>(10u64, 20u64), -10);
+    assert_eq!(delta_shrink::<i8, _>(0u64, u64::MAX), i8::MIN);
+    assert_eq!(delta_shrink::<i32, _>(u64::MAX, 0u64), i32::MAX);
+}
+
+#[test]
+fn test_delta_chop() {
+    assert_eq!(delta_chop::<i16, _>(100usize, 300usize), -200);
+    assert_eq!(delta_chop::<u32, _>(300usize, 100usize), 200);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_delta_chop_negative() {
+    delta_chop::<u16, _>(100usize, 300usize);
+}
@@ -0,0 +1,41 @@
+use truncate_integer::display::{HighHex, LowBits, LowHex};
+
+#[test]
+fn test_low_hex() {
+    let x = LowHex::<u8>::new(0x1234u32);
+    assert_eq!(x.into_inner(), 0x34);
+    assert_eq!(
+        format!("{}", LowHex::<u32>::new(0x1_0000_00ABu64)),
+        "000000ab"
+    );
+    assert_eq!(format!("{:?}", LowHex::<u16>::new(0xABu64)), "00ab");
+    assert_eq!(format!("{:x}", LowHex::<u16>::new(0xABu64)), "ab");
+    assert_eq!(format!("{:#06X}", LowHex::<u16>::new(0xABu64)), "0x00AB");
+}
+
+#[test]
+fn test_high_hex() {
+    let x = HighHex::<u8>::new(0x1234u16);
+    assert_eq!(x.into_inner(), 0x12);
+    assert_eq!(format!("{}", HighHex::<u32>::new(u128::MAX)), "ffffffff");
+    assert_eq!(
+        format!("{:X}", HighHex::<u16>::new(0xABCD_0000_0000_0000u64)),
+        "ABCD"
+    );
+    assert_eq!(
+        format!("{}", HighHex::<u16>::new(0x0001_FFFF_FFFF_FFFFu64)),
+        "0001"
+    );
+}
+
+#[test]
+fn test_low_bits() {
+    assert_eq!(LowBits::<4>::new(0xFFu8).into_inner(), 0xF);
+    assert_eq!(format!("{}", LowBits::<13>::new(0xFFFFu16)), "1fff");
+    assert_eq!(format!("{:?}", LowBits::<1>::new(3u8)), "1");
+    assert_eq!(format!("{:b}", LowBits::<7>::new(2u8)), "0000010");
+    assert_eq!(
+        format!("{:#x}", LowBits::<128>::new(u128::MAX)),
+        "0xffffffffffffffffffffffffffffffff"
+    );
+}
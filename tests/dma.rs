@@ -0,0 +1,59 @@
+use truncate_integer::dma::{DmaConstraint, DmaError};
+
+#[test]
+fn test_new() {
+    assert!(DmaConstraint::new(u64::MAX, 0).is_none());
+    assert!(DmaConstraint::new(u64::MAX, 3).is_none());
+    let device = DmaConstraint::new(0xFFFF_FFFF, 1).unwrap();
+    assert_eq!(device.max_address(), 0xFFFF_FFFF);
+    assert_eq!(device.align(), 1);
+}
+
+#[test]
+fn test_range() {
+    let device = DmaConstraint::new(0xFFFF_FFFF, 1).unwrap();
+    assert_eq!(device.check(0, 0x1_0000_0000), Ok(()));
+    assert_eq!(device.check(0xFFFF_FFFF, 1), Ok(()));
+    assert_eq!(device.check(0x1_0000_0000, 0), Ok(()));
+    assert_eq!(
+        device.check(0xFFFF_FFFF, 2),
+        Err(DmaError::OutOfRange {
+            addr: 0xFFFF_FFFF,
+            len: 2,
+            max_address: 0xFFFF_FFFF
+        })
+    );
+
+    let device = DmaConstraint::new(u64::MAX, 1).unwrap();
+    assert_eq!(device.check(u64::MAX, 1), Ok(()));
+    assert!(device.check(u64::MAX, 2).is_err());
+}
+
+#[test]
+fn test_narrow() {
+    let device = DmaConstraint::new(0xFFFF_FFFF, 64).unwrap();
+    assert_eq!(device.narrow::<u32, u32>(0x40, 0x80), Ok((0x40, 0x80)));
+    assert_eq!(
+        device.narrow::<u32, u32>(0x41, 0x80),
+        Err(DmaError::Misaligned {
+            addr: 0x41,
+            align: 64
+        })
+    );
+    assert_eq!(
+        device.narrow::<u16, u32>(0x1_0000, 0x80),
+        Err(DmaError::AddressField { addr: 0x1_0000 })
+    );
+    assert_eq!(
+        device.narrow::<u32, u8>(0x40, 0x100),
+        Err(DmaError::LengthField { len: 0x100 })
+    );
+    assert_eq!(
+        DmaError::Misaligned {
+            addr: 0x41,
+            align: 64
+        }
+        .to_string(),
+        "DMA address 0x41 is not aligned to 64 bytes"
+    );
+}
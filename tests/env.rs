@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+
+use std::env;
+use truncate_integer::env::{env_shrink, env_try, EnvErrorKind};
+use truncate_integer::TruncateError;
+
+#[test]
+fn test_env_try() {
+    env::set_var("TI_TEST_TRY_PORT", " 443\n");
+    assert_eq!(env_try::<u16>("TI_TEST_TRY_PORT"), Ok(443));
+
+    env::set_var("TI_TEST_TRY_BIG", "70000");
+    let err = env_try::<u16>("TI_TEST_TRY_BIG").unwrap_err();
+    assert_eq!(err.name(), "TI_TEST_TRY_BIG");
+    assert_eq!(err.value(), Some("70000"));
+    assert_eq!(err.kind(), &EnvErrorKind::Truncate(TruncateError::Overflow));
+
+    env::set_var("TI_TEST_TRY_NEG", "-1");
+    let err = env_try::<u8>("TI_TEST_TRY_NEG").unwrap_err();
+    assert_eq!(
+        err.kind(),
+        &EnvErrorKind::Truncate(TruncateError::Underflow)
+    );
+    assert_eq!(env_try::<i8>("TI_TEST_TRY_NEG"), Ok(-1));
+}
+
+#[test]
+fn test_env_shrink() {
+    env::set_var(
+        "TI_TEST_SHRINK_BIG",
+        "340282366920938463463374607431768211455",
+    );
+    assert_eq!(env_shrink::<u8>("TI_TEST_SHRINK_BIG"), Ok(255));
+    env::set_var("TI_TEST_SHRINK_NEG", "-5");
+    assert_eq!(env_shrink::<u32>("TI_TEST_SHRINK_NEG"), Ok(0));
+    assert_eq!(env_shrink::<i64>("TI_TEST_SHRINK_NEG"), Ok(-5));
+}
+
+#[test]
+fn test_env_errors() {
+    env::remove_var("TI_TEST_MISSING");
+    let err = env_shrink::<u8>("TI_TEST_MISSING").unwrap_err();
+    assert_eq!(err.kind(), &EnvErrorKind::Missing);
+    assert_eq!(
+        err.to_string(),
+        "environment variable TI_TEST_MISSING is not set"
+    );
+
+    env::set_var("TI_TEST_GARBAGE", "eight");
+    let err = env_try::<u8>("TI_TEST_GARBAGE").unwrap_err();
+    assert!(matches!(err.kind(), EnvErrorKind::Parse(_)));
+    assert_eq!(
+        err.to_string(),
+        "environment variable TI_TEST_GARBAGE=\"eight\": invalid digit found in string"
+    );
+}
@@ -0,0 +1,57 @@
+use truncate_integer::eui::{Eui48, Eui64};
+use truncate_integer::{Shrink, TryTruncate};
+
+#[test]
+fn test_construction() {
+    assert_eq!(Eui48::new(0xFFFF_FFFF_FFFF), Some(Eui48::MAX));
+    assert_eq!(Eui48::new(0x1_0000_0000_0000), None);
+    assert_eq!(Eui48::new(u64::MAX), None);
+    assert_eq!(Eui64::new(u64::MAX), Some(Eui64::MAX));
+    let eui: Option<Eui64> = (1u128 << 64).try_truncate();
+    assert_eq!(eui, None);
+    let eui: Option<Eui48> = (-1i64).try_truncate();
+    assert_eq!(eui, None);
+    let mac: Eui48 = u64::MAX.shrink();
+    assert_eq!(mac, Eui48::MAX);
+    assert_eq!(u64::from(Eui48::MAX), 0xFFFF_FFFF_FFFF);
+    assert_eq!(i128::from(Eui64::MAX), i128::from(u64::MAX));
+}
+
+#[test]
+fn test_fields() {
+    let mac = Eui48::new(0x0300_5E00_0001).unwrap();
+    assert_eq!(mac.oui(), 0x03005E);
+    assert!(mac.is_multicast());
+    assert!(mac.is_local());
+    let mac = Eui48::new(0xACDE_4800_0080).unwrap();
+    assert_eq!(mac.oui(), 0xACDE48);
+    assert!(!mac.is_multicast());
+    assert!(!mac.is_local());
+
+    let eui = Eui64::new(0x0200_0000_0000_0000).unwrap();
+    assert_eq!(eui.oui(), 0x020000);
+    assert!(eui.is_local());
+}
+
+#[test]
+fn test_bytes() {
+    let mac = Eui48::new(0x0011_2233_4455).unwrap();
+    assert_eq!(mac.to_bytes(), [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+    assert_eq!(Eui48::from_bytes(mac.to_bytes()), mac);
+    let eui = Eui64::from_bytes([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(eui.raw(), 0x0102_0304_0506_0708);
+    assert_eq!(eui.to_string(), "01:02:03:04:05:06:07:08");
+    assert_eq!(Eui48::MIN.to_string(), "00:00:00:00:00:00");
+}
+
+#[test]
+fn test_expansion() {
+    let mac = Eui48::new(0x0011_2233_4455).unwrap();
+    let eui = mac.to_eui64();
+    assert_eq!(eui.raw(), 0x0011_22FF_FE33_4455);
+    assert_eq!(eui.oui(), mac.oui());
+    assert_eq!(eui.to_eui48(), Some(mac));
+    assert_eq!(mac.to_modified_eui64(), 0x0211_22FF_FE33_4455);
+    assert_eq!(Eui64::new(0x0011_2233_4455_6677).unwrap().to_eui48(), None);
+    assert_eq!(Eui48::MAX.to_eui64().to_eui48(), Some(Eui48::MAX));
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "std")]
+
+use truncate_integer::exit::{exit_status_shrink, exit_status_try};
+use truncate_integer::TruncateError;
+
+#[test]
+fn test_status() {
+    assert_eq!(exit_status_try(0i32), Ok(0));
+    assert_eq!(exit_status_try(255i64), Ok(255));
+    assert_eq!(exit_status_try(256i32), Err(TruncateError::Overflow));
+    assert_eq!(exit_status_try(-1i64), Err(TruncateError::Underflow));
+
+    assert_eq!(exit_status_shrink(0i32), 0);
+    assert_eq!(exit_status_shrink(42i64), 42);
+    assert_eq!(exit_status_shrink(i64::MAX), 255);
+    assert_eq!(exit_status_shrink(i32::MIN), 255);
+    assert_eq!(exit_status_shrink(-1i32), 255);
+}
+
+#[cfg(feature = "exit-code")]
+#[test]
+fn test_exit_code() {
+    use std::process::ExitCode;
+    use truncate_integer::exit::{exit_code_shrink, exit_code_try};
+
+    assert_eq!(exit_code_try(3i32), Ok(ExitCode::from(3)));
+    assert_eq!(exit_code_try(1000i64), Err(TruncateError::Overflow));
+    assert_eq!(exit_code_shrink(-2i32), ExitCode::from(255));
+    assert_eq!(exit_code_shrink(0i64), ExitCode::SUCCESS);
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "fault-injection")]
+
+use truncate_integer::len::try_len_u8;
+use truncate_integer::y2038::{time32_try, time32_unsigned_shrink};
+#[cfg(feature = "alloc")]
+use truncate_integer::NarrowVec;
+use truncate_integer::{fault, Chop, Shrink, TryTruncate};
+
+fn count_failures(n: u32) -> u32 {
+    (0..n)
+        .filter(|&x| TryTruncate::<u8>::try_truncate(x % 100).is_none())
+        .count() as u32
+}
+
+// The settings are global, so everything is checked in one test.
+#[test]
+fn test_fault_injection() {
+    assert_eq!(TryTruncate::<u8>::try_truncate(5u32), Some(5));
+    assert_eq!(fault::injected(), 0);
+
+    fault::set_rate(1, 4);
+    fault::set_seed(42);
+    let failures = count_failures(10_000);
+    assert!(failures > 2300 && failures < 2700, "{}", failures);
+    assert_eq!(u64::from(failures), fault::injected());
+
+    // The same seed gives the same faults.
+    fault::set_seed(42);
+    let first: Vec<bool> = (0..100u32)
+        .map(|x| TryTruncate::<u8>::try_truncate(x).is_none())
+        .collect();
+    fault::set_seed(42);
+    let second: Vec<bool> = (0..100u32)
+        .map(|x| TryTruncate::<u8>::try_truncate(x).is_none())
+        .collect();
+    assert_eq!(first, second);
+
+    fault::set_rate(1, 1);
+    assert_eq!(Shrink::<u8>::shrink(0u64), u8::MAX);
+    assert_eq!(Shrink::<i16>::shrink(-1i64), i16::MIN);
+    assert_eq!(Shrink::<i16>::shrink(1i64), i16::MAX);
+    // Chop isn't affected.
+    assert_eq!(Chop::<u8>::chop(7u32), 7);
+    // Neither are conversions that can't overflow.
+    let injected = fault::injected();
+    assert_eq!(Shrink::<u64>::shrink(5u8), 5);
+    assert_eq!(Shrink::<i32>::shrink(5i32), 5);
+    assert_eq!(TryTruncate::<i16>::try_truncate(-5i8), Some(-5));
+    assert_eq!(TryTruncate::<u8>::try_truncate(5u8), Some(5));
+    assert_eq!(fault::injected(), injected);
+
+    // Helpers inside the crate don't see injected faults.
+    assert_eq!(time32_try(5), Ok(5));
+    assert_eq!(time32_unsigned_shrink(5), 5);
+    assert_eq!(try_len_u8(&[0u8; 3][..]), Ok(3));
+    #[cfg(feature = "alloc")]
+    {
+        let mut v = NarrowVec::new();
+        v.push(1);
+        v.push(300);
+        assert_eq!(v.bits(), 16);
+        assert_eq!(v.iter().collect::<Vec<_>>(), [1, 300]);
+    }
+
+    fault::set_rate(0, 1);
+    assert_eq!(count_failures(1000), 0);
+    fault::set_rate(1, 1);
+    fault::disable();
+    assert_eq!(count_failures(1000), 0);
+}
+
+#[test]
+#[should_panic(expected = "invalid fault injection rate")]
+fn test_invalid_rate() {
+    fault::set_rate(2, 1);
+}
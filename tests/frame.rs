@@ -0,0 +1,44 @@
+#![cfg(feature = "alloc")]
+
+use truncate_integer::frame::{Deltas, FrameOfReference};
+
+#[test]
+fn test_frame_widths() {
+    let frame = FrameOfReference::encode(&[10u64, 265, 10]);
+    assert_eq!(frame.base(), 10);
+    assert_eq!(frame.deltas(), &Deltas::U8(vec![0, 255, 0]));
+    assert_eq!(frame.deltas().bits(), 8);
+
+    let frame = FrameOfReference::encode(&[10u64, 266, 10]);
+    assert_eq!(frame.deltas(), &Deltas::U16(vec![0, 256, 0]));
+
+    let frame = FrameOfReference::encode(&[0u64, u64::MAX]);
+    assert_eq!(frame.deltas(), &Deltas::U64(vec![0, u64::MAX]));
+
+    let frame = FrameOfReference::encode(&[7u32; 3]);
+    assert_eq!(frame.deltas(), &Deltas::U8(vec![0; 3]));
+}
+
+#[test]
+fn test_frame_signed() {
+    let values = [-3i64, 100_000, i64::MIN + 5, 0];
+    let frame = FrameOfReference::encode(&values);
+    assert_eq!(frame.base(), i64::MIN + 5);
+    assert_eq!(frame.deltas().bits(), 64);
+    assert_eq!(frame.decode(), values);
+
+    let values = [i32::MAX, i32::MAX - 70000];
+    let frame = FrameOfReference::encode(&values);
+    assert_eq!(frame.deltas(), &Deltas::U32(vec![70000, 0]));
+    assert_eq!(frame.get(0), Some(i32::MAX));
+    assert_eq!(frame.get(2), None);
+    assert_eq!(frame.decode(), values);
+}
+
+#[test]
+fn test_frame_empty() {
+    let frame = FrameOfReference::<u64>::encode(&[]);
+    assert!(frame.is_empty());
+    assert_eq!(frame.base(), 0);
+    assert!(frame.decode().is_empty());
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "glam")]
+
+use glam::{I16Vec3, I64Vec2, IVec4, U64Vec4, U8Vec4, UVec3};
+use truncate_integer::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+
+#[test]
+fn test_glam_try_truncate() {
+    let v: Option<U8Vec4> = IVec4::new(1, 2, 3, 4).try_truncate();
+    assert_eq!(v, Some(U8Vec4::new(1, 2, 3, 4)));
+    let v: Option<U8Vec4> = IVec4::new(1, 2, 3, -4).try_truncate();
+    assert_eq!(v, None);
+    let v: Option<[i16; 3]> = UVec3::new(0, 32767, 32768).try_truncate();
+    assert_eq!(v, None);
+}
+
+#[test]
+fn test_glam_shrink() {
+    let v: I16Vec3 = UVec3::new(0, 40000, 7).shrink();
+    assert_eq!(v, I16Vec3::new(0, i16::MAX, 7));
+    let v: [i8; 2] = I64Vec2::new(i64::MIN, 5).shrink();
+    assert_eq!(v, [i8::MIN, 5]);
+    let v: u16 = 70000u32.shrink();
+    assert_eq!(v, u16::MAX);
+}
+
+#[test]
+fn test_glam_chop_unchecked() {
+    let v: [u8; 4] = IVec4::new(1, 2, 3, 255).chop();
+    assert_eq!(v, [1, 2, 3, 255]);
+    let v: U8Vec4 = U64Vec4::new(0x100, 0x1FF, 2, 3).truncate_unchecked();
+    assert_eq!(v, U8Vec4::new(0, 0xFF, 2, 3));
+    let v: U8Vec4 = IVec4::new(-1, -256, 300, 7).truncate_unchecked();
+    assert_eq!(v, U8Vec4::new(0xFF, 0, 44, 7));
+    let v: [i8; 2] = I64Vec2::new(-129, 128).truncate_unchecked();
+    assert_eq!(v, [127, -128]);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_glam_chop_panic() {
+    let _: [u8; 4] = IVec4::new(1, 2, 3, 256).chop();
+}
@@ -0,0 +1,54 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use truncate_integer::hash::{BuildTruncatingHasher, Fold, TruncatingHasher};
+
+/// A hasher that returns whatever `u64` was last written.
+#[derive(Default)]
+struct Passthrough(u64);
+
+impl Hasher for Passthrough {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {
+        unimplemented!()
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0 = i;
+    }
+}
+
+#[test]
+fn test_fold() {
+    assert_eq!(
+        Fold::LowBits.fold::<u32>(0x1234_5678_9ABC_DEF0),
+        0x9ABC_DEF0u32
+    );
+    assert_eq!(Fold::Xor.fold::<u32>(0x1234_5678_9ABC_DEF0), 0x8888_8888u32);
+    assert_eq!(Fold::LowBits.fold::<u16>(0x1234_5678_9ABC_DEF0), 0xDEF0u16);
+    assert_eq!(Fold::Xor.fold::<u16>(0x0001_0002_0004_0008), 0x000Fu16);
+    assert_eq!(Fold::Xor.fold::<u8>(0x0100_0000_0000_0000), 0x01u8);
+}
+
+#[test]
+fn test_truncating_hasher() {
+    let mut hasher = TruncatingHasher::<_, u16>::new(Passthrough::default(), Fold::Xor);
+    hasher.write_u64(0xFFFF_0000_0000_0001);
+    assert_eq!(hasher.finish_narrow(), 0xFFFE);
+    assert_eq!(hasher.finish(), 0xFFFE);
+    assert_eq!(hasher.into_inner().finish(), 0xFFFF_0000_0000_0001);
+
+    let mut hasher = TruncatingHasher::<_, u32>::new(Passthrough::default(), Fold::LowBits);
+    hasher.write_u64(0xFFFF_0000_0000_0001);
+    assert_eq!(hasher.finish(), 1);
+}
+
+#[test]
+fn test_build_truncating_hasher() {
+    let build = BuildTruncatingHasher::<_, u8>::new(RandomState::new(), Fold::LowBits);
+    let mut hasher = build.build_hasher();
+    hasher.write(b"hello");
+    assert!(hasher.finish() <= 0xFF);
+}
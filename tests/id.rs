@@ -0,0 +1,63 @@
+#![cfg(feature = "alloc")]
+
+use truncate_integer::id::{IdAllocator, IdError, OnExhausted};
+
+#[test]
+fn test_intern() {
+    let mut ids = IdAllocator::<u16, u128>::new(OnExhausted::Error);
+    assert!(ids.is_empty());
+    assert_eq!(ids.intern(u128::MAX), Ok(0));
+    assert_eq!(ids.intern(5), Ok(1));
+    assert_eq!(ids.intern(u128::MAX), Ok(0));
+    assert_eq!(ids.len(), 2);
+    assert_eq!(ids.get(&5), Some(1));
+    assert_eq!(ids.get(&6), None);
+    assert_eq!(ids.key(0), Some(u128::MAX));
+    assert_eq!(ids.key(2), None);
+    assert_eq!(ids.iter().collect::<Vec<_>>(), [(0, u128::MAX), (1, 5)]);
+}
+
+#[test]
+fn test_remove_reuses_lowest() {
+    let mut ids = IdAllocator::<u8>::new(OnExhausted::Error);
+    for key in 0..5 {
+        ids.intern(key * 10).unwrap();
+    }
+    assert_eq!(ids.remove(&30), Some(3));
+    assert_eq!(ids.remove(&10), Some(1));
+    assert_eq!(ids.remove(&10), None);
+    assert_eq!(ids.key(1), None);
+    assert_eq!(ids.intern(100), Ok(1));
+    assert_eq!(ids.intern(200), Ok(3));
+    assert_eq!(ids.intern(300), Ok(5));
+}
+
+#[test]
+fn test_exhausted_error() {
+    let mut ids = IdAllocator::<u8>::new(OnExhausted::Error);
+    for key in 0..256 {
+        assert_eq!(ids.intern(key + 1000), Ok(key as u8));
+    }
+    assert_eq!(ids.intern(1), Err(IdError::new(256)));
+    assert_eq!(ids.intern(1000), Ok(0));
+    ids.remove(&1100);
+    assert_eq!(ids.intern(1), Ok(100));
+}
+
+#[test]
+fn test_exhausted_recycle() {
+    let mut ids = IdAllocator::<u8>::new(OnExhausted::Recycle);
+    for key in 0..256 {
+        ids.intern(key).unwrap();
+    }
+    assert_eq!(ids.intern(1000), Ok(0));
+    assert_eq!(ids.get(&0), None);
+    assert_eq!(ids.key(0), Some(1000));
+    assert_eq!(ids.intern(1001), Ok(1));
+    assert_eq!(ids.len(), 256);
+
+    // Freed ids are used before recycling again.
+    ids.remove(&50);
+    assert_eq!(ids.intern(1002), Ok(50));
+    assert_eq!(ids.intern(1003), Ok(2));
+}
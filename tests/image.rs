@@ -0,0 +1,36 @@
+#![cfg(feature = "image")]
+
+use image::{GrayImage, ImageBuffer, Luma, LumaA, Rgba};
+use truncate_integer::image::{expand_depth, reduce_depth, reduce_depth_dithered};
+
+#[test]
+fn test_reduce_depth_rounding() {
+    let values = [0u16, 128, 129, 257, 385, 386, 65278, 65535];
+    let img: ImageBuffer<Luma<u16>, _> =
+        ImageBuffer::from_raw(values.len() as u32, 1, values.to_vec()).unwrap();
+    let small = reduce_depth(&img);
+    assert_eq!(small.as_raw(), &[0u8, 0, 1, 1, 1, 2, 254, 255]);
+}
+
+#[test]
+fn test_depth_round_trip() {
+    let raw: Vec<u8> = (0..=255).collect();
+    let img = GrayImage::from_raw(16, 16, raw).unwrap();
+    let wide = expand_depth(&img);
+    assert_eq!(wide.get_pixel(15, 15), &Luma([65535u16]));
+    assert_eq!(reduce_depth(&wide), img);
+    assert_eq!(reduce_depth_dithered(&wide), img);
+
+    let img: ImageBuffer<LumaA<u8>, _> = ImageBuffer::from_pixel(3, 2, LumaA([7, 200]));
+    assert_eq!(reduce_depth(&expand_depth(&img)), img);
+}
+
+#[test]
+fn test_reduce_depth_dithered() {
+    // Halfway between two 8-bit steps: 100.5 * 257.
+    let img: ImageBuffer<Rgba<u16>, _> = ImageBuffer::from_pixel(4, 4, Rgba([25828; 4]));
+    let small = reduce_depth_dithered(&img);
+    let high = small.as_raw().iter().filter(|&&x| x == 101).count();
+    let low = small.as_raw().iter().filter(|&&x| x == 100).count();
+    assert_eq!((low, high), (32, 32));
+}
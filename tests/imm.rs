@@ -0,0 +1,84 @@
+use truncate_integer::imm::*;
+
+#[test]
+fn test_signed() {
+    assert!(fits_signed_imm(2047, 12, 0));
+    assert!(fits_signed_imm(-2048, 12, 0));
+    assert!(!fits_signed_imm(2048, 12, 0));
+    assert!(!fits_signed_imm(-2049, 12, 0));
+    assert!(fits_signed_imm(i64::MIN, 64, 0));
+    assert!(fits_signed_imm(-1, 1, 0));
+    assert!(!fits_signed_imm(1, 1, 0));
+
+    assert_eq!(encode_signed_imm(-1, 12, 0), Ok(0xFFF));
+    assert_eq!(encode_signed_imm(2046, 12, 1), Ok(0x3FF));
+    assert_eq!(
+        encode_signed_imm(-6, 12, 2),
+        Err(ImmError::Misaligned {
+            value: -6,
+            shift: 2
+        })
+    );
+    assert_eq!(encode_signed_imm(i64::MIN, 8, 60), Ok(0xF8));
+}
+
+#[test]
+fn test_shift_63() {
+    assert!(fits_signed_imm(i64::MIN, 1, 63));
+    assert!(fits_signed_imm(0, 1, 63));
+    assert_eq!(encode_signed_imm(i64::MIN, 1, 63), Ok(1));
+    assert_eq!(decode_signed_imm(1, 1, 63), i64::MIN);
+    assert_eq!(
+        encode_signed_imm(i64::MAX, 1, 63),
+        Err(ImmError::Misaligned {
+            value: i64::MAX.into(),
+            shift: 63
+        })
+    );
+    assert_eq!(encode_unsigned_imm(1 << 63, 1, 63), Ok(1));
+    assert!(!fits_unsigned_imm(1 << 62, 1, 63));
+}
+
+#[test]
+fn test_unsigned() {
+    assert!(fits_unsigned_imm(4095, 12, 0));
+    assert!(!fits_unsigned_imm(4096, 12, 0));
+    assert!(fits_unsigned_imm(u64::MAX, 64, 0));
+    assert_eq!(encode_unsigned_imm(32760, 12, 3), Ok(4095));
+    assert_eq!(
+        encode_unsigned_imm(32768, 12, 3),
+        Err(ImmError::OutOfRange {
+            value: 32768,
+            bits: 12
+        })
+    );
+    assert_eq!(
+        ImmError::Misaligned { value: 6, shift: 2 }.to_string(),
+        "immediate 6 is not a multiple of 4"
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    for value in (-8192i64..8192).step_by(2) {
+        if let Ok(field) = encode_signed_imm(value, 12, 1) {
+            assert_eq!(decode_signed_imm(field, 12, 1), value);
+        } else {
+            assert!(!(-4096..4096).contains(&value));
+        }
+    }
+    for value in (0u64..20000).step_by(4) {
+        if let Ok(field) = encode_unsigned_imm(value, 12, 2) {
+            assert_eq!(decode_unsigned_imm(field, 12, 2), value);
+        } else {
+            assert!(value >= 16384);
+        }
+    }
+    assert_eq!(decode_signed_imm(0xFFFF_F800, 12, 0), -2048);
+}
+
+#[test]
+#[should_panic(expected = "invalid immediate width")]
+fn test_invalid_width() {
+    fits_unsigned_imm(0, 0, 0);
+}
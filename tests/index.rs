@@ -0,0 +1,41 @@
+use truncate_integer::index::{shrink_to_u16, try_to_u16, IndexError};
+
+#[test]
+fn test_try_to_u16() {
+    let mut out = [0u16; 4];
+    try_to_u16(&[0u32, 1, 65535, 2], &mut out).unwrap();
+    assert_eq!(out, [0, 1, 65535, 2]);
+
+    let err = try_to_u16(&[0usize, 65536, 1 << 20, 3], &mut out).unwrap_err();
+    assert_eq!(err.position(), 1);
+    assert_eq!(err.value(), 65536);
+
+    let err = try_to_u16(&[0u64, 1, 2, u64::MAX], &mut out).unwrap_err();
+    assert_eq!(err, IndexError::new(3, u64::MAX));
+}
+
+#[test]
+fn test_shrink_to_u16() {
+    let mut out = [0u16; 3];
+    shrink_to_u16(&[5u32, 70000, u32::MAX], &mut out);
+    assert_eq!(out, [5, 65535, 65535]);
+}
+
+#[test]
+#[should_panic(expected = "index buffer length mismatch")]
+fn test_index_length_mismatch() {
+    let mut out = [0u16; 2];
+    shrink_to_u16(&[1u32; 3], &mut out);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_try_to_u16_vec() {
+    use truncate_integer::index::try_to_u16_vec;
+
+    assert_eq!(try_to_u16_vec(&[3u32, 2, 1]), Ok(vec![3, 2, 1]));
+    assert_eq!(
+        try_to_u16_vec(&[3u32, 100_000]),
+        Err(IndexError::new(1, 100_000))
+    );
+}
@@ -0,0 +1,93 @@
+use truncate_integer::key::*;
+
+const KEYS: [u64; 9] = [
+    0,
+    1,
+    0xFF,
+    0x0100,
+    0x00FF_FFFF_FFFF_FFFF,
+    0x0100_0000_0000_0000,
+    0x1234_5678_9ABC_DEF0,
+    0xFFFF_0000_0000_0000,
+    u64::MAX,
+];
+
+#[test]
+fn test_prefix_u64_order() {
+    for len in 1..=8 {
+        for &a in &KEYS {
+            for &b in &KEYS {
+                if a <= b {
+                    assert!(prefix_u64(a, len) <= prefix_u64(b, len));
+                }
+                if prefix_u64(a, len) < prefix_u64(b, len) {
+                    assert!(a < b);
+                }
+                // Comparing prefixes as bytes agrees with comparing them
+                // as integers.
+                let (pa, pb) = (a.to_be_bytes(), b.to_be_bytes());
+                assert_eq!(
+                    prefix_bytes(&pa, len).cmp(prefix_bytes(&pb, len)),
+                    prefix_u64(a, len).cmp(&prefix_u64(b, len))
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_prefix_u64_ceil() {
+    for len in 1..8 {
+        let shift = 8 * (8 - len) as u32;
+        for &key in &KEYS {
+            match prefix_u64_ceil(key, len) {
+                Some(ceil) => {
+                    // The smallest prefix whose range starts at or after `key`.
+                    assert!(u128::from(ceil) << shift >= u128::from(key));
+                    assert!(ceil == 0 || u128::from(ceil - 1) << shift < u128::from(key));
+                }
+                None => assert_eq!(prefix_u64(key, len), prefix_u64(u64::MAX, len)),
+            }
+        }
+    }
+    assert_eq!(prefix_u64_ceil(0xFFFF_0000_0000_0000, 2), Some(0xFFFF));
+    assert_eq!(prefix_u64_ceil(0xFFFF_0000_0000_0001, 2), None);
+    assert_eq!(prefix_u64_ceil(u64::MAX, 8), Some(u64::MAX));
+}
+
+#[test]
+fn test_prefix_u128() {
+    let key = 0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10u128;
+    assert_eq!(prefix_u128(key, 1), 0x01);
+    assert_eq!(prefix_u128(key, 16), key);
+    assert_eq!(prefix_u128_ceil(key, 15), Some((key >> 8) + 1));
+    assert_eq!(prefix_u128_ceil(u128::MAX, 3), None);
+}
+
+#[test]
+#[should_panic(expected = "invalid prefix length")]
+fn test_prefix_u64_bad_len() {
+    prefix_u64(1, 9);
+}
+
+#[test]
+fn test_successor() {
+    let mut buf = *b"abc";
+    assert_eq!(successor_in_place(&mut buf), Some(3));
+    assert_eq!(&buf, b"abd");
+
+    let mut buf = [0x01, 0xFF, 0xFF];
+    assert_eq!(successor_in_place(&mut buf), Some(1));
+    assert_eq!(buf[0], 0x02);
+
+    assert_eq!(successor_in_place(&mut [0xFF, 0xFF]), None);
+    assert_eq!(successor_in_place(&mut []), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_successor_vec() {
+    assert_eq!(successor(b"user:"), Some(b"user;".to_vec()));
+    assert_eq!(successor(&[0x7F, 0xFF]), Some(vec![0x80]));
+    assert_eq!(successor(&[0xFF]), None);
+}
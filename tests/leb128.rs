@@ -0,0 +1,140 @@
+use truncate_integer::leb128::{
+    sleb128_shrink, sleb128_try, sleb128_unchecked, uleb128_shrink, uleb128_try, uleb128_unchecked,
+    LebError,
+};
+use truncate_integer::TruncateError;
+
+const OVERFLOW: LebError = LebError::Truncate(TruncateError::Overflow);
+const UNDERFLOW: LebError = LebError::Truncate(TruncateError::Underflow);
+
+/// Reference encoders, for round trips.
+fn uleb(mut value: u128) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn sleb(mut value: i128) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+#[test]
+fn test_uleb128() {
+    assert_eq!(uleb128_try::<u8>(&[0x00]), Ok((0, 1)));
+    assert_eq!(uleb128_try::<u8>(&[0x7F, 0xAA]), Ok((127, 1)));
+    assert_eq!(uleb128_try::<u8>(&[0x80, 0x01]), Ok((128, 2)));
+    assert_eq!(uleb128_try::<i8>(&[0x80, 0x01]), Err(OVERFLOW));
+    assert_eq!(uleb128_try::<u8>(&[0xFF, 0x82, 0x00]), Err(OVERFLOW));
+    // Padding is allowed.
+    assert_eq!(uleb128_try::<u8>(&[0x85, 0x80, 0x80, 0x00]), Ok((5, 4)));
+    assert_eq!(uleb128_shrink::<u8>(&[0x80, 0x02]), Ok((255, 2)));
+    assert_eq!(uleb128_unchecked::<u8>(&[0x81, 0x02]), Ok((0x01, 2)));
+
+    for &value in &[0, 1, 127, 128, 300, u64::MAX as u128, u128::MAX] {
+        let buf = uleb(value);
+        assert_eq!(uleb128_try::<u128>(&buf), Ok((value, buf.len())));
+    }
+}
+
+#[test]
+fn test_uleb128_beyond_128_bits() {
+    let mut buf = uleb(u128::MAX);
+    assert_eq!(buf.len(), 19);
+    // Set bit 128.
+    buf[18] |= 0x80;
+    buf.push(0x00);
+    assert_eq!(uleb128_try::<u128>(&buf), Ok((u128::MAX, 20)));
+    buf[18] = 0x87;
+    assert_eq!(uleb128_try::<u128>(&buf), Err(OVERFLOW));
+    assert_eq!(uleb128_shrink::<u8>(&buf), Ok((255, 20)));
+    assert_eq!(uleb128_unchecked::<u64>(&buf), Ok((u64::MAX, 20)));
+    buf[18] = 0x83;
+    buf[19] = 0x01;
+    assert_eq!(uleb128_shrink::<u128>(&buf), Ok((u128::MAX, 20)));
+}
+
+#[test]
+fn test_sleb128() {
+    assert_eq!(sleb128_try::<i8>(&[0x00]), Ok((0, 1)));
+    assert_eq!(sleb128_try::<i8>(&[0x3F]), Ok((63, 1)));
+    assert_eq!(sleb128_try::<i8>(&[0x40]), Ok((-64, 1)));
+    assert_eq!(sleb128_try::<i8>(&[0x80, 0x7F]), Ok((-128, 2)));
+    assert_eq!(sleb128_try::<i8>(&[0xFF, 0x7E]), Err(UNDERFLOW));
+    assert_eq!(sleb128_try::<u8>(&[0x7F]), Err(UNDERFLOW));
+    assert_eq!(sleb128_try::<u8>(&[0xFF, 0x01]), Ok((255, 2)));
+    assert_eq!(sleb128_shrink::<i8>(&[0xFF, 0x7E]), Ok((-128, 2)));
+    assert_eq!(sleb128_shrink::<u8>(&[0x7F]), Ok((0, 1)));
+    assert_eq!(sleb128_unchecked::<u8>(&[0x7F]), Ok((0xFF, 1)));
+    // Padding with sign bits is allowed.
+    assert_eq!(sleb128_try::<i8>(&[0xFF, 0xFF, 0x7F]), Ok((-1, 3)));
+
+    for &value in &[
+        0,
+        1,
+        -1,
+        63,
+        64,
+        -64,
+        -65,
+        i64::MIN as i128,
+        i128::MIN,
+        i128::MAX,
+    ] {
+        let buf = sleb(value);
+        assert_eq!(sleb128_try::<i128>(&buf), Ok((value, buf.len())));
+    }
+}
+
+#[test]
+fn test_sleb128_beyond_128_bits() {
+    // 2^127 doesn't fit an i128, but does fit a u128.
+    let mut buf = uleb(1 << 127);
+    *buf.last_mut().unwrap() |= 0x80;
+    buf.push(0x00);
+    assert_eq!(sleb128_try::<u128>(&buf), Ok((1 << 127, buf.len())));
+    assert_eq!(sleb128_try::<i128>(&buf), Err(OVERFLOW));
+    assert_eq!(sleb128_shrink::<i128>(&buf), Ok((i128::MAX, buf.len())));
+
+    // -2^128 + 2^126 + 127 doesn't fit anything.
+    let mut buf = sleb(i128::MIN);
+    assert_eq!(buf.len(), 19);
+    buf[0] = 0xFF;
+    buf[18] = 0x7D;
+    assert_eq!(sleb128_try::<i128>(&buf), Err(UNDERFLOW));
+    assert_eq!(sleb128_shrink::<i128>(&buf), Ok((i128::MIN, 19)));
+    assert_eq!(sleb128_unchecked::<i128>(&buf), Ok(((1 << 126) + 127, 19)));
+}
+
+#[test]
+fn test_unexpected_end() {
+    assert_eq!(uleb128_try::<u8>(&[]), Err(LebError::UnexpectedEnd));
+    assert_eq!(
+        uleb128_shrink::<u8>(&[0xFF, 0xFF]),
+        Err(LebError::UnexpectedEnd)
+    );
+    assert_eq!(
+        sleb128_unchecked::<u8>(&[0x80]),
+        Err(LebError::UnexpectedEnd)
+    );
+    assert_eq!(
+        LebError::UnexpectedEnd.to_string(),
+        "unterminated LEB128 value"
+    );
+    assert_eq!(OVERFLOW.to_string(), "integer overflow");
+}
@@ -0,0 +1,48 @@
+use truncate_integer::len::{
+    len_u16, len_u32, len_u8, try_len_u16, try_len_u32, try_len_u8, LenError,
+};
+
+#[test]
+fn test_len() {
+    assert_eq!(len_u8(&[0u8; 0]), 0);
+    assert_eq!(len_u16(&[0u8; 300]), 300);
+    assert_eq!(len_u32("abc"), 3);
+}
+
+#[test]
+fn test_try_len() {
+    assert_eq!(try_len_u8(&[0u8; 255]), Ok(255));
+    assert_eq!(try_len_u8(&[0u8; 256]), Err(LenError::new(256, 255)));
+    assert_eq!(try_len_u8("héllo"), Ok(6));
+    assert_eq!(try_len_u16(&[(); 65535][..]), Ok(65535));
+    assert_eq!(
+        try_len_u16(&[(); 65536][..]),
+        Err(LenError::new(65536, 65535))
+    );
+    assert_eq!(try_len_u32(&[1u32, 2, 3][..]), Ok(3));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_len_collections() {
+    let v: Vec<u32> = (0..300).collect();
+    assert_eq!(len_u16(&v), 300);
+    assert_eq!(len_u32(&v), 300);
+    assert_eq!(len_u8(&String::from("abc")), 3);
+    let map: std::collections::BTreeMap<u8, u8> = (0..10).map(|x| (x, x)).collect();
+    assert_eq!(len_u8(&map), 10);
+}
+
+#[test]
+#[should_panic(expected = "length 256 exceeds the limit of 255")]
+fn test_len_panic() {
+    len_u8(&[0u8; 256]);
+}
+
+#[test]
+fn test_len_error() {
+    let err = LenError::new(70000, 65535);
+    assert_eq!(err.length(), 70000);
+    assert_eq!(err.limit(), 65535);
+    assert_eq!(err.to_string(), "length 70000 exceeds the limit of 65535");
+}
@@ -0,0 +1,44 @@
+#![cfg(all(feature = "libc", unix))]
+
+use truncate_integer::libc::*;
+use truncate_integer::{Chop, Shrink, TryTruncate};
+
+#[test]
+fn test_libc_sizes() {
+    let x: Option<size_t> = (-1 as ssize_t).try_truncate();
+    assert_eq!(x, None);
+    let x: Option<size_t> = (4096 as ssize_t).try_truncate();
+    assert_eq!(x, Some(4096));
+    let x: ssize_t = (size_t::MAX).shrink();
+    assert_eq!(x, ssize_t::MAX);
+    let x: u32 = (size_t::MAX).shrink();
+    assert_eq!(x, u32::MAX);
+}
+
+#[test]
+fn test_libc_offsets() {
+    let x: Option<size_t> = (-1 as off_t).try_truncate();
+    assert_eq!(x, None);
+    let x: size_t = (100 as off_t).chop();
+    assert_eq!(x, 100);
+    let x: u16 = (off_t::MAX).shrink();
+    assert_eq!(x, u16::MAX);
+}
+
+#[test]
+fn test_libc_pid_time() {
+    let x: Option<u16> = (-1 as pid_t).try_truncate();
+    assert_eq!(x, None);
+    let x: u16 = (1234 as pid_t).chop();
+    assert_eq!(x, 1234);
+    let x: i8 = (time_t::MIN).shrink();
+    assert_eq!(x, i8::MIN);
+}
+
+#[test]
+fn test_libc_small_signed_to_size() {
+    let x: Option<size_t> = (-1i32).try_truncate();
+    assert_eq!(x, None);
+    let x: size_t = (-1i8).shrink();
+    assert_eq!(x, 0);
+}
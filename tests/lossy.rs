@@ -0,0 +1,40 @@
+use truncate_integer::bitsint::U;
+use truncate_integer::lossy::Lossy;
+
+#[test]
+fn test_lossy_shrink() {
+    let x = Lossy::new(200u32).shrink::<u8>();
+    assert_eq!(x.into_parts(), (200, false));
+    let x = Lossy::new(300u32).shrink::<u8>();
+    assert_eq!(x.into_parts(), (255, true));
+    let x = Lossy::new(-1i32).shrink::<u16>();
+    assert_eq!(x.into_parts(), (0, true));
+    let x = Lossy::new(20u8).shrink::<U<4>>();
+    assert_eq!((x.get().get(), x.is_lossy()), (15, true));
+}
+
+#[test]
+fn test_lossy_wrap() {
+    let x = Lossy::new(0x1FFu16).wrap::<u8>();
+    assert_eq!(x.into_parts(), (0xFF, true));
+    let x = Lossy::new(-1i64).wrap::<i8>();
+    assert_eq!(x.into_parts(), (-1, false));
+}
+
+#[test]
+fn test_lossy_sticky() {
+    let x = Lossy::new(1000u32).shrink::<u8>().shrink::<u16>();
+    assert_eq!(x.into_parts(), (255, true));
+    assert_eq!(x.exact(), None);
+
+    let a = Lossy::new(1u8);
+    let b = Lossy::new(-1i8).shrink::<u8>();
+    let sum = a.zip(b, |a, b| a + b);
+    assert_eq!(sum.into_parts(), (1, true));
+    assert!(sum.map(|x| x * 2).is_lossy());
+
+    let mut c = Lossy::from(3u8);
+    assert_eq!(c.exact(), Some(3));
+    c.mark_lossy();
+    assert!(c.is_lossy());
+}
@@ -0,0 +1,37 @@
+use truncate_integer::midi::*;
+
+#[test]
+fn test_data_byte() {
+    assert_eq!(try_data_byte(127u8), Some(127));
+    assert_eq!(try_data_byte(128u8), None);
+    assert_eq!(try_data_byte(-1i32), None);
+    assert_eq!(try_data_byte(64u64), Some(64));
+    assert_eq!(shrink_data_byte(1000u32), 127);
+    assert_eq!(shrink_data_byte(-5i8), 0);
+}
+
+#[test]
+fn test_14_bit() {
+    assert_eq!(split_14(0), Some([0, 0]));
+    assert_eq!(split_14(0x3FFF), Some([0x7F, 0x7F]));
+    assert_eq!(split_14(0x4000), None);
+    assert_eq!(shrink_split_14(u16::MAX), [0x7F, 0x7F]);
+    assert_eq!(combine_14([0x01, 0x40]), 0x2001);
+    assert_eq!(combine_14([0xFF, 0xFF]), 0x3FFF);
+    for x in 0..=0x3FFF {
+        assert_eq!(combine_14(split_14(x).unwrap()), x);
+    }
+}
+
+#[test]
+fn test_pitch_bend() {
+    assert_eq!(pitch_bend_split(0), [0x00, 0x40]);
+    assert_eq!(pitch_bend_split(-8192), [0x00, 0x00]);
+    assert_eq!(pitch_bend_split(8191), [0x7F, 0x7F]);
+    assert_eq!(pitch_bend_split(i16::MIN), [0x00, 0x00]);
+    assert_eq!(pitch_bend_combine([0x00, 0x40]), 0);
+    assert_eq!(pitch_bend_combine([0x7F, 0x7F]), 8191);
+    for bend in -8192..=8191 {
+        assert_eq!(pitch_bend_combine(pitch_bend_split(bend)), bend);
+    }
+}
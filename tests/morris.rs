@@ -0,0 +1,126 @@
+use truncate_integer::morris::MorrisCounter;
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+#[test]
+fn test_exact_range() {
+    let mut rng = XorShift(1);
+    let mut counter = MorrisCounter::<u8>::new();
+    for n in 1..=15 {
+        counter.increment(rng.next());
+        assert_eq!(counter.estimate(), n);
+        assert!(counter.is_exact());
+    }
+
+    let mut counter = MorrisCounter::<u16>::new();
+    for _ in 0..2047 {
+        counter.increment(rng.next());
+    }
+    assert_eq!(counter.estimate(), 2047);
+    assert!(counter.is_exact());
+    counter.increment(rng.next());
+    assert!(!counter.is_exact());
+}
+
+#[test]
+fn test_estimate_is_unbiased() {
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+    let runs = 200;
+    let count = 20_000;
+    let mut sum_u8 = 0;
+    let mut sum_u16 = 0;
+    for _ in 0..runs {
+        let mut small = MorrisCounter::<u8>::new();
+        let mut large = MorrisCounter::<u16>::new();
+        for _ in 0..count {
+            small.increment(rng.next());
+            large.increment(rng.next());
+        }
+        sum_u8 += small.estimate();
+        sum_u16 += large.estimate();
+    }
+    let mean_u8 = sum_u8 as f64 / runs as f64;
+    let mean_u16 = sum_u16 as f64 / runs as f64;
+    assert!((mean_u8 / count as f64 - 1.0).abs() < 0.05, "{}", mean_u8);
+    assert!((mean_u16 / count as f64 - 1.0).abs() < 0.01, "{}", mean_u16);
+}
+
+#[test]
+fn test_saturation() {
+    let mut counter = MorrisCounter::from_raw(u8::MAX);
+    assert!(counter.is_saturated());
+    assert_eq!(counter.estimate(), 15 << 30);
+    counter.increment(0);
+    assert_eq!(counter.raw(), u8::MAX);
+
+    let counter = MorrisCounter::from_raw(u16::MAX);
+    assert_eq!(counter.estimate(), u64::MAX);
+
+    // A random value of zero always increments.
+    let mut counter = MorrisCounter::from_raw(u8::MAX - 1);
+    counter.increment(0);
+    assert!(counter.is_saturated());
+}
+
+#[test]
+fn test_merge() {
+    let mut rng = XorShift(7);
+    let mut a = MorrisCounter::<u8>::new();
+    let mut b = MorrisCounter::<u8>::new();
+    for _ in 0..5 {
+        a.increment(rng.next());
+    }
+    for _ in 0..6 {
+        b.increment(rng.next());
+    }
+    a.merge(&b, rng.next());
+    assert_eq!(a.estimate(), 11);
+
+    // 16 + 16 = 32 is representable exactly.
+    let mut a = MorrisCounter::from_raw(16u8);
+    a.merge(&MorrisCounter::from_raw(16), rng.next());
+    assert_eq!(a.estimate(), 32);
+
+    // 16 + 15 = 31 falls between 30 and 32.
+    let mut up = MorrisCounter::from_raw(16u8);
+    up.merge(&MorrisCounter::from_raw(15), 0);
+    assert_eq!(up.estimate(), 32);
+    let mut down = MorrisCounter::from_raw(16u8);
+    down.merge(&MorrisCounter::from_raw(15), 1);
+    assert_eq!(down.estimate(), 30);
+
+    let mut full = MorrisCounter::from_raw(u8::MAX);
+    full.merge(&MorrisCounter::from_raw(u8::MAX), 0);
+    assert!(full.is_saturated());
+}
+
+#[test]
+fn test_merge_is_unbiased() {
+    let mut rng = XorShift(12345);
+    let a = MorrisCounter::from_raw(100u8);
+    let b = MorrisCounter::from_raw(77u8);
+    let expected = (a.estimate() + b.estimate()) as f64;
+    let runs = 10_000;
+    let mut sum = 0;
+    for _ in 0..runs {
+        let mut merged = a;
+        merged.merge(&b, rng.next());
+        sum += merged.estimate();
+    }
+    let mean = sum as f64 / runs as f64;
+    assert!(
+        (mean / expected - 1.0).abs() < 0.01,
+        "{} {}",
+        mean,
+        expected
+    );
+}
@@ -0,0 +1,93 @@
+use truncate_integer::narrow_expr;
+
+#[test]
+fn test_precedence() {
+    let (a, b, c) = (2u8, 3u8, 4u8);
+    assert_eq!(narrow_expr!((a + b * c) => u8, checked), Some(14));
+    assert_eq!(narrow_expr!(((a + b) * c) => u8, checked), Some(20));
+    assert_eq!(narrow_expr!((a - b - c) => i8, checked), Some(-5));
+    assert_eq!(narrow_expr!((a - (b - c)) => i8, checked), Some(3));
+    assert_eq!(narrow_expr!((c / a * b) => u8, checked), Some(6));
+    assert_eq!(narrow_expr!((-a * b % c) => i8, checked), Some(-2));
+    assert_eq!(narrow_expr!((7) => u8, checked), Some(7));
+}
+
+#[test]
+fn test_mixed_types() {
+    let (big, small, signed) = (u64::MAX, 200u8, -1i64);
+    assert_eq!(narrow_expr!((big * 2 / 2) => u64, checked), Some(u64::MAX));
+    assert_eq!(narrow_expr!((small + signed) => u8, checked), Some(199));
+    assert_eq!(narrow_expr!((big - big + small) => i16, panic), 200);
+    let values = [1u32, 2, 3];
+    assert_eq!(
+        narrow_expr!(({ values.iter().sum::<u32>() } - { values.len() }) => u8, checked),
+        Some(3)
+    );
+}
+
+#[test]
+fn test_saturate() {
+    let (a, b) = (1000u32, 3u8);
+    assert_eq!(narrow_expr!((a * b) => u8, saturate), 255);
+    assert_eq!(narrow_expr!((b - a) => u8, saturate), 0);
+    assert_eq!(narrow_expr!((b - a) => i8, saturate), -128);
+}
+
+#[test]
+fn test_wide_overflow() {
+    let (x, zero) = (u128::MAX, 0u8);
+    assert_eq!(narrow_expr!((x * x * zero + 5) => u8, checked), Some(5));
+    assert_eq!(narrow_expr!((x * x) => u8, saturate), 255);
+    assert_eq!(narrow_expr!((-x * x) => i64, saturate), i64::MIN);
+    assert_eq!(narrow_expr!((x * x) => u128, checked), None);
+    assert_eq!(narrow_expr!((7 % x) => u8, checked), Some(7));
+    assert_eq!(narrow_expr!((7 / x) => u8, checked), Some(0));
+    assert_eq!(narrow_expr!((x / 2) => u128, checked), Some(u128::MAX / 2));
+    let min = i128::MIN;
+    assert_eq!(narrow_expr!((min - 1 + 1) => i128, saturate), i128::MIN);
+    assert_eq!(narrow_expr!((min / -1) => u128, saturate), 1 << 127);
+    assert_eq!(narrow_expr!((min % -1) => u8, checked), Some(0));
+}
+
+#[test]
+fn test_above_i128_max() {
+    let (x, one, min) = (u128::MAX, 1u8, i128::MIN);
+    assert_eq!(narrow_expr!((x - 1) => u128, checked), Some(u128::MAX - 1));
+    assert_eq!(narrow_expr!((x - 1) => u128, saturate), u128::MAX - 1);
+    assert_eq!(narrow_expr!((x - 1) => u128, panic), u128::MAX - 1);
+    assert_eq!(narrow_expr!((x + min) => i128, checked), Some(i128::MAX));
+    assert_eq!(narrow_expr!((x + min) => i128, saturate), i128::MAX);
+    assert_eq!(narrow_expr!((x + min) => i128, panic), i128::MAX);
+    assert_eq!(narrow_expr!((-x + x) => u8, checked), Some(0));
+    assert_eq!(narrow_expr!((-x) => i128, checked), None);
+    assert_eq!(narrow_expr!((-x) => i128, saturate), i128::MIN);
+    assert_eq!(narrow_expr!((-x / 2 - 1) => i128, panic), i128::MIN);
+    assert_eq!(narrow_expr!((x + one) => u128, checked), None);
+    assert_eq!(narrow_expr!((x + one) => u128, saturate), u128::MAX);
+    assert_eq!(narrow_expr!((x * 3 / x) => u8, checked), None);
+    assert_eq!(
+        narrow_expr!((-x % 7) => i8, checked),
+        Some(-((u128::MAX % 7) as i8))
+    );
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_panic() {
+    let a = 300u32;
+    narrow_expr!((a + 1) => u8, panic);
+}
+
+#[test]
+#[should_panic(expected = "narrow_expr! intermediate out of range")]
+fn test_unknown() {
+    let x = u128::MAX;
+    narrow_expr!((x * 4 / 2) => u8, saturate);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_divide_by_zero() {
+    let zero = 0u8;
+    narrow_expr!((1 / zero) => u8, checked);
+}
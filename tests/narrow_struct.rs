@@ -0,0 +1,54 @@
+use truncate_integer::{narrow_struct, NarrowStructError};
+
+#[derive(Debug, PartialEq)]
+struct Header {
+    len: u16,
+    id: u8,
+    offset: i8,
+}
+
+fn parse(len: u32, id: u64, offset: i32) -> Result<Header, NarrowStructError> {
+    let header = narrow_struct!(Header {
+        len: len,
+        id: id,
+        offset: offset,
+    })?;
+    Ok(header)
+}
+
+#[test]
+fn test_narrow_struct_ok() {
+    assert_eq!(
+        parse(1000, 7, -3),
+        Ok(Header {
+            len: 1000,
+            id: 7,
+            offset: -3
+        })
+    );
+}
+
+#[test]
+fn test_narrow_struct_errors() {
+    let err = parse(70000, 256, -3).unwrap_err();
+    assert_eq!(err.fields().collect::<Vec<_>>(), ["len", "id"]);
+    assert!(err.contains("id"));
+    assert!(!err.contains("offset"));
+    assert_eq!(err.to_string(), "fields out of range: len, id");
+
+    let err = parse(0, 0, -129).unwrap_err();
+    assert_eq!(err.fields().collect::<Vec<_>>(), ["offset"]);
+}
+
+#[test]
+fn test_narrow_struct_shadowing() {
+    // A value expression may use a name that is also a field name.
+    let id = 5u32;
+    let header = narrow_struct!(Header {
+        len: id,
+        id: id,
+        offset: id,
+    })
+    .unwrap();
+    assert_eq!((header.len, header.id, header.offset), (5, 5, 5));
+}
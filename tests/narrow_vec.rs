@@ -0,0 +1,35 @@
+#![cfg(feature = "alloc")]
+
+use truncate_integer::NarrowVec;
+
+#[test]
+fn test_narrow_vec_widen() {
+    let mut v = NarrowVec::new();
+    assert!(v.is_empty());
+    assert_eq!(v.bits(), 8);
+
+    v.push(255);
+    assert_eq!(v.bits(), 8);
+    v.push(256);
+    assert_eq!(v.bits(), 16);
+    v.push(u64::MAX);
+    assert_eq!(v.bits(), 64);
+    v.push(3);
+    assert_eq!(v.bits(), 64);
+
+    assert_eq!(v.len(), 4);
+    assert_eq!(v.iter().collect::<Vec<_>>(), [255, 256, u64::MAX, 3]);
+    assert_eq!(v.get(4), None);
+
+    v.clear();
+    assert!(v.is_empty());
+    assert_eq!(v.bits(), 64);
+}
+
+#[test]
+fn test_narrow_vec_collect() {
+    let v: NarrowVec = vec![1u64, 2, 1 << 20].into_iter().collect();
+    assert_eq!(v.bits(), 32);
+    assert_eq!(v.get(2), Some(1 << 20));
+    assert_eq!(v, v.clone());
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "derive")]
+
+use truncate_integer::bitsint::U;
+use truncate_integer::narrowing;
+
+#[narrowing]
+#[derive(Debug, PartialEq)]
+struct Packet {
+    kind: U<4>,
+    len: u16,
+    offset: i8,
+}
+
+#[test]
+fn test_try_new() {
+    let packet = Packet::try_new(3u64, 1500usize, -7i64).unwrap();
+    assert_eq!(packet.kind.get(), 3);
+    assert_eq!(packet.len, 1500);
+    assert_eq!(packet.offset, -7);
+
+    let err = Packet::try_new(16u8, 100u32, 200i32).unwrap_err();
+    assert!(err.contains("kind"));
+    assert!(!err.contains("len"));
+    assert!(err.contains("offset"));
+}
+
+#[test]
+fn test_new_saturating() {
+    let packet = Packet::new_saturating(100u32, -1i32, 1000i32);
+    assert_eq!(packet.kind, U::<4>::MAX);
+    assert_eq!(packet.len, 0);
+    assert_eq!(packet.offset, i8::MAX);
+}
+
+struct RawRecord {
+    id: u64,
+    kind: i32,
+    level: i64,
+    checksum: u32,
+    count: usize,
+}
+
+#[derive(Debug, PartialEq, truncate_integer::ShrinkFromStruct)]
+#[narrow(from = RawRecord)]
+struct Record {
+    id: u32,
+    #[narrow(checked)]
+    kind: U<4>,
+    #[narrow(saturate)]
+    level: i8,
+    #[narrow(unchecked, from = checksum)]
+    check: u16,
+    #[narrow(panic)]
+    count: u8,
+}
+
+fn raw(id: u64, kind: i32, level: i64, count: usize) -> RawRecord {
+    RawRecord {
+        id,
+        kind,
+        level,
+        checksum: 0xABCD_1234,
+        count,
+    }
+}
+
+#[test]
+fn test_shrink_from_struct() {
+    use std::convert::TryFrom;
+
+    let record = Record::try_from(raw(7, 3, -1000, 10)).unwrap();
+    assert_eq!(record.id, 7);
+    assert_eq!(record.kind.get(), 3);
+    assert_eq!(record.level, i8::MIN);
+    assert_eq!(record.check, 0x1234);
+    assert_eq!(record.count, 10);
+
+    let err = Record::try_from(raw(1 << 32, 16, 0, 0)).unwrap_err();
+    assert_eq!(err.to_string(), "fields out of range: id, kind");
+    let err = Record::try_from(raw(0, -1, 0, 0)).unwrap_err();
+    assert_eq!(err.to_string(), "fields out of range: kind");
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_shrink_from_struct_panic() {
+    use std::convert::TryFrom;
+
+    let _ = Record::try_from(raw(0, 0, 0, 256));
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "std")]
+
+use std::net::Ipv6Addr;
+use truncate_integer::net::{
+    from_parts, interface_id, mask_prefix, netmask, prefix_value, routing_prefix,
+};
+
+fn addr(s: &str) -> Ipv6Addr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_split() {
+    let a = addr("fe80::1ff:fe23:4567:890a");
+    assert_eq!(routing_prefix(a), 0xfe80_0000_0000_0000);
+    assert_eq!(interface_id(a), 0x01ff_fe23_4567_890a);
+    assert_eq!(from_parts(routing_prefix(a), interface_id(a)), a);
+    assert_eq!(routing_prefix(Ipv6Addr::UNSPECIFIED), 0);
+    assert_eq!(interface_id(Ipv6Addr::LOCALHOST), 1);
+}
+
+#[test]
+fn test_mask_prefix() {
+    let a = addr("2001:db8:ffff:ffff:ffff:ffff:ffff:ffff");
+    assert_eq!(mask_prefix(a, 0), Ipv6Addr::UNSPECIFIED);
+    assert_eq!(mask_prefix(a, 32), addr("2001:db8::"));
+    assert_eq!(mask_prefix(a, 33), addr("2001:db8:8000::"));
+    assert_eq!(mask_prefix(a, 64), addr("2001:db8:ffff:ffff::"));
+    assert_eq!(
+        mask_prefix(a, 127),
+        addr("2001:db8:ffff:ffff:ffff:ffff:ffff:fffe")
+    );
+    assert_eq!(mask_prefix(a, 128), a);
+    assert_eq!(netmask(0), Ipv6Addr::UNSPECIFIED);
+    assert_eq!(netmask(56), addr("ffff:ffff:ffff:ff00::"));
+    assert_eq!(
+        netmask(128),
+        addr("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff")
+    );
+}
+
+#[test]
+fn test_prefix_value() {
+    let a = addr("2001:db8:aaaa:bbbb:cccc:dddd:eeee:ffff");
+    assert_eq!(prefix_value::<u8>(a, 0), Some(0));
+    assert_eq!(prefix_value::<u8>(a, 4), Some(0x2));
+    assert_eq!(prefix_value::<u16>(a, 16), Some(0x2001));
+    assert_eq!(prefix_value::<u8>(a, 16), None);
+    assert_eq!(prefix_value::<u32>(a, 32), Some(0x2001_0db8));
+    assert_eq!(prefix_value::<u64>(a, 64), Some(routing_prefix(a)));
+    assert_eq!(prefix_value::<u128>(a, 128), Some(u128::from(a)));
+    assert_eq!(prefix_value::<u64>(a, 65), Some(routing_prefix(a) << 1 | 1));
+    assert_eq!(prefix_value::<u64>(addr("8000::"), 65), None);
+}
+
+#[test]
+#[should_panic(expected = "invalid prefix length")]
+fn test_invalid_prefix() {
+    mask_prefix(Ipv6Addr::LOCALHOST, 129);
+}
@@ -0,0 +1,42 @@
+use truncate_integer::nibble::*;
+
+#[test]
+fn test_split_join() {
+    for x in 0..=u8::MAX {
+        let (high, low) = x.split_nibbles();
+        assert!(high <= 0xF && low <= 0xF);
+        assert_eq!(join_nibbles(high, low), x);
+        assert_eq!(try_join_nibbles(high, low), Some(x));
+    }
+    assert_eq!(join_nibbles(0x12, 0x34), 0x24);
+    assert_eq!(try_join_nibbles(0x10, 0), None);
+    assert_eq!(try_join_nibbles(0, 0x10), None);
+}
+
+#[test]
+fn test_slices() {
+    let packed = [0x12, 0x34, 0xAB];
+    let mut digits = [0u8; 6];
+    split_nibbles_into(&packed, &mut digits);
+    assert_eq!(digits, [1, 2, 3, 4, 0xA, 0xB]);
+
+    let mut out = [0u8; 3];
+    join_nibbles_into(&[0x11, 0x22, 3, 4, 5, 6], &mut out);
+    assert_eq!(out, [0x12, 0x34, 0x56]);
+
+    try_join_nibbles_into(&digits, &mut out).unwrap();
+    assert_eq!(out, packed);
+
+    let err = try_join_nibbles_into(&[1, 2, 3, 4, 0x10, 0x20], &mut out).unwrap_err();
+    assert_eq!(err, NibbleError::new(4, 0x10));
+    assert_eq!(
+        err.to_string(),
+        "value 16 at position 4 doesn't fit in a nibble"
+    );
+}
+
+#[test]
+#[should_panic(expected = "nibble buffer length mismatch")]
+fn test_length_mismatch() {
+    join_nibbles_into(&[1, 2, 3], &mut [0u8; 1]);
+}
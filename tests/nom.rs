@@ -0,0 +1,75 @@
+#![cfg(feature = "nom")]
+
+use nom::error::{ContextError, Error, ErrorKind, ParseError};
+use truncate_integer::nom::{complete, streaming, OVERFLOW_CONTEXT};
+
+#[test]
+fn test_nom_try() {
+    let input: &[u8] = &[0, 0, 1, 0, 0, 0, 0, 0];
+    let (rest, x) = complete::be_u32_try::<u16, _, Error<_>>(input).unwrap();
+    assert_eq!(x, 256u16);
+    assert_eq!(rest.len(), 4);
+
+    let err = complete::be_u32_try::<u8, _, Error<_>>(input).unwrap_err();
+    assert_eq!(err, nom::Err::Error(Error::new(input, ErrorKind::Verify)));
+
+    let input: &[u8] = &[0xFF, 0xFF];
+    let (_, x) = complete::le_i16_try::<i8, _, Error<_>>(input).unwrap();
+    assert_eq!(x, -1i8);
+}
+
+#[derive(Debug, PartialEq)]
+struct ContextCapture {
+    kind: ErrorKind,
+    context: Option<&'static str>,
+}
+
+impl<I> ParseError<I> for ContextCapture {
+    fn from_error_kind(_input: I, kind: ErrorKind) -> Self {
+        ContextCapture {
+            kind,
+            context: None,
+        }
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I> ContextError<I> for ContextCapture {
+    fn add_context(_input: I, ctx: &'static str, mut other: Self) -> Self {
+        other.context = Some(ctx);
+        other
+    }
+}
+
+#[test]
+fn test_nom_try_context() {
+    let input: &[u8] = &[0xFF, 0xFF, 0xFF, 0xFF];
+    let err = complete::le_u32_try::<u16, _, ContextCapture>(input).unwrap_err();
+    let expected = ContextCapture {
+        kind: ErrorKind::Verify,
+        context: Some(OVERFLOW_CONTEXT),
+    };
+    assert_eq!(err, nom::Err::Error(expected));
+}
+
+#[test]
+fn test_nom_shrink() {
+    let input: &[u8] = &[0x80, 0, 0, 0, 0, 0, 0, 0];
+    let (_, x) = complete::be_i64_shrink::<i16, _, Error<_>>(input).unwrap();
+    assert_eq!(x, i16::MIN);
+    let (_, x) = complete::le_i64_shrink::<i8, _, Error<_>>(input).unwrap();
+    assert_eq!(x, i8::MAX);
+
+    let short: &[u8] = &[0];
+    assert!(matches!(
+        streaming::be_u16_shrink::<u8, _, Error<_>>(short),
+        Err(nom::Err::Incomplete(_))
+    ));
+    assert!(matches!(
+        complete::be_u16_shrink::<u8, _, Error<_>>(short),
+        Err(nom::Err::Error(_))
+    ));
+}
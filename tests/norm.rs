@@ -0,0 +1,44 @@
+use truncate_integer::norm::{FloatToNorm, SnormToFloat, UnormToFloat};
+
+#[test]
+fn test_unorm() {
+    assert_eq!(0.0f32.to_unorm8(), 0);
+    assert_eq!(1.0f32.to_unorm8(), 255);
+    assert_eq!((-0.5f32).to_unorm8(), 0);
+    assert_eq!(1.5f32.to_unorm8(), 255);
+    assert_eq!(f32::NAN.to_unorm8(), 0);
+    assert_eq!(f32::INFINITY.to_unorm16(), u16::MAX);
+    // 0.5 * 255 = 127.5 rounds up.
+    assert_eq!(0.5f32.to_unorm8(), 128);
+    assert_eq!(0.5f32.to_unorm16(), 32768);
+
+    for x in 0..=u8::MAX {
+        assert_eq!(x.to_unorm_f32().to_unorm8(), x);
+    }
+    for x in 0..=u16::MAX {
+        assert_eq!(x.to_unorm_f32().to_unorm16(), x);
+    }
+}
+
+#[test]
+fn test_snorm() {
+    assert_eq!(0.0f32.to_snorm8(), 0);
+    assert_eq!(1.0f32.to_snorm8(), 127);
+    assert_eq!((-1.0f32).to_snorm8(), -127);
+    assert_eq!((-2.0f32).to_snorm16(), -32767);
+    assert_eq!(f32::NAN.to_snorm8(), 0);
+    assert_eq!(f32::NEG_INFINITY.to_snorm8(), -127);
+    // -0.5 / 127 * 127 = -0.5 rounds up to 0.
+    assert_eq!((-0.5f32 / 127.0).to_snorm8(), 0);
+    assert_eq!((-0.6f32 / 127.0).to_snorm8(), -1);
+
+    assert_eq!(i8::MIN.to_snorm_f32(), -1.0);
+    assert_eq!(i16::MIN.to_snorm_f32(), -1.0);
+    assert_eq!(i8::MAX.to_snorm_f32(), 1.0);
+    for x in -127..=i8::MAX {
+        assert_eq!(x.to_snorm_f32().to_snorm8(), x);
+    }
+    for x in -32767..=i16::MAX {
+        assert_eq!(x.to_snorm_f32().to_snorm16(), x);
+    }
+}
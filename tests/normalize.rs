@@ -0,0 +1,54 @@
+use truncate_integer::normalize::normalize_shrink;
+
+#[test]
+fn test_normalize() {
+    assert_eq!(normalize_shrink::<u8, _>(0u32, 0, 1000), 0);
+    assert_eq!(normalize_shrink::<u8, _>(1000u32, 0, 1000), 255);
+    assert_eq!(normalize_shrink::<u8, _>(500u32, 0, 1000), 128);
+    assert_eq!(normalize_shrink::<u8, _>(1u32, 0, 1000), 0);
+    assert_eq!(normalize_shrink::<u8, _>(2u32, 0, 1000), 1);
+    assert_eq!(normalize_shrink::<u16, _>(1u8, 0, 3), 21845);
+    assert_eq!(normalize_shrink::<u16, _>(-1i8, -128, 127), 32639);
+}
+
+#[test]
+fn test_normalize_identity() {
+    for x in 0..=255u8 {
+        assert_eq!(normalize_shrink::<u8, _>(x, 0, 255), x);
+        assert_eq!(normalize_shrink::<u16, _>(x, 0, 255), u16::from(x) * 257);
+        assert_eq!(
+            normalize_shrink::<u8, _>(x as i8, i8::MIN, i8::MAX),
+            (x as i8 as i16 + 128) as u8
+        );
+    }
+}
+
+#[test]
+fn test_normalize_clamp() {
+    assert_eq!(normalize_shrink::<u8, _>(-5i64, 0, 10), 0);
+    assert_eq!(normalize_shrink::<u8, _>(11i64, 0, 10), 255);
+    assert_eq!(normalize_shrink::<u8, _>(5i64, 5, 5), 0);
+    assert_eq!(normalize_shrink::<u8, _>(6i64, 5, 5), 255);
+}
+
+#[test]
+fn test_normalize_reversed() {
+    assert_eq!(normalize_shrink::<u8, _>(10u32, 10, 0), 0);
+    assert_eq!(normalize_shrink::<u8, _>(0u32, 10, 0), 255);
+    assert_eq!(normalize_shrink::<u8, _>(20u32, 10, 0), 0);
+    assert_eq!(normalize_shrink::<u8, _>(3u32, 10, 0), 179);
+}
+
+#[test]
+fn test_normalize_full_range() {
+    assert_eq!(normalize_shrink::<u8, _>(u128::MAX / 2, 0, u128::MAX), 127);
+    assert_eq!(
+        normalize_shrink::<u16, _>(0i128, i128::MIN, i128::MAX),
+        32768
+    );
+    assert_eq!(
+        normalize_shrink::<u16, _>(i128::MAX - 1, i128::MIN, i128::MAX),
+        65535
+    );
+    assert_eq!(normalize_shrink::<u8, _>(u64::MAX / 3, 0, u64::MAX), 85);
+}
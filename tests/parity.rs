@@ -0,0 +1,141 @@
+//! Cross-check the truncation traits against `std`, `az` and
+//! `num-traits`.
+//!
+//! Every implemented conversion is checked on the boundary values of
+//! both types, and on a spread of pseudo-random values:
+//!
+//! - `TryTruncate` agrees with `TryFrom`, `az::checked_cast` and
+//!   `num_traits::cast`.
+//! - `Shrink` agrees with `az::saturating_cast`.
+//! - `Chop` agrees with `TryFrom` on values that fit.
+//! - `TruncateUnchecked` agrees with `az::wrapping_cast` and
+//!   `num_traits::AsPrimitive`.
+//!
+//! Narrowing, same-width and widening pairs are all covered, for both
+//! signed and unsigned sources. `az` and `num-traits` also convert to
+//! and from floats; those aren't covered here.
+
+use num_traits::AsPrimitive;
+use std::convert::TryFrom;
+use truncate_integer::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+
+/// Values at every power-of-two boundary, and a spread of others.
+fn samples() -> Vec<u128> {
+    let mut values = vec![0, u128::MAX];
+    for k in 0..128 {
+        let p = 1u128 << k;
+        values.extend_from_slice(&[p - 1, p, p + 1]);
+        // The same values, negated, as two's complement bits.
+        values.extend_from_slice(&[
+            (p - 1).wrapping_neg(),
+            p.wrapping_neg(),
+            (p + 1).wrapping_neg(),
+        ]);
+    }
+    let mut x = 0x2545_F491_4F6C_DD1Du128;
+    for _ in 0..1000 {
+        x = x
+            .wrapping_mul(0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645)
+            .wrapping_add(0x5851_F42D_4C95_7F2D_1405_7B7E_F767_814F);
+        // Vary the magnitude, so narrow types see in-range values too.
+        values.push(x >> (x % 128));
+        values.push((x >> (x % 128)).wrapping_neg());
+    }
+    values
+}
+
+macro_rules! check_try {
+    ($Source:ty => $($Dest:ty),*) => {
+        $(
+            for &bits in &samples() {
+                let x: $Source = az::wrapping_cast(bits);
+                let expected = <$Dest>::try_from(x).ok();
+                let what = concat!(stringify!($Source), " -> ", stringify!($Dest));
+                assert_eq!(TryTruncate::<$Dest>::try_truncate(x), expected, "{} try_truncate {}", what, x);
+                assert_eq!(az::checked_cast::<_, $Dest>(x), expected, "{} az {}", what, x);
+                assert_eq!(num_traits::cast::<_, $Dest>(x), expected, "{} num-traits {}", what, x);
+                assert_eq!(
+                    Shrink::<$Dest>::shrink(x),
+                    az::saturating_cast::<_, $Dest>(x),
+                    "{} shrink {}",
+                    what,
+                    x
+                );
+                if let Some(value) = expected {
+                    assert_eq!(Chop::<$Dest>::chop(x), value, "{} chop {}", what, x);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! check_unchecked {
+    ($Source:ty => $($Dest:ty),*) => {
+        $(
+            for &bits in &samples() {
+                let x: $Source = az::wrapping_cast(bits);
+                let what = concat!(stringify!($Source), " -> ", stringify!($Dest));
+                let result = TruncateUnchecked::<$Dest>::truncate_unchecked(x);
+                assert_eq!(result, az::wrapping_cast::<_, $Dest>(x), "{} az {}", what, x);
+                assert_eq!(result, AsPrimitive::<$Dest>::as_(x), "{} num-traits {}", what, x);
+            }
+        )*
+    };
+}
+
+#[test]
+fn test_parity_unsigned_source() {
+    check_try!(u128 => u64, u32, u16, u8, i64, i32, i16, i8);
+    check_try!(u64 => u32, u16, u8, i32, i16, i8);
+    check_try!(u32 => u16, u8, i16, i8);
+    check_try!(u16 => u8, i8);
+    check_try!(usize => u32, u16, u8);
+}
+
+#[test]
+fn test_parity_signed_source() {
+    check_try!(i128 => i64, i32, i16, i8, u64, u32, u16, u8);
+    check_try!(i64 => i32, i16, i8, u32, u16, u8, u128);
+    check_try!(i32 => i16, i8, u16, u8, u128, u64);
+    check_try!(i16 => i8, u8, u128, u64, u32);
+    check_try!(i8 => u128, u64, u32, u16);
+}
+
+#[test]
+fn test_parity_unchecked() {
+    check_unchecked!(u128 => u64, u32, u16, u8, i64, i32, i16, i8);
+    check_unchecked!(u64 => u32, u16, u8, i32, i16, i8);
+    check_unchecked!(u32 => u16, u8, i16, i8);
+    check_unchecked!(u16 => u8, i8);
+    check_unchecked!(usize => u32, u16, u8);
+    check_unchecked!(i128 => u64, u32, u16, u8, i64, i32, i16, i8);
+    check_unchecked!(i64 => u32, u16, u8, i32, i16, i8);
+    check_unchecked!(i32 => u16, u8, i16, i8);
+    check_unchecked!(i16 => u8, i8);
+    check_unchecked!(isize => u32, u16, u8, i32, i16, i8);
+}
+
+#[test]
+fn test_parity_same_width_and_widening() {
+    check_try!(u8 => u8, i8, u16, i16, u128, i128);
+    check_try!(i8 => i8, i16, i128, usize, isize);
+    check_try!(u32 => u32, i32, u64, i64, usize);
+    check_try!(i32 => i32, u32, i64, isize);
+    check_try!(u64 => u64, i64, u128, i128);
+    check_try!(i64 => i64, u64, i128);
+    check_try!(u128 => u128, i128);
+    check_try!(i128 => i128);
+    check_try!(usize => usize, isize, u64, u128);
+    check_try!(isize => isize, usize, i64, i128);
+
+    check_unchecked!(u8 => u8, i8, u16, i16, u128, i128);
+    check_unchecked!(i8 => i8, u8, i16, u16, i128, u128, usize, isize);
+    check_unchecked!(u32 => u32, i32, u64, i64, usize);
+    check_unchecked!(i32 => i32, u32, i64, u64, isize);
+    check_unchecked!(u64 => u64, i64, u128, i128);
+    check_unchecked!(i64 => i64, u64, i128, u128);
+    check_unchecked!(u128 => u128, i128);
+    check_unchecked!(i128 => i128, u128);
+    check_unchecked!(usize => usize, isize, u64, u128);
+    check_unchecked!(isize => isize, usize, i64, u128);
+}
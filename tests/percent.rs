@@ -0,0 +1,34 @@
+use truncate_integer::percent::{basis_points_of, percent_of, permille_of};
+
+#[test]
+fn test_percent_of() {
+    assert_eq!(percent_of(0, 10), 0);
+    assert_eq!(percent_of(10, 10), 100);
+    assert_eq!(percent_of(1, 200), 1);
+    assert_eq!(percent_of(1, 201), 0);
+    assert_eq!(percent_of(199, 200), 100);
+    assert_eq!(percent_of(255, 100), 255);
+    assert_eq!(percent_of(256, 100), 255);
+    assert_eq!(percent_of(u64::MAX, u64::MAX), 100);
+    assert_eq!(percent_of(u64::MAX / 2, u64::MAX), 50);
+}
+
+#[test]
+fn test_empty_whole() {
+    assert_eq!(percent_of(0, 0), 0);
+    assert_eq!(percent_of(1, 0), u8::MAX);
+    assert_eq!(permille_of(0, 0), 0);
+    assert_eq!(permille_of(5, 0), u16::MAX);
+    assert_eq!(basis_points_of(u64::MAX, 0), u16::MAX);
+}
+
+#[test]
+fn test_permille_and_basis_points() {
+    assert_eq!(permille_of(1, 3), 333);
+    assert_eq!(permille_of(1, 2000), 1);
+    assert_eq!(permille_of(65, 1), 65000);
+    assert_eq!(permille_of(66, 1), u16::MAX);
+    assert_eq!(basis_points_of(1, 3), 3333);
+    assert_eq!(basis_points_of(2, 3), 6667);
+    assert_eq!(basis_points_of(7, 1), u16::MAX);
+}
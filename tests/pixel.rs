@@ -0,0 +1,41 @@
+use truncate_integer::pixel::*;
+
+#[test]
+fn test_rgb565() {
+    assert_eq!(pack_rgb565([0, 0, 0]), 0);
+    assert_eq!(pack_rgb565([255, 255, 255]), 0xFFFF);
+    assert_eq!(pack_rgb565([255, 0, 0]), 0xF800);
+    assert_eq!(pack_rgb565([0, 255, 0]), 0x07E0);
+    assert_eq!(pack_rgb565([0, 0, 255]), 0x001F);
+    // Rounding, not truncation: 7 is closer to 8.2 (one 5-bit step) than to 0.
+    assert_eq!(pack_rgb565([7, 0, 0]), 0x0800);
+    assert_eq!(unpack_rgb565(0xFFFF), [255, 255, 255]);
+
+    for pixel in 0..=u16::MAX {
+        assert_eq!(pack_rgb565(unpack_rgb565(pixel)), pixel);
+    }
+}
+
+#[test]
+fn test_argb1555() {
+    assert_eq!(pack_argb1555([127, 0, 0, 0]), 0);
+    assert_eq!(pack_argb1555([128, 0, 0, 0]), 0x8000);
+    assert_eq!(pack_argb1555([255, 255, 255, 255]), 0xFFFF);
+    assert_eq!(unpack_argb1555(0x8000), [255, 0, 0, 0]);
+
+    for pixel in 0..=u16::MAX {
+        assert_eq!(pack_argb1555(unpack_argb1555(pixel)), pixel);
+    }
+}
+
+#[test]
+fn test_rgba4444() {
+    assert_eq!(pack_rgba4444([255, 0, 0, 0]), 0xF000);
+    assert_eq!(pack_rgba4444([0, 0, 0, 255]), 0x000F);
+    assert_eq!(pack_rgba4444([8, 9, 0, 0]), 0x0100);
+    assert_eq!(unpack_rgba4444(0x1234), [0x11, 0x22, 0x33, 0x44]);
+
+    for pixel in 0..=u16::MAX {
+        assert_eq!(pack_rgba4444(unpack_rgba4444(pixel)), pixel);
+    }
+}
@@ -0,0 +1,81 @@
+use truncate_integer::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+
+// These hold on every target.
+#[test]
+fn test_pointer_width_common() {
+    let x: u64 = usize::MAX.chop();
+    assert_eq!(x, usize::MAX as u64);
+    let x: i128 = isize::MIN.chop();
+    assert_eq!(x, isize::MIN as i128);
+    let x: Option<u64> = (-1isize).try_truncate();
+    assert_eq!(x, None);
+    let x: Option<usize> = (-1isize).try_truncate();
+    assert_eq!(x, None);
+    let x: isize = usize::MAX.shrink();
+    assert_eq!(x, isize::MAX);
+    let x: isize = usize::MAX.truncate_unchecked();
+    assert_eq!(x, -1);
+    let x: usize = (u64::MAX as u128 + 1).shrink();
+    assert_eq!(x, usize::MAX);
+    let x: Option<isize> = (isize::MAX as i128 + 1).try_truncate();
+    assert_eq!(x, None);
+    let x: Option<usize> = 65535u32.try_truncate();
+    assert_eq!(x, Some(65535));
+}
+
+#[cfg(target_pointer_width = "16")]
+#[test]
+fn test_pointer_width_16() {
+    let x: Option<usize> = 65536u32.try_truncate();
+    assert_eq!(x, None);
+    let x: isize = 40000u16.shrink();
+    assert_eq!(x, i16::MAX as isize);
+    let x: Option<isize> = 32768i32.try_truncate();
+    assert_eq!(x, None);
+    let x: Option<i8> = 128isize.try_truncate();
+    assert_eq!(x, None);
+    let x: u16 = usize::MAX.chop();
+    assert_eq!(x, u16::MAX);
+    let x: Option<u8> = usize::MAX.try_truncate();
+    assert_eq!(x, None);
+}
+
+#[cfg(target_pointer_width = "32")]
+#[test]
+fn test_pointer_width_32() {
+    let x: Option<usize> = 65536u32.try_truncate();
+    assert_eq!(x, Some(65536));
+    let x: Option<usize> = (1u64 << 32).try_truncate();
+    assert_eq!(x, None);
+    let x: usize = u64::MAX.shrink();
+    assert_eq!(x, u32::MAX as usize);
+    let x: Option<isize> = (i32::MAX as i64 + 1).try_truncate();
+    assert_eq!(x, None);
+    let x: isize = u32::MAX.shrink();
+    assert_eq!(x, i32::MAX as isize);
+    let x: i32 = isize::MIN.chop();
+    assert_eq!(x, i32::MIN);
+    let x: Option<i16> = isize::MAX.try_truncate();
+    assert_eq!(x, None);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn test_pointer_width_64() {
+    let x: Option<usize> = (1u64 << 32).try_truncate();
+    assert_eq!(x, Some(1 << 32));
+    let x: usize = u64::MAX.chop();
+    assert_eq!(x, usize::MAX);
+    let x: Option<usize> = (1u128 << 64).try_truncate();
+    assert_eq!(x, None);
+    let x: isize = u32::MAX.chop();
+    assert_eq!(x, u32::MAX as isize);
+    let x: Option<isize> = (i64::MIN as i128 - 1).try_truncate();
+    assert_eq!(x, None);
+    let x: Option<i32> = isize::MIN.try_truncate();
+    assert_eq!(x, None);
+    let x: i32 = isize::MIN.shrink();
+    assert_eq!(x, i32::MIN);
+    let x: u32 = usize::MAX.truncate_unchecked();
+    assert_eq!(x, u32::MAX);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "protobuf")]
+
+use truncate_integer::protobuf::*;
+use truncate_integer::TruncateError;
+
+#[test]
+fn test_field() {
+    assert_eq!(field::<u32, u16>("port", 443), Ok(443));
+    let err = field::<u32, u16>("port", 70000).unwrap_err();
+    assert_eq!(err.field(), "port");
+    assert_eq!(err.error(), TruncateError::Overflow);
+    assert_eq!(err.to_string(), "field port: integer overflow");
+    assert_eq!(
+        field::<i64, u8>("count", -1),
+        Err(FieldError::new("count", TruncateError::Underflow))
+    );
+}
+
+#[test]
+fn test_optional_field() {
+    assert_eq!(optional_field::<i32, u8>("x", None), Ok(None));
+    assert_eq!(optional_field::<i32, u8>("x", Some(5)), Ok(Some(5)));
+    assert!(optional_field::<i32, u8>("x", Some(256)).is_err());
+}
+
+#[test]
+fn test_zigzag() {
+    let cases = [
+        (0, 0),
+        (-1, 1),
+        (1, 2),
+        (-2, 3),
+        (i64::MAX, u64::MAX - 1),
+        (i64::MIN, u64::MAX),
+    ];
+    for &(value, raw) in &cases {
+        assert_eq!(zigzag_encode(value), raw);
+        assert_eq!(zigzag_decode(raw), value);
+    }
+}
+
+#[test]
+fn test_sint_field() {
+    assert_eq!(sint_field::<i16>("d", zigzag_encode(-32768)), Ok(-32768));
+    let err = sint_field::<i16>("d", zigzag_encode(-32769)).unwrap_err();
+    assert_eq!(err.error(), TruncateError::Underflow);
+    assert_eq!(sint_field::<u32>("d", 4), Ok(2));
+}
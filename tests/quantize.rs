@@ -0,0 +1,65 @@
+use truncate_integer::quantize::{
+    quantize_chop, quantize_shrink, quantize_try, snap_chop, snap_shrink, snap_try,
+};
+
+#[test]
+fn test_quantize_rounding() {
+    assert_eq!(quantize_try::<u8, _>(0u32, 10), Some(0));
+    assert_eq!(quantize_try::<u8, _>(4u32, 10), Some(0));
+    assert_eq!(quantize_try::<u8, _>(5u32, 10), Some(1));
+    assert_eq!(quantize_try::<u8, _>(14u32, 10), Some(1));
+    assert_eq!(quantize_try::<u8, _>(7u32, 3), Some(2));
+    assert_eq!(quantize_try::<i8, _>(-4i32, 10), Some(0));
+    assert_eq!(quantize_try::<i8, _>(-5i32, 10), Some(-1));
+    assert_eq!(quantize_try::<i8, _>(-15i32, 10), Some(-2));
+    assert_eq!(quantize_try::<u8, _>(-4i32, 10), Some(0));
+    assert_eq!(quantize_try::<u8, _>(-5i32, 10), None);
+}
+
+#[test]
+fn test_quantize_extremes() {
+    assert_eq!(quantize_try::<u128, _>(u128::MAX, 1), Some(u128::MAX));
+    assert_eq!(quantize_try::<u128, _>(u128::MAX, 2), Some(1 << 127));
+    assert_eq!(quantize_try::<i128, _>(i128::MIN, 1), Some(i128::MIN));
+    assert_eq!(
+        quantize_try::<i128, _>(i128::MIN, 3),
+        Some(i128::MIN / 3 - 1)
+    );
+    assert_eq!(quantize_shrink::<i8, _>(i128::MIN, 1), i8::MIN);
+    assert_eq!(quantize_shrink::<u8, _>(u128::MAX, u64::MAX), 255);
+    assert_eq!(quantize_chop::<u16, _>(1000u64, 7), 143);
+}
+
+#[test]
+#[should_panic(expected = "quantize step is zero")]
+fn test_quantize_zero_step() {
+    quantize_try::<u8, _>(1u8, 0);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_quantize_chop_overflow() {
+    quantize_chop::<u8, _>(10_000u32, 3);
+}
+
+#[test]
+fn test_snap() {
+    assert_eq!(snap_try::<u8, _>(22u32, 15), Some(15));
+    assert_eq!(snap_try::<u8, _>(23u32, 15), Some(30));
+    assert_eq!(snap_try::<u8, _>(248u32, 15), Some(255));
+    assert_eq!(snap_try::<u8, _>(263u32, 15), None);
+    assert_eq!(snap_try::<i16, _>(-23i32, 15), Some(-30));
+    assert_eq!(snap_shrink::<i8, _>(-200i32, 15), i8::MIN);
+    assert_eq!(snap_chop::<u32, _>(1_000_001u64, 1000), 1_000_000);
+}
+
+#[test]
+fn test_snap_overflow() {
+    // The nearest multiple is past the end of every type.
+    assert_eq!(snap_try::<u128, _>(u128::MAX, 10), None);
+    assert_eq!(snap_shrink::<u128, _>(u128::MAX, 10), u128::MAX);
+    assert_eq!(snap_try::<i128, _>(i128::MIN, 10), None);
+    assert_eq!(snap_shrink::<i128, _>(i128::MIN, 10), i128::MIN);
+    assert_eq!(snap_shrink::<u8, _>(i128::MIN, 10), 0);
+    assert_eq!(snap_try::<u128, _>(u128::MAX, 5), Some(u128::MAX));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "quickcheck")]
+
+use quickcheck::{Arbitrary, Gen};
+use truncate_integer::quickcheck::{Boundary, Fits, Overflows};
+use truncate_integer::TryTruncate;
+
+fn check<Src, Dest>()
+where
+    Src: TryTruncate<Dest> + Copy + std::fmt::Debug,
+    Fits<Src, Dest>: Arbitrary,
+    Overflows<Src, Dest>: Arbitrary,
+{
+    let mut g = Gen::new(100);
+    for _ in 0..1000 {
+        let x = Fits::<Src, Dest>::arbitrary(&mut g);
+        assert!(x.get().try_truncate().is_some(), "{:?}", x);
+        for s in x.shrink() {
+            assert!(s.get().try_truncate().is_some(), "{:?}", s);
+        }
+        let y = Overflows::<Src, Dest>::arbitrary(&mut g);
+        assert!(y.get().try_truncate().is_none(), "{:?}", y);
+        for s in y.shrink() {
+            assert!(s.get().try_truncate().is_none(), "{:?}", s);
+        }
+    }
+}
+
+#[test]
+fn test_classes() {
+    check::<u64, u8>();
+    check::<i64, i8>();
+    check::<i32, u16>();
+    check::<u32, i16>();
+    check::<i128, u64>();
+    check::<u128, i64>();
+    check::<u128, u64>();
+}
+
+#[test]
+fn test_edges_are_generated() {
+    let mut g = Gen::new(100);
+    let values: Vec<u64> = (0..1000)
+        .map(|_| Fits::<u64, u8>::arbitrary(&mut g).get())
+        .collect();
+    assert!(values.contains(&0));
+    assert!(values.contains(&255));
+    let values: Vec<i16> = (0..1000)
+        .map(|_| Overflows::<i16, i8>::arbitrary(&mut g).get())
+        .collect();
+    assert!(values.contains(&128));
+    assert!(values.contains(&-129));
+    assert!(values.contains(&i16::MIN));
+}
+
+#[test]
+fn test_shrink() {
+    let x = Overflows::<u32, u8>::arbitrary(&mut Gen::new(100));
+    let shrunk: Vec<u32> = x.shrink().map(|s| s.get()).collect();
+    if x.get() != 256 {
+        assert_eq!(shrunk.first(), Some(&256));
+    }
+}
+
+#[test]
+fn test_boundary() {
+    let mut g = Gen::new(100);
+    let values: Vec<i8> = (0..1000)
+        .map(|_| Boundary::<i8>::arbitrary(&mut g).0)
+        .collect();
+    for &x in &[-128, -127, -1, 0, 1, 126, 127] {
+        assert!(values.contains(&x));
+    }
+    assert!(values
+        .iter()
+        .all(|x| [-128, -127, -1, 0, 1, 126, 127].contains(x)));
+    let values: Vec<u8> = (0..1000)
+        .map(|_| Boundary::<u8>::arbitrary(&mut g).0)
+        .collect();
+    assert!(values.iter().all(|x| [0, 1, 254, 255].contains(x)));
+    assert_eq!(Boundary(5u8).shrink().next(), Some(Boundary(0)));
+}
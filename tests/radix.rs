@@ -0,0 +1,65 @@
+use truncate_integer::radix::{digit, digit_bits, digit_count, digits, digits_bits, Order};
+
+#[test]
+fn test_digit() {
+    let key = 0x0102_0304_0506_0708u64;
+    for k in 0..8 {
+        assert_eq!(digit::<u8, _>(key, k, Order::Lsb), 8 - k as u8);
+        assert_eq!(digit::<u8, _>(key, k, Order::Msb), k as u8 + 1);
+    }
+    assert_eq!(digit::<u16, _>(key, 3, Order::Lsb), 0x0102);
+    assert_eq!(digit::<u32, _>(key, 0, Order::Msb), 0x0102_0304);
+    assert_eq!(digit::<u8, _>(u128::MAX, 15, Order::Lsb), 0xFF);
+}
+
+#[test]
+fn test_digit_signed() {
+    // Signed keys use their own two's complement bits.
+    assert_eq!(digit::<u8, _>(-2i16, 0, Order::Lsb), 0xFE);
+    assert_eq!(digit::<u8, _>(-2i16, 0, Order::Msb), 0xFF);
+    assert_eq!(digit_bits::<u8, _>(-1i8, 3, 0, Order::Msb), 0b11);
+    assert_eq!(digit::<i8, _>(0x80u8, 0, Order::Lsb), i8::MIN);
+}
+
+#[test]
+fn test_digit_bits() {
+    assert_eq!(digit_count::<u8>(3), 3);
+    assert_eq!(digit_count::<u32>(11), 3);
+    assert_eq!(digit_count::<u128>(1), 128);
+
+    let key = 0b10_110_001u8;
+    assert_eq!(digit_bits::<u8, _>(key, 3, 0, Order::Lsb), 0b001);
+    assert_eq!(digit_bits::<u8, _>(key, 3, 1, Order::Lsb), 0b110);
+    assert_eq!(digit_bits::<u8, _>(key, 3, 2, Order::Lsb), 0b10);
+    assert_eq!(digit_bits::<u8, _>(key, 3, 0, Order::Msb), 0b10);
+    assert_eq!(digit_bits::<u16, _>(u32::MAX, 11, 0, Order::Msb), 0x3FF);
+    assert_eq!(digit_bits::<u8, _>(0x8000_0000u32, 1, 0, Order::Msb), 1);
+}
+
+#[test]
+#[should_panic(expected = "digit index out of range")]
+fn test_digit_out_of_range() {
+    digit::<u8, _>(0u32, 4, Order::Lsb);
+}
+
+#[test]
+#[should_panic(expected = "invalid digit width")]
+fn test_digit_too_wide() {
+    digit_bits::<u8, _>(0u32, 9, 0, Order::Lsb);
+}
+
+#[test]
+fn test_digits() {
+    let keys = [0x1234u16, 0xABCD, 0x0000, 0xFFFF];
+    let mut column = [0u8; 4];
+    digits(&keys, 0, Order::Msb, &mut column);
+    assert_eq!(column, [0x12, 0xAB, 0x00, 0xFF]);
+    digits_bits(&keys, 4, 0, Order::Lsb, &mut column);
+    assert_eq!(column, [0x4, 0xD, 0x0, 0xF]);
+}
+
+#[test]
+#[should_panic(expected = "digit buffer length mismatch")]
+fn test_digits_mismatch() {
+    digits(&[1u16, 2], 0, Order::Lsb, &mut [0u8; 1]);
+}
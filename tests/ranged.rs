@@ -0,0 +1,85 @@
+use truncate_integer::ranged::Ranged;
+
+#[test]
+fn test_ranged_new() {
+    assert!(Ranged::new(5u8, 0, 10).is_some());
+    assert!(Ranged::new(11u8, 0, 10).is_none());
+    let x = Ranged::full(-3i8);
+    assert_eq!((x.min(), x.max(), x.get()), (i8::MIN, i8::MAX, -3));
+    let x = Ranged::exact(7u32);
+    assert_eq!((x.min(), x.max()), (7, 7));
+}
+
+#[test]
+fn test_ranged_arith() {
+    let a = Ranged::new(10i32, -5, 20).unwrap();
+    let b = Ranged::new(3i32, 1, 4).unwrap();
+
+    let x = a + b;
+    assert_eq!((x.get(), x.min(), x.max()), (13, -4, 24));
+    let x = a - b;
+    assert_eq!((x.get(), x.min(), x.max()), (7, -9, 19));
+    let x = a * b;
+    assert_eq!((x.get(), x.min(), x.max()), (30, -20, 80));
+    let x = a << 2;
+    assert_eq!((x.get(), x.min(), x.max()), (40, -20, 80));
+    let x = a >> 1;
+    assert_eq!((x.get(), x.min(), x.max()), (5, -3, 10));
+}
+
+#[test]
+fn test_ranged_shl_boundary() {
+    // 2^(BITS - 1) doesn't fit in a signed type, but these shifts do.
+    let x = Ranged::new(-1i8, -1, 0).unwrap() << 7;
+    assert_eq!((x.get(), x.min(), x.max()), (i8::MIN, i8::MIN, 0));
+    let x = Ranged::new(1i8, 0, 1).unwrap() << 6;
+    assert_eq!((x.get(), x.min(), x.max()), (64, 0, 64));
+    let x = Ranged::exact(0i64) << 63;
+    assert_eq!((x.get(), x.min(), x.max()), (0, 0, 0));
+    let x = Ranged::new(1u8, 0, 1).unwrap() << 7;
+    assert_eq!((x.get(), x.min(), x.max()), (128, 0, 128));
+}
+
+#[test]
+#[should_panic(expected = "Ranged overflow")]
+fn test_ranged_shl_overflow() {
+    let _ = Ranged::new(0i8, 0, 1).unwrap() << 7;
+}
+
+#[test]
+fn test_ranged_mask() {
+    let x = Ranged::full(0x1234u32) & 0xFF;
+    assert_eq!((x.get(), x.min(), x.max()), (0x34, 0, 0xFF));
+    let x: u8 = x.into_infallible();
+    assert_eq!(x, 0x34);
+
+    let x = Ranged::new(-1i16, -1, 0).unwrap() & 0xFF;
+    assert_eq!((x.get(), x.min(), x.max()), (0xFF, 0, 0xFF));
+    let x = Ranged::new(-1i16, -8, -1).unwrap() & -4;
+    assert_eq!((x.get(), x.min(), x.max()), (-4, i16::MIN, -4));
+}
+
+#[test]
+fn test_ranged_fits() {
+    let x = Ranged::full(300u16).clamp(0, 255);
+    assert!(x.fits::<u8>());
+    assert!(!x.fits::<i8>());
+    assert_eq!(x.into_infallible::<u8>(), 255);
+
+    let x = Ranged::full(200u8).widen::<i16>() - Ranged::exact(128);
+    assert!(x.fits::<i8>());
+    assert_eq!(x.into_infallible::<i8>(), 72);
+}
+
+#[test]
+#[should_panic(expected = "Ranged interval does not fit")]
+fn test_ranged_into_infallible_panic() {
+    // The value fits, but the range doesn't.
+    let _: u8 = Ranged::full(1u16).into_infallible();
+}
+
+#[test]
+#[should_panic(expected = "Ranged overflow")]
+fn test_ranged_overflow_panic() {
+    let _ = Ranged::full(1u8) + Ranged::exact(1);
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "num-rational")]
+
+use num_rational::{BigRational, Ratio};
+use truncate_integer::rational::{RoundTruncate, Rounding};
+use truncate_integer::TruncateError;
+
+#[test]
+fn test_round_modes() {
+    let x = Ratio::new(-7i32, 2);
+    assert_eq!(x.round_try::<i8>(Rounding::Floor), Ok(-4));
+    assert_eq!(x.round_try::<i8>(Rounding::Ceil), Ok(-3));
+    assert_eq!(x.round_try::<i8>(Rounding::Trunc), Ok(-3));
+    assert_eq!(x.round_try::<i8>(Rounding::Nearest), Ok(-4));
+    assert_eq!(
+        x.round_try::<u8>(Rounding::Ceil),
+        Err(TruncateError::Underflow)
+    );
+    assert_eq!(x.round_shrink::<u8>(Rounding::Ceil), 0);
+    assert_eq!(x.round_chop::<i16>(Rounding::Trunc), -3);
+}
+
+#[test]
+fn test_round_limits() {
+    let x = Ratio::new(2550u64, 10);
+    assert_eq!(x.round_try::<u8>(Rounding::Nearest), Ok(255));
+    let x = Ratio::new(2555u64, 10);
+    assert_eq!(x.round_try::<u8>(Rounding::Floor), Ok(255));
+    assert_eq!(
+        x.round_try::<u8>(Rounding::Nearest),
+        Err(TruncateError::Overflow)
+    );
+    assert_eq!(x.round_shrink::<i8>(Rounding::Nearest), i8::MAX);
+}
+
+#[test]
+fn test_round_bigrational() {
+    let x = BigRational::from_float(1234.5f64).unwrap();
+    assert_eq!(x.round_try::<u16>(Rounding::Nearest), Ok(1235));
+    assert_eq!(
+        x.round_try::<u8>(Rounding::Floor),
+        Err(TruncateError::Overflow)
+    );
+
+    // Larger than any primitive integer.
+    let huge = BigRational::from_float(1e40f64).unwrap();
+    assert_eq!(
+        huge.round_try::<u128>(Rounding::Floor),
+        Err(TruncateError::Overflow)
+    );
+    assert_eq!(huge.round_shrink::<u32>(Rounding::Floor), u32::MAX);
+    let tiny = BigRational::from_float(-1e40f64).unwrap();
+    assert_eq!(
+        tiny.round_try::<i128>(Rounding::Ceil),
+        Err(TruncateError::Underflow)
+    );
+    assert_eq!(tiny.round_shrink::<i64>(Rounding::Ceil), i64::MIN);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_round_chop_panic() {
+    Ratio::new(1000i64, 1).round_chop::<u8>(Rounding::Floor);
+}
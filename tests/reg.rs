@@ -0,0 +1,81 @@
+use truncate_integer::reg::{Flags, RegValue};
+
+#[test]
+fn test_write_wraps() {
+    assert_eq!(RegValue::<8>::new(0x1234u32).bits(), 0x34);
+    assert_eq!(RegValue::<8>::new(-1i64).bits(), 0xFF);
+    assert_eq!(RegValue::<8>::new(-1i64).signed().get(), -1);
+    assert_eq!(RegValue::<1>::new(3u8).bits(), 1);
+    assert_eq!(RegValue::<128>::new(-1i8).bits(), u128::MAX);
+    assert_eq!(RegValue::<24>::new(0xFF_FFFFu32).get().get(), 0xFF_FFFF);
+    assert_eq!(format!("{:x}", RegValue::<16>::new(0xBEEFu32)), "beef");
+    assert_eq!(RegValue::<16>::new(1234u32).to_string(), "1234");
+}
+
+#[test]
+fn test_add() {
+    // Compare against the flags of 8-bit arithmetic with primitives.
+    for a in 0..=255u8 {
+        for b in 0..=255u8 {
+            for &c in &[false, true] {
+                let (sum, flags) = RegValue::<8>::new(a).carrying_add(RegValue::new(b), c);
+                let wide = a as u16 + b as u16 + c as u16;
+                assert_eq!(sum.bits(), wide as u8 as u128);
+                assert_eq!(flags.carry, wide > 255);
+                let signed = a as i8 as i16 + b as i8 as i16 + c as i16;
+                assert_eq!(flags.overflow, !(-128..=127).contains(&signed));
+                assert_eq!(flags.zero, wide as u8 == 0);
+                assert_eq!(flags.negative, wide & 0x80 != 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sub() {
+    for a in 0..=255u8 {
+        for b in 0..=255u8 {
+            for &c in &[false, true] {
+                let (diff, flags) = RegValue::<8>::new(a).borrowing_sub(RegValue::new(b), c);
+                let wide = a as i16 - b as i16 - c as i16;
+                assert_eq!(diff.bits(), wide as u8 as u128);
+                assert_eq!(flags.carry, wide < 0);
+                let signed = a as i8 as i16 - b as i8 as i16 - c as i16;
+                assert_eq!(flags.overflow, !(-128..=127).contains(&signed));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_full_width() {
+    let max = RegValue::<128>::new(u128::MAX);
+    let (sum, flags) = max.overflowing_add(RegValue::new(1u8));
+    assert_eq!(sum.bits(), 0);
+    assert_eq!(
+        flags,
+        Flags {
+            carry: true,
+            overflow: false,
+            zero: true,
+            negative: false
+        }
+    );
+    let (_, flags) = RegValue::<128>::new(i128::MAX).overflowing_add(RegValue::new(1u8));
+    assert!(flags.overflow && flags.negative && !flags.carry);
+    let (diff, flags) = RegValue::<128>::new(0u8).overflowing_sub(RegValue::new(1u8));
+    assert_eq!(diff, max);
+    assert!(flags.carry);
+}
+
+#[test]
+fn test_mul() {
+    let (product, flags) = RegValue::<12>::new(64u32).overflowing_mul(RegValue::new(63u32));
+    assert_eq!(product.bits(), 4032);
+    assert!(!flags.carry);
+    let (product, flags) = RegValue::<12>::new(64u32).overflowing_mul(RegValue::new(64u32));
+    assert_eq!(product.bits(), 0);
+    assert!(flags.carry && flags.overflow && flags.zero);
+    let (_, flags) = RegValue::<128>::new(1u128 << 64).overflowing_mul(RegValue::new(1u128 << 64));
+    assert!(flags.carry);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "rkyv")]
+
+use rkyv::rancor::Error;
+use rkyv::{Archive, Deserialize, Serialize};
+use truncate_integer::rkyv::{ShrinkAs, TryTruncateAs};
+
+#[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+struct Record {
+    #[rkyv(with = ShrinkAs<u8>)]
+    level: i32,
+    #[rkyv(with = TryTruncateAs<i16>)]
+    offset: i64,
+}
+
+#[test]
+fn test_rkyv_round_trip() {
+    let record = Record {
+        level: 12,
+        offset: -300,
+    };
+    let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+    let archived = rkyv::access::<ArchivedRecord, Error>(&bytes).unwrap();
+    assert_eq!(archived.level, 12);
+    assert_eq!(archived.offset, -300);
+    let out: Record = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(out, record);
+}
+
+#[test]
+fn test_rkyv_shrink() {
+    let record = Record {
+        level: -5,
+        offset: 0,
+    };
+    let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+    let out: Record = rkyv::from_bytes::<_, Error>(&bytes).unwrap();
+    assert_eq!(out.level, 0);
+
+    let record = Record {
+        level: 1000,
+        offset: 0,
+    };
+    let bytes = rkyv::to_bytes::<Error>(&record).unwrap();
+    let out: Record = rkyv::from_bytes::<_, Error>(&bytes).unwrap();
+    assert_eq!(out.level, 255);
+}
+
+#[test]
+fn test_rkyv_try_truncate_error() {
+    let record = Record {
+        level: 0,
+        offset: -40000,
+    };
+    let err = rkyv::to_bytes::<Error>(&record).unwrap_err();
+    assert_eq!(err.to_string(), "integer underflow");
+    let record = Record {
+        level: 0,
+        offset: 40000,
+    };
+    let err = rkyv::to_bytes::<Error>(&record).unwrap_err();
+    assert_eq!(err.to_string(), "integer overflow");
+}
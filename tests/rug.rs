@@ -0,0 +1,56 @@
+#![cfg(feature = "rug")]
+
+use rug::Integer;
+use truncate_integer::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+
+fn pow2(bits: u32) -> Integer {
+    Integer::from(1) << bits
+}
+
+#[test]
+fn test_try_truncate() {
+    let x: Option<u8> = Integer::from(255).try_truncate();
+    assert_eq!(x, Some(255));
+    let x: Option<u8> = Integer::from(256).try_truncate();
+    assert_eq!(x, None);
+    let x: Option<u32> = Integer::from(-1).try_truncate();
+    assert_eq!(x, None);
+    let x: Option<i128> = (&Integer::from(i128::MIN)).try_truncate();
+    assert_eq!(x, Some(i128::MIN));
+    let x: Option<u128> = Integer::from(u128::MAX).try_truncate();
+    assert_eq!(x, Some(u128::MAX));
+    let x: Option<u128> = pow2(128).try_truncate();
+    assert_eq!(x, None);
+    let x: Option<i128> = (-pow2(127) - 1u32).try_truncate();
+    assert_eq!(x, None);
+}
+
+#[test]
+fn test_shrink() {
+    let x: u16 = pow2(1000).shrink();
+    assert_eq!(x, u16::MAX);
+    let x: i64 = (-pow2(1000)).shrink();
+    assert_eq!(x, i64::MIN);
+    let x: u64 = (-pow2(1000)).shrink();
+    assert_eq!(x, 0);
+    let x: i8 = Integer::from(-5).shrink();
+    assert_eq!(x, -5);
+}
+
+#[test]
+fn test_truncate_unchecked() {
+    let x: u8 = Integer::from(0x1234).truncate_unchecked();
+    assert_eq!(x, 0x34);
+    let x: i8 = Integer::from(0xFF).truncate_unchecked();
+    assert_eq!(x, -1);
+    let x: u64 = (-pow2(200) - 1u32).truncate_unchecked();
+    assert_eq!(x, u64::MAX);
+    let x: i128 = (pow2(300) + 7u32).truncate_unchecked();
+    assert_eq!(x, 7);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_chop_panic() {
+    let _: i32 = pow2(31).chop();
+}
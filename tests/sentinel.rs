@@ -0,0 +1,42 @@
+use truncate_integer::sentinel::{ShrinkWithSentinel, WithSentinel};
+use truncate_integer::transport::VarInt;
+
+#[test]
+fn test_shrink_with_sentinel() {
+    assert_eq!(254u16.shrink_with_sentinel(u8::MAX).get(), Some(254));
+    assert_eq!(255u16.shrink_with_sentinel(u8::MAX).get(), None);
+    assert_eq!(256u16.shrink_with_sentinel(u8::MAX).get(), None);
+    assert_eq!(256u16.shrink_with_sentinel(u8::MAX).raw(), 255);
+
+    // A sentinel that isn't at either end.
+    let x = 0xFFi32.shrink_with_sentinel(0xFEu8);
+    assert_eq!(x.get(), Some(0xFF));
+    let x = 0xFEi32.shrink_with_sentinel(0xFEu8);
+    assert!(x.is_overflowed());
+    let x = (-1i32).shrink_with_sentinel(0xFEu8);
+    assert_eq!(x.raw(), 0xFE);
+
+    let x = (-200i64).shrink_with_sentinel(i8::MIN);
+    assert_eq!(x.raw(), i8::MIN);
+    let x = (-127i64).shrink_with_sentinel(i8::MIN);
+    assert_eq!(x.get(), Some(-127));
+
+    let x = u64::MAX.shrink_with_sentinel(VarInt::MAX);
+    assert!(x.is_overflowed());
+    assert_eq!(x.sentinel(), VarInt::MAX);
+}
+
+#[test]
+fn test_with_sentinel() {
+    assert_eq!(
+        WithSentinel::new(7u8, u8::MAX).map(WithSentinel::get),
+        Some(Some(7))
+    );
+    assert_eq!(WithSentinel::new(u8::MAX, u8::MAX), None);
+    assert_eq!(
+        WithSentinel::from_raw(u8::MAX, u8::MAX),
+        WithSentinel::overflowed(u8::MAX)
+    );
+    assert_eq!(WithSentinel::from_raw(3u16, 0).get(), Some(3));
+    assert_eq!(WithSentinel::from_raw(0u16, 0).get(), None);
+}
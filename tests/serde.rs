@@ -0,0 +1,120 @@
+#![cfg(feature = "serde")]
+
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Sample {
+    #[serde(serialize_with = "truncate_integer::serde::shrink_to_u8")]
+    level: i32,
+    #[serde(serialize_with = "truncate_integer::serde::try_to_u16")]
+    len: u64,
+    #[serde(serialize_with = "truncate_integer::serde::shrink_to_i16")]
+    offset: i64,
+}
+
+fn to_json(level: i32, len: u64, offset: i64) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&Sample { level, len, offset })
+}
+
+#[test]
+fn test_serialize_shrink() {
+    assert_eq!(
+        to_json(-5, 1, i64::MIN).unwrap(),
+        r#"{"level":0,"len":1,"offset":-32768}"#
+    );
+    assert_eq!(
+        to_json(1000, 1, 7).unwrap(),
+        r#"{"level":255,"len":1,"offset":7}"#
+    );
+}
+
+#[test]
+fn test_serialize_try() {
+    assert_eq!(
+        to_json(0, 65535, 0).unwrap(),
+        r#"{"level":0,"len":65535,"offset":0}"#
+    );
+    let err = to_json(0, 65536, 0).unwrap_err();
+    assert_eq!(err.to_string(), "integer overflow");
+}
+
+#[test]
+fn test_error_round_trip() {
+    use truncate_integer::analyze::IntType;
+    use truncate_integer::index::IndexError;
+    use truncate_integer::len::LenError;
+    use truncate_integer::nibble::NibbleError;
+    use truncate_integer::TruncateError;
+
+    for &err in &[
+        TruncateError::Overflow,
+        TruncateError::Underflow,
+        TruncateError::Invalid,
+    ] {
+        let json = serde_json::to_string(&err).unwrap();
+        assert_eq!(serde_json::from_str::<TruncateError>(&json).unwrap(), err);
+    }
+    assert_eq!(
+        serde_json::to_string(&TruncateError::Underflow).unwrap(),
+        r#""Underflow""#
+    );
+    assert!(serde_json::from_str::<TruncateError>(r#""Sideways""#).is_err());
+
+    let err = IndexError::new(3, 70000);
+    let json = serde_json::to_string(&err).unwrap();
+    assert_eq!(json, r#"{"position":3,"value":70000}"#);
+    assert_eq!(serde_json::from_str::<IndexError>(&json).unwrap(), err);
+
+    // Fields can come in any order, unknown fields are skipped, and a
+    // sequence works too.
+    let err: NibbleError =
+        serde_json::from_str(r#"{"value":16,"extra":[1],"position":2}"#).unwrap();
+    assert_eq!(err, NibbleError::new(2, 16));
+    let err: LenError = serde_json::from_str("[300,255]").unwrap();
+    assert_eq!(err, LenError::new(300, 255));
+    assert!(serde_json::from_str::<LenError>(r#"{"len":300}"#).is_err());
+    assert!(serde_json::from_str::<LenError>(r#"{"len":1,"len":2,"limit":3}"#).is_err());
+
+    assert_eq!(
+        serde_json::from_str::<IntType>(r#""I16""#).unwrap(),
+        IntType::I16
+    );
+}
+
+#[test]
+fn test_serialize_reports() {
+    use truncate_integer::analyze::analyze;
+    use truncate_integer::narrow_struct;
+
+    let report = analyze(&[3, 300]);
+    assert_eq!(
+        serde_json::to_value(&report).unwrap(),
+        serde_json::json!({
+            "len": 2,
+            "min": 3,
+            "max": 300,
+            "bits": 9,
+            "narrowest": "U16",
+            "failures": {
+                "U8": 1, "I8": 1, "U16": 0, "I16": 0,
+                "U32": 0, "I32": 0, "U64": 0, "I64": 0,
+            },
+        })
+    );
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Header {
+        port: u16,
+        ttl: u8,
+    }
+    let err = narrow_struct!(Header {
+        port: 70000u32,
+        ttl: -1i32
+    })
+    .unwrap_err();
+    assert_eq!(
+        serde_json::to_string(&err).unwrap(),
+        r#"{"fields":["port","ttl"]}"#
+    );
+}
@@ -0,0 +1,165 @@
+#![cfg(all(feature = "serde", feature = "alloc"))]
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use truncate_integer::compact::CompactError;
+use truncate_integer::serde_compact::{from_slice, to_vec, Deserializer, Error};
+use truncate_integer::TruncateError;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Event {
+    Reset,
+    Count(u64),
+    Move(i16, i16),
+    Rename { from: String, to: String },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u128,
+    offset: i64,
+    ok: bool,
+    ratio: f32,
+    scale: f64,
+    grade: char,
+    note: Option<String>,
+    samples: Vec<u32>,
+    tags: BTreeMap<u8, i8>,
+    pair: (u16, i32),
+    events: Vec<Event>,
+}
+
+#[test]
+fn test_round_trip() {
+    let record = Record {
+        id: u128::MAX,
+        offset: i64::MIN,
+        ok: true,
+        ratio: 0.5,
+        scale: -1.25,
+        grade: 'é',
+        note: None,
+        samples: vec![0, 1, 255, 256, u32::MAX],
+        tags: vec![(1, -1), (2, 127)].into_iter().collect(),
+        pair: (7, -7),
+        events: vec![
+            Event::Reset,
+            Event::Count(1 << 40),
+            Event::Move(-3, 4),
+            Event::Rename {
+                from: "a".into(),
+                to: "bc".into(),
+            },
+        ],
+    };
+    let bytes = to_vec(&record).unwrap();
+    assert_eq!(from_slice::<Record>(&bytes).unwrap(), record);
+
+    let note = Some(String::from("hi"));
+    let bytes = to_vec(&note).unwrap();
+    assert_eq!(bytes, [1, 1, 2, b'h', b'i']);
+    assert_eq!(from_slice::<Option<String>>(&bytes).unwrap(), note);
+}
+
+#[test]
+fn test_minimal_widths() {
+    assert_eq!(to_vec(&0u64).unwrap(), [0x00]);
+    assert_eq!(to_vec(&255u128).unwrap(), [0x01, 0xFF]);
+    assert_eq!(to_vec(&256i32).unwrap(), [0x02, 0x00, 0x01]);
+    assert_eq!(to_vec(&-1i64).unwrap(), [0x81, 0xFF]);
+    assert_eq!(to_vec(&-129i16).unwrap(), [0x82, 0x7F, 0xFF]);
+    assert_eq!(to_vec(&Event::Count(5)).unwrap(), [0x01, 0x01, 0x01, 0x05]);
+}
+
+#[test]
+fn test_borrowed() {
+    #[derive(Deserialize)]
+    struct Borrowed<'a> {
+        name: &'a str,
+        #[serde(with = "serde_bytes_like")]
+        data: &'a [u8],
+    }
+
+    mod serde_bytes_like {
+        use serde::Deserializer;
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<&'de [u8], D::Error> {
+            serde::Deserialize::deserialize(d)
+        }
+    }
+
+    let bytes = [0x01, 0x02, b'o', b'k', 0x01, 0x03, 1, 2, 3];
+    let value: Borrowed = from_slice(&bytes).unwrap();
+    assert_eq!(value.name, "ok");
+    assert_eq!(value.data, [1, 2, 3]);
+}
+
+#[test]
+fn test_narrowing_on_read() {
+    let bytes = to_vec(&300u64).unwrap();
+    assert_eq!(from_slice::<u16>(&bytes), Ok(300));
+    assert_eq!(
+        from_slice::<u8>(&bytes),
+        Err(Error::Compact(CompactError::Truncate(
+            TruncateError::Overflow
+        )))
+    );
+    let bytes = to_vec(&-1i32).unwrap();
+    assert_eq!(
+        from_slice::<u64>(&bytes),
+        Err(Error::Compact(CompactError::Truncate(
+            TruncateError::Underflow
+        )))
+    );
+    assert_eq!(from_slice::<i8>(&bytes), Ok(-1));
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(from_slice::<u32>(&[0x02, 0x01]), Err(Error::UnexpectedEnd));
+    assert_eq!(
+        from_slice::<u32>(&[0x01, 0x01, 0x00]),
+        Err(Error::TrailingBytes)
+    );
+    assert_eq!(from_slice::<bool>(&[2]), Err(Error::InvalidTag(2)));
+    assert_eq!(
+        from_slice::<String>(&[0x01, 0x01, 0xFF]),
+        Err(Error::InvalidUtf8)
+    );
+    assert_eq!(
+        from_slice::<char>(&[0x02, 0x00, 0xD8]),
+        Err(Error::InvalidChar(0xD800))
+    );
+    assert_eq!(
+        from_slice::<Event>(&[0x01, 0x09]).unwrap_err().to_string(),
+        "invalid value: integer `9`, expected variant index 0 <= i < 4"
+    );
+    assert_eq!(
+        from_slice::<serde::de::IgnoredAny>(&[0]).unwrap_err(),
+        Error::AnyNotSupported
+    );
+}
+
+#[test]
+fn test_unknown_length() {
+    struct Unsized;
+
+    impl Serialize for Unsized {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            use serde::ser::SerializeSeq;
+            let mut seq = serializer.serialize_seq(None)?;
+            seq.serialize_element(&1u8)?;
+            seq.end()
+        }
+    }
+
+    assert_eq!(to_vec(&Unsized), Err(Error::UnknownLength));
+}
+
+#[test]
+fn test_deserializer_remaining() {
+    let bytes = [0x01, 0x05, 0x01, 0x06];
+    let mut de = Deserializer::new(&bytes);
+    assert_eq!(u8::deserialize(&mut de), Ok(5));
+    assert_eq!(de.remaining(), [0x01, 0x06]);
+}
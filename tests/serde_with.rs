@@ -0,0 +1,62 @@
+#![cfg(all(feature = "serde", feature = "serde_with"))]
+
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use std::collections::HashMap;
+use truncate_integer::serde_with::{ChopAs, ShrinkAs, TryAs};
+
+#[serde_as]
+#[derive(Deserialize, Debug, PartialEq)]
+struct Input {
+    #[serde_as(as = "ShrinkAs<i64>")]
+    level: u8,
+    #[serde_as(as = "HashMap<_, TryAs<u64>>")]
+    ports: HashMap<String, u16>,
+    #[serde_as(as = "ChopAs<i32>")]
+    offset: i8,
+}
+
+#[serde_as]
+#[derive(Serialize)]
+struct Output {
+    #[serde_as(as = "Vec<ShrinkAs<u8>>")]
+    levels: Vec<u32>,
+    #[serde_as(as = "TryAs<i16>")]
+    delta: i64,
+}
+
+#[test]
+fn test_serde_with_deserialize() {
+    let input: Input =
+        serde_json::from_str(r#"{"level":-3,"ports":{"http":80},"offset":-7}"#).unwrap();
+    assert_eq!(input.level, 0);
+    assert_eq!(input.ports["http"], 80);
+    assert_eq!(input.offset, -7);
+
+    let err =
+        serde_json::from_str::<Input>(r#"{"level":0,"ports":{"x":65536},"offset":0}"#).unwrap_err();
+    assert!(err.to_string().starts_with("integer overflow"), "{}", err);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_serde_with_chop_panic() {
+    let _ = serde_json::from_str::<Input>(r#"{"level":0,"ports":{},"offset":128}"#);
+}
+
+#[test]
+fn test_serde_with_serialize() {
+    let json = serde_json::to_string(&Output {
+        levels: vec![1, 256],
+        delta: -40,
+    })
+    .unwrap();
+    assert_eq!(json, r#"{"levels":[1,255],"delta":-40}"#);
+
+    let err = serde_json::to_string(&Output {
+        levels: vec![],
+        delta: -40000,
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "integer underflow");
+}
@@ -0,0 +1,68 @@
+use std::cmp::Ordering;
+use truncate_integer::serial::Serial;
+
+#[test]
+fn test_serial_cmp() {
+    assert_eq!(Serial(1u32).partial_cmp(&Serial(1)), Some(Ordering::Equal));
+    assert!(Serial(1u32) < Serial(2));
+    assert!(Serial(u32::MAX) < Serial(0));
+    assert!(Serial(0u32) > Serial(u32::MAX));
+    assert!(Serial(0u32) < Serial(0x7FFF_FFFF));
+    assert!(Serial(0u32) > Serial(0x8000_0001));
+    assert_eq!(Serial(0u32).partial_cmp(&Serial(0x8000_0000)), None);
+    assert_eq!(Serial(5u16).partial_cmp(&Serial(0x8005)), None);
+
+    // Comparison isn't transitive, which is why `Ord` isn't implemented.
+    assert!(Serial(0u8) < Serial(100));
+    assert!(Serial(100u8) < Serial(200));
+    assert!(Serial(200u8) < Serial(0));
+}
+
+#[test]
+fn test_serial_distance() {
+    assert_eq!(Serial(10u64).distance(Serial(3)), Some(-7));
+    assert_eq!(Serial(u64::MAX - 1).distance(Serial(2)), Some(4));
+    assert_eq!(Serial(0u8).distance(Serial(128)), None);
+    assert_eq!(Serial(0u8).distance(Serial(129)), Some(-127));
+}
+
+#[test]
+fn test_serial_add() {
+    assert_eq!(Serial(250u8).checked_add(10), Some(Serial(4)));
+    assert_eq!(Serial(250u8).checked_add(127), Some(Serial(121)));
+    assert_eq!(Serial(250u8).checked_add(128), None);
+    assert_eq!(Serial(250u8).wrapping_add(200), Serial(194));
+    assert_eq!(Serial::<u16>::MAX_ADD, 0x7FFF);
+}
+
+#[test]
+fn test_expand_truncated() {
+    use truncate_integer::serial::expand_truncated;
+
+    assert_eq!(expand_truncated(0x9b32u16, 16, 0xa82f30eb), 0xa82f9b32);
+    assert_eq!(expand_truncated(0xFFu8, 8, 0x100), 0xFF);
+    assert_eq!(expand_truncated(0x00u8, 8, 0xFF), 0x100);
+    // The window is (reference - 128, reference + 128].
+    assert_eq!(expand_truncated(0x80u8, 8, 0x100), 0x180);
+    assert_eq!(expand_truncated(0x81u8, 8, 0x100), 0x81);
+    // No wrap below zero.
+    assert_eq!(expand_truncated(0xF0u8, 8, 3), 0xF0);
+    assert_eq!(expand_truncated(0u8, 8, 3), 0);
+    // No wrap above u64::MAX.
+    assert_eq!(expand_truncated(1u8, 8, u64::MAX), u64::MAX - 0xFE);
+    assert_eq!(expand_truncated(0x1234u32, 12, 0x1000), 0x1234);
+    assert_eq!(expand_truncated(42u64, 64, 7), 42);
+
+    for reference in 0x1000u64..0x1400 {
+        for offset in 1..=256 {
+            let value = reference - 128 + offset;
+            assert_eq!(expand_truncated(value as u8, 8, reference), value);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_expand_truncated_bits() {
+    truncate_integer::serial::expand_truncated(0u8, 0, 0);
+}
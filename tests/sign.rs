@@ -0,0 +1,62 @@
+use truncate_integer::sign::{Sign, SignMagnitude};
+
+#[test]
+fn test_split_sign() {
+    assert_eq!(0i8.split_sign(), (Sign::Positive, 0u8));
+    assert_eq!((-1i8).split_sign(), (Sign::Negative, 1u8));
+    assert_eq!(i8::MAX.split_sign(), (Sign::Positive, 127u8));
+    assert_eq!(i8::MIN.split_sign(), (Sign::Negative, 128u8));
+    assert_eq!(i128::MIN.split_sign(), (Sign::Negative, 1u128 << 127));
+    assert_eq!(200u8.split_sign(), (Sign::Positive, 200u8));
+}
+
+#[test]
+fn test_split_sign_narrow() {
+    assert_eq!(i16::MIN.try_split_sign::<u8>(), None);
+    assert_eq!(i16::MIN.try_split_sign::<i16>(), None);
+    assert_eq!(
+        (-255i16).try_split_sign::<u8>(),
+        Some((Sign::Negative, 255))
+    );
+    assert_eq!(i16::MIN.shrink_split_sign::<u8>(), (Sign::Negative, 255));
+    assert_eq!(
+        i16::MIN.shrink_split_sign::<i16>(),
+        (Sign::Negative, i16::MAX)
+    );
+}
+
+#[test]
+fn test_from_sign_magnitude() {
+    for x in i8::MIN..=i8::MAX {
+        let (sign, magnitude) = x.split_sign();
+        assert_eq!(i8::try_from_sign_magnitude(sign, magnitude), Some(x));
+    }
+    assert_eq!(i8::try_from_sign_magnitude(Sign::Negative, 0u8), Some(0));
+    assert_eq!(i8::try_from_sign_magnitude(Sign::Negative, 129u8), None);
+    assert_eq!(i8::try_from_sign_magnitude(Sign::Positive, -1i8), None);
+    assert_eq!(u8::try_from_sign_magnitude(Sign::Negative, 0u8), Some(0));
+    assert_eq!(u8::try_from_sign_magnitude(Sign::Negative, 1u8), None);
+    assert_eq!(
+        i128::try_from_sign_magnitude(Sign::Negative, 1u128 << 127),
+        Some(i128::MIN)
+    );
+    assert_eq!(
+        i128::try_from_sign_magnitude(Sign::Negative, (1u128 << 127) + 1),
+        None
+    );
+
+    assert_eq!(
+        i8::shrink_from_sign_magnitude(Sign::Negative, 1000u32),
+        i8::MIN
+    );
+    assert_eq!(
+        i8::shrink_from_sign_magnitude(Sign::Positive, 1000u32),
+        i8::MAX
+    );
+    assert_eq!(i8::shrink_from_sign_magnitude(Sign::Positive, -5i32), 0);
+    assert_eq!(u8::shrink_from_sign_magnitude(Sign::Negative, 5u32), 0);
+    assert_eq!(
+        i64::shrink_from_sign_magnitude(Sign::Negative, u128::MAX),
+        i64::MIN
+    );
+}
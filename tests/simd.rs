@@ -0,0 +1,57 @@
+#![cfg(feature = "portable_simd")]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+
+use core::simd::Simd;
+use truncate_integer::simd::TryTruncateLanes;
+use truncate_integer::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+
+#[test]
+fn test_simd_unsigned() {
+    let v = Simd::from_array([0u32, 255, 256, u32::MAX]);
+    let x: Simd<u8, 4> = v.shrink();
+    assert_eq!(x.to_array(), [0, 255, 255, 255]);
+    let x: Simd<u8, 4> = v.truncate_unchecked();
+    assert_eq!(x.to_array(), [0, 255, 0, 255]);
+    let x: Option<Simd<u8, 4>> = v.try_truncate();
+    assert_eq!(x, None);
+    let x: Option<Simd<u16, 4>> = Simd::from_array([0u32, 1, 2, 65535]).try_truncate();
+    assert_eq!(x.map(Simd::to_array), Some([0, 1, 2, 65535]));
+
+    let (x, fits) = TryTruncateLanes::<Simd<i16, 4>>::try_truncate_lanes(v);
+    assert_eq!(x.to_array(), [0, 255, 256, i16::MAX]);
+    assert_eq!(fits.to_array(), [true, true, true, false]);
+}
+
+#[test]
+fn test_simd_signed() {
+    let v = Simd::from_array([-129i64, -128, -1, 0, 127, 128, 255, 256]);
+    let x: Simd<i8, 8> = v.shrink();
+    assert_eq!(x.to_array(), [-128, -128, -1, 0, 127, 127, 127, 127]);
+    let x: Simd<u8, 8> = v.shrink();
+    assert_eq!(x.to_array(), [0, 0, 0, 0, 127, 128, 255, 255]);
+    let x: Simd<u8, 8> = v.truncate_unchecked();
+    assert_eq!(x.to_array(), [127, 128, 255, 0, 127, 128, 255, 0]);
+
+    let (_, fits) = TryTruncateLanes::<Simd<u8, 8>>::try_truncate_lanes(v);
+    assert_eq!(
+        fits.to_array(),
+        [false, false, false, true, true, true, true, false]
+    );
+
+    let x: Simd<u64, 2> = Simd::from_array([-1i64, i64::MAX]).shrink();
+    assert_eq!(x.to_array(), [0, i64::MAX as u64]);
+    let x: Simd<i8, 2> = Simd::from_array([255u8, 1]).truncate_unchecked();
+    assert_eq!(x.to_array(), [-1, 1]);
+}
+
+#[test]
+fn test_simd_chop() {
+    let x: Simd<i16, 2> = Simd::from_array([-5i32, 5]).chop();
+    assert_eq!(x.to_array(), [-5, 5]);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_simd_chop_panic() {
+    let _: Simd<u16, 2> = Simd::from_array([-1i32, 5]).chop();
+}
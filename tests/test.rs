@@ -1,4 +1,8 @@
-use truncate_integer::{Chop, TryTruncate, TryTruncateFrom, Shrink, TruncateUnchecked};
+use truncate_integer::{
+    cast, narrowest, narrowest_signed, BitCast, BitCastFrom, Chop, CommonWider, CrossCmp,
+    IntegerRepr, Shrink, TruncateError, TruncateInto, TruncateUnchecked, TryTruncate,
+    TryTruncateFrom, Wider,
+};
 
 #[test]
 #[should_panic]
@@ -56,6 +60,16 @@ fn test_try_truncate() {
     assert!(x.is_none());
 }
 
+#[test]
+fn test_try_truncate_or_keep() {
+    let x: Result<u8, u16> = 255u16.try_truncate_or_keep();
+    assert_eq!(x, Ok(255));
+    let x: Result<u8, u16> = 257u16.try_truncate_or_keep();
+    assert_eq!(x, Err(257));
+    let x: Result<i8, i64> = i64::MIN.try_truncate_or_keep();
+    assert_eq!(x, Err(i64::MIN));
+}
+
 #[test]
 fn test_shrink() {
     let x: u8 = 257u16.shrink();
@@ -69,3 +83,406 @@ fn test_truncate_unchecked() {
     let x: u8 = 257u16.truncate_unchecked();
     assert_eq!(x, 1u8);
 }
+
+#[derive(Debug, PartialEq)]
+enum Opcode {
+    Nop,
+    Jump,
+}
+
+impl std::convert::TryFrom<u8> for Opcode {
+    type Error = ();
+
+    fn try_from(x: u8) -> Result<Self, ()> {
+        match x {
+            0 => Ok(Opcode::Nop),
+            0x80 => Ok(Opcode::Jump),
+            _ => Err(()),
+        }
+    }
+}
+
+impl IntegerRepr for Opcode {
+    type Repr = u8;
+}
+
+#[test]
+fn test_truncate_into() {
+    assert_eq!(0x80u64.try_truncate_into::<Opcode>(), Ok(Opcode::Jump));
+    assert_eq!(0i16.try_truncate_into::<Opcode>(), Ok(Opcode::Nop));
+    assert_eq!(
+        1u16.try_truncate_into::<Opcode>(),
+        Err(TruncateError::Invalid)
+    );
+    assert_eq!(
+        0x180u16.try_truncate_into::<Opcode>(),
+        Err(TruncateError::Overflow)
+    );
+    assert_eq!(
+        (-1i32).try_truncate_into::<Opcode>(),
+        Err(TruncateError::Underflow)
+    );
+    assert_eq!(0x80u32.chop_into::<Opcode>(), Opcode::Jump);
+}
+
+#[test]
+#[should_panic(expected = "chop invalid value")]
+fn test_chop_into_panic() {
+    let _x: Opcode = 1u32.chop_into();
+}
+
+#[test]
+fn test_cross_cmp() {
+    use std::cmp::Ordering;
+
+    assert_eq!(3000000000u64.cmp_across(i32::MAX), Ordering::Greater);
+    assert_eq!(0u8.cmp_across(-1i128), Ordering::Greater);
+    assert_eq!(i128::MIN.cmp_across(0usize), Ordering::Less);
+    assert_eq!(u128::MAX.cmp_across(i128::MAX), Ordering::Greater);
+    assert_eq!((-5isize).cmp_across(-4i8), Ordering::Less);
+    assert_eq!(7u16.cmp_across(7i32), Ordering::Equal);
+    assert!(u32::MAX.eq_across(u32::MAX as u64));
+    assert!(!u32::MAX.eq_across(-1i32));
+}
+
+#[test]
+fn test_min_max_shrink() {
+    use truncate_integer::{max_shrink, min_shrink};
+
+    let x: u8 = min_shrink(u64::MAX, 7u8);
+    assert_eq!(x, 7);
+    let x: u8 = min_shrink(-1i64, 7u8);
+    assert_eq!(x, 0);
+    let x: i8 = min_shrink(i128::MIN, 0u128);
+    assert_eq!(x, i8::MIN);
+    let x: u8 = max_shrink(u64::MAX, 7u8);
+    assert_eq!(x, u8::MAX);
+    let x: i16 = max_shrink(-20i8, -30i64);
+    assert_eq!(x, -20);
+    let x: usize = max_shrink(u128::MAX, 0i8);
+    assert_eq!(x, usize::MAX);
+}
+
+#[test]
+fn test_min_bits() {
+    use truncate_integer::MinBits;
+
+    assert_eq!(0u8.min_bits(), 0);
+    assert_eq!(1u8.min_bits(), 1);
+    assert_eq!(u128::MAX.min_bits(), 128);
+    assert_eq!(0i8.min_bits(), 1);
+    assert_eq!((-1i8).min_bits(), 1);
+    assert_eq!(1i8.min_bits(), 2);
+    assert_eq!(i8::MIN.min_bits(), 8);
+    assert_eq!(i128::MIN.min_bits(), 128);
+    assert_eq!(i128::MAX.min_bits(), 128);
+}
+
+#[test]
+fn test_conversion_kind() {
+    use truncate_integer::{conversion_kind, ConversionKind::*};
+
+    assert_eq!(conversion_kind::<u8, u8>(), Lossless);
+    assert_eq!(conversion_kind::<u8, i8>(), RangeChecked);
+    assert_eq!(conversion_kind::<u32, i64>(), Lossless);
+    assert_eq!(conversion_kind::<i8, i128>(), Lossless);
+    assert_eq!(conversion_kind::<i8, u128>(), SignCheckOnly);
+    assert_eq!(conversion_kind::<i64, u64>(), SignCheckOnly);
+    assert_eq!(conversion_kind::<i64, u32>(), RangeChecked);
+    assert_eq!(conversion_kind::<u128, u64>(), RangeChecked);
+    assert_eq!(conversion_kind::<u16, f32>(), Lossless);
+    assert_eq!(conversion_kind::<i32, f64>(), Lossless);
+    assert_eq!(conversion_kind::<u32, f32>(), Lossy);
+    assert_eq!(conversion_kind::<i64, f64>(), Lossy);
+    assert!(conversion_kind::<u16, usize>().is_lossless());
+}
+
+#[test]
+fn test_sign_check_only() {
+    let x: Option<u64> = (-1i32).try_truncate();
+    assert!(x.is_none());
+    let x: Option<u128> = i64::MAX.try_truncate();
+    assert_eq!(x, Some(i64::MAX as u128));
+    let x: u16 = (-100i8).shrink();
+    assert_eq!(x, 0);
+    let x: u32 = i16::MAX.shrink();
+    assert_eq!(x, 32767);
+    let x: u128 = 5i8.chop();
+    assert_eq!(x, 5);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_sign_check_only_panic() {
+    let _x: u64 = (-1i16).chop();
+}
+
+fn wider_name<A: CommonWider<B>, B>() -> &'static str {
+    std::any::type_name::<Wider<A, B>>()
+}
+
+#[test]
+fn test_common_wider() {
+    assert_eq!(wider_name::<u8, u8>(), "u8");
+    assert_eq!(wider_name::<u8, u32>(), "u32");
+    assert_eq!(wider_name::<i64, i8>(), "i64");
+    assert_eq!(wider_name::<u32, i16>(), "i64");
+    assert_eq!(wider_name::<i16, u32>(), "i64");
+    assert_eq!(wider_name::<u8, i16>(), "i16");
+    assert_eq!(wider_name::<u8, i8>(), "i16");
+    assert_eq!(wider_name::<u64, i128>(), "i128");
+    assert_eq!(wider_name::<u64, i8>(), "i128");
+    assert_eq!(wider_name::<u128, u8>(), "u128");
+
+    let x: Wider<u32, i16> = u32::MAX.into();
+    let y: Wider<u32, i16> = i16::MIN.into();
+    assert_eq!(x - y, 4294967295 + 32768);
+}
+
+#[test]
+fn test_shrink_unsigned_to_signed() {
+    let x: i16 = 40000u32.shrink();
+    assert_eq!(x, i16::MAX);
+    let x: i32 = 0xFFFF_FFFF_0000_0000u64.shrink();
+    assert_eq!(x, i32::MAX);
+    let x: i64 = (u128::MAX - u64::MAX as u128).shrink();
+    assert_eq!(x, i64::MAX);
+}
+
+#[test]
+fn test_cast_macro() {
+    assert_eq!(cast!(300u32 => u8, checked), None);
+    assert_eq!(cast!(-1i64 => i8, checked), Some(-1));
+    assert_eq!(cast!(300u32 => u8, saturate), 255);
+    assert_eq!(cast!(-300i32 => i8, saturate), -128);
+    assert_eq!(cast!(0x1FFu16 => u8, wrap), 0xFF);
+    assert_eq!(cast!(100u64 + 1 => u8, panic), 101);
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn test_cast_macro_panic() {
+    let _ = cast!(256u16 => u8, panic);
+}
+
+#[test]
+fn test_narrowest() {
+    let a: u8 = narrowest!(0);
+    let b: u8 = narrowest!(255);
+    let c: u16 = narrowest!(256);
+    let d: u32 = narrowest!(70000);
+    let e: u64 = narrowest!(1 << 32);
+    let f: u128 = narrowest!(u128::MAX);
+    assert_eq!((a, b, c, d, e, f), (0, 255, 256, 70000, 1 << 32, u128::MAX));
+
+    let a: i8 = narrowest_signed!(-128);
+    let b: i16 = narrowest_signed!(128);
+    let c: i16 = narrowest_signed!(-129);
+    let d: i32 = narrowest_signed!(-40000);
+    let e: i64 = narrowest_signed!(i32::MAX as i128 + 1);
+    let f: i128 = narrowest_signed!(i128::MIN);
+    assert_eq!(
+        (a, b, c, d, e, f),
+        (-128, 128, -129, -40000, 1 << 31, i128::MIN)
+    );
+}
+
+#[test]
+fn test_128_bit_limb_boundaries() {
+    use std::convert::TryFrom;
+
+    // Values around every 64-bit limb boundary, and around the range of
+    // each destination type.
+    let mut values = vec![0i128, 1, -1, i128::MIN, i128::MAX];
+    for &edge in &[
+        u8::MAX as i128,
+        i8::MIN as i128,
+        u16::MAX as i128,
+        i16::MIN as i128,
+        u32::MAX as i128,
+        i32::MIN as i128,
+        u64::MAX as i128,
+        i64::MIN as i128,
+        i64::MAX as i128,
+        1 << 64,
+        -(1 << 64),
+    ] {
+        for delta in -2..=2 {
+            values.push(edge + delta);
+        }
+    }
+
+    macro_rules! check {
+        ($($Dest:ty),*) => {$(
+            for &v in &values {
+                let x: Option<$Dest> = v.try_truncate();
+                assert_eq!(x, <$Dest>::try_from(v).ok(), "{}i128", v);
+                let u = v as u128;
+                let x: Option<$Dest> = u.try_truncate();
+                assert_eq!(x, <$Dest>::try_from(u).ok(), "{}u128", u);
+            }
+        )*};
+    }
+    check!(u8, i8, u16, i16, u32, i32, u64, i64);
+}
+
+#[test]
+fn test_isize_source() {
+    let x: Option<i8> = (-128isize).try_truncate();
+    assert_eq!(x, Some(-128));
+    let x: Option<i8> = (-129isize).try_truncate();
+    assert_eq!(x, None);
+    let x: Option<u32> = (-1isize).try_truncate();
+    assert_eq!(x, None);
+    let x: u16 = (-1isize).shrink();
+    assert_eq!(x, 0);
+    let x: u16 = isize::MAX.shrink();
+    assert_eq!(x, u16::MAX);
+    let x: i16 = isize::MIN.shrink();
+    assert_eq!(x, i16::MIN);
+    let x: u8 = 200isize.chop();
+    assert_eq!(x, 200);
+}
+
+#[test]
+fn test_pointer_sized_dest() {
+    let x: Option<usize> = 5u64.try_truncate();
+    assert_eq!(x, Some(5));
+    let x: Option<usize> = (-1i64).try_truncate();
+    assert_eq!(x, None);
+    let x: usize = u128::MAX.shrink();
+    assert_eq!(x, usize::MAX);
+    let x: usize = i128::MIN.shrink();
+    assert_eq!(x, 0);
+    let x: isize = u64::MAX.shrink();
+    assert_eq!(x, isize::MAX);
+    let x: isize = i128::MIN.shrink();
+    assert_eq!(x, isize::MIN);
+    let x: Option<isize> = (-7i128).try_truncate();
+    assert_eq!(x, Some(-7));
+    let x: usize = 10i8.chop();
+    assert_eq!(x, 10);
+}
+
+fn identity<T>(x: T) -> (Option<T>, T, T, T)
+where
+    T: TryTruncate<T> + Shrink<T> + Chop<T> + TruncateUnchecked<T> + Copy,
+{
+    (
+        x.try_truncate(),
+        x.shrink(),
+        x.chop(),
+        x.truncate_unchecked(),
+    )
+}
+
+#[test]
+fn test_identity() {
+    assert_eq!(
+        identity(u8::MAX),
+        (Some(u8::MAX), u8::MAX, u8::MAX, u8::MAX)
+    );
+    assert_eq!(identity(-1i32), (Some(-1), -1, -1, -1));
+    assert_eq!(identity(usize::MAX).0, Some(usize::MAX));
+    assert_eq!(identity(isize::MIN).1, isize::MIN);
+    assert_eq!(
+        identity(u128::MAX),
+        (Some(u128::MAX), u128::MAX, u128::MAX, u128::MAX)
+    );
+    assert_eq!(
+        identity(i128::MIN),
+        (Some(i128::MIN), i128::MIN, i128::MIN, i128::MIN)
+    );
+    assert_eq!(identity(i128::MAX).0, Some(i128::MAX));
+    assert_eq!(identity(i64::MIN).2, i64::MIN);
+}
+
+fn try_from_any<D: TryTruncateFrom<S>, S>(x: S) -> Option<D> {
+    D::try_truncate_from(x)
+}
+
+#[test]
+fn test_widening() {
+    assert_eq!(try_from_any::<u64, u8>(255), Some(255));
+    assert_eq!(try_from_any::<i128, i8>(-128), Some(-128));
+    assert_eq!(try_from_any::<i32, u16>(65535), Some(65535));
+    assert_eq!(try_from_any::<isize, i16>(-1), Some(-1));
+    assert_eq!(try_from_any::<u8, u64>(256), None);
+
+    let x: u128 = u64::MAX.shrink();
+    assert_eq!(x, u64::MAX as u128);
+    let x: i64 = i8::MIN.chop();
+    assert_eq!(x, -128);
+    let x: usize = u16::MAX.truncate_unchecked();
+    assert_eq!(x, 65535);
+}
+
+#[test]
+fn test_same_width_cross_sign() {
+    let x: Option<i8> = 128u8.try_truncate();
+    assert_eq!(x, None);
+    let x: i8 = 200u8.shrink();
+    assert_eq!(x, i8::MAX);
+    let x: i8 = 200u8.truncate_unchecked();
+    assert_eq!(x, -56);
+    let x: Option<u64> = (-1i64).try_truncate();
+    assert_eq!(x, None);
+    let x: u64 = i64::MAX.chop();
+    assert_eq!(x, i64::MAX as u64);
+    let x: u16 = i16::MIN.shrink();
+    assert_eq!(x, 0);
+    let x: u32 = i32::MAX.shrink();
+    assert_eq!(x, i32::MAX as u32);
+
+    let x: Option<i128> = u128::MAX.try_truncate();
+    assert_eq!(x, None);
+    let x: Option<i128> = (i128::MAX as u128).try_truncate();
+    assert_eq!(x, Some(i128::MAX));
+    let x: i128 = u128::MAX.shrink();
+    assert_eq!(x, i128::MAX);
+    let x: Option<u128> = i128::MAX.try_truncate();
+    assert_eq!(x, Some(i128::MAX as u128));
+    let x: u128 = i128::MIN.shrink();
+    assert_eq!(x, 0);
+    let x: Option<u128> = (-1i128).try_truncate();
+    assert_eq!(x, None);
+}
+
+#[test]
+fn test_bit_cast() {
+    let x: i8 = 0x80u8.bit_cast();
+    assert_eq!(x, i8::MIN);
+    let x: u16 = (-2i16).bit_cast();
+    assert_eq!(x, 0xFFFE);
+    let x: i32 = u32::MAX.bit_cast();
+    assert_eq!(x, -1);
+    let x: u128 = i128::MIN.bit_cast();
+    assert_eq!(x, 1 << 127);
+    let x: isize = usize::MAX.bit_cast();
+    assert_eq!(x, -1);
+    assert_eq!(u64::bit_cast_from(-1i64), u64::MAX);
+    assert_eq!(i8::bit_cast_from(0x7Fu8), 0x7F);
+}
+
+#[test]
+fn test_truncate_unchecked_signed() {
+    let x: u8 = (-1i64).truncate_unchecked();
+    assert_eq!(x, 255);
+    let x: u8 = (-256i64).truncate_unchecked();
+    assert_eq!(x, 0);
+    let x: i8 = (-129i32).truncate_unchecked();
+    assert_eq!(x, 127);
+    let x: i8 = 128i32.truncate_unchecked();
+    assert_eq!(x, -128);
+    let x: i16 = (-70000i128).truncate_unchecked();
+    assert_eq!(x, -70000i128 as i16);
+    let x: u32 = i32::MIN.truncate_unchecked();
+    assert_eq!(x, 0x8000_0000);
+    let x: u128 = (-1i8).truncate_unchecked();
+    assert_eq!(x, u128::MAX);
+    let x: i64 = (-5i16).truncate_unchecked();
+    assert_eq!(x, -5);
+    let x: usize = (-1isize).truncate_unchecked();
+    assert_eq!(x, usize::MAX);
+    assert_eq!(cast!(-2i32 => u16, wrap), 0xFFFE);
+}
@@ -0,0 +1,53 @@
+use truncate_integer::transport::{FrameLength, StreamId, VarInt};
+use truncate_integer::{Chop, Shrink, TryTruncate};
+
+#[test]
+fn frame_length() {
+    assert_eq!(FrameLength::MAX.raw(), 0xFF_FFFF);
+    assert_eq!(FrameLength::new(0xFF_FFFF), Some(FrameLength::MAX));
+    assert_eq!(FrameLength::new(0x100_0000), None);
+    let len: FrameLength = (-5i32).shrink();
+    assert_eq!(len, FrameLength::MIN);
+    let len: FrameLength = u64::MAX.shrink();
+    assert_eq!(u32::from(len), 0xFF_FFFF);
+    assert_eq!(len.to_string(), "16777215");
+}
+
+#[test]
+fn stream_id() {
+    let id: Option<StreamId> = 0x7FFF_FFFFu32.try_truncate();
+    assert_eq!(id, Some(StreamId::MAX));
+    let id: Option<StreamId> = 0x8000_0000u32.try_truncate();
+    assert_eq!(id, None);
+
+    let id = StreamId::from_wire(0xFFFF_FFFF);
+    assert_eq!(id, StreamId::MAX);
+    assert_eq!(id.to_wire(), 0x7FFF_FFFF);
+    assert!(id.is_client_initiated());
+    assert!(!StreamId::from_wire(2).is_client_initiated());
+}
+
+#[test]
+#[should_panic(expected = "chop overflow")]
+fn stream_id_chop() {
+    let _: StreamId = u32::MAX.chop();
+}
+
+#[test]
+fn varint() {
+    assert_eq!(VarInt::MAX.raw(), (1 << 62) - 1);
+    assert_eq!(VarInt::new(1 << 62), None);
+    let n: Option<VarInt> = (-1i64).try_truncate();
+    assert_eq!(n, None);
+
+    let len = |raw: u64| VarInt::new(raw).unwrap().encoded_len();
+    assert_eq!(len(0), 1);
+    assert_eq!(len(63), 1);
+    assert_eq!(len(64), 2);
+    assert_eq!(len(16_383), 2);
+    assert_eq!(len(16_384), 4);
+    assert_eq!(len(1_073_741_823), 4);
+    assert_eq!(len(1_073_741_824), 8);
+    assert_eq!(VarInt::MAX.encoded_len(), 8);
+    assert_eq!(u128::from(VarInt::MAX), (1 << 62) - 1);
+}
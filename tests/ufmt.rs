@@ -0,0 +1,81 @@
+#![cfg(feature = "ufmt")]
+
+use core::convert::Infallible;
+use std::fmt;
+use truncate_integer::align::AlignError;
+use truncate_integer::compact::CompactError;
+use truncate_integer::dma::DmaError;
+use truncate_integer::imm::ImmError;
+use truncate_integer::leb128::LebError;
+use truncate_integer::len::LenError;
+use truncate_integer::y2038::Time32Error;
+use truncate_integer::{NarrowStructError, TruncateError};
+use ufmt::{uDebug, uDisplay, uWrite, uwrite};
+
+struct Out(String);
+
+impl uWrite for Out {
+    type Error = Infallible;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+fn udisplay<T: uDisplay>(value: &T) -> String {
+    let mut out = Out(String::new());
+    uwrite!(out, "{}", value).unwrap();
+    out.0
+}
+
+fn udebug<T: uDebug>(value: &T) -> String {
+    let mut out = Out(String::new());
+    uwrite!(out, "{:?}", value).unwrap();
+    out.0
+}
+
+fn check<T: uDisplay + uDebug + fmt::Display + fmt::Debug>(value: T) {
+    assert_eq!(udisplay(&value), value.to_string());
+    assert_eq!(udebug(&value), format!("{:?}", value));
+}
+
+#[test]
+fn matches_core_fmt() {
+    check(TruncateError::Overflow);
+    check(TruncateError::Underflow);
+    check(TruncateError::Invalid);
+    check(AlignError::NotPowerOfTwo);
+    check(CompactError::InvalidHeader(0x0F));
+    check(CompactError::Truncate(TruncateError::Underflow));
+    check(LebError::UnexpectedEnd);
+    check(LebError::Truncate(TruncateError::Overflow));
+    check(LenError::new(300, 255));
+    check(ImmError::Misaligned {
+        value: -6,
+        shift: 2,
+    });
+    check(ImmError::OutOfRange {
+        value: i128::MIN,
+        bits: 12,
+    });
+    check(DmaError::Misaligned {
+        addr: 0x1003,
+        align: 16,
+    });
+    check(DmaError::OutOfRange {
+        addr: 0xFFFF_F000,
+        len: 0x2000,
+        max_address: 0xFFFF_FFFF,
+    });
+    check(DmaError::LengthField { len: 0x1_0000 });
+    check(Time32Error::BeyondY2038);
+    check(Time32Error::BeforeEpoch);
+}
+
+#[test]
+fn narrow_struct_error() {
+    let err = NarrowStructError::new(&["a", "b", "c"], 0b101);
+    assert_eq!(udisplay(&err), "fields out of range: a, c");
+    assert_eq!(udebug(&err), r#"NarrowStructError { fields: ["a", "c"] }"#);
+}
@@ -0,0 +1,83 @@
+use truncate_integer::varwidth::{Endian, VarWidth};
+use truncate_integer::TruncateError;
+
+#[test]
+fn test_varwidth_new() {
+    assert!(VarWidth::new(0, Endian::Big).is_none());
+    assert!(VarWidth::new(17, Endian::Big).is_none());
+    let width = VarWidth::new(16, Endian::Little).unwrap();
+    assert_eq!(width.width(), 16);
+    assert_eq!(width.endian(), Endian::Little);
+}
+
+#[test]
+fn test_varwidth_read() {
+    let bytes = [0x81, 0x02, 0x03, 0xFF];
+    let be = VarWidth::new(3, Endian::Big).unwrap();
+    let le = VarWidth::new(3, Endian::Little).unwrap();
+    assert_eq!(be.read_unsigned(&bytes), 0x810203);
+    assert_eq!(le.read_unsigned(&bytes), 0x030281);
+    assert_eq!(be.read_signed(&bytes), 0x810203 - 0x1000000);
+    assert_eq!(le.read_signed(&bytes), 0x030281);
+
+    let full = VarWidth::new(16, Endian::Big).unwrap();
+    assert_eq!(full.read_unsigned(&[0xFF; 16]), u128::MAX);
+    assert_eq!(full.read_signed(&[0xFF; 16]), -1);
+}
+
+#[test]
+#[should_panic]
+fn test_varwidth_short() {
+    let width = VarWidth::new(5, Endian::Big).unwrap();
+    width.read_unsigned(&[0; 4]);
+}
+
+#[test]
+fn test_varwidth_write_unsigned() {
+    let width = VarWidth::new(2, Endian::Big).unwrap();
+    let mut buf = [0u8; 3];
+    width.try_write_unsigned(&mut buf, 0x1234u16).unwrap();
+    assert_eq!(buf, [0x12, 0x34, 0x00]);
+    assert_eq!(
+        width.try_write_unsigned(&mut buf, 0x10000u32),
+        Err(TruncateError::Overflow)
+    );
+    assert_eq!(buf, [0x12, 0x34, 0x00]);
+    width.shrink_write_unsigned(&mut buf, 0x10000u32);
+    assert_eq!(buf, [0xFF, 0xFF, 0x00]);
+    width.write_unsigned_unchecked(&mut buf, 0x10203u32);
+    assert_eq!(buf, [0x02, 0x03, 0x00]);
+
+    let full = VarWidth::new(16, Endian::Little).unwrap();
+    let mut buf = [0u8; 16];
+    full.try_write_unsigned(&mut buf, u128::MAX).unwrap();
+    assert_eq!(buf, [0xFF; 16]);
+}
+
+#[test]
+fn test_varwidth_write_signed() {
+    let width = VarWidth::new(1, Endian::Little).unwrap();
+    let mut buf = [0u8; 1];
+    width.try_write_signed(&mut buf, -128i16).unwrap();
+    assert_eq!(buf, [0x80]);
+    assert_eq!(
+        width.try_write_signed(&mut buf, 128i16),
+        Err(TruncateError::Overflow)
+    );
+    assert_eq!(
+        width.try_write_signed(&mut buf, -129i16),
+        Err(TruncateError::Underflow)
+    );
+    width.shrink_write_signed(&mut buf, -1000i32);
+    assert_eq!(buf, [0x80]);
+    width.shrink_write_signed(&mut buf, 1000i32);
+    assert_eq!(buf, [0x7F]);
+    width.write_signed_unchecked(&mut buf, -1i64);
+    assert_eq!(buf, [0xFF]);
+
+    let width = VarWidth::new(3, Endian::Big).unwrap();
+    let mut buf = [0u8; 3];
+    width.try_write_signed(&mut buf, -2i32).unwrap();
+    assert_eq!(buf, [0xFF, 0xFF, 0xFE]);
+    assert_eq!(width.read_signed(&buf), -2);
+}
@@ -0,0 +1,46 @@
+use truncate_integer::wasm::*;
+
+#[test]
+fn test_memory32() {
+    assert_eq!(memory32_address(0), Ok(0));
+    let err = memory32_address(1 << 32).unwrap_err();
+    assert_eq!(err.address(), 1 << 32);
+    assert!(err.is_empty());
+    assert_eq!(
+        err.to_string(),
+        "address 0x100000000 is out of the addressable range"
+    );
+    assert_eq!(memory32_address_saturating(1 << 40), u32::MAX);
+    assert_eq!(memory32_address_saturating(5), 5);
+
+    assert_eq!(memory32_range(0, MEMORY32_SIZE), Ok(0..MEMORY32_SIZE));
+    assert_eq!(
+        memory32_range(MEMORY32_SIZE, 0),
+        Ok(MEMORY32_SIZE..MEMORY32_SIZE)
+    );
+    let err = memory32_range(u64::MAX, 2).unwrap_err();
+    assert_eq!(err.len(), 2);
+    assert_eq!(
+        err.to_string(),
+        "address range 0xffffffffffffffff+0x2 is out of the addressable range"
+    );
+}
+
+#[test]
+fn test_host() {
+    assert_eq!(host_address(1234), Ok(1234));
+    assert_eq!(host_range(16, 16), Ok(16..32));
+    assert!(host_range(u64::MAX, 1).is_err());
+
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert_eq!(host_address(u64::MAX), Ok(usize::MAX));
+        assert_eq!(host_range(0, u64::MAX), Ok(0..usize::MAX));
+    }
+    #[cfg(target_pointer_width = "32")]
+    {
+        assert!(host_address(1 << 32).is_err());
+        assert_eq!(host_address_saturating(1 << 32), usize::MAX);
+        assert!(host_range(0xFFFF_FFFF, 1).is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use half::f16;
+use truncate_integer::wgsl::*;
+
+#[test]
+fn test_pack_norm() {
+    assert_eq!(pack4x8snorm([1.0, -1.0, 0.0, -2.0]), 0x8100_817F);
+    assert_eq!(pack4x8snorm([0.5, f32::NAN, 0.0, 0.0]), 0x0000_0040);
+    assert_eq!(pack4x8unorm([0.0, 1.0, 0.5, -1.0]), 0x0080_FF00);
+    assert_eq!(pack2x16snorm([-1.0, 1.0]), 0x7FFF_8001);
+    assert_eq!(pack2x16unorm([1.0, 0.25]), 0x4000_FFFF);
+}
+
+#[test]
+fn test_unpack_norm() {
+    assert_eq!(unpack4x8snorm(0x8180_7F00), [0.0, 1.0, -1.0, -1.0]);
+    assert_eq!(unpack4x8unorm(0xFF00_FF00), [0.0, 1.0, 0.0, 1.0]);
+    assert_eq!(unpack2x16snorm(0x8000_7FFF), [1.0, -1.0]);
+    assert_eq!(unpack2x16unorm(0xFFFF_0000), [0.0, 1.0]);
+    for x in 0..=255u32 {
+        let packed = x * 0x0101_0101;
+        assert_eq!(pack4x8unorm(unpack4x8unorm(packed)), packed);
+    }
+}
+
+#[test]
+fn test_pack_float() {
+    assert_eq!(pack2x16float([0.0, -0.0]), 0x8000_0000);
+    assert_eq!(pack2x16float([65504.0, 1e10]), 0x7C00_7BFF);
+    assert_eq!(
+        pack2x16float([f32::NEG_INFINITY, 5.960_464_5e-8]),
+        0x0001_FC00
+    );
+    let [nan, _] = unpack2x16float(pack2x16float([f32::NAN, 0.0]));
+    assert!(nan.is_nan());
+}
+
+#[test]
+fn test_f16_matches_half() {
+    // Every f16 survives a round trip through f32.
+    for bits in 0..=u16::MAX {
+        let value = f16::from_bits(bits);
+        let [unpacked, _] = unpack2x16float(u32::from(bits));
+        if value.is_nan() {
+            assert!(unpacked.is_nan());
+            continue;
+        }
+        assert_eq!(
+            unpacked.to_bits(),
+            value.to_f32().to_bits(),
+            "{:#06x}",
+            bits
+        );
+        assert_eq!(pack2x16float([unpacked, 0.0]), u32::from(bits));
+    }
+
+    // Rounding agrees with `half` on a spread of f32s, including the
+    // ties between adjacent f16s.
+    let mut x = 0x1234_5678u32;
+    for _ in 0..200_000 {
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        for &bits in &[x, x & 0xFFFF_E000 | 0x1000, x & 0x8FFF_FFFF] {
+            let value = f32::from_bits(bits);
+            if value.is_nan() {
+                continue;
+            }
+            let expected = f16::from_f32(value).to_bits();
+            assert_eq!(
+                pack2x16float([value, 0.0]),
+                u32::from(expected),
+                "{:e}",
+                value
+            );
+        }
+    }
+}
+
+#[test]
+fn test_pack_int() {
+    assert_eq!(pack4x_i8([-1, 1, 256, -129]), 0x7F00_01FF);
+    assert_eq!(pack4x_u8([1, 2, 0x1FF, 3]), 0x03FF_0201);
+    assert_eq!(pack4x_i8_clamp([-1000, 127, 128, -128]), 0x807F_7F80);
+    assert_eq!(pack4x_u8_clamp([0, u32::MAX, 256, 7]), 0x07FF_FF00);
+    assert_eq!(unpack4x_i8(0x7F80_01FF), [-1, 1, -128, 127]);
+    assert_eq!(unpack4x_u8(0x7F80_01FF), [255, 1, 128, 127]);
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "windows")]
+
+use truncate_integer::windows::*;
+use truncate_integer::{Chop, Shrink, TruncateUnchecked, TryTruncate};
+
+#[test]
+fn test_windows_pointer_sized() {
+    let x: Option<DWORD> = (-1 as LONG_PTR).try_truncate();
+    assert_eq!(x, None);
+    let x: WORD = (70000 as ULONG_PTR).shrink();
+    assert_eq!(x, u16::MAX);
+    let x: INT = (SIZE_T::MAX).shrink();
+    assert_eq!(x, i32::MAX);
+    let x: SSIZE_T = (SIZE_T::MAX).shrink();
+    assert_eq!(x, isize::MAX);
+    let x: SIZE_T = (-5 as SSIZE_T).shrink();
+    assert_eq!(x, 0);
+    let x: SIZE_T = (4096 as LONGLONG).chop();
+    assert_eq!(x, 4096);
+    let x: SHORT = (0x12345 as SIZE_T).truncate_unchecked();
+    assert_eq!(x, 0x2345);
+    let x: Option<SIZE_T> = (u128::MAX).try_truncate();
+    assert_eq!(x, None);
+}
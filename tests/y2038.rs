@@ -0,0 +1,30 @@
+use truncate_integer::y2038::{
+    time32_shrink, time32_try, time32_unsigned_shrink, time32_unsigned_try, Time32Error,
+};
+
+#[test]
+fn signed() {
+    assert_eq!(time32_try(0), Ok(0));
+    assert_eq!(time32_try(-2_147_483_648), Ok(i32::MIN));
+    assert_eq!(time32_try(-2_147_483_649), Err(Time32Error::BeforeY1901));
+    assert_eq!(time32_try(i64::MAX), Err(Time32Error::BeyondY2038));
+    assert_eq!(time32_shrink(i64::MIN), i32::MIN);
+    assert_eq!(time32_shrink(1_700_000_000), 1_700_000_000);
+    assert_eq!(
+        Time32Error::BeyondY2038.to_string(),
+        "time is after 2038-01-19T03:14:07Z"
+    );
+}
+
+#[test]
+fn unsigned() {
+    assert_eq!(time32_unsigned_try(2_147_483_648), Ok(1 << 31));
+    assert_eq!(time32_unsigned_try(4_294_967_295), Ok(u32::MAX));
+    assert_eq!(
+        time32_unsigned_try(4_294_967_296),
+        Err(Time32Error::BeyondY2106)
+    );
+    assert_eq!(time32_unsigned_try(-1), Err(Time32Error::BeforeEpoch));
+    assert_eq!(time32_unsigned_shrink(-1), 0);
+    assert_eq!(time32_unsigned_shrink(i64::MAX), u32::MAX);
+}